@@ -11,6 +11,24 @@ use ink::env::Environment;
 use propchain_traits::*;
 use ml_pipeline::*;
 
+/// Locale used when no template is registered for the requested one
+pub const DEFAULT_EXPLANATION_LOCALE: &str = "en";
+
+/// Built-in English template; placeholders are substituted from stored features
+pub const DEFAULT_EXPLANATION_TEMPLATE: &str = "Valuation based on {model} model: Location score: {location}, Size: {size}sqm, Age: {age} years, Condition: {condition}/100, Market trend: {trend}";
+
+/// Share of a worker's bond slashed for citing a superseded artifact (basis points)
+pub const STALE_ARTIFACT_SLASH_BPS: u128 = 1_000;
+
+/// Depth of the per-property Merkle tree archived predictions are committed to
+pub const PREDICTION_ARCHIVE_DEPTH: usize = 32;
+
+/// Maximum number of federated external valuation providers
+pub const MAX_EXTERNAL_PROVIDERS: usize = 32;
+
+/// Maximum size of the model-governance council
+pub const MAX_COUNCIL_MEMBERS: usize = 16;
+
 /// AI-powered property valuation engine
 #[ink::contract]
 mod ai_valuation {
@@ -41,6 +59,36 @@ mod ai_valuation {
         pub economic_indicators: u32, // 0-100 economic health score
     }
 
+    /// Input feature of the valuation models
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum FeatureId {
+        LocationScore,
+        SizeSqm,
+        AgeYears,
+        ConditionScore,
+        AmenitiesScore,
+        MarketTrend,
+        ComparableAvg,
+        EconomicIndicators,
+    }
+
+    /// Direction the valuation must move as a feature grows
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum Monotonicity {
+        NonDecreasing, // Coefficient must be >= 0
+        NonIncreasing, // Coefficient must be <= 0
+    }
+
+    /// Sign restriction on a linear model's coefficient for one feature
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct MonotonicityConstraint {
+        pub feature: FeatureId,
+        pub direction: Monotonicity,
+    }
+
     /// AI model metadata and versioning
     #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
@@ -53,10 +101,45 @@ mod ai_valuation {
         pub last_updated: u64,       // Timestamp
         pub is_active: bool,
         pub weight: u32,             // 0-100 weight in ensemble
+        pub coefficients: Vec<(FeatureId, i64)>, // LinearRegression only; absent features weigh 0
+        pub constraints: Vec<MonotonicityConstraint>, // LinearRegression only
+    }
+
+    /// Why a model was taken out of service
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum DeactivationReason {
+        AccuracyDegraded,
+        BiasDetected,
+        DataDrift,
+        SecurityIncident,
+        Superseded,
+        Other,
+    }
+
+    /// Lifecycle change a council member can vote for
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum LifecycleAction {
+        Deactivate(DeactivationReason),
+        Reactivate,
+    }
+
+    /// (model_id, action) a set of council votes is tallied under
+    pub type LifecycleVoteKey = (String, LifecycleAction);
+
+    /// Record of a model's deactivation, kept until it is reactivated
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ModelDeactivation {
+        pub reason: DeactivationReason,
+        pub deactivated_at: u64,
+        pub by_council: bool,        // Council vote rather than the admin
+        pub approvers: Vec<AccountId>,
     }
     /// AI valuation prediction with confidence metrics
     #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
-    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct AIPrediction {
         pub predicted_value: u128,
         pub confidence_score: u32,    // 0-100
@@ -101,6 +184,82 @@ mod ai_valuation {
         pub prediction_count: u64,
         pub last_evaluated: u64,
     }
+    /// Off-chain inference result submitted by a bonded worker
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct InferenceSubmission {
+        pub submission_id: u64,
+        pub worker: AccountId,
+        pub property_id: u64,
+        pub model_id: String,
+        pub model_version: u32,      // Version whose artifact was cited
+        pub artifact_hash: [u8; 32],
+        pub predicted_value: u128,
+        pub submitted_at: u64,
+        pub stale: bool,             // Cited artifact was superseded when submitted
+    }
+
+    /// External valuation provider federated into the ensemble
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ExternalProvider {
+        pub provider: AccountId,
+        pub model_id: String,        // Model entry carrying the provider's ensemble weight
+        pub registered_at: u64,
+        pub submission_count: u64,
+        pub is_active: bool,
+    }
+
+    /// Prediction rate limits applied to every non-exempt consumer (0 disables a limit)
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct RateLimitConfig {
+        pub min_interval: u64,       // Milliseconds between prediction calls
+        pub daily_quota: u32,        // Prediction calls per UTC day
+    }
+
+    /// Incremental Merkle tree over a property's archived predictions
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PredictionArchive {
+        pub root: [u8; 32],
+        pub leaf_count: u64,
+        pub frontier: Vec<[u8; 32]>, // Left siblings on the path of the next leaf
+    }
+
+    /// Prediction usage counters for a consumer account
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ConsumerUsage {
+        pub day: u64,                // Day index the daily counter refers to
+        pub calls_today: u32,
+        pub total_calls: u64,
+        pub last_call_at: u64,
+    }
+
+    /// Differential privacy settings for public aggregate queries
+    #[derive(Debug, Clone, Default, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PrivacyConfig {
+        pub enabled: bool,
+        pub epsilon_milli: u32,      // Privacy budget per query, in thousandths
+        pub value_bound: u128,       // Per-record clamp bounding the sum's sensitivity
+    }
+
+    /// Aggregate over recorded sale values, with the noise parameters applied to it
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ValueStatistics {
+        pub count: u64,
+        pub sum_value: u128,
+        pub mean_value: u128,
+        pub noised: bool,
+        pub epsilon_milli: u32,      // Budget spent by this response (0 when exact)
+        pub count_scale_milli: u128, // Laplace scale of the count noise, in thousandths
+        pub sum_scale_milli: u128,   // Laplace scale of the sum noise, in thousandths
+        pub value_bound: u128,       // Clamp applied to each value before noising
+    }
+
     /// AI Valuation Engine Contract
     #[ink(storage)]
     pub struct AIValuationEngine {
@@ -114,6 +273,10 @@ mod ai_valuation {
         property_features: Mapping<u64, PropertyFeatures>,
         /// Historical predictions for validation
         predictions: Mapping<u64, Vec<AIPrediction>>,
+        /// Predictions kept in storage per property before older ones are archived (0 = unbounded)
+        prediction_retention: u32,
+        /// Merkle commitments to pruned predictions
+        prediction_archives: Mapping<u64, PredictionArchive>,
         /// Training data storage
         training_data: Vec<TrainingDataPoint>,
         /// ML pipelines for model training
@@ -138,6 +301,50 @@ mod ai_valuation {
         bias_threshold: u32,
         /// Contract pause state
         paused: bool,
+        /// Explanation templates keyed by locale
+        explanation_templates: Mapping<String, String>,
+        /// Locales with a registered template
+        template_locales: Vec<String>,
+        /// Bonds posted by inference workers
+        worker_bonds: Mapping<AccountId, Balance>,
+        /// Inference submissions for reproducibility audits
+        inference_submissions: Mapping<u64, InferenceSubmission>,
+        /// Number of inference submissions
+        inference_count: u64,
+        /// Total bond slashed from workers citing stale artifacts
+        slashed_bonds: Balance,
+        /// Federated external valuation providers
+        external_providers: Mapping<AccountId, ExternalProvider>,
+        /// Registration order of external providers
+        provider_list: Vec<AccountId>,
+        /// Latest accepted prediction per (property, provider)
+        federated_predictions: Mapping<(u64, AccountId), AIPrediction>,
+        /// Prediction rate limits
+        rate_limit: RateLimitConfig,
+        /// Prediction usage per consumer
+        consumer_usage: Mapping<AccountId, ConsumerUsage>,
+        /// Registered contracts exempt from rate limits
+        rate_limit_exempt: Mapping<AccountId, bool>,
+        /// Analytics contract notified of low-consensus valuations
+        analytics_contract: Option<AccountId>,
+        /// Consensus score below which a disagreement alert is raised (basis points)
+        disagreement_threshold: u32,
+        /// Noise applied to public aggregate statistics
+        privacy_config: PrivacyConfig,
+        /// Admin-supplied secret mixed into aggregate noise; never returned by a message
+        noise_seed: Option<Hash>,
+        /// Period (ms) over which each band's noise stays fixed
+        noise_epoch: u64,
+        /// Accounts licensed to read exact aggregates
+        licensed_readers: Mapping<AccountId, bool>,
+        /// Model-governance council members
+        council: Vec<AccountId>,
+        /// Council votes needed to change a model's lifecycle
+        council_threshold: u32,
+        /// Pending council votes per model and action
+        lifecycle_votes: Mapping<LifecycleVoteKey, Vec<AccountId>>,
+        /// Reason each inactive model was deactivated
+        deactivations: Mapping<String, ModelDeactivation>,
     }
 
     /// Events emitted by the AI Valuation Engine
@@ -174,6 +381,73 @@ mod ai_valuation {
         affected_properties: Vec<u64>,
     }
 
+    #[ink(event)]
+    pub struct ArtifactCommitted {
+        #[ink(topic)]
+        model_id: String,
+        version: u32,
+        artifact_hash: [u8; 32],
+        training_snapshot_hash: [u8; 32],
+    }
+
+    #[ink(event)]
+    pub struct InferenceSubmitted {
+        #[ink(topic)]
+        submission_id: u64,
+        #[ink(topic)]
+        worker: AccountId,
+        model_id: String,
+        artifact_hash: [u8; 32],
+        stale: bool,
+    }
+
+    #[ink(event)]
+    pub struct WorkerSlashed {
+        #[ink(topic)]
+        worker: AccountId,
+        submission_id: u64,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct ExternalProviderRegistered {
+        #[ink(topic)]
+        provider: AccountId,
+        model_id: String,
+        weight: u32,
+    }
+
+    #[ink(event)]
+    pub struct ExternalPredictionSubmitted {
+        #[ink(topic)]
+        provider: AccountId,
+        #[ink(topic)]
+        property_id: u64,
+        submission_id: u64,
+        predicted_value: u128,
+        stale: bool,
+    }
+
+    #[ink(event)]
+    pub struct DisagreementAlert {
+        #[ink(topic)]
+        property_id: u64,
+        consensus_score: u32,
+        threshold: u32,
+        final_valuation: u128,
+        model_count: u32,
+        analytics_notified: bool,
+    }
+
+    #[ink(event)]
+    pub struct PredictionsArchived {
+        #[ink(topic)]
+        property_id: u64,
+        archived: u32,
+        leaf_count: u64,
+        root: [u8; 32],
+    }
+
     #[ink(event)]
     pub struct TrainingDataAdded {
         #[ink(topic)]
@@ -181,6 +455,39 @@ mod ai_valuation {
         data_points_count: u64,
     }
 
+    #[ink(event)]
+    pub struct ModelDeactivated {
+        #[ink(topic)]
+        model_id: String,
+        reason: DeactivationReason,
+        by_council: bool,
+    }
+
+    #[ink(event)]
+    pub struct ModelReactivated {
+        #[ink(topic)]
+        model_id: String,
+        by_council: bool,
+    }
+
+    #[ink(event)]
+    pub struct LifecycleVoteCast {
+        #[ink(topic)]
+        model_id: String,
+        #[ink(topic)]
+        voter: AccountId,
+        action: LifecycleAction,
+        votes: u32,
+        threshold: u32,
+    }
+
+    #[ink(event)]
+    pub struct PrivacyConfigUpdated {
+        enabled: bool,
+        epsilon_milli: u32,
+        value_bound: u128,
+    }
+
     /// AI Valuation Engine errors
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -211,6 +518,20 @@ mod ai_valuation {
         PredictionFailed,
         /// Invalid parameters
         InvalidParameters,
+        /// Artifact hash not committed for the model, or already committed
+        InvalidArtifact,
+        /// Caller has not posted an inference bond
+        WorkerNotBonded,
+        /// Caller exceeded its prediction rate limit or daily quota
+        RateLimited,
+        /// Caller is not an active external valuation provider
+        ProviderNotRegistered,
+        /// Provider already registered or provider limit reached
+        ProviderRegistrationFailed,
+        /// A linear model's coefficient contradicts a declared monotonicity constraint
+        MonotonicityViolation,
+        /// Council member already voted for this lifecycle change
+        AlreadyVoted,
     }
 
     impl AIValuationEngine {
@@ -223,6 +544,8 @@ mod ai_valuation {
                 performance: Mapping::default(),
                 property_features: Mapping::default(),
                 predictions: Mapping::default(),
+                prediction_retention: 0,
+                prediction_archives: Mapping::default(),
                 training_data: Vec::new(),
                 ml_pipelines: Mapping::default(),
                 model_versions: Mapping::default(),
@@ -235,6 +558,28 @@ mod ai_valuation {
                 feature_cache_ttl: 3600, // 1 hour
                 bias_threshold: 2000,  // 20% bias threshold
                 paused: false,
+                explanation_templates: Mapping::default(),
+                template_locales: Vec::new(),
+                worker_bonds: Mapping::default(),
+                inference_submissions: Mapping::default(),
+                inference_count: 0,
+                slashed_bonds: 0,
+                external_providers: Mapping::default(),
+                provider_list: Vec::new(),
+                federated_predictions: Mapping::default(),
+                rate_limit: RateLimitConfig { min_interval: 0, daily_quota: 0 },
+                consumer_usage: Mapping::default(),
+                rate_limit_exempt: Mapping::default(),
+                analytics_contract: None,
+                disagreement_threshold: 6000, // 60% consensus
+                privacy_config: PrivacyConfig::default(),
+                noise_seed: None,
+                noise_epoch: 86_400_000, // one day
+                licensed_readers: Mapping::default(),
+                council: Vec::new(),
+                council_threshold: 0,
+                lifecycle_votes: Mapping::default(),
+                deactivations: Mapping::default(),
             }
         }
         /// Set oracle contract address
@@ -262,6 +607,7 @@ mod ai_valuation {
             if model.model_id.is_empty() || model.accuracy_score > 10000 {
                 return Err(AIValuationError::InvalidModel);
             }
+            Self::validate_constraints(&model)?;
 
             self.models.insert(&model.model_id, &model);
             
@@ -281,6 +627,11 @@ mod ai_valuation {
             self.ensure_not_paused()?;
 
             let old_model = self.models.get(&model_id).ok_or(AIValuationError::ModelNotFound)?;
+            // Declared constraints may be added to but never dropped
+            if old_model.constraints.iter().any(|c| !new_model.constraints.contains(c)) {
+                return Err(AIValuationError::InvalidModel);
+            }
+            Self::validate_constraints(&new_model)?;
             
             // Calculate accuracy improvement
             let accuracy_improvement = new_model.accuracy_score as i32 - old_model.accuracy_score as i32;
@@ -296,6 +647,77 @@ mod ai_valuation {
 
             Ok(())
         }
+
+        /// Set the model-governance council and the votes it needs to change a model's lifecycle
+        #[ink(message)]
+        pub fn set_governance_council(&mut self, members: Vec<AccountId>, threshold: u32) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            let duplicate = members.iter().enumerate().any(|(i, m)| members[..i].contains(m));
+            if duplicate || members.len() > MAX_COUNCIL_MEMBERS || threshold as usize > members.len() || (threshold == 0 && !members.is_empty()) {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            self.council = members;
+            self.council_threshold = threshold;
+            Ok(())
+        }
+
+        /// Get the council members and vote threshold
+        #[ink(message)]
+        pub fn get_governance_council(&self) -> (Vec<AccountId>, u32) {
+            (self.council.clone(), self.council_threshold)
+        }
+
+        /// Take a model out of service. The admin acts alone; a council member's call is a
+        /// vote, applied once the threshold is reached. Returns whether the model was deactivated.
+        #[ink(message)]
+        pub fn deactivate_model(&mut self, model_id: String, reason_code: DeactivationReason) -> Result<bool, AIValuationError> {
+            let model = self.models.get(&model_id).ok_or(AIValuationError::ModelNotFound)?;
+            if !model.is_active {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            let Some(approvers) = self.lifecycle_approval(&model_id, LifecycleAction::Deactivate(reason_code))? else {
+                return Ok(false);
+            };
+            let by_council = !approvers.is_empty();
+            self.set_model_active(&model_id, model, false);
+            self.deactivations.insert(&model_id, &ModelDeactivation {
+                reason: reason_code,
+                deactivated_at: self.env().block_timestamp(),
+                by_council,
+                approvers,
+            });
+            self.env().emit_event(ModelDeactivated { model_id, reason: reason_code, by_council });
+            Ok(true)
+        }
+
+        /// Return a deactivated model to service, under the same approval rules as deactivation.
+        /// Returns whether the model was reactivated.
+        #[ink(message)]
+        pub fn reactivate_model(&mut self, model_id: String) -> Result<bool, AIValuationError> {
+            let model = self.models.get(&model_id).ok_or(AIValuationError::ModelNotFound)?;
+            if model.is_active {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            let Some(approvers) = self.lifecycle_approval(&model_id, LifecycleAction::Reactivate)? else {
+                return Ok(false);
+            };
+            self.set_model_active(&model_id, model, true);
+            self.deactivations.remove(&model_id);
+            self.env().emit_event(ModelReactivated { model_id, by_council: !approvers.is_empty() });
+            Ok(true)
+        }
+
+        /// Why a model is out of service, if it was deactivated through the lifecycle messages
+        #[ink(message)]
+        pub fn get_model_deactivation(&self, model_id: String) -> Option<ModelDeactivation> {
+            self.deactivations.get(&model_id)
+        }
+
+        /// Council members who have voted for a pending lifecycle change
+        #[ink(message)]
+        pub fn get_lifecycle_votes(&self, model_id: String, action: LifecycleAction) -> Vec<AccountId> {
+            self.lifecycle_votes.get((model_id, action)).unwrap_or_default()
+        }
         /// Extract features from property metadata
         #[ink(message)]
         pub fn extract_features(&mut self, property_id: u64) -> Result<PropertyFeatures, AIValuationError> {
@@ -321,6 +743,7 @@ mod ai_valuation {
         #[ink(message)]
         pub fn predict_valuation(&mut self, property_id: u64, model_id: String) -> Result<AIPrediction, AIValuationError> {
             self.ensure_not_paused()?;
+            self.consume_prediction_quota()?;
 
             let model = self.models.get(&model_id).ok_or(AIValuationError::ModelNotFound)?;
             
@@ -352,6 +775,7 @@ mod ai_valuation {
             // Store prediction for validation
             let mut property_predictions = self.predictions.get(&property_id).unwrap_or_default();
             property_predictions.push(prediction.clone());
+            self.archive_excess_predictions(property_id, &mut property_predictions);
             self.predictions.insert(&property_id, &property_predictions);
 
             self.env().emit_event(PredictionGenerated {
@@ -367,6 +791,7 @@ mod ai_valuation {
         #[ink(message)]
         pub fn ensemble_predict(&mut self, property_id: u64) -> Result<EnsemblePrediction, AIValuationError> {
             self.ensure_not_paused()?;
+            self.consume_prediction_quota()?;
 
             let features = self.extract_features(property_id)?;
             let mut individual_predictions = Vec::new();
@@ -395,6 +820,19 @@ mod ai_valuation {
                 }
             }
 
+            // Federated predictions pushed by external providers, weighted like internal models
+            for provider in self.provider_list.clone() {
+                let Some(model_id) = self.external_providers.get(provider).filter(|p| p.is_active).map(|p| p.model_id) else { continue };
+                let Some(model) = self.models.get(&model_id).filter(|m| m.is_active) else { continue };
+                if let Some(prediction) = self.federated_predictions.get((property_id, provider)) {
+                    if prediction.confidence_score >= self.min_confidence && prediction.bias_score <= self.bias_threshold {
+                        weighted_sum += prediction.predicted_value * model.weight as u128;
+                        total_weight += model.weight;
+                        individual_predictions.push(prediction);
+                    }
+                }
+            }
+
             if individual_predictions.is_empty() {
                 return Err(AIValuationError::InsufficientData);
             }
@@ -410,6 +848,7 @@ mod ai_valuation {
             let ensemble_confidence = self.calculate_ensemble_confidence(&individual_predictions);
             let consensus_score = self.calculate_consensus_score(&individual_predictions);
             let explanation = self.generate_explanation(&individual_predictions, final_valuation);
+            self.report_disagreement(property_id, consensus_score, final_valuation, individual_predictions.len() as u32);
 
             Ok(EnsemblePrediction {
                 final_valuation,
@@ -481,24 +920,70 @@ mod ai_valuation {
             Ok(avg_bias)
         }
 
-        /// Get explanation for a valuation
+        /// Get explanation for a valuation in the requested locale.
+        /// Falls back to the English template when the locale has none registered.
         #[ink(message)]
-        pub fn explain_valuation(&self, property_id: u64, model_id: String) -> Result<String, AIValuationError> {
-            let model = self.models.get(&model_id).ok_or(AIValuationError::ModelNotFound)?;
+        pub fn explain_valuation(&self, property_id: u64, model_id: String, locale: String) -> Result<String, AIValuationError> {
+            let _model = self.models.get(&model_id).ok_or(AIValuationError::ModelNotFound)?;
             let features = self.property_features.get(&property_id).ok_or(AIValuationError::PropertyNotFound)?;
-            
-            // Generate human-readable explanation
-            let explanation = format!(
-                "Valuation based on {} model: Location score: {}, Size: {}sqm, Age: {} years, Condition: {}/100, Market trend: {}",
-                model_id,
-                features.location_score,
-                features.size_sqm,
-                features.age_years,
-                features.condition_score,
-                features.market_trend
-            );
-            
-            Ok(explanation)
+
+            let template = self.explanation_templates.get(&locale)
+                .or_else(|| self.explanation_templates.get(DEFAULT_EXPLANATION_LOCALE.to_string()))
+                .unwrap_or_else(|| DEFAULT_EXPLANATION_TEMPLATE.to_string());
+
+            let values = [
+                ("model", model_id),
+                ("location", features.location_score.to_string()),
+                ("size", features.size_sqm.to_string()),
+                ("age", features.age_years.to_string()),
+                ("condition", features.condition_score.to_string()),
+                ("amenities", features.amenities_score.to_string()),
+                ("trend", features.market_trend.to_string()),
+                ("comparable", features.comparable_avg.to_string()),
+                ("economic", features.economic_indicators.to_string()),
+            ];
+
+            Ok(Self::render_template(&template, &values))
+        }
+
+        /// Register or replace the explanation template for a locale.
+        /// Supported placeholders: {model}, {location}, {size}, {age}, {condition},
+        /// {amenities}, {trend}, {comparable}, {economic}
+        #[ink(message)]
+        pub fn set_explanation_template(&mut self, locale: String, template: String) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            if locale.is_empty() || template.is_empty() {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            if !self.template_locales.contains(&locale) {
+                self.template_locales.push(locale.clone());
+            }
+            self.explanation_templates.insert(&locale, &template);
+            Ok(())
+        }
+
+        /// Remove the explanation template for a locale
+        #[ink(message)]
+        pub fn remove_explanation_template(&mut self, locale: String) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            if !self.template_locales.contains(&locale) {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            self.template_locales.retain(|l| l != &locale);
+            self.explanation_templates.remove(&locale);
+            Ok(())
+        }
+
+        /// Get the explanation template registered for a locale
+        #[ink(message)]
+        pub fn get_explanation_template(&self, locale: String) -> Option<String> {
+            self.explanation_templates.get(&locale)
+        }
+
+        /// Get all locales with a registered explanation template
+        #[ink(message)]
+        pub fn get_template_locales(&self) -> Vec<String> {
+            self.template_locales.clone()
         }
         /// Pause the contract
         #[ink(message)]
@@ -516,6 +1001,159 @@ mod ai_valuation {
             Ok(())
         }
 
+        /// Configure prediction rate limits (0 disables a limit)
+        #[ink(message)]
+        pub fn set_rate_limit(&mut self, min_interval: u64, daily_quota: u32) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            self.rate_limit = RateLimitConfig { min_interval, daily_quota };
+            Ok(())
+        }
+
+        /// Exempt a registered contract from prediction rate limits, or revoke the exemption
+        #[ink(message)]
+        pub fn set_rate_limit_exemption(&mut self, account: AccountId, exempt: bool) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            if exempt {
+                self.rate_limit_exempt.insert(account, &true);
+            } else {
+                self.rate_limit_exempt.remove(account);
+            }
+            Ok(())
+        }
+
+        /// Set the analytics contract that receives disagreement records
+        #[ink(message)]
+        pub fn set_analytics_contract(&mut self, analytics: Option<AccountId>) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            self.analytics_contract = analytics;
+            Ok(())
+        }
+
+        /// Set the consensus score (basis points) below which ensembles raise an alert
+        #[ink(message)]
+        pub fn set_disagreement_threshold(&mut self, threshold: u32) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            if threshold > 10000 {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            self.disagreement_threshold = threshold;
+            Ok(())
+        }
+
+        /// Get the disagreement alert threshold
+        #[ink(message)]
+        pub fn get_disagreement_threshold(&self) -> u32 {
+            self.disagreement_threshold
+        }
+
+        /// Get prediction rate limits
+        #[ink(message)]
+        pub fn get_rate_limit(&self) -> RateLimitConfig {
+            self.rate_limit.clone()
+        }
+
+        /// Get prediction usage counters for an account
+        #[ink(message)]
+        pub fn get_consumer_usage(&self, account: AccountId) -> ConsumerUsage {
+            self.consumer_usage.get(account).unwrap_or_default()
+        }
+
+        /// Configure the noise added to public aggregate statistics
+        #[ink(message)]
+        pub fn set_privacy_config(&mut self, config: PrivacyConfig) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            if config.enabled && (config.epsilon_milli == 0 || config.value_bound == 0 || self.noise_seed.is_none()) {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            self.env().emit_event(PrivacyConfigUpdated {
+                enabled: config.enabled,
+                epsilon_milli: config.epsilon_milli,
+                value_bound: config.value_bound,
+            });
+            self.privacy_config = config;
+            Ok(())
+        }
+
+        /// Replace the secret the noise is derived from (required before enabling privacy)
+        #[ink(message)]
+        pub fn rotate_noise_seed(&mut self, seed: Hash) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            self.noise_seed = Some(seed);
+            Ok(())
+        }
+
+        /// Set how long each band's noise stays fixed before it is redrawn
+        #[ink(message)]
+        pub fn set_noise_epoch(&mut self, epoch: u64) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            if epoch == 0 {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            self.noise_epoch = epoch;
+            Ok(())
+        }
+
+        /// Get the differential privacy settings
+        #[ink(message)]
+        pub fn get_privacy_config(&self) -> PrivacyConfig {
+            self.privacy_config.clone()
+        }
+
+        /// Grant or revoke access to exact aggregate statistics
+        #[ink(message)]
+        pub fn set_licensed_reader(&mut self, account: AccountId, licensed: bool) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            if licensed {
+                self.licensed_readers.insert(account, &true);
+            } else {
+                self.licensed_readers.remove(account);
+            }
+            Ok(())
+        }
+
+        /// Check whether an account may read exact aggregates
+        #[ink(message)]
+        pub fn is_licensed_reader(&self, account: AccountId) -> bool {
+            self.licensed_readers.get(account).unwrap_or(false)
+        }
+
+        /// Public statistics over recorded sale values for a location score band.
+        /// With privacy enabled, count and sum each carry Laplace-style noise at half
+        /// the per-query budget; the parameters used are disclosed in the response.
+        #[ink(message)]
+        pub fn get_value_statistics(&self, min_location: u32, max_location: u32) -> ValueStatistics {
+            let config = &self.privacy_config;
+            if !config.enabled {
+                return self.value_statistics(min_location, max_location, None);
+            }
+            let (count, sum) = self.aggregate_values(min_location, max_location, Some(config.value_bound));
+            let band = (min_location, max_location);
+            let count_scale_milli = 2_000_000 / config.epsilon_milli as u128;
+            let sum_scale_milli = config.value_bound.saturating_mul(2_000_000) / config.epsilon_milli as u128;
+            let count = Self::apply_noise(count as u128, self.laplace_noise(0, band, count_scale_milli)) as u64;
+            let sum = Self::apply_noise(sum, self.laplace_noise(1, band, sum_scale_milli));
+            ValueStatistics {
+                count,
+                sum_value: sum,
+                mean_value: if count == 0 { 0 } else { sum / count as u128 },
+                noised: true,
+                epsilon_milli: config.epsilon_milli,
+                count_scale_milli,
+                sum_scale_milli,
+                value_bound: config.value_bound,
+            }
+        }
+
+        /// Exact statistics for licensed readers and the admin
+        #[ink(message)]
+        pub fn get_exact_value_statistics(&self, min_location: u32, max_location: u32) -> Result<ValueStatistics, AIValuationError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.is_licensed_reader(caller) {
+                return Err(AIValuationError::Unauthorized);
+            }
+            Ok(self.value_statistics(min_location, max_location, None))
+        }
+
         /// Get contract admin
         #[ink(message)]
         pub fn admin(&self) -> AccountId {
@@ -536,6 +1174,12 @@ mod ai_valuation {
             self.models.get(&model_id)
         }
 
+        /// Monotonicity constraints a model's coefficients are guaranteed to satisfy
+        #[ink(message)]
+        pub fn get_model_constraints(&self, model_id: String) -> Option<Vec<MonotonicityConstraint>> {
+            self.models.get(&model_id).map(|model| model.constraints)
+        }
+
         /// Get property features
         #[ink(message)]
         pub fn get_property_features(&self, property_id: u64) -> Option<PropertyFeatures> {
@@ -548,6 +1192,60 @@ mod ai_valuation {
             self.predictions.get(&property_id).unwrap_or_default()
         }
 
+        /// Set how many recent predictions stay in storage per property (0 keeps all)
+        #[ink(message)]
+        pub fn set_prediction_retention(&mut self, keep_recent: u32) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            self.prediction_retention = keep_recent;
+            Ok(())
+        }
+
+        /// Get the prediction retention limit
+        #[ink(message)]
+        pub fn get_prediction_retention(&self) -> u32 {
+            self.prediction_retention
+        }
+
+        /// Archive a property's predictions beyond the retention limit; returns how many were archived
+        #[ink(message)]
+        pub fn prune_predictions(&mut self, property_id: u64) -> u32 {
+            let mut property_predictions = self.predictions.get(&property_id).unwrap_or_default();
+            let archived = self.archive_excess_predictions(property_id, &mut property_predictions);
+            if archived > 0 {
+                self.predictions.insert(&property_id, &property_predictions);
+            }
+            archived
+        }
+
+        /// Merkle root and leaf count of a property's archived predictions
+        #[ink(message)]
+        pub fn get_archive_commitment(&self, property_id: u64) -> Option<([u8; 32], u64)> {
+            self.prediction_archives
+                .get(&property_id)
+                .map(|archive| (archive.root, archive.leaf_count))
+        }
+
+        /// Prove an archived prediction: `proof` holds the PREDICTION_ARCHIVE_DEPTH
+        /// sibling hashes from the leaf up to the root
+        #[ink(message)]
+        pub fn verify_archived_prediction(&self, property_id: u64, leaf_index: u64, prediction: AIPrediction, proof: Vec<[u8; 32]>) -> bool {
+            let Some(archive) = self.prediction_archives.get(&property_id) else {
+                return false;
+            };
+            if leaf_index >= archive.leaf_count || proof.len() != PREDICTION_ARCHIVE_DEPTH {
+                return false;
+            }
+            let mut node = Self::archive_leaf(leaf_index, &prediction);
+            for (level, sibling) in proof.iter().enumerate() {
+                node = if (leaf_index >> level) & 1 == 1 {
+                    Self::hash_pair(sibling, &node)
+                } else {
+                    Self::hash_pair(&node, sibling)
+                };
+            }
+            node == archive.root
+        }
+
         /// Get training data count
         #[ink(message)]
         pub fn get_training_data_count(&self) -> u64 {
@@ -589,11 +1287,217 @@ mod ai_valuation {
             self.ensure_not_paused()?;
 
             let mut versions = self.model_versions.get(&model_id).unwrap_or_default();
+            if version.artifact_hash == [0u8; 32]
+                || versions.iter().any(|v| v.version == version.version || v.artifact_hash == version.artifact_hash)
+            {
+                return Err(AIValuationError::InvalidArtifact);
+            }
+            self.env().emit_event(ArtifactCommitted {
+                model_id: model_id.clone(),
+                version: version.version,
+                artifact_hash: version.artifact_hash,
+                training_snapshot_hash: version.training_snapshot_hash,
+            });
             versions.push(version);
             self.model_versions.insert(&model_id, &versions);
             Ok(())
         }
 
+        /// Artifact and training-snapshot hashes committed for a model version
+        #[ink(message)]
+        pub fn get_artifact_commitment(&self, model_id: String, version: u32) -> Option<([u8; 32], [u8; 32])> {
+            self.model_versions
+                .get(&model_id)
+                .unwrap_or_default()
+                .into_iter()
+                .find(|v| v.version == version)
+                .map(|v| (v.artifact_hash, v.training_snapshot_hash))
+        }
+
+        /// Post (or top up) the bond required to submit inference results
+        #[ink(message, payable)]
+        pub fn register_inference_worker(&mut self) -> Result<(), AIValuationError> {
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            let caller = self.env().caller();
+            let bond = self.worker_bonds.get(caller).unwrap_or(0);
+            self.worker_bonds.insert(caller, &(bond + amount));
+            Ok(())
+        }
+
+        /// Submit an off-chain inference result citing the artifact it was produced with.
+        /// Citing a superseded artifact is recorded as stale and slashes the worker's bond.
+        #[ink(message)]
+        pub fn submit_inference(&mut self, property_id: u64, model_id: String, artifact_hash: [u8; 32], predicted_value: u128) -> Result<u64, AIValuationError> {
+            self.ensure_not_paused()?;
+            let worker = self.env().caller();
+            let bond = self.worker_bonds.get(worker).ok_or(AIValuationError::WorkerNotBonded)?;
+
+            let versions = self.model_versions.get(&model_id).unwrap_or_default();
+            let cited = versions
+                .iter()
+                .find(|v| v.artifact_hash == artifact_hash)
+                .ok_or(AIValuationError::InvalidArtifact)?;
+            let current = Self::current_version(&versions).ok_or(AIValuationError::InvalidArtifact)?;
+            let stale = cited.version != current.version;
+
+            let submission_id = self.inference_count + 1;
+            self.inference_count = submission_id;
+            let submission = InferenceSubmission {
+                submission_id,
+                worker,
+                property_id,
+                model_id: model_id.clone(),
+                model_version: cited.version,
+                artifact_hash,
+                predicted_value,
+                submitted_at: self.env().block_timestamp(),
+                stale,
+            };
+            self.inference_submissions.insert(submission_id, &submission);
+
+            if stale {
+                let slashed = bond * STALE_ARTIFACT_SLASH_BPS / 10_000;
+                self.worker_bonds.insert(worker, &(bond - slashed));
+                self.slashed_bonds += slashed;
+                self.env().emit_event(WorkerSlashed {
+                    worker,
+                    submission_id,
+                    amount: slashed,
+                });
+            }
+
+            self.env().emit_event(InferenceSubmitted {
+                submission_id,
+                worker,
+                model_id,
+                artifact_hash,
+                stale,
+            });
+
+            Ok(submission_id)
+        }
+
+        /// Get an inference submission for audit
+        #[ink(message)]
+        pub fn get_inference_submission(&self, submission_id: u64) -> Option<InferenceSubmission> {
+            self.inference_submissions.get(submission_id)
+        }
+
+        /// Get a worker's remaining bond
+        #[ink(message)]
+        pub fn get_worker_bond(&self, worker: AccountId) -> Balance {
+            self.worker_bonds.get(worker).unwrap_or(0)
+        }
+
+        /// Federate an external valuation provider. Its model entry carries the provider's
+        /// ensemble weight and is tracked, calibrated and versioned like an internal model.
+        #[ink(message)]
+        pub fn register_external_provider(&mut self, provider: AccountId, model: AIModel) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            self.ensure_not_paused()?;
+
+            if model.model_id.is_empty() || model.accuracy_score > 10000 || self.models.contains(&model.model_id) {
+                return Err(AIValuationError::InvalidModel);
+            }
+            if self.external_providers.contains(provider) || self.provider_list.len() >= MAX_EXTERNAL_PROVIDERS {
+                return Err(AIValuationError::ProviderRegistrationFailed);
+            }
+
+            self.models.insert(&model.model_id, &model);
+            self.external_providers.insert(provider, &ExternalProvider {
+                provider,
+                model_id: model.model_id.clone(),
+                registered_at: self.env().block_timestamp(),
+                submission_count: 0,
+                is_active: true,
+            });
+            self.provider_list.push(provider);
+
+            self.env().emit_event(ExternalProviderRegistered {
+                provider,
+                model_id: model.model_id,
+                weight: model.weight,
+            });
+            Ok(())
+        }
+
+        /// Suspend or reinstate an external provider's participation in ensembles
+        #[ink(message)]
+        pub fn set_external_provider_active(&mut self, provider: AccountId, active: bool) -> Result<(), AIValuationError> {
+            self.ensure_admin()?;
+            let mut entry = self.external_providers.get(provider).ok_or(AIValuationError::ProviderNotRegistered)?;
+            entry.is_active = active;
+            self.external_providers.insert(provider, &entry);
+            Ok(())
+        }
+
+        /// Push a prediction from an external provider. The extrinsic signature authenticates the
+        /// provider; it must be bonded and cite a committed artifact of its model, and citing a
+        /// superseded artifact slashes its bond and keeps the prediction out of ensembles.
+        #[ink(message)]
+        pub fn submit_external_prediction(&mut self, property_id: u64, prediction: AIPrediction, artifact_hash: [u8; 32]) -> Result<u64, AIValuationError> {
+            self.ensure_not_paused()?;
+            let provider = self.env().caller();
+            let mut entry = self.external_providers.get(provider).filter(|p| p.is_active).ok_or(AIValuationError::ProviderNotRegistered)?;
+            if prediction.model_id != entry.model_id
+                || prediction.confidence_score > 10000
+                || prediction.uncertainty_range.0 > prediction.predicted_value
+                || prediction.uncertainty_range.1 < prediction.predicted_value
+            {
+                return Err(AIValuationError::InvalidParameters);
+            }
+            if prediction.bias_score > self.bias_threshold {
+                self.env().emit_event(BiasDetected {
+                    model_id: entry.model_id.clone(),
+                    bias_score: prediction.bias_score,
+                    affected_properties: vec![property_id],
+                });
+                return Err(AIValuationError::BiasDetected);
+            }
+
+            let submission_id = self.submit_inference(property_id, entry.model_id.clone(), artifact_hash, prediction.predicted_value)?;
+            let stale = self.inference_submissions.get(submission_id).is_some_and(|s| s.stale);
+            if !stale {
+                self.federated_predictions.insert((property_id, provider), &prediction);
+                let mut property_predictions = self.predictions.get(&property_id).unwrap_or_default();
+                property_predictions.push(prediction.clone());
+                self.archive_excess_predictions(property_id, &mut property_predictions);
+                self.predictions.insert(&property_id, &property_predictions);
+            }
+            entry.submission_count += 1;
+            self.external_providers.insert(provider, &entry);
+
+            self.env().emit_event(ExternalPredictionSubmitted {
+                provider,
+                property_id,
+                submission_id,
+                predicted_value: prediction.predicted_value,
+                stale,
+            });
+            Ok(submission_id)
+        }
+
+        /// Get an external provider's registration
+        #[ink(message)]
+        pub fn get_external_provider(&self, provider: AccountId) -> Option<ExternalProvider> {
+            self.external_providers.get(provider)
+        }
+
+        /// List registered external providers
+        #[ink(message)]
+        pub fn get_external_providers(&self) -> Vec<AccountId> {
+            self.provider_list.clone()
+        }
+
+        /// Latest prediction an external provider has contributed for a property
+        #[ink(message)]
+        pub fn get_federated_prediction(&self, property_id: u64, provider: AccountId) -> Option<AIPrediction> {
+            self.federated_predictions.get((property_id, provider))
+        }
+
         /// Detect data drift
         #[ink(message)]
         pub fn detect_data_drift(&mut self, model_id: String, detection_method: DriftDetectionMethod) -> Result<DriftDetectionResult, AIValuationError> {
@@ -669,6 +1573,182 @@ mod ai_valuation {
         }
 
         // Private helper methods
+
+        /// Move the oldest predictions beyond the retention limit into the property's archive
+        fn archive_excess_predictions(&mut self, property_id: u64, predictions: &mut Vec<AIPrediction>) -> u32 {
+            let keep = self.prediction_retention as usize;
+            if keep == 0 || predictions.len() <= keep {
+                return 0;
+            }
+            let excess = predictions.len() - keep;
+            let mut archive = self.prediction_archives.get(&property_id).unwrap_or_else(|| PredictionArchive {
+                root: [0u8; 32],
+                leaf_count: 0,
+                frontier: vec![[0u8; 32]; PREDICTION_ARCHIVE_DEPTH],
+            });
+            for prediction in predictions.drain(..excess) {
+                let leaf = Self::archive_leaf(archive.leaf_count, &prediction);
+                Self::append_leaf(&mut archive, leaf);
+            }
+            archive.root = Self::archive_root(&archive);
+            self.prediction_archives.insert(&property_id, &archive);
+
+            self.env().emit_event(PredictionsArchived {
+                property_id,
+                archived: excess as u32,
+                leaf_count: archive.leaf_count,
+                root: archive.root,
+            });
+            excess as u32
+        }
+
+        fn archive_leaf(index: u64, prediction: &AIPrediction) -> [u8; 32] {
+            let mut leaf = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&(index, prediction), &mut leaf);
+            leaf
+        }
+
+        fn hash_pair(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+            let mut input = [0u8; 64];
+            input[..32].copy_from_slice(left);
+            input[32..].copy_from_slice(right);
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&input, &mut output);
+            output
+        }
+
+        /// Insert a leaf, keeping only the left siblings needed for the next insertion
+        fn append_leaf(archive: &mut PredictionArchive, leaf: [u8; 32]) {
+            let mut node = leaf;
+            let mut size = archive.leaf_count + 1;
+            for level in 0..PREDICTION_ARCHIVE_DEPTH {
+                if size & 1 == 1 {
+                    archive.frontier[level] = node;
+                    break;
+                }
+                node = Self::hash_pair(&archive.frontier[level], &node);
+                size >>= 1;
+            }
+            archive.leaf_count += 1;
+        }
+
+        /// Root of the tree with empty leaves hashed as zero
+        fn archive_root(archive: &PredictionArchive) -> [u8; 32] {
+            let mut node = [0u8; 32];
+            let mut zero = [0u8; 32];
+            let mut size = archive.leaf_count;
+            for level in 0..PREDICTION_ARCHIVE_DEPTH {
+                node = if size & 1 == 1 {
+                    Self::hash_pair(&archive.frontier[level], &node)
+                } else {
+                    Self::hash_pair(&node, &zero)
+                };
+                zero = Self::hash_pair(&zero, &zero);
+                size >>= 1;
+            }
+            node
+        }
+
+        fn value_statistics(&self, min_location: u32, max_location: u32, bound: Option<u128>) -> ValueStatistics {
+            let (count, sum) = self.aggregate_values(min_location, max_location, bound);
+            ValueStatistics {
+                count,
+                sum_value: sum,
+                mean_value: if count == 0 { 0 } else { sum / count as u128 },
+                noised: false,
+                epsilon_milli: 0,
+                count_scale_milli: 0,
+                sum_scale_milli: 0,
+                value_bound: bound.unwrap_or(0),
+            }
+        }
+
+        /// Count and sum of recorded sale values whose location score falls in the band
+        fn aggregate_values(&self, min_location: u32, max_location: u32, bound: Option<u128>) -> (u64, u128) {
+            self.training_data
+                .iter()
+                .filter(|point| {
+                    point.features.location_score >= min_location && point.features.location_score <= max_location
+                })
+                .fold((0u64, 0u128), |(count, sum), point| {
+                    let value = bound.map_or(point.actual_value, |bound| point.actual_value.min(bound));
+                    (count + 1, sum.saturating_add(value))
+                })
+        }
+
+        /// Signed Laplace sample with the given scale (in thousandths), drawn from the
+        /// secret noise seed. A band gets the same noise for the whole epoch, so repeating
+        /// a query cannot average the noise away.
+        fn laplace_noise(&self, label: u8, band: (u32, u32), scale_milli: u128) -> i128 {
+            let epoch = self.env().block_timestamp() / self.noise_epoch.max(1);
+            let mut seed = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(self.noise_seed, epoch, label, band),
+                &mut seed,
+            );
+            let uniform = u32::from_le_bytes([seed[0], seed[1], seed[2], seed[3]]);
+            let magnitude = scale_milli.saturating_mul(Self::neg_ln_millionths(uniform)) / 1_000_000_000;
+            let magnitude = magnitude.min(i128::MAX as u128) as i128;
+            if seed[4] & 1 == 0 { magnitude } else { -magnitude }
+        }
+
+        /// -ln(u / 2^32) in millionths, i.e. an Exp(1) sample for uniform u. log2 is
+        /// interpolated linearly between powers of two.
+        fn neg_ln_millionths(uniform: u32) -> u128 {
+            let u = uniform.max(1) as u64;
+            let msb = 63 - u.leading_zeros() as u64;
+            let log2_fixed = (msb << 20) + (((u - (1 << msb)) << 20) >> msb);
+            (((32u64 << 20) - log2_fixed) as u128 * 693_147) >> 20
+        }
+
+        /// Add signed noise, clamping at zero
+        fn apply_noise(value: u128, noise: i128) -> u128 {
+            if noise >= 0 {
+                value.saturating_add(noise as u128)
+            } else {
+                value.saturating_sub(noise.unsigned_abs())
+            }
+        }
+
+        /// Approvers of a lifecycle change once it may be applied: empty for the admin, the
+        /// voters once the council threshold is met, or None while votes are still pending
+        fn lifecycle_approval(&mut self, model_id: &str, action: LifecycleAction) -> Result<Option<Vec<AccountId>>, AIValuationError> {
+            let caller = self.env().caller();
+            if caller == self.admin {
+                self.lifecycle_votes.remove((model_id.to_string(), action));
+                return Ok(Some(Vec::new()));
+            }
+            if !self.council.contains(&caller) {
+                return Err(AIValuationError::Unauthorized);
+            }
+            let key: LifecycleVoteKey = (model_id.to_string(), action);
+            let mut votes = self.lifecycle_votes.get(&key).unwrap_or_default();
+            // Votes from members since removed from the council no longer count
+            votes.retain(|voter| self.council.contains(voter));
+            if votes.contains(&caller) {
+                return Err(AIValuationError::AlreadyVoted);
+            }
+            votes.push(caller);
+            self.env().emit_event(LifecycleVoteCast {
+                model_id: model_id.to_string(),
+                voter: caller,
+                action,
+                votes: votes.len() as u32,
+                threshold: self.council_threshold,
+            });
+            if votes.len() < self.council_threshold as usize {
+                self.lifecycle_votes.insert(&key, &votes);
+                return Ok(None);
+            }
+            self.lifecycle_votes.remove(&key);
+            Ok(Some(votes))
+        }
+
+        fn set_model_active(&mut self, model_id: &str, mut model: AIModel, active: bool) {
+            model.is_active = active;
+            self.models.insert(model_id, &model);
+        }
+
         fn ensure_admin(&self) -> Result<(), AIValuationError> {
             if self.env().caller() != self.admin {
                 return Err(AIValuationError::Unauthorized);
@@ -682,6 +1762,43 @@ mod ai_valuation {
             }
             Ok(())
         }
+
+        /// Count a prediction call against the caller's interval and daily quota
+        fn consume_prediction_quota(&mut self) -> Result<(), AIValuationError> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            let mut usage = self.consumer_usage.get(caller).unwrap_or_default();
+            let day = now / 86_400_000;
+            if usage.day != day {
+                usage.day = day;
+                usage.calls_today = 0;
+            }
+
+            if !self.rate_limit_exempt.get(caller).unwrap_or(false) {
+                let limit = &self.rate_limit;
+                if limit.min_interval > 0 && usage.total_calls > 0 && now < usage.last_call_at.saturating_add(limit.min_interval) {
+                    return Err(AIValuationError::RateLimited);
+                }
+                if limit.daily_quota > 0 && usage.calls_today >= limit.daily_quota {
+                    return Err(AIValuationError::RateLimited);
+                }
+            }
+
+            usage.calls_today = usage.calls_today.saturating_add(1);
+            usage.total_calls = usage.total_calls.saturating_add(1);
+            usage.last_call_at = now;
+            self.consumer_usage.insert(caller, &usage);
+            Ok(())
+        }
+
+        /// Latest production version, falling back to the latest committed version
+        fn current_version(versions: &[ModelVersion]) -> Option<&ModelVersion> {
+            versions
+                .iter()
+                .rev()
+                .find(|v| v.deployment_status == DeploymentStatus::Production)
+                .or_else(|| versions.last())
+        }
         fn generate_mock_features(&self, property_id: u64) -> Result<PropertyFeatures, AIValuationError> {
             // Mock feature generation based on property_id
             // In production, this would extract real features from property metadata
@@ -699,6 +1816,36 @@ mod ai_valuation {
             })
         }
 
+        /// Constraints apply to linear models only, name each feature at most once
+        /// and must agree with the sign of the feature's coefficient
+        fn validate_constraints(model: &AIModel) -> Result<(), AIValuationError> {
+            if model.model_type != AIModelType::LinearRegression {
+                if model.coefficients.is_empty() && model.constraints.is_empty() {
+                    return Ok(());
+                }
+                return Err(AIValuationError::InvalidModel);
+            }
+            for (i, constraint) in model.constraints.iter().enumerate() {
+                if model.constraints[..i].iter().any(|c| c.feature == constraint.feature) {
+                    return Err(AIValuationError::InvalidModel);
+                }
+                let coefficient = model
+                    .coefficients
+                    .iter()
+                    .filter(|(feature, _)| *feature == constraint.feature)
+                    .map(|(_, coefficient)| *coefficient)
+                    .sum::<i64>();
+                let respected = match constraint.direction {
+                    Monotonicity::NonDecreasing => coefficient >= 0,
+                    Monotonicity::NonIncreasing => coefficient <= 0,
+                };
+                if !respected {
+                    return Err(AIValuationError::MonotonicityViolation);
+                }
+            }
+            Ok(())
+        }
+
         fn generate_prediction(&self, model: &AIModel, features: &PropertyFeatures, property_id: u64) -> Result<AIPrediction, AIValuationError> {
             // Simplified prediction generation
             // In production, this would use actual ML model inference
@@ -747,6 +1894,35 @@ mod ai_valuation {
             total_confidence / predictions.len() as u32
         }
 
+        /// Emits a DisagreementAlert for low-consensus ensembles and pushes a record to analytics.
+        /// An unreachable analytics contract never blocks the valuation itself.
+        pub(crate) fn report_disagreement(&mut self, property_id: u64, consensus_score: u32, final_valuation: u128, model_count: u32) {
+            if consensus_score >= self.disagreement_threshold {
+                return;
+            }
+            let threshold = self.disagreement_threshold;
+            let analytics_notified = self.analytics_contract.is_some_and(|analytics| {
+                use ink::codegen::TraitCallBuilder;
+                use ink::env::call::FromAccountId;
+                let mut sink: ink::contract_ref!(ValuationAlertSink) = FromAccountId::from_account_id(analytics);
+                matches!(
+                    sink.call_mut()
+                        .ingest_valuation_disagreement(property_id, consensus_score, threshold, final_valuation, model_count)
+                        .try_invoke(),
+                    Ok(Ok(_))
+                )
+            });
+
+            self.env().emit_event(DisagreementAlert {
+                property_id,
+                consensus_score,
+                threshold,
+                final_valuation,
+                model_count,
+                analytics_notified,
+            });
+        }
+
         fn calculate_consensus_score(&self, predictions: &[AIPrediction]) -> u32 {
             if predictions.len() < 2 {
                 return 10000; // Perfect consensus with single prediction
@@ -777,6 +1953,32 @@ mod ai_valuation {
             }
         }
 
+        /// Substitutes `{name}` placeholders; unknown placeholders are left untouched
+        fn render_template(template: &str, values: &[(&str, String)]) -> String {
+            let mut output = String::new();
+            let mut rest = template;
+            while let Some(start) = rest.find('{') {
+                output.push_str(&rest[..start]);
+                let after = &rest[start + 1..];
+                match after.find('}') {
+                    Some(end) => {
+                        let key = &after[..end];
+                        match values.iter().find(|(name, _)| *name == key) {
+                            Some((_, value)) => output.push_str(value),
+                            None => output.push_str(&rest[start..start + end + 2]),
+                        }
+                        rest = &after[end + 1..];
+                    }
+                    None => {
+                        output.push_str(&rest[start..]);
+                        rest = "";
+                    }
+                }
+            }
+            output.push_str(rest);
+            output
+        }
+
         fn generate_explanation(&self, predictions: &[AIPrediction], final_value: u128) -> String {
             if predictions.is_empty() {
                 return "No predictions available".to_string();
@@ -794,6 +1996,27 @@ mod ai_valuation {
         }
     }
 
+    impl RiskScoreSource for AIValuationEngine {
+        /// Maps cached features onto the insurance sub-scores: location desirability
+        /// discounted by comps volatility, condition, age, and economic health blended
+        /// with the stability of the property's valuation history
+        #[ink(message)]
+        fn derive_risk_scores(&self, property_id: u64) -> Option<DerivedRiskScores> {
+            let features = self.property_features.get(&property_id)?;
+            let history = self.predictions.get(&property_id).unwrap_or_default();
+            // 0-100; a single or missing valuation counts as fully stable
+            let stability = self.calculate_consensus_score(&history) / 100;
+            let location = features.location_score.min(1000) / 10;
+            Some(DerivedRiskScores {
+                location_score: location * stability / 100,
+                construction_score: features.condition_score.min(100),
+                age_score: 100 - features.age_years.min(100),
+                claims_history_score: (features.economic_indicators.min(100) + stability) / 2,
+                derived_at: self.env().block_timestamp(),
+            })
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -821,6 +2044,8 @@ mod ai_valuation {
                 last_updated: 1234567890,
                 is_active: true,
                 weight: 100,
+                coefficients: Vec::new(),
+                constraints: Vec::new(),
             };
             
             assert!(engine.register_model(model.clone()).is_ok());
@@ -219,6 +219,10 @@ pub struct ModelVersion {
     pub parent_version: Option<u32>,
     pub training_data_hash: String,
     pub model_hash: String,
+    /// Content hash of the off-chain model artifact; inference submissions cite it
+    pub artifact_hash: [u8; 32],
+    /// Content hash of the training-data snapshot the artifact was built from
+    pub training_snapshot_hash: [u8; 32],
     pub performance_metrics: ModelMetrics,
     pub deployment_status: DeploymentStatus,
     pub created_at: u64,
@@ -3,6 +3,8 @@ mod tests {
     use super::*;
     use crate::ai_valuation::*;
     use crate::ml_pipeline::*;
+    use crate::PREDICTION_ARCHIVE_DEPTH;
+    use propchain_traits::RiskScoreSource;
     use ink::env::test;
 
     fn default_accounts() -> test::DefaultAccounts<ink::env::DefaultEnvironment> {
@@ -29,6 +31,8 @@ mod tests {
             last_updated: 1234567890,
             is_active: true,
             weight: 100,
+            coefficients: Vec::new(),
+            constraints: Vec::new(),
         }
     }
 
@@ -101,6 +105,106 @@ mod tests {
         assert_eq!(engine.get_model("test_model".to_string()), Some(updated_model));
     }
 
+    #[ink::test]
+    fn test_monotonicity_constraints_enforced_on_linear_models() {
+        let mut engine = setup_ai_engine();
+        let size_increases = MonotonicityConstraint {
+            feature: FeatureId::SizeSqm,
+            direction: Monotonicity::NonDecreasing,
+        };
+        let mut model = create_sample_model();
+        model.constraints = vec![size_increases.clone()];
+
+        // Bigger houses worth less
+        model.coefficients = vec![(FeatureId::SizeSqm, -1_000), (FeatureId::AgeYears, -50)];
+        assert_eq!(engine.register_model(model.clone()), Err(AIValuationError::MonotonicityViolation));
+
+        model.coefficients = vec![(FeatureId::SizeSqm, 1_000), (FeatureId::AgeYears, -50)];
+        assert!(engine.register_model(model.clone()).is_ok());
+        assert_eq!(
+            engine.get_model_constraints("test_model".to_string()),
+            Some(vec![size_increases.clone()])
+        );
+
+        // Updates are validated too, and cannot drop a declared constraint
+        let mut updated = model.clone();
+        updated.version = 2;
+        updated.coefficients = vec![(FeatureId::SizeSqm, -1)];
+        assert_eq!(
+            engine.update_model("test_model".to_string(), updated.clone()),
+            Err(AIValuationError::MonotonicityViolation)
+        );
+        updated.coefficients = model.coefficients.clone();
+        updated.constraints = Vec::new();
+        assert_eq!(
+            engine.update_model("test_model".to_string(), updated.clone()),
+            Err(AIValuationError::InvalidModel)
+        );
+        updated.constraints = vec![
+            size_increases,
+            MonotonicityConstraint { feature: FeatureId::AgeYears, direction: Monotonicity::NonIncreasing },
+        ];
+        assert!(engine.update_model("test_model".to_string(), updated.clone()).is_ok());
+        assert_eq!(engine.get_model_constraints("test_model".to_string()), Some(updated.constraints));
+
+        // Sign restrictions only make sense for linear models
+        let mut forest = create_sample_model();
+        forest.model_id = "forest".to_string();
+        forest.model_type = AIModelType::RandomForest;
+        forest.constraints = model.constraints;
+        assert_eq!(engine.register_model(forest), Err(AIValuationError::InvalidModel));
+    }
+
+    #[ink::test]
+    fn test_model_lifecycle_needs_admin_or_council_vote() {
+        let mut engine = setup_ai_engine();
+        let accounts = default_accounts();
+        let model_id = "test_model".to_string();
+        assert!(engine.register_model(create_sample_model()).is_ok());
+
+        set_next_caller(accounts.bob);
+        assert_eq!(
+            engine.deactivate_model(model_id.clone(), DeactivationReason::DataDrift),
+            Err(AIValuationError::Unauthorized)
+        );
+        set_next_caller(accounts.alice);
+        assert_eq!(
+            engine.set_governance_council(vec![accounts.bob, accounts.bob], 1),
+            Err(AIValuationError::InvalidParameters)
+        );
+        assert!(engine.set_governance_council(vec![accounts.bob, accounts.charlie, accounts.django], 2).is_ok());
+
+        // Votes for different reasons are tallied separately
+        set_next_caller(accounts.bob);
+        assert_eq!(engine.deactivate_model(model_id.clone(), DeactivationReason::DataDrift), Ok(false));
+        assert_eq!(
+            engine.deactivate_model(model_id.clone(), DeactivationReason::DataDrift),
+            Err(AIValuationError::AlreadyVoted)
+        );
+        set_next_caller(accounts.charlie);
+        assert_eq!(engine.deactivate_model(model_id.clone(), DeactivationReason::BiasDetected), Ok(false));
+        assert!(engine.get_model(model_id.clone()).unwrap().is_active);
+        set_next_caller(accounts.django);
+        assert_eq!(engine.deactivate_model(model_id.clone(), DeactivationReason::DataDrift), Ok(true));
+
+        let deactivation = engine.get_model_deactivation(model_id.clone()).unwrap();
+        assert_eq!(deactivation.reason, DeactivationReason::DataDrift);
+        assert!(deactivation.by_council);
+        assert_eq!(deactivation.approvers, vec![accounts.bob, accounts.django]);
+        assert!(engine.get_lifecycle_votes(model_id.clone(), LifecycleAction::Deactivate(DeactivationReason::DataDrift)).is_empty());
+        assert_eq!(engine.predict_valuation(123, model_id.clone()), Err(AIValuationError::ModelNotFound));
+
+        // The admin acts alone
+        set_next_caller(accounts.alice);
+        assert_eq!(
+            engine.deactivate_model(model_id.clone(), DeactivationReason::Other),
+            Err(AIValuationError::InvalidParameters)
+        );
+        assert_eq!(engine.reactivate_model(model_id.clone()), Ok(true));
+        assert!(engine.get_model(model_id.clone()).unwrap().is_active);
+        assert_eq!(engine.get_model_deactivation(model_id), None);
+    }
+
     #[ink::test]
     fn test_extract_features_works() {
         let mut engine = setup_ai_engine();
@@ -132,6 +236,71 @@ mod tests {
         assert_eq!(prediction.model_id, "test_model");
     }
 
+    #[ink::test]
+    fn test_prediction_rate_limits_and_quota() {
+        let mut engine = setup_ai_engine();
+        let accounts = default_accounts();
+        assert!(engine.register_model(create_sample_model()).is_ok());
+        assert!(engine.set_rate_limit(1_000, 2).is_ok());
+        assert!(engine.set_rate_limit_exemption(accounts.charlie, true).is_ok());
+
+        set_next_caller(accounts.bob);
+        assert!(engine.predict_valuation(123, "test_model".to_string()).is_ok());
+        // Too soon after the previous call
+        assert_eq!(engine.predict_valuation(123, "test_model".to_string()), Err(AIValuationError::RateLimited));
+
+        test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+        assert!(engine.predict_valuation(123, "test_model".to_string()).is_ok());
+        test::set_block_timestamp::<ink::env::DefaultEnvironment>(4_000);
+        // Daily quota of two is used up
+        assert_eq!(engine.predict_valuation(123, "test_model".to_string()), Err(AIValuationError::RateLimited));
+        let usage = engine.get_consumer_usage(accounts.bob);
+        assert_eq!(usage.calls_today, 2);
+        assert_eq!(usage.total_calls, 2);
+
+        // The next day the quota resets
+        test::set_block_timestamp::<ink::env::DefaultEnvironment>(86_400_000);
+        assert!(engine.predict_valuation(123, "test_model".to_string()).is_ok());
+        assert_eq!(engine.get_consumer_usage(accounts.bob).calls_today, 1);
+
+        // Exempt contracts are never limited
+        set_next_caller(accounts.charlie);
+        for _ in 0..3 {
+            assert!(engine.predict_valuation(123, "test_model".to_string()).is_ok());
+        }
+        assert_eq!(engine.get_consumer_usage(accounts.charlie).total_calls, 3);
+    }
+
+    #[ink::test]
+    fn test_prediction_retention_archives_into_merkle_root() {
+        let mut engine = setup_ai_engine();
+        assert!(engine.register_model(create_sample_model()).is_ok());
+        assert!(engine.set_prediction_retention(2).is_ok());
+
+        let first = engine.predict_valuation(123, "test_model".to_string()).unwrap();
+        for _ in 0..2 {
+            assert!(engine.predict_valuation(123, "test_model".to_string()).is_ok());
+        }
+        assert_eq!(engine.get_prediction_history(123).len(), 2);
+        let (root, leaf_count) = engine.get_archive_commitment(123).unwrap();
+        assert_eq!(leaf_count, 1);
+        assert_ne!(root, [0u8; 32]);
+
+        // The only leaf sits at index 0, so every sibling is an empty subtree
+        let mut proof = Vec::new();
+        let mut zero = [0u8; 32];
+        for _ in 0..PREDICTION_ARCHIVE_DEPTH {
+            proof.push(zero);
+            let mut input = [0u8; 64];
+            input[..32].copy_from_slice(&zero);
+            input[32..].copy_from_slice(&zero);
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&input, &mut zero);
+        }
+        assert!(engine.verify_archived_prediction(123, 0, first.clone(), proof.clone()));
+        assert!(!engine.verify_archived_prediction(123, 1, first, proof));
+        assert_eq!(engine.prune_predictions(123), 0);
+    }
+
     #[ink::test]
     fn test_predict_valuation_inactive_model_fails() {
         let mut engine = setup_ai_engine();
@@ -159,6 +328,8 @@ mod tests {
                 last_updated: 1234567890,
                 is_active: true,
                 weight: 30,
+                coefficients: Vec::new(),
+                constraints: Vec::new(),
             },
             AIModel {
                 model_id: "random_forest_v2".to_string(),
@@ -169,6 +340,8 @@ mod tests {
                 last_updated: 1234567890,
                 is_active: true,
                 weight: 40,
+                coefficients: Vec::new(),
+                constraints: Vec::new(),
             },
             AIModel {
                 model_id: "neural_net_v1".to_string(),
@@ -179,6 +352,8 @@ mod tests {
                 last_updated: 1234567890,
                 is_active: true,
                 weight: 30,
+                coefficients: Vec::new(),
+                constraints: Vec::new(),
             },
         ];
         
@@ -196,6 +371,43 @@ mod tests {
         assert!(!ensemble.explanation.is_empty());
     }
 
+    #[ink::test]
+    fn test_disagreement_threshold_gates_alerts() {
+        let mut engine = setup_ai_engine();
+        assert_eq!(engine.get_disagreement_threshold(), 6000);
+        assert_eq!(engine.set_disagreement_threshold(10_001), Err(AIValuationError::InvalidParameters));
+        let mut model = create_sample_model();
+        model.model_id = "linear_reg_v1".to_string();
+        assert!(engine.register_model(model).is_ok());
+
+        // A single model is in perfect consensus, so no alert is raised
+        let events_before = test::recorded_events().count();
+        assert_eq!(engine.ensemble_predict(123).unwrap().consensus_score, 10000);
+        assert_eq!(test::recorded_events().count(), events_before);
+
+        set_next_caller(default_accounts().bob);
+        assert_eq!(engine.set_disagreement_threshold(9_000), Err(AIValuationError::Unauthorized));
+        assert_eq!(engine.set_analytics_contract(None), Err(AIValuationError::Unauthorized));
+    }
+
+    #[ink::test]
+    fn test_low_consensus_raises_disagreement_alert() {
+        use scale::Encode;
+        let mut engine = setup_ai_engine();
+
+        // At the threshold the ensemble still agrees well enough
+        let events_before = test::recorded_events().count();
+        engine.report_disagreement(123, 6000, 700_000, 3);
+        assert_eq!(test::recorded_events().count(), events_before);
+
+        // Below it an alert is raised; no analytics contract is set, so none is notified
+        engine.report_disagreement(123, 4500, 700_000, 3);
+        let events: Vec<_> = test::recorded_events().collect();
+        assert_eq!(events.len(), events_before + 1);
+        let alert = events.last().unwrap();
+        assert_eq!(alert.data, (123u64, 4500u32, 6000u32, 700_000u128, 3u32, false).encode());
+    }
+
     #[ink::test]
     fn test_add_training_data_works() {
         let mut engine = setup_ai_engine();
@@ -213,6 +425,60 @@ mod tests {
         assert_eq!(engine.get_training_data_count(), 1);
     }
 
+    #[ink::test]
+    fn test_public_statistics_are_noised_and_exact_ones_licensed() {
+        let mut engine = setup_ai_engine();
+        let accounts = default_accounts();
+        for (location_score, actual_value) in [(750, 100_000), (760, 200_000), (790, 300_000), (200, 900_000)] {
+            let features = PropertyFeatures { location_score, ..create_sample_features() };
+            assert!(engine
+                .add_training_data(TrainingDataPoint {
+                    property_id: 1,
+                    features,
+                    actual_value,
+                    timestamp: 1234567890,
+                    data_source: "market_sale".to_string(),
+                })
+                .is_ok());
+        }
+
+        // Privacy off: public aggregates are exact
+        let stats = engine.get_value_statistics(700, 800);
+        assert_eq!((stats.count, stats.sum_value, stats.mean_value), (3, 600_000, 200_000));
+        assert!(!stats.noised);
+
+        let config = PrivacyConfig { enabled: true, epsilon_milli: 0, value_bound: 250_000 };
+        assert_eq!(engine.set_privacy_config(config), Err(AIValuationError::InvalidParameters));
+        let config = PrivacyConfig { enabled: true, epsilon_milli: 1_000, value_bound: 250_000 };
+        // Noise needs a secret seed first
+        assert_eq!(engine.set_privacy_config(config.clone()), Err(AIValuationError::InvalidParameters));
+        assert!(engine.rotate_noise_seed(ink::primitives::Hash::from([7u8; 32])).is_ok());
+        assert!(engine.set_privacy_config(config).is_ok());
+
+        let stats = engine.get_value_statistics(700, 800);
+        assert!(stats.noised);
+        assert_eq!(stats.epsilon_milli, 1_000);
+        assert_eq!(stats.count_scale_milli, 2_000);
+        assert_eq!(stats.sum_scale_milli, 500_000_000);
+        assert_eq!(stats.value_bound, 250_000);
+        assert_eq!(engine.get_value_statistics(700, 800), stats);
+        // Later blocks in the same epoch repeat the band's noise
+        test::advance_block::<ink::env::DefaultEnvironment>();
+        test::set_block_timestamp::<ink::env::DefaultEnvironment>(86_399_999);
+        assert_eq!(engine.get_value_statistics(700, 800), stats);
+
+        set_next_caller(accounts.bob);
+        assert_eq!(engine.get_exact_value_statistics(700, 800), Err(AIValuationError::Unauthorized));
+        assert_eq!(engine.set_licensed_reader(accounts.bob, true), Err(AIValuationError::Unauthorized));
+
+        set_next_caller(accounts.alice);
+        assert!(engine.set_licensed_reader(accounts.bob, true).is_ok());
+        set_next_caller(accounts.bob);
+        let exact = engine.get_exact_value_statistics(700, 800).unwrap();
+        assert_eq!((exact.count, exact.sum_value), (3, 600_000));
+        assert!(!exact.noised);
+    }
+
     #[ink::test]
     fn test_detect_bias_works() {
         let mut engine = setup_ai_engine();
@@ -239,11 +505,42 @@ mod tests {
         assert!(engine.extract_features(property_id).is_ok());
         
         // Get explanation
-        let explanation = engine.explain_valuation(property_id, "test_model".to_string()).unwrap();
+        let explanation = engine.explain_valuation(property_id, "test_model".to_string(), "en".to_string()).unwrap();
         assert!(!explanation.is_empty());
         assert!(explanation.contains("test_model"));
     }
 
+    #[ink::test]
+    fn test_explain_valuation_uses_locale_template() {
+        let mut engine = setup_ai_engine();
+        let property_id = 123;
+        assert!(engine.register_model(create_sample_model()).is_ok());
+        let features = engine.extract_features(property_id).unwrap();
+
+        assert!(engine.set_explanation_template(
+            "es".to_string(),
+            "Modelo {model}: superficie {size} m2, estado {condition}/100 {unknown}".to_string(),
+        ).is_ok());
+        assert_eq!(engine.get_template_locales(), vec!["es".to_string()]);
+
+        let explanation = engine.explain_valuation(property_id, "test_model".to_string(), "es".to_string()).unwrap();
+        assert_eq!(
+            explanation,
+            format!("Modelo test_model: superficie {} m2, estado {}/100 {{unknown}}", features.size_sqm, features.condition_score)
+        );
+
+        // Unregistered locales fall back to the built-in English template
+        let fallback = engine.explain_valuation(property_id, "test_model".to_string(), "fr".to_string()).unwrap();
+        assert!(fallback.starts_with("Valuation based on test_model model"));
+
+        let accounts = default_accounts();
+        set_next_caller(accounts.bob);
+        assert_eq!(
+            engine.set_explanation_template("de".to_string(), "{model}".to_string()),
+            Err(AIValuationError::Unauthorized)
+        );
+    }
+
     #[ink::test]
     fn test_pause_resume_works() {
         let mut engine = setup_ai_engine();
@@ -359,6 +656,8 @@ mod tests {
             parent_version: None,
             training_data_hash: "hash123".to_string(),
             model_hash: "model_hash456".to_string(),
+            artifact_hash: [1u8; 32],
+            training_snapshot_hash: [2u8; 32],
             performance_metrics: ModelMetrics {
                 accuracy: 8500,
                 precision: 8200,
@@ -383,6 +682,158 @@ mod tests {
         assert_eq!(versions[0], version);
     }
 
+    #[ink::test]
+    fn test_inference_must_cite_current_artifact() {
+        let mut engine = setup_ai_engine();
+        let accounts = default_accounts();
+        let mut version = ModelVersion {
+            model_id: "test_model".to_string(),
+            version: 1,
+            parent_version: None,
+            training_data_hash: "hash123".to_string(),
+            model_hash: "model_hash456".to_string(),
+            artifact_hash: [1u8; 32],
+            training_snapshot_hash: [2u8; 32],
+            performance_metrics: ModelMetrics {
+                accuracy: 8500,
+                precision: 8200,
+                recall: 8800,
+                f1_score: 8500,
+                mae: 50000,
+                rmse: 75000,
+                r_squared: 7500,
+                bias_score: 500,
+                fairness_score: 9500,
+            },
+            deployment_status: DeploymentStatus::Production,
+            created_at: 1234567890,
+            deployed_at: None,
+            deprecated_at: None,
+        };
+        assert!(engine.add_model_version("test_model".to_string(), version.clone()).is_ok());
+        // Re-committing an existing artifact is rejected
+        assert_eq!(engine.add_model_version("test_model".to_string(), version.clone()), Err(AIValuationError::InvalidArtifact));
+
+        set_next_caller(accounts.bob);
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(10_000);
+        assert!(engine.register_inference_worker().is_ok());
+
+        let id = engine.submit_inference(1, "test_model".to_string(), [1u8; 32], 500_000).unwrap();
+        assert!(!engine.get_inference_submission(id).unwrap().stale);
+        assert_eq!(engine.submit_inference(1, "test_model".to_string(), [9u8; 32], 500_000), Err(AIValuationError::InvalidArtifact));
+
+        // A newer production artifact makes version 1 stale
+        set_next_caller(accounts.alice);
+        version.version = 2;
+        version.artifact_hash = [3u8; 32];
+        assert!(engine.add_model_version("test_model".to_string(), version).is_ok());
+
+        set_next_caller(accounts.bob);
+        let id = engine.submit_inference(1, "test_model".to_string(), [1u8; 32], 500_000).unwrap();
+        let submission = engine.get_inference_submission(id).unwrap();
+        assert!(submission.stale);
+        assert_eq!(submission.model_version, 1);
+        assert_eq!(engine.get_worker_bond(accounts.bob), 9_000);
+        assert_eq!(engine.get_artifact_commitment("test_model".to_string(), 2), Some(([3u8; 32], [2u8; 32])));
+    }
+
+    #[ink::test]
+    fn test_external_provider_predictions_join_ensemble() {
+        let mut engine = setup_ai_engine();
+        let accounts = default_accounts();
+        let mut model = create_sample_model();
+        model.model_id = "ext_avm".to_string();
+        model.weight = 50;
+        assert!(engine.register_external_provider(accounts.charlie, model.clone()).is_ok());
+        assert_eq!(engine.register_external_provider(accounts.charlie, model), Err(AIValuationError::InvalidModel));
+
+        let mut version = ModelVersion {
+            model_id: "ext_avm".to_string(),
+            version: 1,
+            parent_version: None,
+            training_data_hash: "hash123".to_string(),
+            model_hash: "model_hash456".to_string(),
+            artifact_hash: [7u8; 32],
+            training_snapshot_hash: [8u8; 32],
+            performance_metrics: ModelMetrics {
+                accuracy: 8500,
+                precision: 8200,
+                recall: 8800,
+                f1_score: 8500,
+                mae: 50000,
+                rmse: 75000,
+                r_squared: 7500,
+                bias_score: 500,
+                fairness_score: 9500,
+            },
+            deployment_status: DeploymentStatus::Production,
+            created_at: 1234567890,
+            deployed_at: None,
+            deprecated_at: None,
+        };
+        assert!(engine.add_model_version("ext_avm".to_string(), version.clone()).is_ok());
+
+        let prediction = AIPrediction {
+            predicted_value: 900_000,
+            confidence_score: 9000,
+            uncertainty_range: (850_000, 950_000),
+            model_id: "ext_avm".to_string(),
+            features_used: create_sample_features(),
+            bias_score: 500,
+            fairness_score: 9500,
+        };
+
+        // Unregistered callers and unbonded providers are rejected
+        set_next_caller(accounts.bob);
+        assert_eq!(engine.submit_external_prediction(1, prediction.clone(), [7u8; 32]), Err(AIValuationError::ProviderNotRegistered));
+        set_next_caller(accounts.charlie);
+        assert_eq!(engine.submit_external_prediction(1, prediction.clone(), [7u8; 32]), Err(AIValuationError::WorkerNotBonded));
+
+        test::set_value_transferred::<ink::env::DefaultEnvironment>(10_000);
+        assert!(engine.register_inference_worker().is_ok());
+        let mut foreign = prediction.clone();
+        foreign.model_id = "linear_reg_v1".to_string();
+        assert_eq!(engine.submit_external_prediction(1, foreign, [7u8; 32]), Err(AIValuationError::InvalidParameters));
+        assert!(engine.submit_external_prediction(1, prediction.clone(), [7u8; 32]).is_ok());
+        assert_eq!(engine.get_federated_prediction(1, accounts.charlie), Some(prediction.clone()));
+
+        let ensemble = engine.ensemble_predict(1).unwrap();
+        assert_eq!(ensemble.individual_predictions.len(), 1);
+        assert_eq!(ensemble.final_valuation, 900_000);
+
+        // Citing a superseded artifact slashes the provider like an inference worker
+        set_next_caller(accounts.alice);
+        version.version = 2;
+        version.artifact_hash = [9u8; 32];
+        assert!(engine.add_model_version("ext_avm".to_string(), version).is_ok());
+        set_next_caller(accounts.charlie);
+        let mut late = prediction.clone();
+        late.predicted_value = 920_000;
+        let id = engine.submit_external_prediction(2, late, [7u8; 32]).unwrap();
+        assert!(engine.get_inference_submission(id).unwrap().stale);
+        assert_eq!(engine.get_worker_bond(accounts.charlie), 9_000);
+        assert_eq!(engine.get_federated_prediction(2, accounts.charlie), None);
+        assert_eq!(engine.get_external_provider(accounts.charlie).unwrap().submission_count, 2);
+
+        // Suspended providers drop out of ensembles
+        set_next_caller(accounts.alice);
+        assert!(engine.set_external_provider_active(accounts.charlie, false).is_ok());
+        assert_eq!(engine.ensemble_predict(1), Err(AIValuationError::InsufficientData));
+    }
+
+    #[ink::test]
+    fn test_derive_risk_scores_from_features() {
+        let mut engine = setup_ai_engine();
+        assert_eq!(engine.derive_risk_scores(42), None);
+
+        let features = engine.extract_features(42).unwrap();
+        let scores = engine.derive_risk_scores(42).unwrap();
+        assert_eq!(scores.location_score, features.location_score / 10);
+        assert_eq!(scores.construction_score, features.condition_score);
+        assert_eq!(scores.age_score, 100 - features.age_years);
+        assert_eq!(scores.claims_history_score, (features.economic_indicators + 100) / 2);
+    }
+
     #[ink::test]
     fn test_ab_testing() {
         let mut engine = setup_ai_engine();
@@ -2,9 +2,47 @@
 #![allow(unexpected_cfgs)]
 #![allow(clippy::new_without_default)]
 
+use ink::prelude::collections::BTreeSet;
 use ink::prelude::string::String;
 use ink::prelude::vec::Vec;
 
+/// Fixed-point scale for forecast model coefficients
+const FORECAST_SCALE: i128 = 1_000_000;
+
+/// Upper bound on the nodes of a KPI expression
+const MAX_KPI_NODES: usize = 32;
+
+/// Milliseconds per day, for bucketing realized gains into calendar years
+const MS_PER_DAY: u64 = 86_400_000;
+
+/// Rows and counters per row of the hot-token count-min sketch
+const SKETCH_DEPTH: usize = 4;
+const SKETCH_WIDTH: usize = 64;
+
+/// Multipliers hashing a token into each sketch row
+const SKETCH_SEEDS: [u64; SKETCH_DEPTH] = [
+    0x9E37_79B9_7F4A_7C15,
+    0xC2B2_AE3D_27D4_EB4F,
+    0x1656_67B1_9E37_79F9,
+    0x85EB_CA77_C2B2_AE63,
+];
+
+/// Most records pruned from one stream per write, bounding the cost of catching up
+const MAX_PRUNE_BATCH: u64 = 32;
+
+/// Hottest tokens tracked alongside the sketch
+const HOT_TOKEN_SLOTS: usize = 5;
+
+/// Upper bounds on benchmark segments and the tokens in each
+const MAX_SEGMENTS: usize = 16;
+const MAX_SEGMENT_TOKENS: usize = 64;
+
+/// Upper bound on sibling deployments federated into the global view
+const MAX_FEDERATED_CHAINS: usize = 16;
+
+/// Tokens returned from each leaderboard
+const LEADERBOARD_SIZE: usize = 50;
+
 #[ink::contract]
 mod propchain_analytics {
     use super::*;
@@ -69,6 +107,766 @@ mod propchain_analytics {
         pub insights: String,
     }
 
+    /// Source-contract activity ingested for per-token return attribution.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum TokenActivity {
+        Trade {
+            price_per_share: u128,
+            amount: u128,
+            fee: u128,
+        },
+        Dividend {
+            per_share: u128,
+        },
+        /// Compensating entry cancelling an earlier record after an upheld challenge
+        Reversal {
+            record_index: u64,
+        },
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TokenActivityRecord {
+        pub timestamp: u64,
+        pub activity: TokenActivity,
+    }
+
+    /// Record folded into a token's integrity hash chain, in ingestion order.
+    /// Each link is `blake2x256(scale(previous_hash, record))`, starting from the zero hash.
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum IngestedRecord {
+        Activity(TokenActivityRecord),
+        Acquisition {
+            account: AccountId,
+            shares: u128,
+            cost_per_share: u128,
+            timestamp: u64,
+        },
+        Shares {
+            total_shares: u128,
+        },
+        Holders(Vec<(AccountId, u128)>),
+        Rent {
+            period_start: u64,
+            period_end: u64,
+            rent_due: u128,
+            rent_paid: u128,
+        },
+        RentArrears {
+            period_start: u64,
+            amount: u128,
+        },
+    }
+
+    /// Holder return over a period split into its components, in basis points of the start price.
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ReturnAttribution {
+        pub token_id: u64,
+        pub from: u64,
+        pub to: u64,
+        pub start_price: u128,
+        pub end_price: u128,
+        pub price_return_bps: i64,
+        pub income_return_bps: i64,
+        pub fee_drag_bps: i64,
+        pub total_return_bps: i64,
+    }
+
+    /// Outcome of a bonded provider's submission.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum SubmissionStatus {
+        Pending,
+        Challenged,
+        Dismissed,
+        Reverted,
+    }
+
+    /// Token activity pushed by a bonded data provider, challengeable for a window.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ProviderSubmission {
+        pub provider: AccountId,
+        pub token_id: u64,
+        pub record_index: u64,
+        pub submitted_at: u64,
+        pub status: SubmissionStatus,
+        pub challenger: Option<AccountId>,
+        pub evidence: Option<Hash>,
+        /// Bond posted by the challenger, returned if the challenge is upheld
+        pub bond: Balance,
+    }
+
+    /// How a record submitted under an idempotency key was handled.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum KeyedIngestion {
+        Recorded,
+        /// Already reported by a source of equal or higher precedence; dropped
+        Duplicate,
+        /// Replaced a lower-precedence report, which was reversed
+        Superseded,
+    }
+
+    /// Record currently counted for an idempotency key.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct KeyedRecord {
+        pub source: AccountId,
+        pub precedence: u32,
+        pub token_id: u64,
+        pub record_index: u64,
+        /// Lower-precedence reports this key has replaced
+        pub superseded: u32,
+    }
+
+    /// Stored data subject to a retention limit.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Dataset {
+        /// Ingested token activity, one stream per token
+        RawEvents,
+        /// Metric series buckets, one stream per `ForecastMetric`
+        Rollups,
+        /// Discrepancy reports, a single stream
+        Alerts,
+    }
+
+    /// (dataset, subject) identifying one prunable stream: the token id for raw
+    /// events, the metric's position in `ForecastMetric` for rollups, zero for alerts
+    pub type ArchiveStream = (Dataset, u64);
+
+    /// Platform operation whose latency is tracked for service-level reporting.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum OpsDomain {
+        BridgeRequest,
+        InsuranceClaim,
+    }
+
+    /// Lifecycle point reported by a source contract.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum LifecycleStage {
+        Opened,
+        Closed,
+    }
+
+    /// (domain, period, sample index)
+    pub type OpsSampleKey = (OpsDomain, u64, u32);
+
+    /// Latency distribution of an operation domain over one reporting period.
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct OpsMetrics {
+        pub domain: OpsDomain,
+        pub period: u64,
+        pub samples: u32,
+        pub median_latency: u64,
+        pub p95_latency: u64,
+        pub max_latency: u64,
+    }
+
+    /// Aggregate compared between analytics and a source contract.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ConsistencyMetric {
+        TotalSupply,
+        TokenShares,
+        ActivePolicies,
+        PoolCapital,
+    }
+
+    /// Mismatch between an ingested aggregate and the source contract's value.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct DiscrepancyReport {
+        pub metric: ConsistencyMetric,
+        /// Token or pool id; zero for contract-wide metrics
+        pub subject_id: u64,
+        pub ingested: u128,
+        pub observed: u128,
+        /// Drift relative to the observed value, in basis points
+        pub drift_bps: u32,
+        pub checked_at: u64,
+        pub alerted: bool,
+    }
+
+    /// Low-consensus valuation pushed by the AI valuation engine, pending manual appraisal.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ValuationDisagreement {
+        pub property_id: u64,
+        /// Agreement between models, in basis points
+        pub consensus_score: u32,
+        pub threshold: u32,
+        pub valuation: u128,
+        pub model_count: u32,
+        pub reported_at: u64,
+        pub appraised: bool,
+    }
+
+    /// Market metric recorded as a time series for forecasting.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ForecastMetric {
+        AveragePrice,
+        TotalVolume,
+        PropertiesListed,
+    }
+
+    /// Trend model fitted over the most recent buckets of a series.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum ForecastModel {
+        /// Least-squares line through the bucket values
+        Linear,
+        /// Exponentially weighted moving average; flat projection
+        Ewma,
+    }
+
+    /// One time-series bucket, captured on every metrics update.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SeriesPoint {
+        pub timestamp: u64,
+        pub value: u128,
+    }
+
+    /// Point estimate `horizon` buckets ahead with a naive confidence band.
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Forecast {
+        pub metric: ForecastMetric,
+        pub model: ForecastModel,
+        pub horizon: u32,
+        pub point: u128,
+        pub lower: u128,
+        pub upper: u128,
+        /// Buckets the model was fitted on
+        pub samples: u32,
+        pub generated_at: u64,
+    }
+
+    /// Holder concentration of a token, computed from an ingested holder snapshot.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ConcentrationMetrics {
+        pub token_id: u64,
+        pub holders: u32,
+        pub total_shares: u128,
+        /// Herfindahl-Hirschman index on a 0..=10_000 scale
+        pub hhi: u32,
+        /// Shares held by the ten largest holders, in basis points
+        pub top10_bps: u32,
+        pub largest_holder_bps: u32,
+        pub computed_at: u64,
+        /// Whether any configured threshold is exceeded
+        pub breached: bool,
+    }
+
+    /// Moving aggregates updated on every ingested record, in constant space.
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct StreamingMetrics {
+        pub records_ingested: u64,
+        /// EWMA of trade price per share, seeded by the first trade
+        pub ewma_price: u128,
+        /// EWMA of shares per trade
+        pub ewma_trade_size: u128,
+        /// EWMA of dividend paid per share
+        pub ewma_dividend: u128,
+        /// Most active tokens with their approximate record counts, hottest first
+        pub hot_tokens: Vec<(u64, u32)>,
+        pub updated_at: u64,
+    }
+
+    /// Concentration limits checked on every snapshot; zero disables a limit.
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ConcentrationThresholds {
+        pub max_hhi: u32,
+        pub max_top10_bps: u32,
+        /// E.g. 500 for the 5% beneficial ownership reporting line
+        pub max_holder_bps: u32,
+    }
+
+    /// One node of a KPI expression. Operands refer to earlier nodes by index;
+    /// the last node is the KPI's value.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum KpiNode {
+        Constant(i128),
+        /// Current value of a market metric
+        Metric(ForecastMetric),
+        /// Change of a metric between its two most recent buckets
+        Delta(ForecastMetric),
+        Sum(u32, u32),
+        Difference(u32, u32),
+        /// First operand over the second, in basis points
+        Ratio(u32, u32),
+    }
+
+    /// Admin-registered KPI evaluated over the dashboard's metrics.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct KpiDefinition {
+        pub name: String,
+        pub nodes: Vec<KpiNode>,
+        /// Re-evaluate and store the value on every metrics update
+        pub snapshot: bool,
+        pub active: bool,
+        pub created_at: u64,
+    }
+
+    /// KPI value captured at snapshot time.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct KpiValue {
+        pub value: i128,
+        pub evaluated_at: u64,
+    }
+
+    /// Open acquisition lot of a wallet's shares, consumed first-in first-out.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TaxLot {
+        pub acquired_at: u64,
+        pub shares: u128,
+        pub cost_per_share: u128,
+    }
+
+    /// Secondary-market trade with both parties, as settled by the marketplace
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SettledTrade {
+        pub token_id: u64,
+        pub seller: AccountId,
+        pub buyer: AccountId,
+        pub price_per_share: u128,
+        pub amount: u128,
+        pub fee: u128,
+        pub timestamp: u64,
+    }
+
+    /// (account, token)
+    pub type HoldingKey = (AccountId, u64);
+
+    /// (account, token, year)
+    pub type GainKey = (AccountId, u64, u32);
+
+    /// Disposals of one token by one wallet within a calendar year (UTC). Proceeds
+    /// are net of trading fees, matching the PropertyToken TaxRecord.
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct RealizedGainSummary {
+        pub year: u32,
+        pub shares_sold: u128,
+        pub proceeds: u128,
+        pub cost_basis: u128,
+        /// Shares sold beyond the tracked lots, assigned a zero basis
+        pub unmatched_shares: u128,
+        pub realized_gain: i128,
+    }
+
+    /// (segment, period)
+    pub type SegmentPeriodKey = (String, u64);
+
+    /// A segment's performance over one benchmark period against the external index.
+    /// Returns are in basis points; excess return compares like-for-like price returns.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct BenchmarkComparison {
+        pub segment: String,
+        pub period: u64,
+        pub benchmark_return_bps: i64,
+        pub segment_price_return_bps: i64,
+        pub segment_total_return_bps: i64,
+        pub excess_return_bps: i64,
+        /// Segment tokens with trade prices in the period
+        pub tokens_compared: u32,
+    }
+
+    /// Ranking a leaderboard orders tokens by, within the current period.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum LeaderboardKind {
+        /// Traded value (price times shares)
+        Volume,
+        /// Last trade price against the period's opening price, in basis points
+        PriceAppreciation,
+        /// Dividends per share against the last trade price, in basis points
+        DividendYield,
+    }
+
+    /// How often leaderboards start over; periods are aligned to the block clock.
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum LeaderboardPeriod {
+        #[default]
+        Daily,
+        Weekly,
+        /// 30 days
+        Monthly,
+    }
+
+    /// Every token scored for one ranking this period, best first.
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Leaderboard {
+        pub period_start: u64,
+        pub entries: Vec<(u64, i128)>,
+    }
+
+    /// A token's running figures for the current leaderboard period.
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TokenPeriodStats {
+        pub period_start: u64,
+        /// Activity index of the token's first record in the period
+        pub first_index: u64,
+        pub volume: u128,
+        /// Last price of the previous period, or the period's first trade
+        pub open_price: u128,
+        /// Carried across periods
+        pub last_price: u128,
+        pub dividends_per_share: u128,
+    }
+
+    /// Occupancy and collection totals inferred from rental payment records. A
+    /// period with rent due is occupied; gaps between rent periods are vacant.
+    #[derive(
+        Debug,
+        Default,
+        Clone,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct OccupancyStats {
+        /// Start of the earliest rent period seen (0 before the first record)
+        pub first_period_start: u64,
+        /// End of the latest rent period seen
+        pub covered_until: u64,
+        pub occupied_ms: u64,
+        pub vacant_ms: u64,
+        pub vacancy_periods: u32,
+        pub rent_due: u128,
+        pub rent_collected: u128,
+        pub payments: u64,
+    }
+
+    /// Occupancy and rent collection rates over a token, segment or the portfolio.
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct OccupancyReport {
+        pub stats: OccupancyStats,
+        /// Occupied share of the observed time, in basis points
+        pub occupancy_rate_bps: u32,
+        pub vacancy_rate_bps: u32,
+        /// Rent collected against rent due, in basis points
+        pub collection_ratio_bps: u32,
+    }
+
+    /// Market metrics a sibling deployment attests to, relayed from its chain.
+    /// The deployment's attester signs `federation_digest_hash(digest)`.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct FederatedDigest {
+        pub chain_id: u64,
+        /// Strictly increasing per chain, so relayed digests cannot be replayed
+        pub sequence: u64,
+        pub metrics: MarketMetrics,
+        /// When the source computed the metrics, in its timestamp unit
+        pub observed_at: u64,
+    }
+
+    /// One deployment's contribution to the federation-wide view.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ChainMetrics {
+        pub chain_id: u64,
+        pub metrics: MarketMetrics,
+        pub observed_at: u64,
+        /// When the metrics reached this contract (0 if never)
+        pub received_at: u64,
+    }
+
+    /// A sibling deployment registered for federation and its latest digest.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct FederatedSource {
+        /// Account of the attester key signing the deployment's digests
+        pub signer: AccountId,
+        pub sequence: u64,
+        pub latest: Option<ChainMetrics>,
+    }
+
+    /// Combined metrics across every deployment, with per-chain breakdown. The
+    /// combined average price is weighted by each chain's listed properties.
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct GlobalMetrics {
+        pub combined: MarketMetrics,
+        /// Local deployment first, then federated chains that have reported
+        pub sources: Vec<ChainMetrics>,
+    }
+
+    #[ink(event)]
+    pub struct FederatedMetricsReceived {
+        #[ink(topic)]
+        chain_id: u64,
+        sequence: u64,
+        relayer: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct BenchmarkPosted {
+        #[ink(topic)]
+        period: u64,
+        value: u128,
+        segments_compared: u32,
+    }
+
+    /// Emitted when a token's concentration first crosses a configured threshold.
+    #[ink(event)]
+    pub struct ConcentrationAlert {
+        #[ink(topic)]
+        token_id: u64,
+        hhi: u32,
+        top10_bps: u32,
+        largest_holder_bps: u32,
+    }
+
+    /// Emitted when an ingested aggregate drifts beyond tolerance.
+    #[ink(event)]
+    pub struct ConsistencyAlert {
+        #[ink(topic)]
+        metric: ConsistencyMetric,
+        #[ink(topic)]
+        subject_id: u64,
+        report_id: u64,
+        ingested: u128,
+        observed: u128,
+        drift_bps: u32,
+    }
+
+    /// Emitted when a higher-precedence source replaces a keyed record.
+    #[ink(event)]
+    pub struct KeyedRecordSuperseded {
+        #[ink(topic)]
+        key: Hash,
+        #[ink(topic)]
+        token_id: u64,
+        previous_source: AccountId,
+        source: AccountId,
+        reversed_index: u64,
+    }
+
+    /// Emitted for each batch pruned under a retention limit. `batch_hash` extends
+    /// `previous_hash` over the pruned records in index order, each link being
+    /// `blake2x256(scale(link, index, Option<record>))`, so an archive can replay its
+    /// copy of `[first_index, end_index)` and prove it is complete.
+    #[ink(event)]
+    pub struct ArchivalCheckpoint {
+        #[ink(topic)]
+        dataset: Dataset,
+        #[ink(topic)]
+        subject_id: u64,
+        first_index: u64,
+        end_index: u64,
+        previous_hash: Hash,
+        batch_hash: Hash,
+    }
+
+    #[ink(event)]
+    pub struct RentPaymentIngested {
+        #[ink(topic)]
+        token_id: u64,
+        period_start: u64,
+        period_end: u64,
+        rent_due: u128,
+        rent_paid: u128,
+        /// Vacant time between the previous rent period and this one
+        vacancy_ms: u64,
+    }
+
+    #[ink(event)]
+    pub struct RentArrearsIngested {
+        #[ink(topic)]
+        token_id: u64,
+        period_start: u64,
+        amount: u128,
+        /// Rent still owed across the token's recorded periods
+        outstanding: u128,
+    }
+
     #[ink(storage)]
     pub struct AnalyticsDashboard {
         /// Administrator of the analytics dashboard
@@ -79,6 +877,113 @@ mod propchain_analytics {
         historical_trends: ink::storage::Mapping<u64, MarketTrend>,
         /// Trend count
         trend_count: u64,
+        /// Ingested trades and dividends per token
+        token_activity: ink::storage::Mapping<(u64, u64), TokenActivityRecord>,
+        /// Activity record count per token
+        token_activity_count: ink::storage::Mapping<u64, u64>,
+        /// Open timestamp of in-flight operations
+        ops_opened: ink::storage::Mapping<(OpsDomain, u64), u64>,
+        /// Completed operation latencies per (domain, period)
+        ops_latencies: ink::storage::Mapping<OpsSampleKey, u64>,
+        ops_latency_count: ink::storage::Mapping<(OpsDomain, u64), u32>,
+        /// Length of a reporting period, in the timestamp unit of ingested events
+        ops_period_length: u64,
+        /// Funds bonded by data providers
+        provider_stakes: ink::storage::Mapping<AccountId, Balance>,
+        /// Provider stake locked until their last challenge window closes
+        provider_locked_until: ink::storage::Mapping<AccountId, u64>,
+        /// Unresolved challenges per provider
+        open_challenges: ink::storage::Mapping<AccountId, u32>,
+        provider_submissions: ink::storage::Mapping<u64, ProviderSubmission>,
+        submission_count: u64,
+        /// Minimum bond required to submit
+        min_provider_stake: Balance,
+        /// Milliseconds a submission stays challengeable
+        challenge_window: u64,
+        /// Share of stake slashed on an upheld challenge (basis points)
+        slash_bps: u32,
+        /// Bond a challenger posts, forfeited to the provider on dismissal
+        challenge_bond: Balance,
+        /// Contracts reconciled against ingested aggregates
+        property_token_source: Option<AccountId>,
+        insurance_source: Option<AccountId>,
+        /// Ingested share totals per token and state per insurance pool
+        ingested_token_shares: ink::storage::Mapping<u64, u128>,
+        ingested_pool_state: ink::storage::Mapping<u64, (u64, u128)>,
+        /// Drift above which a ConsistencyAlert is emitted (basis points)
+        drift_tolerance_bps: u32,
+        discrepancy_reports: ink::storage::Mapping<u64, DiscrepancyReport>,
+        discrepancy_count: u64,
+        /// Valuation engine allowed to push disagreement records
+        valuation_source: Option<AccountId>,
+        valuation_disagreements: ink::storage::Mapping<u64, ValuationDisagreement>,
+        disagreement_count: u64,
+        /// Latest disagreement record per property
+        property_disagreement: ink::storage::Mapping<u64, u64>,
+        /// Metric buckets appended on each metrics update
+        metric_series: ink::storage::Mapping<(ForecastMetric, u64), SeriesPoint>,
+        metric_series_len: ink::storage::Mapping<ForecastMetric, u64>,
+        /// Most recent buckets used to fit a forecast
+        forecast_window: u32,
+        forecast_model: ForecastModel,
+        /// EWMA smoothing factor (basis points)
+        ewma_alpha_bps: u32,
+        /// Latest concentration computed per token
+        token_concentration: ink::storage::Mapping<u64, ConcentrationMetrics>,
+        concentration_thresholds: ConcentrationThresholds,
+        /// Custom KPI registry and latest snapshot values
+        kpi_definitions: ink::storage::Mapping<u32, KpiDefinition>,
+        kpi_count: u32,
+        kpi_snapshots: ink::storage::Mapping<u32, KpiValue>,
+        /// Open FIFO lots per (account, token)
+        tax_lots: ink::storage::Mapping<HoldingKey, Vec<TaxLot>>,
+        /// Realized gains per (account, token, year)
+        realized_gains: ink::storage::Mapping<GainKey, RealizedGainSummary>,
+        /// Head of each token's hash chain over ingested records
+        integrity_hashes: ink::storage::Mapping<u64, Hash>,
+        /// Streaming aggregates over all ingested records
+        streaming_metrics: StreamingMetrics,
+        /// Count-min sketch of records per token, `SKETCH_DEPTH` rows of `SKETCH_WIDTH`
+        activity_sketch: Vec<u32>,
+        /// Oracle allowed to post external index values
+        benchmark_oracle: Option<AccountId>,
+        /// Length of a benchmark period, in the timestamp unit of ingested events
+        benchmark_period_length: u64,
+        /// External index value at the end of each period
+        benchmark_values: ink::storage::Mapping<u64, u128>,
+        /// Tokens making up each named segment
+        segments: ink::storage::Mapping<String, Vec<u64>>,
+        segment_names: Vec<String>,
+        benchmark_comparisons: ink::storage::Mapping<SegmentPeriodKey, BenchmarkComparison>,
+        /// Precedence of each source allowed to submit keyed records (higher wins)
+        source_precedence: ink::storage::Mapping<AccountId, u32>,
+        /// Record counted for each idempotency key
+        keyed_records: ink::storage::Mapping<Hash, KeyedRecord>,
+        duplicates_dropped: u64,
+        records_superseded: u64,
+        /// Records kept per stream of each dataset (absent = kept forever)
+        retention_limits: ink::storage::Mapping<Dataset, u64>,
+        /// First index still stored in each pruned stream
+        pruned_before: ink::storage::Mapping<ArchiveStream, u64>,
+        /// Latest archival checkpoint hash per stream
+        archive_heads: ink::storage::Mapping<ArchiveStream, Hash>,
+        /// Chain this deployment reports as in the federation-wide view
+        local_chain_id: u64,
+        /// When `current_metrics` was last updated
+        metrics_updated_at: u64,
+        /// Accounts allowed to relay digests from sibling deployments
+        federation_relayers: ink::storage::Mapping<AccountId, bool>,
+        federated_sources: ink::storage::Mapping<u64, FederatedSource>,
+        federated_chains: Vec<u64>,
+        leaderboard_period: LeaderboardPeriod,
+        leaderboards: ink::storage::Mapping<LeaderboardKind, Leaderboard>,
+        leaderboard_stats: ink::storage::Mapping<u64, TokenPeriodStats>,
+        /// Occupancy inferred from each token's rental payments
+        token_occupancy: ink::storage::Mapping<u64, OccupancyStats>,
+        /// Whether a token's latest rent period had no rent due
+        token_vacant: ink::storage::Mapping<u64, bool>,
+        /// Running totals over every token with rental payments
+        portfolio_occupancy: OccupancyStats,
     }
 
     impl AnalyticsDashboard {
@@ -94,6 +999,71 @@ mod propchain_analytics {
                 },
                 historical_trends: ink::storage::Mapping::default(),
                 trend_count: 0,
+                token_activity: ink::storage::Mapping::default(),
+                token_activity_count: ink::storage::Mapping::default(),
+                ops_opened: ink::storage::Mapping::default(),
+                ops_latencies: ink::storage::Mapping::default(),
+                ops_latency_count: ink::storage::Mapping::default(),
+                ops_period_length: 86_400_000, // one day in milliseconds
+                provider_stakes: ink::storage::Mapping::default(),
+                provider_locked_until: ink::storage::Mapping::default(),
+                open_challenges: ink::storage::Mapping::default(),
+                provider_submissions: ink::storage::Mapping::default(),
+                submission_count: 0,
+                min_provider_stake: 1,
+                challenge_window: 86_400_000, // one day in milliseconds
+                slash_bps: 5_000,
+                challenge_bond: 1,
+                property_token_source: None,
+                insurance_source: None,
+                ingested_token_shares: ink::storage::Mapping::default(),
+                ingested_pool_state: ink::storage::Mapping::default(),
+                drift_tolerance_bps: 100,
+                discrepancy_reports: ink::storage::Mapping::default(),
+                discrepancy_count: 0,
+                valuation_source: None,
+                valuation_disagreements: ink::storage::Mapping::default(),
+                disagreement_count: 0,
+                property_disagreement: ink::storage::Mapping::default(),
+                metric_series: ink::storage::Mapping::default(),
+                metric_series_len: ink::storage::Mapping::default(),
+                forecast_window: 12,
+                forecast_model: ForecastModel::Linear,
+                ewma_alpha_bps: 3_000,
+                token_concentration: ink::storage::Mapping::default(),
+                concentration_thresholds: ConcentrationThresholds::default(),
+                kpi_definitions: ink::storage::Mapping::default(),
+                kpi_count: 0,
+                kpi_snapshots: ink::storage::Mapping::default(),
+                tax_lots: ink::storage::Mapping::default(),
+                realized_gains: ink::storage::Mapping::default(),
+                integrity_hashes: ink::storage::Mapping::default(),
+                streaming_metrics: StreamingMetrics::default(),
+                activity_sketch: ink::prelude::vec![0; SKETCH_DEPTH * SKETCH_WIDTH],
+                benchmark_oracle: None,
+                benchmark_period_length: 30 * MS_PER_DAY,
+                benchmark_values: ink::storage::Mapping::default(),
+                segments: ink::storage::Mapping::default(),
+                segment_names: Vec::new(),
+                benchmark_comparisons: ink::storage::Mapping::default(),
+                source_precedence: ink::storage::Mapping::default(),
+                keyed_records: ink::storage::Mapping::default(),
+                duplicates_dropped: 0,
+                records_superseded: 0,
+                retention_limits: ink::storage::Mapping::default(),
+                pruned_before: ink::storage::Mapping::default(),
+                archive_heads: ink::storage::Mapping::default(),
+                local_chain_id: 0,
+                metrics_updated_at: 0,
+                federation_relayers: ink::storage::Mapping::default(),
+                federated_sources: ink::storage::Mapping::default(),
+                federated_chains: Vec::new(),
+                leaderboard_period: LeaderboardPeriod::default(),
+                leaderboards: ink::storage::Mapping::default(),
+                leaderboard_stats: ink::storage::Mapping::default(),
+                token_occupancy: ink::storage::Mapping::default(),
+                token_vacant: ink::storage::Mapping::default(),
+                portfolio_occupancy: OccupancyStats::default(),
             }
         }
 
@@ -116,6 +1086,11 @@ mod propchain_analytics {
                 total_volume,
                 properties_listed,
             };
+            self.metrics_updated_at = self.env().block_timestamp();
+            self.push_series(ForecastMetric::AveragePrice, average_price);
+            self.push_series(ForecastMetric::TotalVolume, total_volume);
+            self.push_series(ForecastMetric::PropertiesListed, properties_listed as u128);
+            self.snapshot_kpis();
         }
 
         /// Create market trend analysis with historical data
@@ -168,58 +1143,3125 @@ mod propchain_analytics {
             }
         }
 
-        /// Add gas usage optimization recommendations
+        /// Record a secondary-market trade for a property token
         #[ink(message)]
-        pub fn get_gas_optimization_recommendations(&self) -> String {
-            String::from("Use batched operations and limit nested looping over dynamic collections (e.g. vectors). Store large items in Mappings instead of Vecs.")
+        pub fn ingest_trade(
+            &mut self,
+            token_id: u64,
+            price_per_share: u128,
+            amount: u128,
+            fee: u128,
+            timestamp: u64,
+        ) {
+            self.ensure_admin();
+            self.push_activity(
+                token_id,
+                TokenActivityRecord {
+                    timestamp,
+                    activity: TokenActivity::Trade {
+                        price_per_share,
+                        amount,
+                        fee,
+                    },
+                },
+            );
         }
 
-        /// Ensure only the admin can modify metrics
-        fn ensure_admin(&self) {
-            assert_eq!(
-                self.env().caller(),
-                self.admin,
-                "Unauthorized: Analytics admin only"
+        /// Record a dividend distribution (amount per share) for a property token
+        #[ink(message)]
+        pub fn ingest_dividend(&mut self, token_id: u64, per_share: u128, timestamp: u64) {
+            self.ensure_admin();
+            self.push_activity(
+                token_id,
+                TokenActivityRecord {
+                    timestamp,
+                    activity: TokenActivity::Dividend { per_share },
+                },
             );
         }
-    }
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+        /// Rank a source contract for keyed ingestion (0 revokes it)
+        #[ink(message)]
+        pub fn set_source_precedence(&mut self, source: AccountId, precedence: u32) {
+            self.ensure_admin();
+            if precedence == 0 {
+                self.source_precedence.remove(source);
+            } else {
+                self.source_precedence.insert(source, &precedence);
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_source_precedence(&self, source: AccountId) -> u32 {
+            self.source_precedence.get(source).unwrap_or(0)
+        }
+
+        /// Record a trade or dividend reported under an idempotency key shared by
+        /// every contract that observes the same economic event. Repeats from a
+        /// source of equal or lower precedence are dropped; a higher-precedence
+        /// report arriving late reverses the counted record and replaces it.
+        #[ink(message)]
+        pub fn ingest_keyed_activity(
+            &mut self,
+            key: Hash,
+            token_id: u64,
+            activity: TokenActivity,
+            timestamp: u64,
+        ) -> KeyedIngestion {
+            let source = self.env().caller();
+            let precedence = self.source_precedence.get(source).unwrap_or(0);
+            assert!(precedence > 0, "Unregistered ingestion source");
+            assert!(
+                !matches!(activity, TokenActivity::Reversal { .. }),
+                "Reversals are not ingestible"
+            );
+            let existing = self.keyed_records.get(key);
+            if let Some(existing) = &existing {
+                if precedence <= existing.precedence {
+                    self.duplicates_dropped += 1;
+                    return KeyedIngestion::Duplicate;
+                }
+                let reversed_timestamp = self
+                    .token_activity
+                    .get((existing.token_id, existing.record_index))
+                    .map_or(timestamp, |record| record.timestamp);
+                self.push_activity(
+                    existing.token_id,
+                    TokenActivityRecord {
+                        timestamp: reversed_timestamp,
+                        activity: TokenActivity::Reversal {
+                            record_index: existing.record_index,
+                        },
+                    },
+                );
+                self.records_superseded += 1;
+                self.env().emit_event(KeyedRecordSuperseded {
+                    key,
+                    token_id,
+                    previous_source: existing.source,
+                    source,
+                    reversed_index: existing.record_index,
+                });
+            }
+            let record_index = self.token_activity_count.get(token_id).unwrap_or(0);
+            self.push_activity(
+                token_id,
+                TokenActivityRecord {
+                    timestamp,
+                    activity,
+                },
+            );
+            self.keyed_records.insert(
+                key,
+                &KeyedRecord {
+                    source,
+                    precedence,
+                    token_id,
+                    record_index,
+                    superseded: existing.as_ref().map_or(0, |e| e.superseded + 1),
+                },
+            );
+            if existing.is_some() {
+                KeyedIngestion::Superseded
+            } else {
+                KeyedIngestion::Recorded
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_keyed_record(&self, key: Hash) -> Option<KeyedRecord> {
+            self.keyed_records.get(key)
+        }
+
+        /// Keyed reports dropped as duplicates and records replaced by a
+        /// higher-precedence source
+        #[ink(message)]
+        pub fn get_dedup_counts(&self) -> (u64, u64) {
+            (self.duplicates_dropped, self.records_superseded)
+        }
+
+        /// Keep only the latest `keep_latest` records of each stream in a dataset
+        /// (0 keeps everything). Older records are pruned as new ones arrive.
+        #[ink(message)]
+        pub fn set_retention(&mut self, dataset: Dataset, keep_latest: u64) {
+            self.ensure_admin();
+            if keep_latest == 0 {
+                self.retention_limits.remove(dataset);
+            } else {
+                self.retention_limits.insert(dataset, &keep_latest);
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_retention(&self, dataset: Dataset) -> u64 {
+            self.retention_limits.get(dataset).unwrap_or(0)
+        }
+
+        /// Prune one batch from a stream that is over its limit, e.g. after the limit
+        /// was lowered. Callable by anyone; returns the number of records pruned.
+        #[ink(message)]
+        pub fn prune_stream(&mut self, dataset: Dataset, subject_id: u64) -> u64 {
+            let end = match dataset {
+                Dataset::RawEvents => self.token_activity_count.get(subject_id).unwrap_or(0),
+                Dataset::Rollups => {
+                    assert!(subject_id <= 2, "Unknown rollup series");
+                    self.metric_series_len
+                        .get(Self::series_metric(subject_id))
+                        .unwrap_or(0)
+                }
+                Dataset::Alerts => {
+                    assert_eq!(subject_id, 0, "Alerts form a single stream");
+                    self.discrepancy_count + 1
+                }
+            };
+            self.prune(dataset, subject_id, end)
+        }
+
+        /// First index still stored in a stream and the hash of its latest checkpoint
+        #[ink(message)]
+        pub fn get_archive_head(&self, dataset: Dataset, subject_id: u64) -> (u64, Hash) {
+            let stream = (dataset, subject_id);
+            (
+                self.pruned_before
+                    .get(stream)
+                    .unwrap_or(Self::first_index(dataset)),
+                self.archive_heads.get(stream).unwrap_or_default(),
+            )
+        }
+
+        /// Decompose a holder's return over [from, to] into price appreciation,
+        /// dividend income and fee drag. Returns None without a reference price.
+        #[ink(message)]
+        pub fn get_return_attribution(
+            &self,
+            token_id: u64,
+            from: u64,
+            to: u64,
+        ) -> Option<ReturnAttribution> {
+            if from > to {
+                return None;
+            }
+            let count = self.token_activity_count.get(token_id).unwrap_or(0);
+            let first = self
+                .pruned_before
+                .get((Dataset::RawEvents, token_id))
+                .unwrap_or(0);
+            let reversed: BTreeSet<u64> = (first..count)
+                .filter_map(|i| match self.token_activity.get((token_id, i))?.activity {
+                    TokenActivity::Reversal { record_index } => Some(record_index),
+                    _ => None,
+                })
+                .collect();
+            // (timestamp, price) of the latest trade at or before `from`, else the first in window
+            let mut start: Option<(u64, u128)> = None;
+            let mut first_in_window: Option<(u64, u128)> = None;
+            let mut end: Option<(u64, u128)> = None;
+            let mut income: u128 = 0;
+            let mut fees: u128 = 0;
+            let mut volume: u128 = 0;
+            for i in first..count {
+                let record = match self.token_activity.get((token_id, i)) {
+                    Some(record) if !reversed.contains(&i) => record,
+                    _ => continue,
+                };
+                let ts = record.timestamp;
+                match record.activity {
+                    TokenActivity::Trade {
+                        price_per_share,
+                        amount,
+                        fee,
+                    } => {
+                        if ts <= from && start.map_or(true, |(t, _)| ts >= t) {
+                            start = Some((ts, price_per_share));
+                        }
+                        if ts >= from && ts <= to && first_in_window.map_or(true, |(t, _)| ts < t) {
+                            first_in_window = Some((ts, price_per_share));
+                        }
+                        if ts <= to && end.map_or(true, |(t, _)| ts >= t) {
+                            end = Some((ts, price_per_share));
+                        }
+                        if ts > from && ts <= to {
+                            fees = fees.saturating_add(fee);
+                            volume = volume.saturating_add(amount);
+                        }
+                    }
+                    TokenActivity::Dividend { per_share } => {
+                        if ts > from && ts <= to {
+                            income = income.saturating_add(per_share);
+                        }
+                    }
+                    TokenActivity::Reversal { .. } => {}
+                }
+            }
+            let (_, start_price) = start.or(first_in_window)?;
+            let (_, end_price) = end?;
+            if start_price == 0 {
+                return None;
+            }
+            let fee_per_share = fees.checked_div(volume).unwrap_or(0);
+            let price_return_bps =
+                Self::to_bps(end_price as i128 - start_price as i128, start_price);
+            let income_return_bps = Self::to_bps(income as i128, start_price);
+            let fee_drag_bps = Self::to_bps(fee_per_share as i128, start_price);
+            Some(ReturnAttribution {
+                token_id,
+                from,
+                to,
+                start_price,
+                end_price,
+                price_return_bps,
+                income_return_bps,
+                fee_drag_bps,
+                total_return_bps: price_return_bps + income_return_bps - fee_drag_bps,
+            })
+        }
+
+        /// Record a lifecycle event for a bridge request or insurance claim. A close
+        /// is attributed to the period containing its timestamp.
+        #[ink(message)]
+        pub fn ingest_lifecycle_event(
+            &mut self,
+            domain: OpsDomain,
+            subject_id: u64,
+            stage: LifecycleStage,
+            timestamp: u64,
+        ) {
+            self.ensure_admin();
+            match stage {
+                LifecycleStage::Opened => {
+                    self.ops_opened.insert((domain, subject_id), &timestamp);
+                }
+                LifecycleStage::Closed => {
+                    let Some(opened) = self.ops_opened.take((domain, subject_id)) else {
+                        return;
+                    };
+                    let period = timestamp / self.ops_period_length;
+                    let index = self.ops_latency_count.get((domain, period)).unwrap_or(0);
+                    self.ops_latencies
+                        .insert((domain, period, index), &timestamp.saturating_sub(opened));
+                    self.ops_latency_count
+                        .insert((domain, period), &(index + 1));
+                }
+            }
+        }
+
+        /// Set the reporting period length; only affects events ingested afterwards
+        #[ink(message)]
+        pub fn set_ops_period_length(&mut self, period_length: u64) {
+            self.ensure_admin();
+            assert!(period_length > 0, "Period length must be positive");
+            self.ops_period_length = period_length;
+        }
+
+        #[ink(message)]
+        pub fn get_ops_period_length(&self) -> u64 {
+            self.ops_period_length
+        }
+
+        /// Median and 95th percentile (nearest-rank) latency for a domain and period
+        #[ink(message)]
+        pub fn get_ops_metrics(&self, domain: OpsDomain, period: u64) -> Option<OpsMetrics> {
+            let count = self.ops_latency_count.get((domain, period)).unwrap_or(0);
+            if count == 0 {
+                return None;
+            }
+            let mut latencies: Vec<u64> = (0..count)
+                .filter_map(|i| self.ops_latencies.get((domain, period, i)))
+                .collect();
+            latencies.sort_unstable();
+            let n = latencies.len();
+            let rank = |pct: usize| latencies[((n * pct + 99) / 100).max(1) - 1];
+            Some(OpsMetrics {
+                domain,
+                period,
+                samples: n as u32,
+                median_latency: rank(50),
+                p95_latency: rank(95),
+                max_latency: latencies[n - 1],
+            })
+        }
+
+        /// Bond (or top up) funds as a data provider
+        #[ink(message, payable)]
+        pub fn bond_provider(&mut self) {
+            let caller = self.env().caller();
+            let stake = self.provider_stakes.get(caller).unwrap_or(0);
+            self.provider_stakes.insert(
+                caller,
+                &stake.saturating_add(self.env().transferred_value()),
+            );
+        }
+
+        /// Withdraw bonded funds once no submission is challengeable or under challenge
+        #[ink(message)]
+        pub fn unbond_provider(&mut self, amount: Balance) {
+            let caller = self.env().caller();
+            let stake = self.provider_stakes.get(caller).unwrap_or(0);
+            assert!(amount <= stake, "Insufficient stake");
+            assert!(
+                self.open_challenges.get(caller).unwrap_or(0) == 0
+                    && self.env().block_timestamp()
+                        > self.provider_locked_until.get(caller).unwrap_or(0),
+                "Stake locked by open submissions"
+            );
+            self.provider_stakes.insert(caller, &(stake - amount));
+            self.env()
+                .transfer(caller, amount)
+                .expect("Stake transfer failed");
+        }
+
+        /// Set the minimum bond, challenge window and slash rate for providers
+        #[ink(message)]
+        pub fn set_provider_params(
+            &mut self,
+            min_stake: Balance,
+            challenge_window: u64,
+            slash_bps: u32,
+        ) {
+            self.ensure_admin();
+            assert!(slash_bps <= 10_000, "Slash rate above 100%");
+            self.min_provider_stake = min_stake;
+            self.challenge_window = challenge_window;
+            self.slash_bps = slash_bps;
+        }
+
+        /// Set the bond a challenger must post with each challenge
+        #[ink(message)]
+        pub fn set_challenge_bond(&mut self, bond: Balance) {
+            self.ensure_admin();
+            self.challenge_bond = bond;
+        }
+
+        #[ink(message)]
+        pub fn get_challenge_bond(&self) -> Balance {
+            self.challenge_bond
+        }
+
+        /// Record a trade from a bonded provider; returns the submission id
+        #[ink(message)]
+        pub fn submit_trade(
+            &mut self,
+            token_id: u64,
+            price_per_share: u128,
+            amount: u128,
+            fee: u128,
+            timestamp: u64,
+        ) -> u64 {
+            self.record_submission(
+                token_id,
+                TokenActivityRecord {
+                    timestamp,
+                    activity: TokenActivity::Trade {
+                        price_per_share,
+                        amount,
+                        fee,
+                    },
+                },
+            )
+        }
+
+        /// Record a dividend from a bonded provider; returns the submission id
+        #[ink(message)]
+        pub fn submit_dividend(&mut self, token_id: u64, per_share: u128, timestamp: u64) -> u64 {
+            self.record_submission(
+                token_id,
+                TokenActivityRecord {
+                    timestamp,
+                    activity: TokenActivity::Dividend { per_share },
+                },
+            )
+        }
+
+        /// Dispute a provider submission within its challenge window, posting the
+        /// challenge bond with the call
+        #[ink(message, payable)]
+        pub fn challenge_submission(&mut self, submission_id: u64, evidence: Hash) {
+            let bond = self.env().transferred_value();
+            assert!(bond >= self.challenge_bond, "Challenge bond required");
+            let mut submission = self
+                .provider_submissions
+                .get(submission_id)
+                .expect("Submission not found");
+            assert!(
+                submission.status == SubmissionStatus::Pending,
+                "Submission not challengeable"
+            );
+            assert!(
+                self.env().block_timestamp()
+                    <= submission
+                        .submitted_at
+                        .saturating_add(self.challenge_window),
+                "Challenge window closed"
+            );
+            submission.status = SubmissionStatus::Challenged;
+            submission.challenger = Some(self.env().caller());
+            submission.evidence = Some(evidence);
+            submission.bond = bond;
+            self.provider_submissions.insert(submission_id, &submission);
+            let open = self.open_challenges.get(submission.provider).unwrap_or(0);
+            self.open_challenges
+                .insert(submission.provider, &(open + 1));
+        }
+
+        /// Decide a challenge. Upheld challenges slash the provider in favour of the
+        /// challenger and append a compensating entry that cancels the record.
+        #[ink(message)]
+        pub fn resolve_challenge(&mut self, submission_id: u64, upheld: bool) {
+            self.ensure_admin();
+            let mut submission = self
+                .provider_submissions
+                .get(submission_id)
+                .expect("Submission not found");
+            assert!(
+                submission.status == SubmissionStatus::Challenged,
+                "Submission not under challenge"
+            );
+            let open = self.open_challenges.get(submission.provider).unwrap_or(0);
+            self.open_challenges
+                .insert(submission.provider, &open.saturating_sub(1));
+
+            if upheld {
+                submission.status = SubmissionStatus::Reverted;
+                let stake = self.provider_stakes.get(submission.provider).unwrap_or(0);
+                let slashed = stake.saturating_mul(self.slash_bps as u128) / 10_000;
+                self.provider_stakes
+                    .insert(submission.provider, &(stake - slashed));
+                let timestamp = self
+                    .token_activity
+                    .get((submission.token_id, submission.record_index))
+                    .map_or(0, |record| record.timestamp);
+                self.push_activity(
+                    submission.token_id,
+                    TokenActivityRecord {
+                        timestamp,
+                        activity: TokenActivity::Reversal {
+                            record_index: submission.record_index,
+                        },
+                    },
+                );
+                let payout = slashed.saturating_add(submission.bond);
+                if let Some(challenger) = submission.challenger {
+                    if payout > 0 {
+                        self.env()
+                            .transfer(challenger, payout)
+                            .expect("Slash payout failed");
+                    }
+                }
+            } else {
+                submission.status = SubmissionStatus::Dismissed;
+                // A frivolous challenge's bond compensates the provider as added stake
+                let stake = self.provider_stakes.get(submission.provider).unwrap_or(0);
+                self.provider_stakes
+                    .insert(submission.provider, &stake.saturating_add(submission.bond));
+            }
+            self.provider_submissions.insert(submission_id, &submission);
+        }
+
+        #[ink(message)]
+        pub fn get_provider_submission(&self, submission_id: u64) -> Option<ProviderSubmission> {
+            self.provider_submissions.get(submission_id)
+        }
+
+        #[ink(message)]
+        pub fn get_provider_stake(&self, provider: AccountId) -> Balance {
+            self.provider_stakes.get(provider).unwrap_or(0)
+        }
+
+        /// Point reconciliation at the property token and insurance contracts
+        #[ink(message)]
+        pub fn set_reconciliation_sources(
+            &mut self,
+            property_token: Option<AccountId>,
+            insurance: Option<AccountId>,
+            drift_tolerance_bps: u32,
+        ) {
+            self.ensure_admin();
+            self.property_token_source = property_token;
+            self.insurance_source = insurance;
+            self.drift_tolerance_bps = drift_tolerance_bps;
+        }
+
+        /// Record the share total analytics has derived for a token
+        #[ink(message)]
+        pub fn ingest_token_shares(&mut self, token_id: u64, total_shares: u128) {
+            self.ensure_admin();
+            self.ingested_token_shares.insert(token_id, &total_shares);
+            self.chain_record(token_id, &IngestedRecord::Shares { total_shares });
+        }
+
+        /// Record the active policy count and capital analytics has derived for a pool
+        #[ink(message)]
+        pub fn ingest_pool_state(&mut self, pool_id: u64, active_policies: u64, capital: u128) {
+            self.ensure_admin();
+            self.ingested_pool_state
+                .insert(pool_id, &(active_policies, capital));
+        }
+
+        /// Compare token supply and a token's shares with the property token
+        /// contract; returns the number of discrepancies recorded
+        #[ink(message)]
+        pub fn reconcile_token(&mut self, token_id: u64) -> u32 {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::TokenAggregates;
+            let source = self
+                .property_token_source
+                .expect("Property token source not set");
+            let token: ink::contract_ref!(TokenAggregates) = FromAccountId::from_account_id(source);
+            let supply = token
+                .call()
+                .aggregate_total_supply()
+                .try_invoke()
+                .expect("Property token unreachable")
+                .expect("Property token call reverted");
+            let shares = token
+                .call()
+                .aggregate_total_shares(token_id)
+                .try_invoke()
+                .expect("Property token unreachable")
+                .expect("Property token call reverted");
+
+            let ingested_supply = self.current_metrics.properties_listed as u128;
+            let ingested_shares = self.ingested_token_shares.get(token_id).unwrap_or(0);
+            [
+                self.record_consistency(
+                    ConsistencyMetric::TotalSupply,
+                    0,
+                    ingested_supply,
+                    supply as u128,
+                ),
+                self.record_consistency(
+                    ConsistencyMetric::TokenShares,
+                    token_id,
+                    ingested_shares,
+                    shares,
+                ),
+            ]
+            .iter()
+            .filter(|found| **found)
+            .count() as u32
+        }
+
+        /// Compare a pool's active policies and capital with the insurance contract;
+        /// returns the number of discrepancies recorded
+        #[ink(message)]
+        pub fn reconcile_pool(&mut self, pool_id: u64) -> u32 {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::PoolAggregates;
+            let source = self.insurance_source.expect("Insurance source not set");
+            let insurance: ink::contract_ref!(PoolAggregates) =
+                FromAccountId::from_account_id(source);
+            let policies = insurance
+                .call()
+                .aggregate_active_policies(pool_id)
+                .try_invoke()
+                .expect("Insurance unreachable")
+                .expect("Insurance call reverted");
+            let capital = insurance
+                .call()
+                .aggregate_pool_capital(pool_id)
+                .try_invoke()
+                .expect("Insurance unreachable")
+                .expect("Insurance call reverted");
+
+            let (ingested_policies, ingested_capital) =
+                self.ingested_pool_state.get(pool_id).unwrap_or((0, 0));
+            [
+                self.record_consistency(
+                    ConsistencyMetric::ActivePolicies,
+                    pool_id,
+                    ingested_policies as u128,
+                    policies as u128,
+                ),
+                self.record_consistency(
+                    ConsistencyMetric::PoolCapital,
+                    pool_id,
+                    ingested_capital,
+                    capital,
+                ),
+            ]
+            .iter()
+            .filter(|found| **found)
+            .count() as u32
+        }
+
+        #[ink(message)]
+        pub fn get_discrepancy_report(&self, report_id: u64) -> Option<DiscrepancyReport> {
+            self.discrepancy_reports.get(report_id)
+        }
+
+        #[ink(message)]
+        pub fn get_discrepancy_count(&self) -> u64 {
+            self.discrepancy_count
+        }
+
+        /// Allow the AI valuation engine to push disagreement records
+        #[ink(message)]
+        pub fn set_valuation_source(&mut self, source: Option<AccountId>) {
+            self.ensure_admin();
+            self.valuation_source = source;
+        }
+
+        /// Mark a disagreement as settled by a manual appraisal
+        #[ink(message)]
+        pub fn mark_disagreement_appraised(&mut self, record_id: u64) {
+            self.ensure_admin();
+            let mut record = self
+                .valuation_disagreements
+                .get(record_id)
+                .expect("Disagreement not found");
+            record.appraised = true;
+            self.valuation_disagreements.insert(record_id, &record);
+        }
+
+        #[ink(message)]
+        pub fn get_valuation_disagreement(&self, record_id: u64) -> Option<ValuationDisagreement> {
+            self.valuation_disagreements.get(record_id)
+        }
+
+        /// Latest disagreement recorded for a property
+        #[ink(message)]
+        pub fn get_property_disagreement(&self, property_id: u64) -> Option<ValuationDisagreement> {
+            self.property_disagreement
+                .get(property_id)
+                .and_then(|record_id| self.valuation_disagreements.get(record_id))
+        }
+
+        #[ink(message)]
+        pub fn get_disagreement_count(&self) -> u64 {
+            self.disagreement_count
+        }
+
+        /// Configure the forecasting model, fit window and EWMA smoothing
+        #[ink(message)]
+        pub fn set_forecast_params(&mut self, model: ForecastModel, window: u32, alpha_bps: u32) {
+            self.ensure_admin();
+            assert!(window >= 2, "Forecast window too small");
+            assert!(
+                alpha_bps > 0 && alpha_bps <= 10_000,
+                "Smoothing factor out of range"
+            );
+            self.forecast_model = model;
+            self.forecast_window = window;
+            self.ewma_alpha_bps = alpha_bps;
+        }
+
+        #[ink(message)]
+        pub fn get_series_length(&self, metric: ForecastMetric) -> u64 {
+            self.metric_series_len.get(metric).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn get_series_point(&self, metric: ForecastMetric, index: u64) -> Option<SeriesPoint> {
+            self.metric_series.get((metric, index))
+        }
+
+        /// Forecast `metric` `horizon` buckets past the latest one. The band is
+        /// twice the mean absolute in-sample error, widened linearly with the
+        /// horizon. Returns None until at least two buckets exist.
+        #[ink(message)]
+        pub fn forecast(&self, metric: ForecastMetric, horizon: u32) -> Option<Forecast> {
+            let len = self.metric_series_len.get(metric).unwrap_or(0);
+            let samples = len.min(self.forecast_window as u64);
+            if samples < 2 {
+                return None;
+            }
+            let values: Vec<i128> = (len - samples..len)
+                .filter_map(|index| self.metric_series.get((metric, index)))
+                .map(|point| point.value.min(i128::MAX as u128) as i128)
+                .collect();
+            let (point, mean_error) = match self.forecast_model {
+                ForecastModel::Linear => Self::fit_linear(&values, horizon),
+                ForecastModel::Ewma => Self::fit_ewma(&values, self.ewma_alpha_bps),
+            };
+            let n = values.len() as i128;
+            let band = mean_error
+                .saturating_mul(2)
+                .saturating_mul(n.saturating_add(horizon as i128))
+                / n;
+            Some(Forecast {
+                metric,
+                model: self.forecast_model,
+                horizon,
+                point: point.max(0) as u128,
+                lower: point.saturating_sub(band).max(0) as u128,
+                upper: point.saturating_add(band).max(0) as u128,
+                samples: values.len() as u32,
+                generated_at: self.env().block_timestamp(),
+            })
+        }
+
+        /// Configure the concentration limits that trigger a ConcentrationAlert
+        #[ink(message)]
+        pub fn set_concentration_thresholds(&mut self, thresholds: ConcentrationThresholds) {
+            self.ensure_admin();
+            assert!(
+                thresholds.max_hhi <= 10_000
+                    && thresholds.max_top10_bps <= 10_000
+                    && thresholds.max_holder_bps <= 10_000,
+                "Threshold out of range"
+            );
+            self.concentration_thresholds = thresholds;
+        }
+
+        #[ink(message)]
+        pub fn get_concentration_thresholds(&self) -> ConcentrationThresholds {
+            self.concentration_thresholds.clone()
+        }
+
+        /// Ingest a token's holder balances and recompute its concentration.
+        /// Alerts when a threshold is crossed that the previous snapshot was under.
+        #[ink(message)]
+        pub fn ingest_holder_snapshot(
+            &mut self,
+            token_id: u64,
+            balances: Vec<(AccountId, u128)>,
+        ) -> ConcentrationMetrics {
+            self.ensure_admin();
+            self.chain_record(token_id, &IngestedRecord::Holders(balances.clone()));
+            // An account listed more than once is still a single holder
+            let mut merged: Vec<(AccountId, u128)> = Vec::new();
+            for (account, balance) in balances {
+                match merged.iter_mut().find(|(holder, _)| *holder == account) {
+                    Some((_, total)) => *total = total.saturating_add(balance),
+                    None => merged.push((account, balance)),
+                }
+            }
+            let mut shares: Vec<u128> = merged
+                .into_iter()
+                .map(|(_, balance)| balance)
+                .filter(|balance| *balance > 0)
+                .collect();
+            shares.sort_unstable_by(|a, b| b.cmp(a));
+            let total: u128 = shares.iter().fold(0, |acc, s| acc.saturating_add(*s));
+            let share_bps = |amount: u128| -> u32 {
+                amount
+                    .saturating_mul(10_000)
+                    .checked_div(total)
+                    .unwrap_or(0) as u32
+            };
+            let hhi = shares
+                .iter()
+                .map(|s| {
+                    let bps = share_bps(*s) as u128;
+                    bps * bps
+                })
+                .sum::<u128>()
+                / 10_000;
+            let top10: u128 = shares
+                .iter()
+                .take(10)
+                .fold(0, |acc, s| acc.saturating_add(*s));
+
+            let thresholds = &self.concentration_thresholds;
+            let mut metrics = ConcentrationMetrics {
+                token_id,
+                holders: shares.len() as u32,
+                total_shares: total,
+                hhi: hhi as u32,
+                top10_bps: share_bps(top10),
+                largest_holder_bps: shares.first().map_or(0, |s| share_bps(*s)),
+                computed_at: self.env().block_timestamp(),
+                breached: false,
+            };
+            metrics.breached = (thresholds.max_hhi > 0 && metrics.hhi > thresholds.max_hhi)
+                || (thresholds.max_top10_bps > 0 && metrics.top10_bps > thresholds.max_top10_bps)
+                || (thresholds.max_holder_bps > 0
+                    && metrics.largest_holder_bps > thresholds.max_holder_bps);
+
+            let was_breached = self
+                .token_concentration
+                .get(token_id)
+                .is_some_and(|previous| previous.breached);
+            if metrics.breached && !was_breached {
+                self.env().emit_event(ConcentrationAlert {
+                    token_id,
+                    hhi: metrics.hhi,
+                    top10_bps: metrics.top10_bps,
+                    largest_holder_bps: metrics.largest_holder_bps,
+                });
+            }
+            self.token_concentration.insert(token_id, &metrics);
+            metrics
+        }
+
+        /// Latest concentration metrics for a token
+        #[ink(message)]
+        pub fn get_concentration(&self, token_id: u64) -> Option<ConcentrationMetrics> {
+            self.token_concentration.get(token_id)
+        }
+
+        /// Register a KPI expression; returns its id
+        #[ink(message)]
+        pub fn register_kpi(&mut self, name: String, nodes: Vec<KpiNode>, snapshot: bool) -> u32 {
+            self.ensure_admin();
+            assert!(
+                !nodes.is_empty() && nodes.len() <= MAX_KPI_NODES,
+                "KPI expression size out of range"
+            );
+            for (index, node) in nodes.iter().enumerate() {
+                if let KpiNode::Sum(a, b) | KpiNode::Difference(a, b) | KpiNode::Ratio(a, b) = node
+                {
+                    assert!(
+                        (*a as usize) < index && (*b as usize) < index,
+                        "KPI operand must refer to an earlier node"
+                    );
+                }
+            }
+            let kpi_id = self.kpi_count;
+            self.kpi_definitions.insert(
+                kpi_id,
+                &KpiDefinition {
+                    name,
+                    nodes,
+                    snapshot,
+                    active: true,
+                    created_at: self.env().block_timestamp(),
+                },
+            );
+            self.kpi_count += 1;
+            kpi_id
+        }
+
+        /// Stop evaluating a KPI; its last snapshot is kept
+        #[ink(message)]
+        pub fn retire_kpi(&mut self, kpi_id: u32) {
+            self.ensure_admin();
+            let mut definition = self.kpi_definitions.get(kpi_id).expect("KPI not found");
+            definition.active = false;
+            self.kpi_definitions.insert(kpi_id, &definition);
+        }
+
+        #[ink(message)]
+        pub fn get_kpi_definition(&self, kpi_id: u32) -> Option<KpiDefinition> {
+            self.kpi_definitions.get(kpi_id)
+        }
+
+        #[ink(message)]
+        pub fn get_kpi_count(&self) -> u32 {
+            self.kpi_count
+        }
+
+        /// Evaluate an active KPI against current metrics. None when a ratio
+        /// divides by zero, a delta lacks two buckets, or arithmetic overflows.
+        #[ink(message)]
+        pub fn evaluate_kpi(&self, kpi_id: u32) -> Option<i128> {
+            let definition = self.kpi_definitions.get(kpi_id)?;
+            if !definition.active {
+                return None;
+            }
+            self.evaluate_nodes(&definition.nodes)
+        }
+
+        /// Value stored at the most recent metrics update
+        #[ink(message)]
+        pub fn get_kpi_snapshot(&self, kpi_id: u32) -> Option<KpiValue> {
+            self.kpi_snapshots.get(kpi_id)
+        }
+
+        /// Record a settled trade with its parties: feeds market activity like
+        /// `ingest_trade`, opens a lot for the buyer and realizes the seller's gain
+        #[ink(message)]
+        pub fn ingest_settled_trade(&mut self, trade: SettledTrade) {
+            let SettledTrade {
+                token_id,
+                seller,
+                buyer,
+                price_per_share,
+                amount,
+                fee,
+                timestamp,
+            } = trade;
+            self.ingest_trade(token_id, price_per_share, amount, fee, timestamp);
+            let cost = price_per_share.saturating_mul(amount);
+            self.dispose_lots(
+                seller,
+                token_id,
+                amount,
+                cost.saturating_sub(fee),
+                timestamp,
+            );
+            self.open_lot(buyer, token_id, amount, price_per_share, timestamp);
+        }
+
+        /// Record shares acquired outside the secondary market (primary issuance,
+        /// transfers in) with their cost basis
+        #[ink(message)]
+        pub fn ingest_acquisition(
+            &mut self,
+            account: AccountId,
+            token_id: u64,
+            shares: u128,
+            cost_per_share: u128,
+            timestamp: u64,
+        ) {
+            self.ensure_admin();
+            self.open_lot(account, token_id, shares, cost_per_share, timestamp);
+            self.chain_record(
+                token_id,
+                &IngestedRecord::Acquisition {
+                    account,
+                    shares,
+                    cost_per_share,
+                    timestamp,
+                },
+            );
+        }
+
+        /// Open lots of `account` in `token_id`, oldest first
+        #[ink(message)]
+        pub fn get_tax_lots(&self, account: AccountId, token_id: u64) -> Vec<TaxLot> {
+            self.tax_lots.get((account, token_id)).unwrap_or_default()
+        }
+
+        /// Realized gains of `account` in `token_id` for calendar `year`
+        #[ink(message)]
+        pub fn get_realized_gains(
+            &self,
+            account: AccountId,
+            token_id: u64,
+            year: u32,
+        ) -> RealizedGainSummary {
+            self.realized_gains
+                .get((account, token_id, year))
+                .unwrap_or(RealizedGainSummary {
+                    year,
+                    ..Default::default()
+                })
+        }
+
+        /// Head of the token's hash chain over every record ingested for it. Anyone
+        /// replaying the source contracts' events can recompute it to prove the
+        /// dashboard holds exactly those records; the zero hash means none yet.
+        #[ink(message)]
+        pub fn get_integrity_hash(&self, token_id: u64) -> Hash {
+            self.integrity_hashes.get(token_id).unwrap_or_default()
+        }
+
+        /// Moving price, trade size and dividend averages plus the hottest tokens,
+        /// smoothed with the forecast EWMA factor
+        #[ink(message)]
+        pub fn get_streaming_metrics(&self) -> StreamingMetrics {
+            self.streaming_metrics.clone()
+        }
+
+        /// Choose how often leaderboards reset
+        #[ink(message)]
+        pub fn set_leaderboard_period(&mut self, period: LeaderboardPeriod) {
+            self.ensure_admin();
+            self.leaderboard_period = period;
+        }
+
+        #[ink(message)]
+        pub fn get_leaderboard_period(&self) -> LeaderboardPeriod {
+            self.leaderboard_period
+        }
+
+        /// Up to `limit` (token, score) pairs of the current period, best first,
+        /// capped at `LEADERBOARD_SIZE`. Boards are maintained on ingestion; one not
+        /// yet touched this period is empty.
+        #[ink(message)]
+        pub fn get_leaderboard(&self, kind: LeaderboardKind, limit: u32) -> Vec<(u64, i128)> {
+            match self.leaderboards.get(kind) {
+                Some(board) if board.period_start == self.leaderboard_period_start() => board
+                    .entries
+                    .into_iter()
+                    .take((limit as usize).min(LEADERBOARD_SIZE))
+                    .collect(),
+                _ => Vec::new(),
+            }
+        }
+
+        /// Approximate records ingested for a token; never undercounts
+        #[ink(message)]
+        pub fn estimate_token_activity(&self, token_id: u64) -> u32 {
+            (0..SKETCH_DEPTH)
+                .map(|row| self.activity_sketch[Self::sketch_index(row, token_id)])
+                .min()
+                .unwrap_or(0)
+        }
+
+        /// Allow an oracle to post external index values
+        #[ink(message)]
+        pub fn set_benchmark_oracle(&mut self, oracle: Option<AccountId>) {
+            self.ensure_admin();
+            self.benchmark_oracle = oracle;
+        }
+
+        /// Set the benchmark period length; comparisons already stored are kept
+        #[ink(message)]
+        pub fn set_benchmark_period_length(&mut self, period_length: u64) {
+            self.ensure_admin();
+            assert!(period_length > 0, "Period length must be positive");
+            self.benchmark_period_length = period_length;
+        }
+
+        /// Define (or redefine) the tokens a named segment is compared over
+        #[ink(message)]
+        pub fn define_segment(&mut self, segment: String, token_ids: Vec<u64>) {
+            self.ensure_admin();
+            assert!(
+                !token_ids.is_empty() && token_ids.len() <= MAX_SEGMENT_TOKENS,
+                "Invalid segment size"
+            );
+            if !self.segments.contains(&segment) {
+                assert!(self.segment_names.len() < MAX_SEGMENTS, "Too many segments");
+                self.segment_names.push(segment.clone());
+            }
+            self.segments.insert(&segment, &token_ids);
+        }
+
+        #[ink(message)]
+        pub fn get_segment(&self, segment: String) -> Vec<u64> {
+            self.segments.get(&segment).unwrap_or_default()
+        }
+
+        /// Post the external index value closing `period` and compare every segment
+        /// against it. A later period already posted is recompared as well.
+        #[ink(message)]
+        pub fn post_benchmark_value(&mut self, period: u64, value: u128) {
+            let caller = self.env().caller();
+            assert!(
+                caller == self.admin || Some(caller) == self.benchmark_oracle,
+                "Unauthorized: benchmark oracle only"
+            );
+            assert!(value > 0, "Index value must be positive");
+            self.benchmark_values.insert(period, &value);
+            let mut segments_compared = self.compare_segments(period);
+            if self.benchmark_values.contains(period + 1) {
+                segments_compared += self.compare_segments(period + 1);
+            }
+            self.env().emit_event(BenchmarkPosted {
+                period,
+                value,
+                segments_compared,
+            });
+        }
+
+        #[ink(message)]
+        pub fn get_benchmark_value(&self, period: u64) -> Option<u128> {
+            self.benchmark_values.get(period)
+        }
+
+        /// Relative performance of a segment against the benchmark over a period
+        #[ink(message)]
+        pub fn get_benchmark_comparison(
+            &self,
+            segment: String,
+            period: u64,
+        ) -> Option<BenchmarkComparison> {
+            self.benchmark_comparisons.get((segment, period))
+        }
+
+        /// Record a rental payment covering `[period_start, period_end)` for a
+        /// property token. Periods must arrive in order; overlap with an earlier
+        /// period counts as occupied once, and a gap since the last one as vacant.
+        /// Late payments for an earlier period go through `ingest_rent_arrears`.
+        #[ink(message)]
+        pub fn ingest_rent_payment(
+            &mut self,
+            token_id: u64,
+            period_start: u64,
+            period_end: u64,
+            rent_due: u128,
+            rent_paid: u128,
+        ) {
+            self.ensure_admin();
+            assert!(period_end > period_start, "Invalid rent period");
+            let mut stats = self.token_occupancy.get(token_id).unwrap_or_default();
+            assert!(
+                stats.payments == 0 || period_end > stats.covered_until,
+                "Rent period already recorded"
+            );
+            let vacancy_ms = if stats.payments == 0 {
+                0
+            } else {
+                period_start.saturating_sub(stats.covered_until)
+            };
+            let span = period_end - period_start.max(stats.covered_until);
+            // No rent owed: the period itself was vacant
+            let (occupied_ms, vacant_ms) = if rent_due > 0 {
+                (span, vacancy_ms)
+            } else {
+                (0, vacancy_ms + span)
+            };
+            let was_vacant = self.token_vacant.get(token_id).unwrap_or(false);
+            let opens_vacancy = vacant_ms > 0 && (vacancy_ms > 0 || !was_vacant);
+            self.token_vacant.insert(token_id, &(rent_due == 0));
+            for totals in [&mut stats, &mut self.portfolio_occupancy] {
+                if totals.payments == 0 {
+                    totals.first_period_start = period_start;
+                }
+                totals.first_period_start = totals.first_period_start.min(period_start);
+                totals.covered_until = totals.covered_until.max(period_end);
+                totals.occupied_ms = totals.occupied_ms.saturating_add(occupied_ms);
+                totals.vacant_ms = totals.vacant_ms.saturating_add(vacant_ms);
+                totals.vacancy_periods += u32::from(opens_vacancy);
+                totals.rent_due = totals.rent_due.saturating_add(rent_due);
+                totals.rent_collected = totals.rent_collected.saturating_add(rent_paid);
+                totals.payments += 1;
+            }
+            self.token_occupancy.insert(token_id, &stats);
+            self.chain_record(
+                token_id,
+                &IngestedRecord::Rent {
+                    period_start,
+                    period_end,
+                    rent_due,
+                    rent_paid,
+                },
+            );
+            self.env().emit_event(RentPaymentIngested {
+                token_id,
+                period_start,
+                period_end,
+                rent_due,
+                rent_paid,
+                vacancy_ms,
+            });
+        }
+
+        /// Record late rent paid against an already recorded period starting at
+        /// `period_start`. Only collection is credited; occupancy is unchanged.
+        #[ink(message)]
+        pub fn ingest_rent_arrears(&mut self, token_id: u64, period_start: u64, amount: u128) {
+            self.ensure_admin();
+            let mut stats = self
+                .token_occupancy
+                .get(token_id)
+                .expect("No rent recorded for token");
+            assert!(
+                period_start >= stats.first_period_start && period_start < stats.covered_until,
+                "Rent period not recorded"
+            );
+            let outstanding = stats.rent_due.saturating_sub(stats.rent_collected);
+            assert!(
+                amount > 0 && amount <= outstanding,
+                "Arrears exceed outstanding rent"
+            );
+            for totals in [&mut stats, &mut self.portfolio_occupancy] {
+                totals.rent_collected = totals.rent_collected.saturating_add(amount);
+            }
+            self.token_occupancy.insert(token_id, &stats);
+            self.chain_record(
+                token_id,
+                &IngestedRecord::RentArrears {
+                    period_start,
+                    amount,
+                },
+            );
+            self.env().emit_event(RentArrearsIngested {
+                token_id,
+                period_start,
+                amount,
+                outstanding: outstanding - amount,
+            });
+        }
+
+        /// Occupancy and rent collection for one property token
+        #[ink(message)]
+        pub fn get_token_occupancy(&self, token_id: u64) -> Option<OccupancyReport> {
+            self.token_occupancy
+                .get(token_id)
+                .map(Self::occupancy_report)
+        }
+
+        /// Occupancy and rent collection summed over a segment's tokens
+        #[ink(message)]
+        pub fn get_segment_occupancy(&self, segment: String) -> Option<OccupancyReport> {
+            let token_ids = self.segments.get(&segment)?;
+            let mut totals = OccupancyStats::default();
+            for stats in token_ids
+                .iter()
+                .filter_map(|token_id| self.token_occupancy.get(token_id))
+            {
+                totals.first_period_start = if totals.payments == 0 {
+                    stats.first_period_start
+                } else {
+                    totals.first_period_start.min(stats.first_period_start)
+                };
+                totals.covered_until = totals.covered_until.max(stats.covered_until);
+                totals.occupied_ms = totals.occupied_ms.saturating_add(stats.occupied_ms);
+                totals.vacant_ms = totals.vacant_ms.saturating_add(stats.vacant_ms);
+                totals.vacancy_periods += stats.vacancy_periods;
+                totals.rent_due = totals.rent_due.saturating_add(stats.rent_due);
+                totals.rent_collected = totals.rent_collected.saturating_add(stats.rent_collected);
+                totals.payments += stats.payments;
+            }
+            Some(Self::occupancy_report(totals))
+        }
+
+        /// Occupancy and rent collection across every token with rental payments
+        #[ink(message)]
+        pub fn get_portfolio_occupancy(&self) -> OccupancyReport {
+            Self::occupancy_report(self.portfolio_occupancy.clone())
+        }
+
+        /// Set the chain this deployment reports as in the federation
+        #[ink(message)]
+        pub fn set_local_chain_id(&mut self, chain_id: u64) {
+            self.ensure_admin();
+            assert!(
+                !self.federated_chains.contains(&chain_id),
+                "Chain is registered as a federated source"
+            );
+            self.local_chain_id = chain_id;
+        }
+
+        #[ink(message)]
+        pub fn set_federation_relayer(&mut self, relayer: AccountId, authorized: bool) {
+            self.ensure_admin();
+            if authorized {
+                self.federation_relayers.insert(relayer, &true);
+            } else {
+                self.federation_relayers.remove(relayer);
+            }
+        }
+
+        /// Register a sibling deployment, or rotate its attester key
+        #[ink(message)]
+        pub fn register_federated_chain(&mut self, chain_id: u64, signer: AccountId) {
+            self.ensure_admin();
+            assert!(
+                chain_id != self.local_chain_id,
+                "Cannot federate the local chain"
+            );
+            let source = match self.federated_sources.get(chain_id) {
+                Some(source) => FederatedSource { signer, ..source },
+                None => {
+                    assert!(
+                        self.federated_chains.len() < MAX_FEDERATED_CHAINS,
+                        "Too many federated chains"
+                    );
+                    self.federated_chains.push(chain_id);
+                    FederatedSource {
+                        signer,
+                        sequence: 0,
+                        latest: None,
+                    }
+                }
+            };
+            self.federated_sources.insert(chain_id, &source);
+        }
+
+        /// Message hash a sibling deployment's attester signs for `digest`. Binding
+        /// this contract's account keeps digests from being replayed elsewhere.
+        #[ink(message)]
+        pub fn federation_digest_hash(&self, digest: FederatedDigest) -> [u8; 32] {
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(self.env().account_id(), digest),
+                &mut output,
+            );
+            output
+        }
+
+        /// Record a sibling deployment's metrics (authorized relayers only). The
+        /// signature is a 65-byte recoverable ECDSA signature by the chain's attester,
+        /// whose account is the Blake2x256 hash of the compressed public key.
+        #[ink(message)]
+        pub fn post_federated_metrics(&mut self, digest: FederatedDigest, signature: [u8; 65]) {
+            let relayer = self.env().caller();
+            assert!(
+                self.federation_relayers.get(relayer).unwrap_or(false),
+                "Unauthorized: federation relayer only"
+            );
+            let source = self
+                .federated_sources
+                .get(digest.chain_id)
+                .expect("Unknown federated chain");
+            let message = self.federation_digest_hash(digest.clone());
+            let public_key = self
+                .env()
+                .ecdsa_recover(&signature, &message)
+                .expect("Invalid digest signature");
+            let mut signer = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&public_key, &mut signer);
+            assert!(
+                AccountId::from(signer) == source.signer,
+                "Invalid digest signature"
+            );
+            self.apply_federated_digest(source, digest, relayer);
+        }
+
+        /// Federation-wide metrics with the freshness of every contributing source
+        #[ink(message)]
+        pub fn get_global_metrics(&self) -> GlobalMetrics {
+            let mut sources = ink::prelude::vec![ChainMetrics {
+                chain_id: self.local_chain_id,
+                metrics: self.current_metrics.clone(),
+                observed_at: self.metrics_updated_at,
+                received_at: self.metrics_updated_at,
+            }];
+            sources.extend(
+                self.federated_chains
+                    .iter()
+                    .filter_map(|chain_id| self.federated_sources.get(chain_id)?.latest),
+            );
+
+            let mut total_volume: u128 = 0;
+            let mut properties_listed: u64 = 0;
+            let mut weighted_price: u128 = 0;
+            for source in sources.iter() {
+                total_volume = total_volume.saturating_add(source.metrics.total_volume);
+                properties_listed =
+                    properties_listed.saturating_add(source.metrics.properties_listed);
+                weighted_price = weighted_price.saturating_add(
+                    source
+                        .metrics
+                        .average_price
+                        .saturating_mul(source.metrics.properties_listed as u128),
+                );
+            }
+            GlobalMetrics {
+                combined: MarketMetrics {
+                    average_price: weighted_price
+                        .checked_div(properties_listed as u128)
+                        .unwrap_or(0),
+                    total_volume,
+                    properties_listed,
+                },
+                sources,
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_federated_source(&self, chain_id: u64) -> Option<FederatedSource> {
+            self.federated_sources.get(chain_id)
+        }
+
+        /// Add gas usage optimization recommendations
+        #[ink(message)]
+        pub fn get_gas_optimization_recommendations(&self) -> String {
+            String::from("Use batched operations and limit nested looping over dynamic collections (e.g. vectors). Store large items in Mappings instead of Vecs.")
+        }
+
+        fn push_activity(&mut self, token_id: u64, record: TokenActivityRecord) {
+            let index = self.token_activity_count.get(token_id).unwrap_or(0);
+            self.token_activity.insert((token_id, index), &record);
+            self.token_activity_count.insert(token_id, &(index + 1));
+            self.update_leaderboards(token_id, index, &record);
+            self.chain_record(token_id, &IngestedRecord::Activity(record));
+            self.prune(Dataset::RawEvents, token_id, index + 1);
+        }
+
+        fn leaderboard_period_start(&self) -> u64 {
+            let length = match self.leaderboard_period {
+                LeaderboardPeriod::Daily => MS_PER_DAY,
+                LeaderboardPeriod::Weekly => 7 * MS_PER_DAY,
+                LeaderboardPeriod::Monthly => 30 * MS_PER_DAY,
+            };
+            let now = self.env().block_timestamp();
+            now - now % length
+        }
+
+        /// Folds a token's `index`th activity record into its period figures and
+        /// re-ranks it on the boards the record moves. Reversals only undo records
+        /// ingested in the same period.
+        fn update_leaderboards(&mut self, token_id: u64, index: u64, record: &TokenActivityRecord) {
+            let period_start = self.leaderboard_period_start();
+            let mut stats = self.leaderboard_stats.get(token_id).unwrap_or_default();
+            if stats.period_start != period_start {
+                stats = TokenPeriodStats {
+                    period_start,
+                    first_index: index,
+                    open_price: stats.last_price,
+                    last_price: stats.last_price,
+                    ..Default::default()
+                };
+            }
+
+            let mut volume_moved = false;
+            let mut price_moved = false;
+            match &record.activity {
+                TokenActivity::Trade {
+                    price_per_share,
+                    amount,
+                    ..
+                } => {
+                    stats.volume = stats
+                        .volume
+                        .saturating_add(price_per_share.saturating_mul(*amount));
+                    if stats.open_price == 0 {
+                        stats.open_price = *price_per_share;
+                    }
+                    stats.last_price = *price_per_share;
+                    volume_moved = true;
+                    price_moved = true;
+                }
+                TokenActivity::Dividend { per_share } => {
+                    stats.dividends_per_share =
+                        stats.dividends_per_share.saturating_add(*per_share);
+                }
+                TokenActivity::Reversal { record_index } => {
+                    if *record_index < stats.first_index {
+                        return;
+                    }
+                    match self.token_activity.get((token_id, *record_index)) {
+                        Some(TokenActivityRecord {
+                            activity:
+                                TokenActivity::Trade {
+                                    price_per_share,
+                                    amount,
+                                    ..
+                                },
+                            ..
+                        }) => {
+                            stats.volume = stats
+                                .volume
+                                .saturating_sub(price_per_share.saturating_mul(amount));
+                            self.replay_prices(token_id, index, &mut stats);
+                            volume_moved = true;
+                            price_moved = true;
+                        }
+                        Some(TokenActivityRecord {
+                            activity: TokenActivity::Dividend { per_share },
+                            ..
+                        }) => {
+                            stats.dividends_per_share =
+                                stats.dividends_per_share.saturating_sub(per_share);
+                        }
+                        _ => return,
+                    }
+                }
+            }
+            self.leaderboard_stats.insert(token_id, &stats);
+
+            if volume_moved {
+                let volume = stats.volume.min(i128::MAX as u128) as i128;
+                self.rank(
+                    LeaderboardKind::Volume,
+                    period_start,
+                    token_id,
+                    Some(volume),
+                );
+            }
+            if price_moved {
+                // A reversal can leave the token without a price to measure from
+                let appreciation = (stats.open_price > 0).then(|| {
+                    (stats.last_price as i128 - stats.open_price as i128).saturating_mul(10_000)
+                        / stats.open_price as i128
+                });
+                self.rank(
+                    LeaderboardKind::PriceAppreciation,
+                    period_start,
+                    token_id,
+                    appreciation,
+                );
+            }
+            // Tokens without dividends or a price this period stay off the yield board
+            let dividend_yield = stats
+                .dividends_per_share
+                .saturating_mul(10_000)
+                .checked_div(stats.last_price)
+                .map(|dividend_yield| dividend_yield.min(i128::MAX as u128) as i128)
+                .filter(|dividend_yield| *dividend_yield > 0);
+            if dividend_yield.is_some() || price_moved {
+                self.rank(
+                    LeaderboardKind::DividendYield,
+                    period_start,
+                    token_id,
+                    dividend_yield,
+                );
+            }
+        }
+
+        /// Recomputes a token's open and last price from the unreversed trades up
+        /// to its `end`th record, after a reversal took one of them back
+        fn replay_prices(&self, token_id: u64, end: u64, stats: &mut TokenPeriodStats) {
+            let first = self
+                .pruned_before
+                .get((Dataset::RawEvents, token_id))
+                .unwrap_or(0);
+            // Reversals follow the record they undo, so walking back sees them first
+            let mut reversed = BTreeSet::new();
+            let (mut open_price, mut last_price) = (0, 0);
+            for i in (first..=end).rev() {
+                let Some(record) = self.token_activity.get((token_id, i)) else {
+                    continue;
+                };
+                match record.activity {
+                    TokenActivity::Reversal { record_index } => {
+                        reversed.insert(record_index);
+                    }
+                    TokenActivity::Trade {
+                        price_per_share, ..
+                    } if !reversed.contains(&i) => {
+                        if last_price == 0 {
+                            last_price = price_per_share;
+                        }
+                        open_price = price_per_share;
+                        // The previous period's close opens this one
+                        if i < stats.first_index {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            stats.open_price = open_price;
+            stats.last_price = last_price;
+        }
+
+        /// Moves a token to its score's place on a board; a `None` score takes the
+        /// token off the board. Every scored token is kept so one outside the top
+        /// `LEADERBOARD_SIZE` moves back up when a higher entry is reversed.
+        fn rank(
+            &mut self,
+            kind: LeaderboardKind,
+            period_start: u64,
+            token_id: u64,
+            score: Option<i128>,
+        ) {
+            let mut board = self.leaderboards.get(kind).unwrap_or_default();
+            if board.period_start != period_start {
+                board = Leaderboard {
+                    period_start,
+                    entries: Vec::new(),
+                };
+            }
+            board.entries.retain(|(token, _)| *token != token_id);
+            let Some(score) = score else {
+                self.leaderboards.insert(kind, &board);
+                return;
+            };
+            let position = board.entries.partition_point(|(_, s)| *s >= score);
+            board.entries.insert(position, (token_id, score));
+            self.leaderboards.insert(kind, &board);
+        }
+
+        fn first_index(dataset: Dataset) -> u64 {
+            match dataset {
+                Dataset::Alerts => 1, // Report ids start at one
+                Dataset::RawEvents | Dataset::Rollups => 0,
+            }
+        }
+
+        fn series_metric(subject_id: u64) -> ForecastMetric {
+            match subject_id {
+                0 => ForecastMetric::AveragePrice,
+                1 => ForecastMetric::TotalVolume,
+                _ => ForecastMetric::PropertiesListed,
+            }
+        }
+
+        /// Removes up to `MAX_PRUNE_BATCH` records in front of the retained window of a
+        /// stream ending at `end` and emits the checkpoint covering them
+        fn prune(&mut self, dataset: Dataset, subject_id: u64, end: u64) -> u64 {
+            let Some(limit) = self.retention_limits.get(dataset) else {
+                return 0;
+            };
+            let stream = (dataset, subject_id);
+            let first = self
+                .pruned_before
+                .get(stream)
+                .unwrap_or(Self::first_index(dataset));
+            let until = end
+                .saturating_sub(limit)
+                .min(first.saturating_add(MAX_PRUNE_BATCH));
+            if until <= first {
+                return 0;
+            }
+            let previous_hash = self.archive_heads.get(stream).unwrap_or_default();
+            let mut head = previous_hash;
+            for index in first..until {
+                let link = match dataset {
+                    Dataset::RawEvents => {
+                        let record = self.token_activity.take((subject_id, index));
+                        self.env()
+                            .hash_encoded::<ink::env::hash::Blake2x256, _>(&(head, index, record))
+                    }
+                    Dataset::Rollups => {
+                        let metric = Self::series_metric(subject_id);
+                        let point = self.metric_series.take((metric, index));
+                        self.env()
+                            .hash_encoded::<ink::env::hash::Blake2x256, _>(&(head, index, point))
+                    }
+                    Dataset::Alerts => {
+                        let report = self.discrepancy_reports.take(index);
+                        self.env()
+                            .hash_encoded::<ink::env::hash::Blake2x256, _>(&(head, index, report))
+                    }
+                };
+                head = Hash::from(link);
+            }
+            self.pruned_before.insert(stream, &until);
+            self.archive_heads.insert(stream, &head);
+            self.env().emit_event(ArchivalCheckpoint {
+                dataset,
+                subject_id,
+                first_index: first,
+                end_index: until,
+                previous_hash,
+                batch_hash: head,
+            });
+            until - first
+        }
+
+        fn chain_record(&mut self, token_id: u64, record: &IngestedRecord) {
+            let previous = self.get_integrity_hash(token_id);
+            let link = self
+                .env()
+                .hash_encoded::<ink::env::hash::Blake2x256, _>(&(previous, record));
+            self.integrity_hashes.insert(token_id, &Hash::from(link));
+            self.update_streaming(token_id, record);
+        }
+
+        fn update_streaming(&mut self, token_id: u64, record: &IngestedRecord) {
+            let alpha = self.ewma_alpha_bps as u128;
+            let blend = |level: u128, value: u128| {
+                if level == 0 {
+                    return value;
+                }
+                alpha
+                    .saturating_mul(value)
+                    .saturating_add((10_000 - alpha).saturating_mul(level))
+                    / 10_000
+            };
+            let mut metrics = self.streaming_metrics.clone();
+            match record {
+                IngestedRecord::Activity(TokenActivityRecord {
+                    activity:
+                        TokenActivity::Trade {
+                            price_per_share,
+                            amount,
+                            ..
+                        },
+                    ..
+                }) => {
+                    metrics.ewma_price = blend(metrics.ewma_price, *price_per_share);
+                    metrics.ewma_trade_size = blend(metrics.ewma_trade_size, *amount);
+                }
+                IngestedRecord::Activity(TokenActivityRecord {
+                    activity: TokenActivity::Dividend { per_share },
+                    ..
+                }) => {
+                    metrics.ewma_dividend = blend(metrics.ewma_dividend, *per_share);
+                }
+                _ => {}
+            }
+
+            for row in 0..SKETCH_DEPTH {
+                let index = Self::sketch_index(row, token_id);
+                self.activity_sketch[index] = self.activity_sketch[index].saturating_add(1);
+            }
+            let estimate = self.estimate_token_activity(token_id);
+            let hot = &mut metrics.hot_tokens;
+            match hot.iter().position(|(token, _)| *token == token_id) {
+                Some(slot) => hot[slot].1 = estimate,
+                None if hot.len() < HOT_TOKEN_SLOTS => hot.push((token_id, estimate)),
+                None => {
+                    if let Some(coldest) = hot.last_mut() {
+                        if coldest.1 < estimate {
+                            *coldest = (token_id, estimate);
+                        }
+                    }
+                }
+            }
+            hot.sort_by_key(|(_, count)| core::cmp::Reverse(*count));
+
+            metrics.records_ingested += 1;
+            metrics.updated_at = self.env().block_timestamp();
+            self.streaming_metrics = metrics;
+        }
+
+        fn sketch_index(row: usize, token_id: u64) -> usize {
+            let hashed = token_id.wrapping_add(1).wrapping_mul(SKETCH_SEEDS[row]) >> 32;
+            row * SKETCH_WIDTH + (hashed as usize % SKETCH_WIDTH)
+        }
+
+        fn record_submission(&mut self, token_id: u64, record: TokenActivityRecord) -> u64 {
+            let provider = self.env().caller();
+            assert!(
+                self.provider_stakes.get(provider).unwrap_or(0) >= self.min_provider_stake,
+                "Provider not bonded"
+            );
+            let now = self.env().block_timestamp();
+            let record_index = self.token_activity_count.get(token_id).unwrap_or(0);
+            self.push_activity(token_id, record);
+
+            self.submission_count += 1;
+            self.provider_submissions.insert(
+                self.submission_count,
+                &ProviderSubmission {
+                    provider,
+                    token_id,
+                    record_index,
+                    submitted_at: now,
+                    status: SubmissionStatus::Pending,
+                    challenger: None,
+                    evidence: None,
+                    bond: 0,
+                },
+            );
+            self.provider_locked_until
+                .insert(provider, &now.saturating_add(self.challenge_window));
+            self.submission_count
+        }
+
+        /// Stores a report when the values differ and alerts past tolerance;
+        /// returns whether a discrepancy was found
+        fn record_consistency(
+            &mut self,
+            metric: ConsistencyMetric,
+            subject_id: u64,
+            ingested: u128,
+            observed: u128,
+        ) -> bool {
+            if ingested == observed {
+                return false;
+            }
+            let drift_bps = ingested
+                .abs_diff(observed)
+                .saturating_mul(10_000)
+                .checked_div(observed)
+                .map_or(u32::MAX, |drift| drift.min(u32::MAX as u128) as u32);
+            let alerted = drift_bps > self.drift_tolerance_bps;
+            self.discrepancy_count += 1;
+            let report_id = self.discrepancy_count;
+            self.discrepancy_reports.insert(
+                report_id,
+                &DiscrepancyReport {
+                    metric,
+                    subject_id,
+                    ingested,
+                    observed,
+                    drift_bps,
+                    checked_at: self.env().block_timestamp(),
+                    alerted,
+                },
+            );
+            self.prune(Dataset::Alerts, 0, report_id + 1);
+            if alerted {
+                self.env().emit_event(ConsistencyAlert {
+                    metric,
+                    subject_id,
+                    report_id,
+                    ingested,
+                    observed,
+                    drift_bps,
+                });
+            }
+            true
+        }
+
+        fn snapshot_kpis(&mut self) {
+            let evaluated_at = self.env().block_timestamp();
+            for kpi_id in 0..self.kpi_count {
+                let Some(definition) = self.kpi_definitions.get(kpi_id) else {
+                    continue;
+                };
+                if !definition.active || !definition.snapshot {
+                    continue;
+                }
+                if let Some(value) = self.evaluate_nodes(&definition.nodes) {
+                    self.kpi_snapshots.insert(
+                        kpi_id,
+                        &KpiValue {
+                            value,
+                            evaluated_at,
+                        },
+                    );
+                }
+            }
+        }
+
+        fn evaluate_nodes(&self, nodes: &[KpiNode]) -> Option<i128> {
+            let mut values: Vec<i128> = Vec::with_capacity(nodes.len());
+            for node in nodes {
+                let value = match *node {
+                    KpiNode::Constant(value) => value,
+                    KpiNode::Metric(metric) => self.metric_value(metric) as i128,
+                    KpiNode::Delta(metric) => {
+                        let len = self.metric_series_len.get(metric).unwrap_or(0);
+                        if len < 2 {
+                            return None;
+                        }
+                        let latest = self.metric_series.get((metric, len - 1))?.value as i128;
+                        let previous = self.metric_series.get((metric, len - 2))?.value as i128;
+                        latest.checked_sub(previous)?
+                    }
+                    KpiNode::Sum(a, b) => values[a as usize].checked_add(values[b as usize])?,
+                    KpiNode::Difference(a, b) => {
+                        values[a as usize].checked_sub(values[b as usize])?
+                    }
+                    KpiNode::Ratio(a, b) => values[a as usize]
+                        .checked_mul(10_000)?
+                        .checked_div(values[b as usize])?,
+                };
+                values.push(value);
+            }
+            values.pop()
+        }
+
+        fn metric_value(&self, metric: ForecastMetric) -> u128 {
+            match metric {
+                ForecastMetric::AveragePrice => self.current_metrics.average_price,
+                ForecastMetric::TotalVolume => self.current_metrics.total_volume,
+                ForecastMetric::PropertiesListed => self.current_metrics.properties_listed as u128,
+            }
+        }
+
+        fn push_series(&mut self, metric: ForecastMetric, value: u128) {
+            let index = self.metric_series_len.get(metric).unwrap_or(0);
+            self.metric_series.insert(
+                (metric, index),
+                &SeriesPoint {
+                    timestamp: self.env().block_timestamp(),
+                    value,
+                },
+            );
+            self.metric_series_len.insert(metric, &(index + 1));
+            self.prune(Dataset::Rollups, metric as u64, index + 1);
+        }
+
+        /// Least-squares fit over x = 0..n in fixed point; returns the projection
+        /// `horizon` steps past the last bucket and the mean absolute residual
+        fn fit_linear(values: &[i128], horizon: u32) -> (i128, i128) {
+            let n = values.len() as i128;
+            let sum_x = n * (n - 1) / 2;
+            let sum_xx = (n - 1) * n * (2 * n - 1) / 6;
+            let (sum_y, sum_xy) =
+                values
+                    .iter()
+                    .enumerate()
+                    .fold((0i128, 0i128), |(sy, sxy), (x, y)| {
+                        (
+                            sy.saturating_add(*y),
+                            sxy.saturating_add((x as i128).saturating_mul(*y)),
+                        )
+                    });
+            let denominator = n * sum_xx - sum_x * sum_x;
+            let slope = n
+                .saturating_mul(sum_xy)
+                .saturating_sub(sum_x.saturating_mul(sum_y))
+                .saturating_mul(FORECAST_SCALE)
+                / denominator;
+            let intercept = sum_y
+                .saturating_mul(FORECAST_SCALE)
+                .saturating_sub(slope.saturating_mul(sum_x))
+                / n;
+            let fitted =
+                |x: i128| intercept.saturating_add(slope.saturating_mul(x)) / FORECAST_SCALE;
+            let error: i128 = values
+                .iter()
+                .enumerate()
+                .map(|(x, y)| (y - fitted(x as i128)).abs())
+                .sum();
+            (fitted(n - 1 + horizon as i128), error / n)
+        }
+
+        /// EWMA level and mean absolute one-step-ahead error
+        fn fit_ewma(values: &[i128], alpha_bps: u32) -> (i128, i128) {
+            let alpha = alpha_bps as i128;
+            let mut level = values[0];
+            let mut error = 0i128;
+            for value in &values[1..] {
+                error = error.saturating_add((value - level).abs());
+                level = alpha
+                    .saturating_mul(*value)
+                    .saturating_add((10_000 - alpha).saturating_mul(level))
+                    / 10_000;
+            }
+            (level, error / (values.len() as i128 - 1))
+        }
+
+        fn open_lot(
+            &mut self,
+            account: AccountId,
+            token_id: u64,
+            shares: u128,
+            cost_per_share: u128,
+            acquired_at: u64,
+        ) {
+            if shares == 0 {
+                return;
+            }
+            let mut lots = self.tax_lots.get((account, token_id)).unwrap_or_default();
+            lots.push(TaxLot {
+                acquired_at,
+                shares,
+                cost_per_share,
+            });
+            self.tax_lots.insert((account, token_id), &lots);
+        }
+
+        /// Consume the oldest lots for a sale and add it to the year's summary
+        fn dispose_lots(
+            &mut self,
+            account: AccountId,
+            token_id: u64,
+            shares: u128,
+            proceeds: u128,
+            timestamp: u64,
+        ) {
+            let mut lots = self.tax_lots.get((account, token_id)).unwrap_or_default();
+            let mut remaining = shares;
+            let mut cost_basis: u128 = 0;
+            let mut consumed = 0;
+            for lot in lots.iter_mut() {
+                if remaining == 0 {
+                    break;
+                }
+                let take = remaining.min(lot.shares);
+                cost_basis = cost_basis.saturating_add(take.saturating_mul(lot.cost_per_share));
+                lot.shares -= take;
+                remaining -= take;
+                if lot.shares == 0 {
+                    consumed += 1;
+                }
+            }
+            lots.drain(..consumed);
+            self.tax_lots.insert((account, token_id), &lots);
+
+            let year = Self::year_of(timestamp);
+            let mut summary = self.get_realized_gains(account, token_id, year);
+            summary.shares_sold = summary.shares_sold.saturating_add(shares);
+            summary.proceeds = summary.proceeds.saturating_add(proceeds);
+            summary.cost_basis = summary.cost_basis.saturating_add(cost_basis);
+            summary.unmatched_shares = summary.unmatched_shares.saturating_add(remaining);
+            summary.realized_gain = summary
+                .realized_gain
+                .saturating_add(proceeds as i128 - cost_basis as i128);
+            self.realized_gains
+                .insert((account, token_id, year), &summary);
+        }
+
+        /// Proleptic Gregorian year of a millisecond Unix timestamp
+        fn year_of(timestamp: u64) -> u32 {
+            // Days-to-civil conversion over 400-year eras starting 0000-03-01
+            let z = timestamp / MS_PER_DAY + 719_468;
+            let era = z / 146_097;
+            let doe = z - era * 146_097;
+            let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+            let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+            let month_index = (5 * doy + 2) / 153; // 0 = March
+            let year = yoe + era * 400 + u64::from(month_index >= 10);
+            year as u32
+        }
+
+        /// Store each segment's comparison for a period whose opening and closing
+        /// index values are known; returns the number of segments compared
+        fn compare_segments(&mut self, period: u64) -> u32 {
+            let (Some(opening), Some(closing)) = (
+                period
+                    .checked_sub(1)
+                    .and_then(|p| self.benchmark_values.get(p)),
+                self.benchmark_values.get(period),
+            ) else {
+                return 0;
+            };
+            let benchmark_return_bps = Self::to_bps(closing as i128 - opening as i128, opening);
+            let from = period.saturating_mul(self.benchmark_period_length);
+            let to = from.saturating_add(self.benchmark_period_length - 1);
+            let mut compared = 0;
+            for segment in self.segment_names.clone() {
+                let attributions: Vec<ReturnAttribution> = self
+                    .segments
+                    .get(&segment)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|token_id| self.get_return_attribution(token_id, from, to))
+                    .collect();
+                if attributions.is_empty() {
+                    continue;
+                }
+                let count = attributions.len() as i64;
+                let segment_price_return_bps =
+                    attributions.iter().map(|a| a.price_return_bps).sum::<i64>() / count;
+                let segment_total_return_bps =
+                    attributions.iter().map(|a| a.total_return_bps).sum::<i64>() / count;
+                self.benchmark_comparisons.insert(
+                    (segment.clone(), period),
+                    &BenchmarkComparison {
+                        segment,
+                        period,
+                        benchmark_return_bps,
+                        segment_price_return_bps,
+                        segment_total_return_bps,
+                        excess_return_bps: segment_price_return_bps - benchmark_return_bps,
+                        tokens_compared: count as u32,
+                    },
+                );
+                compared += 1;
+            }
+            compared
+        }
+
+        fn to_bps(value: i128, base: u128) -> i64 {
+            (value.saturating_mul(10_000) / base as i128) as i64
+        }
+
+        fn apply_federated_digest(
+            &mut self,
+            mut source: FederatedSource,
+            digest: FederatedDigest,
+            relayer: AccountId,
+        ) {
+            assert!(digest.sequence > source.sequence, "Stale federation digest");
+            source.sequence = digest.sequence;
+            source.latest = Some(ChainMetrics {
+                chain_id: digest.chain_id,
+                metrics: digest.metrics,
+                observed_at: digest.observed_at,
+                received_at: self.env().block_timestamp(),
+            });
+            self.federated_sources.insert(digest.chain_id, &source);
+            self.env().emit_event(FederatedMetricsReceived {
+                chain_id: digest.chain_id,
+                sequence: digest.sequence,
+                relayer,
+            });
+        }
+
+        /// Rates over the observed time and rent due; zero when nothing is observed
+        fn occupancy_report(stats: OccupancyStats) -> OccupancyReport {
+            let observed = stats.occupied_ms.saturating_add(stats.vacant_ms) as u128;
+            let occupancy_rate_bps = (stats.occupied_ms as u128 * 10_000)
+                .checked_div(observed)
+                .unwrap_or(0) as u32;
+            let vacancy_rate_bps = if observed == 0 {
+                0
+            } else {
+                10_000 - occupancy_rate_bps
+            };
+            let collection_ratio_bps = stats
+                .rent_collected
+                .saturating_mul(10_000)
+                .checked_div(stats.rent_due)
+                .unwrap_or(0)
+                .min(u32::MAX as u128) as u32;
+            OccupancyReport {
+                stats,
+                occupancy_rate_bps,
+                vacancy_rate_bps,
+                collection_ratio_bps,
+            }
+        }
+
+        /// Ensure only the admin can modify metrics
+        fn ensure_admin(&self) {
+            assert_eq!(
+                self.env().caller(),
+                self.admin,
+                "Unauthorized: Analytics admin only"
+            );
+        }
+    }
+
+    impl propchain_traits::ValuationAlertSink for AnalyticsDashboard {
+        #[ink(message)]
+        fn ingest_valuation_disagreement(
+            &mut self,
+            property_id: u64,
+            consensus_score: u32,
+            threshold: u32,
+            valuation: u128,
+            model_count: u32,
+        ) -> u64 {
+            let caller = self.env().caller();
+            assert!(
+                caller == self.admin || Some(caller) == self.valuation_source,
+                "Unauthorized: valuation source only"
+            );
+            self.disagreement_count += 1;
+            let record_id = self.disagreement_count;
+            self.valuation_disagreements.insert(
+                record_id,
+                &ValuationDisagreement {
+                    property_id,
+                    consensus_score,
+                    threshold,
+                    valuation,
+                    model_count,
+                    reported_at: self.env().block_timestamp(),
+                    appraised: false,
+                },
+            );
+            self.property_disagreement.insert(property_id, &record_id);
+            record_id
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[ink::test]
+        fn market_metrics_defaults() {
+            let contract = AnalyticsDashboard::new();
+            let metrics = contract.get_market_metrics();
+            assert_eq!(metrics.average_price, 0);
+            assert_eq!(metrics.total_volume, 0);
+            assert_eq!(metrics.properties_listed, 0);
+        }
+
+        #[ink::test]
+        fn update_market_metrics_works() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.update_market_metrics(1000, 5000, 10);
+            let metrics = contract.get_market_metrics();
+            assert_eq!(metrics.average_price, 1000);
+            assert_eq!(metrics.total_volume, 5000);
+            assert_eq!(metrics.properties_listed, 10);
+        }
+
+        #[ink::test]
+        fn add_market_trend_works() {
+            let mut contract = AnalyticsDashboard::new();
+            let trend = MarketTrend {
+                period_start: 100,
+                period_end: 200,
+                price_change_percentage: 5,
+                volume_change_percentage: 10,
+            };
+            contract.add_market_trend(trend.clone());
+            let trends = contract.get_historical_trends();
+            assert_eq!(trends.len(), 1);
+            assert_eq!(trends[0].price_change_percentage, 5);
+        }
+
+        #[ink::test]
+        fn return_attribution_splits_price_income_and_fees() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.ingest_trade(1, 1_000, 10, 0, 50);
+            contract.ingest_dividend(1, 20, 120);
+            contract.ingest_trade(1, 1_100, 10, 500, 150);
+            // Other tokens and out-of-window activity are ignored
+            contract.ingest_trade(2, 5, 1, 0, 120);
+            contract.ingest_dividend(1, 999, 400);
+
+            let attribution = contract
+                .get_return_attribution(1, 100, 200)
+                .expect("reference prices exist");
+            assert_eq!(attribution.start_price, 1_000);
+            assert_eq!(attribution.end_price, 1_100);
+            assert_eq!(attribution.price_return_bps, 1_000);
+            assert_eq!(attribution.income_return_bps, 200);
+            assert_eq!(attribution.fee_drag_bps, 500);
+            assert_eq!(attribution.total_return_bps, 700);
+        }
+
+        #[ink::test]
+        fn settled_trades_track_fifo_lots_and_yearly_gains() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = AnalyticsDashboard::new();
+            // 2023-06-01 and 2024-03-01, 2024-12-31 and 2025-01-01 (UTC, ms)
+            let (jun_2023, mar_2024) = (1_685_577_600_000, 1_709_251_200_000);
+            let (dec_2024, jan_2025) = (1_735_603_200_000, 1_735_689_600_000);
+            contract.ingest_acquisition(accounts.bob, 1, 100, 10, jun_2023);
+            let trade = |seller, buyer, price_per_share, amount, fee, timestamp| SettledTrade {
+                token_id: 1,
+                seller,
+                buyer,
+                price_per_share,
+                amount,
+                fee,
+                timestamp,
+            };
+            contract.ingest_settled_trade(trade(accounts.alice, accounts.bob, 20, 50, 0, mar_2024));
+
+            // 120 shares: all of the first lot and 20 of the second
+            contract.ingest_settled_trade(trade(
+                accounts.bob,
+                accounts.charlie,
+                30,
+                120,
+                100,
+                dec_2024,
+            ));
+            let lots = contract.get_tax_lots(accounts.bob, 1);
+            assert_eq!(lots.len(), 1);
+            assert_eq!(lots[0].shares, 30);
+            assert_eq!(lots[0].cost_per_share, 20);
+            let gains = contract.get_realized_gains(accounts.bob, 1, 2024);
+            assert_eq!(gains.shares_sold, 120);
+            assert_eq!(gains.proceeds, 3_500);
+            assert_eq!(gains.cost_basis, 1_400);
+            assert_eq!(gains.realized_gain, 2_100);
+            assert_eq!(contract.get_tax_lots(accounts.charlie, 1)[0].shares, 120);
+
+            // Selling past the tracked lots books the excess at zero basis
+            contract.ingest_settled_trade(trade(
+                accounts.bob,
+                accounts.charlie,
+                5,
+                40,
+                0,
+                jan_2025,
+            ));
+            assert!(contract.get_tax_lots(accounts.bob, 1).is_empty());
+            let gains = contract.get_realized_gains(accounts.bob, 1, 2025);
+            assert_eq!(gains.cost_basis, 600);
+            assert_eq!(gains.unmatched_shares, 10);
+            assert_eq!(gains.realized_gain, -400);
+            // Unknown seller: no lots, gain equals proceeds
+            assert_eq!(
+                contract
+                    .get_realized_gains(accounts.alice, 1, 2024)
+                    .realized_gain,
+                1_000
+            );
+        }
 
         #[ink::test]
-        fn market_metrics_defaults() {
-            let contract = AnalyticsDashboard::new();
-            let metrics = contract.get_market_metrics();
-            assert_eq!(metrics.average_price, 0);
-            assert_eq!(metrics.total_volume, 0);
-            assert_eq!(metrics.properties_listed, 0);
+        fn integrity_hash_chains_ingested_records_per_token() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = AnalyticsDashboard::new();
+            assert_eq!(contract.get_integrity_hash(1), Hash::default());
+            contract.ingest_trade(1, 1_000, 10, 5, 50);
+            contract.ingest_trade(2, 7, 1, 0, 60);
+            contract.ingest_acquisition(accounts.bob, 1, 100, 10, 70);
+            contract.ingest_token_shares(1, 1_000);
+
+            // A third party replays the same records independently
+            let replay = [
+                IngestedRecord::Activity(TokenActivityRecord {
+                    timestamp: 50,
+                    activity: TokenActivity::Trade {
+                        price_per_share: 1_000,
+                        amount: 10,
+                        fee: 5,
+                    },
+                }),
+                IngestedRecord::Acquisition {
+                    account: accounts.bob,
+                    shares: 100,
+                    cost_per_share: 10,
+                    timestamp: 70,
+                },
+                IngestedRecord::Shares {
+                    total_shares: 1_000,
+                },
+            ]
+            .iter()
+            .fold(Hash::default(), |previous, record| {
+                let mut link = [0u8; 32];
+                ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                    &(previous, record),
+                    &mut link,
+                );
+                Hash::from(link)
+            });
+            assert_eq!(contract.get_integrity_hash(1), replay);
+            assert_ne!(contract.get_integrity_hash(2), replay);
+
+            // Any further record moves the head
+            contract.ingest_dividend(1, 3, 80);
+            assert_ne!(contract.get_integrity_hash(1), replay);
         }
 
         #[ink::test]
-        fn update_market_metrics_works() {
+        fn return_attribution_requires_trades() {
             let mut contract = AnalyticsDashboard::new();
-            contract.update_market_metrics(1000, 5000, 10);
-            let metrics = contract.get_market_metrics();
-            assert_eq!(metrics.average_price, 1000);
-            assert_eq!(metrics.total_volume, 5000);
-            assert_eq!(metrics.properties_listed, 10);
+            contract.ingest_dividend(1, 20, 120);
+            assert_eq!(contract.get_return_attribution(1, 100, 200), None);
+            assert_eq!(contract.get_return_attribution(1, 200, 100), None);
         }
 
         #[ink::test]
-        fn add_market_trend_works() {
+        fn upheld_challenge_slashes_provider_and_reverts_record() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
             let mut contract = AnalyticsDashboard::new();
-            let trend = MarketTrend {
-                period_start: 100,
-                period_end: 200,
-                price_change_percentage: 5,
-                volume_change_percentage: 10,
+            contract.set_provider_params(100, 1_000, 5_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            contract.bond_provider();
+            contract.submit_trade(1, 1_000, 10, 0, 50);
+            contract.submit_trade(1, 1_100, 10, 0, 150);
+            let bad = contract.submit_dividend(1, 500, 120);
+            assert_eq!(
+                contract
+                    .get_return_attribution(1, 100, 200)
+                    .unwrap()
+                    .income_return_bps,
+                5_000
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(50);
+            contract.challenge_submission(bad, Hash::from([1u8; 32]));
+            assert_eq!(contract.get_provider_submission(bad).unwrap().bond, 50);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                ink::env::test::callee::<ink::env::DefaultEnvironment>(),
+                1_001_050,
+            );
+            let before = ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(
+                accounts.charlie,
+            )
+            .unwrap();
+            contract.resolve_challenge(bad, true);
+
+            assert_eq!(contract.get_provider_stake(accounts.bob), 500);
+            // Slash plus the returned bond
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(
+                    accounts.charlie
+                )
+                .unwrap(),
+                before + 550
+            );
+            assert_eq!(
+                contract.get_provider_submission(bad).unwrap().status,
+                SubmissionStatus::Reverted
+            );
+            let attribution = contract.get_return_attribution(1, 100, 200).unwrap();
+            assert_eq!(attribution.income_return_bps, 0);
+            assert_eq!(attribution.price_return_bps, 1_000);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Challenge bond required")]
+        fn challenge_without_bond_is_rejected() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = AnalyticsDashboard::new();
+            contract.set_provider_params(100, 1_000, 5_000);
+            contract.set_challenge_bond(50);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            contract.bond_provider();
+            let id = contract.submit_dividend(1, 500, 120);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(10);
+            contract.challenge_submission(id, Hash::from([1u8; 32]));
+        }
+
+        #[ink::test]
+        fn dismissed_challenge_forfeits_bond_to_provider() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = AnalyticsDashboard::new();
+            contract.set_provider_params(100, 1_000, 5_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(1_000);
+            contract.bond_provider();
+            let id = contract.submit_dividend(1, 500, 120);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(50);
+            contract.challenge_submission(id, Hash::from([1u8; 32]));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.resolve_challenge(id, false);
+            assert_eq!(contract.get_provider_stake(accounts.bob), 1_050);
+            assert_eq!(
+                contract.get_provider_submission(id).unwrap().status,
+                SubmissionStatus::Dismissed
+            );
+        }
+
+        #[ink::test]
+        fn keyed_ingestion_drops_duplicates_and_reconciles_late_sources() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = AnalyticsDashboard::new();
+            // PropertyToken (bob) reports trades; FeeManager (charlie) has the final fee
+            contract.set_source_precedence(accounts.bob, 1);
+            contract.set_source_precedence(accounts.charlie, 2);
+            let key = Hash::from([7u8; 32]);
+            let trade = |fee| TokenActivity::Trade {
+                price_per_share: 1_100,
+                amount: 10,
+                fee,
             };
-            contract.add_market_trend(trend.clone());
-            let trends = contract.get_historical_trends();
-            assert_eq!(trends.len(), 1);
-            assert_eq!(trends[0].price_change_percentage, 5);
+            contract.ingest_trade(1, 1_000, 10, 0, 50);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.ingest_keyed_activity(key, 1, trade(0), 150),
+                KeyedIngestion::Recorded
+            );
+            assert_eq!(
+                contract.ingest_keyed_activity(key, 1, trade(0), 150),
+                KeyedIngestion::Duplicate
+            );
+            let attribution = contract.get_return_attribution(1, 100, 200).unwrap();
+            assert_eq!(attribution.price_return_bps, 1_000);
+            assert_eq!(attribution.fee_drag_bps, 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                contract.ingest_keyed_activity(key, 1, trade(500), 150),
+                KeyedIngestion::Superseded
+            );
+            let attribution = contract.get_return_attribution(1, 100, 200).unwrap();
+            assert_eq!(attribution.fee_drag_bps, 500);
+            let record = contract.get_keyed_record(key).unwrap();
+            assert_eq!((record.source, record.superseded), (accounts.charlie, 1));
+
+            // The lower-precedence source arriving after the fact changes nothing
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.ingest_keyed_activity(key, 1, trade(0), 150),
+                KeyedIngestion::Duplicate
+            );
+            assert_eq!(contract.get_dedup_counts(), (2, 1));
+            assert_eq!(
+                contract
+                    .get_return_attribution(1, 100, 200)
+                    .unwrap()
+                    .fee_drag_bps,
+                500
+            );
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Unregistered ingestion source")]
+        fn keyed_ingestion_requires_registered_source() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.ingest_keyed_activity(
+                Hash::from([7u8; 32]),
+                1,
+                TokenActivity::Dividend { per_share: 1 },
+                0,
+            );
+        }
+
+        #[ink::test]
+        fn retention_prunes_streams_and_checkpoints_pruned_batches() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.set_retention(Dataset::RawEvents, 3);
+            contract.set_retention(Dataset::Rollups, 2);
+            let trade = |i: u64| TokenActivityRecord {
+                timestamp: 1_000 * i,
+                activity: TokenActivity::Trade {
+                    price_per_share: 100 + i as u128,
+                    amount: 10,
+                    fee: 1,
+                },
+            };
+            for i in 0..5u64 {
+                contract.ingest_trade(7, 100 + i as u128, 10, 1, 1_000 * i);
+            }
+            // Each trade over the limit prunes the oldest record and checkpoints it
+            assert_eq!(ink::env::test::recorded_events().count(), 2);
+            let mut expected = Hash::default();
+            for index in 0..2u64 {
+                let mut link = [0u8; 32];
+                ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                    &(expected, index, Some(trade(index))),
+                    &mut link,
+                );
+                expected = Hash::from(link);
+            }
+            assert_eq!(
+                contract.get_archive_head(Dataset::RawEvents, 7),
+                (2, expected)
+            );
+            assert_eq!(contract.get_archive_head(Dataset::RawEvents, 8).0, 0);
+            // Attribution now starts from the oldest retained trade
+            let attribution = contract
+                .get_return_attribution(7, 0, 10_000)
+                .expect("retained trades");
+            assert_eq!(attribution.start_price, 102);
+
+            for value in 1..=3u128 {
+                contract.update_market_metrics(value, value, value as u64);
+            }
+            assert_eq!(
+                contract.get_series_point(ForecastMetric::AveragePrice, 0),
+                None
+            );
+            assert!(contract
+                .get_series_point(ForecastMetric::AveragePrice, 1)
+                .is_some());
+            assert_eq!(
+                contract
+                    .get_archive_head(Dataset::Rollups, ForecastMetric::TotalVolume as u64)
+                    .0,
+                1
+            );
+
+            // Lowering a limit is caught up in bounded batches
+            contract.set_retention(Dataset::RawEvents, 1);
+            assert_eq!(contract.prune_stream(Dataset::RawEvents, 7), 2);
+            assert_eq!(contract.prune_stream(Dataset::RawEvents, 7), 0);
+            assert_eq!(contract.get_archive_head(Dataset::RawEvents, 7).0, 4);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Unknown rollup series")]
+        fn prune_stream_rejects_unknown_rollup_series() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.set_retention(Dataset::Rollups, 1);
+            contract.prune_stream(Dataset::Rollups, 3);
+        }
+
+        #[ink::test]
+        fn ops_metrics_report_median_and_p95_per_period() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.set_ops_period_length(1_000);
+            for i in 0..20u64 {
+                contract.ingest_lifecycle_event(
+                    OpsDomain::InsuranceClaim,
+                    i,
+                    LifecycleStage::Opened,
+                    100,
+                );
+                contract.ingest_lifecycle_event(
+                    OpsDomain::InsuranceClaim,
+                    i,
+                    LifecycleStage::Closed,
+                    100 + (i + 1) * 10,
+                );
+            }
+            // Closed without a matching open, and other domains, are ignored
+            contract.ingest_lifecycle_event(
+                OpsDomain::InsuranceClaim,
+                99,
+                LifecycleStage::Closed,
+                500,
+            );
+            contract.ingest_lifecycle_event(
+                OpsDomain::BridgeRequest,
+                1,
+                LifecycleStage::Opened,
+                100,
+            );
+
+            let metrics = contract
+                .get_ops_metrics(OpsDomain::InsuranceClaim, 0)
+                .expect("samples exist");
+            assert_eq!(metrics.samples, 20);
+            assert_eq!(metrics.median_latency, 100);
+            assert_eq!(metrics.p95_latency, 190);
+            assert_eq!(metrics.max_latency, 200);
+            assert_eq!(contract.get_ops_metrics(OpsDomain::BridgeRequest, 0), None);
+        }
+
+        #[ink::test]
+        fn forecast_projects_linear_and_ewma_trends() {
+            let mut contract = AnalyticsDashboard::new();
+            assert_eq!(contract.forecast(ForecastMetric::AveragePrice, 1), None);
+            for (i, price) in [100u128, 110, 120, 130].iter().enumerate() {
+                contract.update_market_metrics(*price, 1_000, i as u64);
+            }
+            assert_eq!(contract.get_series_length(ForecastMetric::AveragePrice), 4);
+
+            let forecast = contract
+                .forecast(ForecastMetric::AveragePrice, 2)
+                .expect("enough buckets");
+            assert_eq!(forecast.point, 150);
+            assert_eq!((forecast.lower, forecast.upper), (150, 150));
+            assert_eq!(forecast.samples, 4);
+
+            contract.set_forecast_params(ForecastModel::Ewma, 12, 5_000);
+            let forecast = contract
+                .forecast(ForecastMetric::AveragePrice, 2)
+                .expect("enough buckets");
+            // Level 100 -> 105 -> 112 -> 121; one-step errors 10, 15, 18 average 14,
+            // doubled and widened by (4 + 2) / 4
+            assert_eq!(forecast.point, 121);
+            assert_eq!(forecast.lower, 121 - 42);
+            assert_eq!(forecast.upper, 121 + 42);
+        }
+
+        #[ink::test]
+        fn consistency_drift_is_reported_and_alerted() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.set_reconciliation_sources(None, None, 100);
+
+            assert!(!contract.record_consistency(ConsistencyMetric::TokenShares, 1, 1_000, 1_000));
+            assert_eq!(contract.get_discrepancy_count(), 0);
+
+            // 0.5% drift is recorded but within tolerance
+            assert!(contract.record_consistency(ConsistencyMetric::PoolCapital, 2, 995, 1_000));
+            let report = contract.get_discrepancy_report(1).expect("report stored");
+            assert_eq!(report.drift_bps, 50);
+            assert!(!report.alerted);
+
+            assert!(contract.record_consistency(ConsistencyMetric::ActivePolicies, 2, 8, 10));
+            let report = contract.get_discrepancy_report(2).expect("report stored");
+            assert_eq!(report.drift_bps, 2_000);
+            assert!(report.alerted);
+            assert_eq!(ink::env::test::recorded_events().count(), 1);
+        }
+
+        #[ink::test]
+        fn valuation_disagreements_are_recorded_for_appraisal() {
+            use propchain_traits::ValuationAlertSink;
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.set_valuation_source(Some(accounts.bob));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.ingest_valuation_disagreement(7, 4_200, 6_000, 1_000_000, 3),
+                1
+            );
+            assert_eq!(
+                contract.ingest_valuation_disagreement(7, 5_100, 6_000, 1_050_000, 3),
+                2
+            );
+            let latest = contract.get_property_disagreement(7).expect("recorded");
+            assert_eq!(latest.consensus_score, 5_100);
+            assert!(!latest.appraised);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.mark_disagreement_appraised(2);
+            assert!(contract.get_property_disagreement(7).unwrap().appraised);
+            assert_eq!(contract.get_disagreement_count(), 2);
+        }
+
+        #[ink::test]
+        fn holder_snapshot_computes_concentration_and_alerts_once() {
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.set_concentration_thresholds(ConcentrationThresholds {
+                max_hhi: 2_500,
+                max_top10_bps: 0,
+                max_holder_bps: 0,
+            });
+
+            let even = contract.ingest_holder_snapshot(
+                1,
+                vec![
+                    (accounts.alice, 250),
+                    (accounts.bob, 250),
+                    (accounts.charlie, 250),
+                    (accounts.django, 250),
+                    (accounts.eve, 0),
+                ],
+            );
+            assert_eq!(even.holders, 4);
+            assert_eq!(even.hhi, 2_500);
+            assert_eq!(even.top10_bps, 10_000);
+            assert!(!even.breached);
+
+            let skewed = contract
+                .ingest_holder_snapshot(1, vec![(accounts.alice, 800), (accounts.bob, 200)]);
+            assert_eq!(skewed.hhi, 6_800);
+            assert_eq!(skewed.largest_holder_bps, 8_000);
+            assert!(skewed.breached);
+            contract.ingest_holder_snapshot(1, vec![(accounts.alice, 900), (accounts.bob, 100)]);
+            assert_eq!(ink::env::test::recorded_events().count(), 1);
+            assert_eq!(contract.get_concentration(1).unwrap().hhi, 8_200);
+            assert_eq!(contract.get_concentration(2), None);
+
+            // Split rows for one account count as one holder
+            let split = contract.ingest_holder_snapshot(
+                3,
+                vec![
+                    (accounts.alice, 300),
+                    (accounts.bob, 500),
+                    (accounts.alice, 200),
+                ],
+            );
+            assert_eq!(split.holders, 2);
+            assert_eq!(split.largest_holder_bps, 5_000);
+            assert_eq!(split.hhi, 5_000);
+        }
+
+        #[ink::test]
+        fn custom_kpis_evaluate_on_demand_and_at_snapshot() {
+            let mut contract = AnalyticsDashboard::new();
+            // Volume per listing, in basis points of a unit
+            let per_listing = contract.register_kpi(
+                String::from("volume_per_listing"),
+                vec![
+                    KpiNode::Metric(ForecastMetric::TotalVolume),
+                    KpiNode::Metric(ForecastMetric::PropertiesListed),
+                    KpiNode::Ratio(0, 1),
+                ],
+                true,
+            );
+            // Price change since the previous update plus a fixed offset
+            let price_move = contract.register_kpi(
+                String::from("price_move"),
+                vec![
+                    KpiNode::Delta(ForecastMetric::AveragePrice),
+                    KpiNode::Constant(-5),
+                    KpiNode::Sum(0, 1),
+                ],
+                false,
+            );
+            assert_eq!(contract.get_kpi_count(), 2);
+            assert_eq!(contract.evaluate_kpi(per_listing), None);
+
+            contract.update_market_metrics(1_000, 5_000, 10);
+            assert_eq!(contract.evaluate_kpi(per_listing), Some(5_000_000));
+            assert_eq!(contract.evaluate_kpi(price_move), None);
+            contract.update_market_metrics(900, 6_000, 12);
+            assert_eq!(contract.evaluate_kpi(price_move), Some(-105));
+            assert_eq!(
+                contract.get_kpi_snapshot(per_listing).unwrap().value,
+                5_000_000
+            );
+            assert_eq!(contract.get_kpi_snapshot(price_move), None);
+
+            contract.retire_kpi(per_listing);
+            contract.update_market_metrics(900, 9_000, 12);
+            assert_eq!(contract.evaluate_kpi(per_listing), None);
+            assert_eq!(
+                contract.get_kpi_snapshot(per_listing).unwrap().value,
+                5_000_000
+            );
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "KPI operand must refer to an earlier node")]
+        fn kpi_operands_must_precede_their_node() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.register_kpi(
+                String::from("cyclic"),
+                vec![KpiNode::Constant(1), KpiNode::Sum(0, 1)],
+                false,
+            );
+        }
+
+        #[ink::test]
+        fn streaming_metrics_track_moving_averages_and_hot_tokens() {
+            let mut contract = AnalyticsDashboard::new();
+            assert_eq!(
+                contract.get_streaming_metrics(),
+                StreamingMetrics::default()
+            );
+            contract.ingest_trade(1, 100, 10, 0, 1);
+            contract.ingest_trade(1, 200, 30, 0, 2);
+            contract.ingest_trade(1, 200, 30, 0, 3);
+            contract.ingest_dividend(2, 5, 4);
+            contract.ingest_token_shares(3, 1_000);
+
+            let metrics = contract.get_streaming_metrics();
+            assert_eq!(metrics.records_ingested, 5);
+            // alpha = 30%: 100 -> 130 -> 151
+            assert_eq!(metrics.ewma_price, 151);
+            assert_eq!(metrics.ewma_trade_size, 20);
+            assert_eq!(metrics.ewma_dividend, 5);
+            assert_eq!(metrics.hot_tokens[0], (1, 3));
+            assert_eq!(metrics.hot_tokens.len(), 3);
+            assert_eq!(contract.estimate_token_activity(1), 3);
+            assert_eq!(contract.estimate_token_activity(3), 1);
+        }
+
+        #[ink::test]
+        fn leaderboards_rank_tokens_and_reset_each_period() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.ingest_trade(1, 100, 10, 0, 1);
+            contract.ingest_trade(1, 120, 10, 0, 2);
+            contract.ingest_trade(2, 200, 50, 0, 3);
+            contract.ingest_trade(3, 50, 1, 0, 4);
+            contract.ingest_trade(3, 40, 1, 0, 5);
+            contract.ingest_dividend(1, 6, 6);
+            contract.ingest_dividend(2, 4, 7);
+
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::Volume, 2),
+                vec![(2, 10_000), (1, 2_200)]
+            );
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::PriceAppreciation, 10),
+                vec![(1, 2_000), (2, 0), (3, -2_000)]
+            );
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::DividendYield, 10),
+                vec![(1, 500), (2, 200)]
+            );
+
+            // Boards are bounded
+            for token_id in 100..100 + LEADERBOARD_SIZE as u64 {
+                contract.ingest_trade(token_id, 1_000, 100, 0, 8);
+            }
+            let volume = contract.get_leaderboard(LeaderboardKind::Volume, 100);
+            assert_eq!(volume.len(), LEADERBOARD_SIZE);
+            assert_eq!(volume[0], (100, 100_000));
+            assert!(!volume.iter().any(|(token, _)| *token == 3));
+
+            // A new day starts empty; prices open at the previous close
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(MS_PER_DAY);
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::Volume, 10),
+                Vec::new()
+            );
+            contract.ingest_trade(3, 60, 1, 0, MS_PER_DAY);
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::PriceAppreciation, 10),
+                vec![(3, 5_000)]
+            );
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::Volume, 10),
+                vec![(3, 60)]
+            );
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::DividendYield, 10),
+                Vec::new()
+            );
+        }
+
+        #[ink::test]
+        fn reversed_trades_rerank_leaderboards() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = AnalyticsDashboard::new();
+            contract.set_source_precedence(accounts.bob, 1);
+            contract.set_source_precedence(accounts.charlie, 2);
+            let trade = |price_per_share, amount| TokenActivity::Trade {
+                price_per_share,
+                amount,
+                fee: 0,
+            };
+            let (top, rise) = (Hash::from([1u8; 32]), Hash::from([2u8; 32]));
+            contract.ingest_trade(1, 30, 10, 0, 0);
+            contract.ingest_trade(2, 100, 1, 0, 0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.ingest_keyed_activity(top, 100, trade(1_000, 100), 0);
+            contract.ingest_keyed_activity(rise, 2, trade(150, 1), 0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            for token_id in 101..100 + LEADERBOARD_SIZE as u64 {
+                contract.ingest_trade(token_id, 1_000, 100, 0, 0);
+            }
+            let volume = contract.get_leaderboard(LeaderboardKind::Volume, 100);
+            assert!(!volume.iter().any(|(token, _)| *token == 1));
+            assert_eq!(
+                contract.get_leaderboard(LeaderboardKind::PriceAppreciation, 1),
+                vec![(2, 5_000)]
+            );
+
+            // Token 100's trade is corrected down; token 1 climbs back into view
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract.ingest_keyed_activity(top, 100, trade(1, 1), 0);
+            let volume = contract.get_leaderboard(LeaderboardKind::Volume, 100);
+            assert_eq!(volume.len(), LEADERBOARD_SIZE);
+            assert_eq!(volume[LEADERBOARD_SIZE - 1], (1, 300));
+            assert!(!volume.iter().any(|(token, _)| *token == 100));
+
+            // Token 2's rise belonged to token 3; its price falls back to the open
+            contract.ingest_keyed_activity(rise, 3, trade(150, 1), 0);
+            let appreciation = contract.get_leaderboard(LeaderboardKind::PriceAppreciation, 100);
+            assert!(appreciation.iter().all(|(_, score)| *score == 0));
+            assert_eq!(contract.leaderboard_stats.get(2).unwrap().last_price, 100);
+        }
+
+        #[ink::test]
+        fn benchmark_comparison_measures_segments_against_index() {
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.set_benchmark_period_length(1_000);
+            contract.set_benchmark_oracle(Some(accounts.bob));
+            contract.define_segment("residential".into(), ink::prelude::vec![1, 2]);
+            // Period 1 covers [1_000, 1_999]: token 1 gains 10%, token 2 gains 30%
+            contract.ingest_trade(1, 100, 10, 0, 900);
+            contract.ingest_trade(1, 110, 10, 0, 1_500);
+            contract.ingest_trade(2, 200, 10, 0, 950);
+            contract.ingest_trade(2, 260, 10, 0, 1_900);
+            contract.ingest_dividend(2, 10, 1_200);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.post_benchmark_value(1, 1_050);
+            // Without the opening value there is nothing to compare yet
+            assert_eq!(
+                contract.get_benchmark_comparison("residential".into(), 1),
+                None
+            );
+            contract.post_benchmark_value(0, 1_000);
+            let comparison = contract
+                .get_benchmark_comparison("residential".into(), 1)
+                .expect("compared once both values are known");
+            assert_eq!(comparison.benchmark_return_bps, 500);
+            assert_eq!(comparison.segment_price_return_bps, 2_000);
+            assert_eq!(comparison.segment_total_return_bps, 2_250);
+            assert_eq!(comparison.excess_return_bps, 1_500);
+            assert_eq!(comparison.tokens_compared, 2);
+            assert_eq!(contract.get_benchmark_value(1), Some(1_050));
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Unauthorized: benchmark oracle only")]
+        fn benchmark_values_require_oracle() {
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract.post_benchmark_value(1, 1_000);
+        }
+
+        #[ink::test]
+        fn federation_combines_sibling_metrics_with_freshness() {
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.set_local_chain_id(1);
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            contract.update_market_metrics(100, 5_000, 10);
+            contract.register_federated_chain(2, accounts.django);
+            contract.register_federated_chain(3, accounts.eve);
+
+            let digest = |sequence: u64, average_price: u128| FederatedDigest {
+                chain_id: 2,
+                sequence,
+                metrics: MarketMetrics {
+                    average_price,
+                    total_volume: 3_000,
+                    properties_listed: 30,
+                },
+                observed_at: 900,
+            };
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+            let source = contract.get_federated_source(2).unwrap();
+            contract.apply_federated_digest(source, digest(1, 200), accounts.bob);
+
+            let global = contract.get_global_metrics();
+            // Listing-weighted: (100 * 10 + 200 * 30) / 40
+            assert_eq!(
+                global.combined,
+                MarketMetrics {
+                    average_price: 175,
+                    total_volume: 8_000,
+                    properties_listed: 40,
+                }
+            );
+            // Chain 3 has not reported yet
+            assert_eq!(
+                global
+                    .sources
+                    .iter()
+                    .map(|s| (s.chain_id, s.observed_at, s.received_at))
+                    .collect::<Vec<_>>(),
+                ink::prelude::vec![(1, 1_000, 1_000), (2, 900, 2_000)]
+            );
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Stale federation digest")]
+        fn federated_digests_cannot_be_replayed() {
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.register_federated_chain(2, accounts.django);
+            let digest = FederatedDigest {
+                chain_id: 2,
+                sequence: 1,
+                metrics: contract.get_market_metrics(),
+                observed_at: 0,
+            };
+            let source = contract.get_federated_source(2).unwrap();
+            contract.apply_federated_digest(source, digest.clone(), accounts.bob);
+            let source = contract.get_federated_source(2).unwrap();
+            contract.apply_federated_digest(source, digest, accounts.bob);
+        }
+
+        #[ink::test]
+        fn federated_digest_signed_by_attester_is_applied() {
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let key = secp256k1::SecretKey::from_slice(&[5u8; 32]).unwrap();
+            let mut attester = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(
+                &key.public_key(secp256k1::SECP256K1).serialize(),
+                &mut attester,
+            );
+            contract.register_federated_chain(2, AccountId::from(attester));
+            contract.set_federation_relayer(accounts.bob, true);
+
+            let digest = FederatedDigest {
+                chain_id: 2,
+                sequence: 1,
+                metrics: MarketMetrics {
+                    average_price: 300,
+                    total_volume: 9_000,
+                    properties_listed: 30,
+                },
+                observed_at: 0,
+            };
+            let message =
+                secp256k1::Message::from_digest(contract.federation_digest_hash(digest.clone()));
+            let (recovery_id, compact) = secp256k1::SECP256K1
+                .sign_ecdsa_recoverable(&message, &key)
+                .serialize_compact();
+            let mut signature = [0u8; 65];
+            signature[..64].copy_from_slice(&compact);
+            signature[64] = recovery_id.to_i32() as u8;
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.post_federated_metrics(digest.clone(), signature);
+            let source = contract.get_federated_source(2).unwrap();
+            assert_eq!(source.sequence, 1);
+            assert_eq!(source.latest.unwrap().metrics, digest.metrics);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Invalid digest signature")]
+        fn federated_digests_require_attester_signature() {
+            let mut contract = AnalyticsDashboard::new();
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.register_federated_chain(2, accounts.django);
+            contract.set_federation_relayer(accounts.bob, true);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.post_federated_metrics(
+                FederatedDigest {
+                    chain_id: 2,
+                    sequence: 1,
+                    metrics: MarketMetrics {
+                        average_price: 1,
+                        total_volume: 1,
+                        properties_listed: 1,
+                    },
+                    observed_at: 0,
+                },
+                [1u8; 65],
+            );
+        }
+
+        #[ink::test]
+        fn occupancy_inferred_from_rent_payments() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.ingest_rent_payment(1, 0, 100, 10, 10);
+            contract.ingest_rent_payment(1, 100, 200, 10, 5);
+            // Fifty vacant between tenancies
+            contract.ingest_rent_payment(1, 250, 300, 10, 10);
+            let token = contract.get_token_occupancy(1).unwrap();
+            assert_eq!((token.stats.occupied_ms, token.stats.vacant_ms), (250, 50));
+            assert_eq!(token.stats.vacancy_periods, 1);
+            assert_eq!(token.occupancy_rate_bps, 8_333);
+            assert_eq!(token.vacancy_rate_bps, 1_667);
+            assert_eq!(token.collection_ratio_bps, 8_333);
+
+            // Late rent for the short-paid second period
+            let before = contract.get_integrity_hash(1);
+            contract.ingest_rent_arrears(1, 100, 5);
+            assert_ne!(contract.get_integrity_hash(1), before);
+            let token = contract.get_token_occupancy(1).unwrap();
+            assert_eq!(token.collection_ratio_bps, 10_000);
+            assert_eq!((token.stats.occupied_ms, token.stats.payments), (250, 3));
+
+            // A period with no rent due is itself vacant
+            contract.ingest_rent_payment(2, 0, 100, 0, 0);
+            contract.ingest_rent_payment(2, 100, 200, 20, 20);
+            let token = contract.get_token_occupancy(2).unwrap();
+            assert_eq!(token.stats.vacancy_periods, 1);
+            assert_eq!(token.occupancy_rate_bps, 5_000);
+            assert_eq!(token.collection_ratio_bps, 10_000);
+
+            contract.define_segment("residential".into(), ink::prelude::vec![1, 2, 3]);
+            let segment = contract
+                .get_segment_occupancy("residential".into())
+                .unwrap();
+            assert_eq!(
+                (segment.stats.occupied_ms, segment.stats.vacant_ms),
+                (350, 150)
+            );
+            assert_eq!(segment.occupancy_rate_bps, 7_000);
+            assert_eq!(segment.collection_ratio_bps, 10_000);
+            assert_eq!(contract.get_portfolio_occupancy(), segment);
+            assert_eq!(contract.get_segment_occupancy("office".into()), None);
+            assert_eq!(contract.get_token_occupancy(3), None);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Rent period already recorded")]
+        fn rent_periods_must_advance() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.ingest_rent_payment(1, 100, 200, 10, 10);
+            contract.ingest_rent_payment(1, 0, 150, 10, 10);
+        }
+
+        #[ink::test]
+        #[should_panic(expected = "Arrears exceed outstanding rent")]
+        fn rent_arrears_capped_at_outstanding() {
+            let mut contract = AnalyticsDashboard::new();
+            contract.ingest_rent_payment(1, 0, 100, 10, 5);
+            contract.ingest_rent_arrears(1, 0, 6);
         }
 
         #[ink::test]
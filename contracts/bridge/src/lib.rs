@@ -27,6 +27,8 @@ mod bridge {
         InvalidMetadata,
         DuplicateRequest,
         GasLimitExceeded,
+        UnsupportedSchemaVersion,
+        DeliveryWindowOpen,
     }
 
     /// Bridge contract for cross-chain property token transfers
@@ -58,6 +60,18 @@ mod bridge {
 
         /// Admin account
         admin: AccountId,
+
+        /// Versioned metadata envelopes committed per bridge request
+        bridged_metadata: Mapping<u64, BridgedMetadata>,
+
+        /// Metadata reconstructed on this chain, keyed by source transaction hash
+        received_metadata: Mapping<Hash, ReceivedMetadata>,
+
+        /// Blocks operators have to confirm the destination mint after execution
+        delivery_window_blocks: u64,
+
+        /// Delivery tracking per executed bridge request
+        deliveries: Mapping<u64, DeliveryRecord>,
     }
 
     /// Events for bridge operations
@@ -95,6 +109,17 @@ mod bridge {
         pub transaction_hash: Hash,
     }
 
+    #[ink(event)]
+    pub struct DeliveryConfirmed {
+        #[ink(topic)]
+        pub request_id: u64,
+        #[ink(topic)]
+        pub remote_token_id: TokenId,
+        #[ink(topic)]
+        pub confirmed_by: AccountId,
+        pub proof: Hash,
+    }
+
     #[ink(event)]
     pub struct BridgeFailed {
         #[ink(topic)]
@@ -112,6 +137,16 @@ mod bridge {
         pub recovery_action: RecoveryAction,
     }
 
+    #[ink(event)]
+    pub struct MetadataReconstructed {
+        #[ink(topic)]
+        pub transaction_hash: Hash,
+        #[ink(topic)]
+        pub token_id: TokenId,
+        pub policy: MetadataPreservation,
+        pub complete: bool,
+    }
+
     impl PropertyBridge {
         /// Creates a new PropertyBridge contract
         #[ink(constructor)]
@@ -130,7 +165,7 @@ mod bridge {
                 default_timeout_blocks: default_timeout,
                 gas_limit_per_bridge: gas_limit,
                 emergency_pause: false,
-                metadata_preservation: true,
+                metadata_preservation: MetadataPreservation::Full,
             };
 
             // Initialize chain info for supported chains
@@ -144,6 +179,10 @@ mod bridge {
                 request_counter: 0,
                 transaction_counter: 0,
                 admin: caller,
+                bridged_metadata: Mapping::default(),
+                received_metadata: Mapping::default(),
+                delivery_window_blocks: default_timeout,
+                deliveries: Mapping::default(),
             };
 
             // Set up default chain information
@@ -204,6 +243,14 @@ mod bridge {
             let current_block = u64::from(self.env().block_number());
             let expires_at = timeout_blocks.map(|blocks| current_block + blocks);
 
+            let envelope = BridgedMetadata {
+                schema_version: METADATA_SCHEMA_VERSION,
+                policy: self.config.metadata_preservation,
+                metadata_hash: Self::hash_metadata(&metadata),
+                payload: Self::strip_metadata(&metadata, self.config.metadata_preservation),
+            };
+            self.bridged_metadata.insert(request_id, &envelope);
+
             let request = MultisigBridgeRequest {
                 request_id,
                 token_id,
@@ -322,13 +369,31 @@ mod bridge {
                 timestamp: self.env().block_timestamp(),
                 gas_used: self.estimate_gas_usage(&request),
                 status: BridgeOperationStatus::InTransit,
-                metadata: request.metadata.clone(),
+                metadata: self
+                    .bridged_metadata
+                    .get(request_id)
+                    .map(|envelope| envelope.payload)
+                    .unwrap_or_else(|| request.metadata.clone()),
             };
 
-            // Update request status
-            request.status = BridgeOperationStatus::Completed;
+            // The request stays in transit until the destination mint is confirmed
+            request.status = BridgeOperationStatus::InTransit;
             self.bridge_requests.insert(request_id, &request);
 
+            let executed_at = u64::from(self.env().block_number());
+            self.deliveries.insert(
+                request_id,
+                &DeliveryRecord {
+                    transaction_hash,
+                    executed_at,
+                    confirm_by: executed_at.saturating_add(self.delivery_window_blocks),
+                    remote_token_id: None,
+                    proof: None,
+                    confirmed_by: None,
+                    confirmed_at: None,
+                },
+            );
+
             // Store transaction verification
             self.verified_transactions.insert(transaction_hash, &true);
 
@@ -346,6 +411,92 @@ mod bridge {
             Ok(())
         }
 
+        /// Confirms the destination mint of an in-transit bridge (operators only)
+        ///
+        /// `proof` references the mint on the destination chain. Confirmation
+        /// must land within the delivery window; after that the request can
+        /// only be recovered.
+        #[ink(message)]
+        pub fn confirm_delivery(
+            &mut self,
+            request_id: u64,
+            remote_token_id: TokenId,
+            proof: Hash,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if !self.bridge_operators.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            let mut request = self
+                .bridge_requests
+                .get(request_id)
+                .ok_or(Error::InvalidRequest)?;
+            if request.status != BridgeOperationStatus::InTransit {
+                return Err(Error::InvalidRequest);
+            }
+
+            let mut delivery = self
+                .deliveries
+                .get(request_id)
+                .ok_or(Error::InvalidRequest)?;
+            let current_block = u64::from(self.env().block_number());
+            if current_block > delivery.confirm_by {
+                return Err(Error::RequestExpired);
+            }
+
+            delivery.remote_token_id = Some(remote_token_id);
+            delivery.proof = Some(proof);
+            delivery.confirmed_by = Some(caller);
+            delivery.confirmed_at = Some(current_block);
+            self.deliveries.insert(request_id, &delivery);
+
+            request.status = BridgeOperationStatus::Completed;
+            self.bridge_requests.insert(request_id, &request);
+
+            let mut history = self.bridge_history.get(request.sender).unwrap_or_default();
+            if let Some(transaction) = history
+                .iter_mut()
+                .find(|tx| tx.transaction_hash == delivery.transaction_hash)
+            {
+                transaction.status = BridgeOperationStatus::Completed;
+                self.bridge_history.insert(request.sender, &history);
+            }
+
+            self.env().emit_event(DeliveryConfirmed {
+                request_id,
+                remote_token_id,
+                confirmed_by: caller,
+                proof,
+            });
+
+            Ok(())
+        }
+
+        /// Gets delivery tracking for an executed bridge request
+        #[ink(message)]
+        pub fn get_delivery_record(&self, request_id: u64) -> Option<DeliveryRecord> {
+            self.deliveries.get(request_id)
+        }
+
+        /// Sets the delivery confirmation window in blocks (admin only)
+        #[ink(message)]
+        pub fn set_delivery_window(&mut self, blocks: u64) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.delivery_window_blocks = blocks;
+            Ok(())
+        }
+
+        /// Gets the delivery confirmation window in blocks
+        #[ink(message)]
+        pub fn get_delivery_window(&self) -> u64 {
+            self.delivery_window_blocks
+        }
+
         /// Recovers from a failed bridge operation
         #[ink(message)]
         pub fn recover_failed_bridge(
@@ -365,12 +516,19 @@ mod bridge {
                 .get(request_id)
                 .ok_or(Error::InvalidRequest)?;
 
-            // Check if request is in a failed state
-            if !matches!(
-                request.status,
-                BridgeOperationStatus::Failed | BridgeOperationStatus::Expired
-            ) {
-                return Err(Error::InvalidRequest);
+            // Check if request is in a failed state, or in transit past its delivery window
+            match request.status {
+                BridgeOperationStatus::Failed | BridgeOperationStatus::Expired => {}
+                BridgeOperationStatus::InTransit => {
+                    let delivery = self
+                        .deliveries
+                        .get(request_id)
+                        .ok_or(Error::InvalidRequest)?;
+                    if u64::from(self.env().block_number()) <= delivery.confirm_by {
+                        return Err(Error::DeliveryWindowOpen);
+                    }
+                }
+                _ => return Err(Error::InvalidRequest),
             }
 
             // Execute recovery action
@@ -527,6 +685,100 @@ mod bridge {
             Ok(())
         }
 
+        /// Sets which metadata fields cross the bridge (admin only)
+        #[ink(message)]
+        pub fn set_metadata_preservation(
+            &mut self,
+            policy: MetadataPreservation,
+        ) -> Result<(), Error> {
+            let caller = self.env().caller();
+            if caller != self.admin {
+                return Err(Error::Unauthorized);
+            }
+
+            self.config.metadata_preservation = policy;
+            Ok(())
+        }
+
+        /// Gets the metadata envelope committed for a bridge request
+        #[ink(message)]
+        pub fn get_bridged_metadata(&self, request_id: u64) -> Option<BridgedMetadata> {
+            self.bridged_metadata.get(request_id)
+        }
+
+        /// Reconstructs bridged metadata on the destination chain (operators only)
+        ///
+        /// `supplement` carries the full metadata obtained off-chain. It is
+        /// required under `HashOnly`, optional under `Minimal`, and must hash
+        /// to the committed value whenever it is given.
+        #[ink(message)]
+        pub fn receive_bridged_metadata(
+            &mut self,
+            transaction_hash: Hash,
+            token_id: TokenId,
+            envelope: BridgedMetadata,
+            supplement: Option<PropertyMetadata>,
+        ) -> Result<PropertyMetadata, Error> {
+            let caller = self.env().caller();
+            if !self.bridge_operators.contains(&caller) {
+                return Err(Error::Unauthorized);
+            }
+
+            if envelope.schema_version == 0 || envelope.schema_version > METADATA_SCHEMA_VERSION {
+                return Err(Error::UnsupportedSchemaVersion);
+            }
+
+            if self.received_metadata.contains(transaction_hash) {
+                return Err(Error::DuplicateRequest);
+            }
+
+            let (metadata, complete) = match (envelope.policy, supplement) {
+                (MetadataPreservation::Full, _) => {
+                    if Self::hash_metadata(&envelope.payload) != envelope.metadata_hash {
+                        return Err(Error::InvalidMetadata);
+                    }
+                    (envelope.payload.clone(), true)
+                }
+                (MetadataPreservation::Minimal, None) => (envelope.payload.clone(), false),
+                (policy, Some(full)) => {
+                    if Self::hash_metadata(&full) != envelope.metadata_hash
+                        || Self::strip_metadata(&full, policy) != envelope.payload
+                    {
+                        return Err(Error::InvalidMetadata);
+                    }
+                    (full, true)
+                }
+                (MetadataPreservation::HashOnly, None) => return Err(Error::InvalidMetadata),
+            };
+
+            self.received_metadata.insert(
+                transaction_hash,
+                &ReceivedMetadata {
+                    token_id,
+                    schema_version: envelope.schema_version,
+                    policy: envelope.policy,
+                    metadata: metadata.clone(),
+                    complete,
+                    received_at: self.env().block_timestamp(),
+                },
+            );
+
+            self.env().emit_event(MetadataReconstructed {
+                transaction_hash,
+                token_id,
+                policy: envelope.policy,
+                complete,
+            });
+
+            Ok(metadata)
+        }
+
+        /// Gets metadata reconstructed for a source transaction
+        #[ink(message)]
+        pub fn get_received_metadata(&self, transaction_hash: Hash) -> Option<ReceivedMetadata> {
+            self.received_metadata.get(transaction_hash)
+        }
+
         /// Gets chain information
         #[ink(message)]
         pub fn get_chain_info(&self, chain_id: ChainId) -> Option<ChainBridgeInfo> {
@@ -583,6 +835,36 @@ mod bridge {
             Hash::from(hash_bytes)
         }
 
+        fn hash_metadata(metadata: &PropertyMetadata) -> Hash {
+            let mut output =
+                <ink::env::hash::Blake2x256 as ink::env::hash::HashOutput>::Type::default();
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(metadata, &mut output);
+            Hash::from(output)
+        }
+
+        fn strip_metadata(
+            metadata: &PropertyMetadata,
+            policy: MetadataPreservation,
+        ) -> PropertyMetadata {
+            match policy {
+                MetadataPreservation::Full => metadata.clone(),
+                MetadataPreservation::Minimal => PropertyMetadata {
+                    location: metadata.location.clone(),
+                    size: metadata.size,
+                    legal_description: String::new(),
+                    valuation: metadata.valuation,
+                    documents_url: String::new(),
+                },
+                MetadataPreservation::HashOnly => PropertyMetadata {
+                    location: String::new(),
+                    size: 0,
+                    legal_description: String::new(),
+                    valuation: 0,
+                    documents_url: String::new(),
+                },
+            }
+        }
+
         fn estimate_gas_usage(&self, request: &MultisigBridgeRequest) -> u64 {
             // Estimate gas usage based on request complexity
             let base_gas = 100000; // Base gas for bridge operation
@@ -653,5 +935,144 @@ mod bridge {
             let result = bridge.sign_bridge_request(request_id, true);
             assert!(result.is_ok());
         }
+
+        #[ink::test]
+        fn test_minimal_metadata_policy_round_trip() {
+            let mut bridge = setup_bridge();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            bridge
+                .set_metadata_preservation(MetadataPreservation::Minimal)
+                .unwrap();
+            bridge.add_bridge_operator(accounts.bob).unwrap();
+
+            let metadata = PropertyMetadata {
+                location: String::from("Test Property"),
+                size: 1000,
+                legal_description: String::from("Lot 7, Block 3"),
+                valuation: 100000,
+                documents_url: String::from("ipfs://test"),
+            };
+            let request_id = bridge
+                .initiate_bridge_multisig(1, 2, accounts.bob, 2, Some(50), metadata.clone())
+                .unwrap();
+            bridge.sign_bridge_request(request_id, true).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            bridge.sign_bridge_request(request_id, true).unwrap();
+            bridge.execute_bridge(request_id).unwrap();
+
+            let transaction = bridge.get_bridge_history(accounts.alice)[0].clone();
+            assert_eq!(transaction.metadata.location, metadata.location);
+            assert!(transaction.metadata.legal_description.is_empty());
+
+            let envelope = bridge.get_bridged_metadata(request_id).unwrap();
+            assert_eq!(envelope.schema_version, METADATA_SCHEMA_VERSION);
+
+            let mut tampered = metadata.clone();
+            tampered.legal_description = String::from("Lot 8, Block 3");
+            assert_eq!(
+                bridge.receive_bridged_metadata(
+                    transaction.transaction_hash,
+                    1,
+                    envelope.clone(),
+                    Some(tampered)
+                ),
+                Err(Error::InvalidMetadata)
+            );
+
+            let restored = bridge
+                .receive_bridged_metadata(
+                    transaction.transaction_hash,
+                    1,
+                    envelope,
+                    Some(metadata.clone()),
+                )
+                .unwrap();
+            assert_eq!(restored, metadata);
+            assert!(
+                bridge
+                    .get_received_metadata(transaction.transaction_hash)
+                    .unwrap()
+                    .complete
+            );
+        }
+
+        #[ink::test]
+        fn test_delivery_confirmation_and_recovery() {
+            let mut bridge = setup_bridge();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            bridge.add_bridge_operator(accounts.bob).unwrap();
+            bridge.set_delivery_window(5).unwrap();
+
+            let metadata = PropertyMetadata {
+                location: String::from("Test Property"),
+                size: 1000,
+                legal_description: String::from("Test"),
+                valuation: 100000,
+                documents_url: String::from("ipfs://test"),
+            };
+            let execute = |bridge: &mut PropertyBridge| {
+                test::set_caller::<DefaultEnvironment>(accounts.alice);
+                let request_id = bridge
+                    .initiate_bridge_multisig(1, 2, accounts.bob, 2, None, metadata.clone())
+                    .unwrap();
+                bridge.sign_bridge_request(request_id, true).unwrap();
+                test::set_caller::<DefaultEnvironment>(accounts.bob);
+                bridge.sign_bridge_request(request_id, true).unwrap();
+                bridge.execute_bridge(request_id).unwrap();
+                request_id
+            };
+
+            // Executed bridges wait in transit for the destination mint
+            let confirmed = execute(&mut bridge);
+            assert_eq!(
+                bridge.monitor_bridge_status(confirmed).unwrap().status,
+                BridgeOperationStatus::InTransit
+            );
+            let proof = Hash::from([7u8; 32]);
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                bridge.confirm_delivery(confirmed, 42, proof),
+                Err(Error::Unauthorized)
+            );
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            bridge.confirm_delivery(confirmed, 42, proof).unwrap();
+            assert_eq!(
+                bridge.monitor_bridge_status(confirmed).unwrap().status,
+                BridgeOperationStatus::Completed
+            );
+            let record = bridge.get_delivery_record(confirmed).unwrap();
+            assert_eq!(record.remote_token_id, Some(42));
+            assert_eq!(record.proof, Some(proof));
+            assert_eq!(
+                bridge.get_bridge_history(accounts.alice)[0].status,
+                BridgeOperationStatus::Completed
+            );
+
+            // Unconfirmed deliveries become recoverable once the window lapses
+            let stalled = execute(&mut bridge);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                bridge.recover_failed_bridge(stalled, RecoveryAction::UnlockToken),
+                Err(Error::DeliveryWindowOpen)
+            );
+            for _ in 0..6 {
+                test::advance_block::<DefaultEnvironment>();
+            }
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                bridge.confirm_delivery(stalled, 43, proof),
+                Err(Error::RequestExpired)
+            );
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            bridge
+                .recover_failed_bridge(stalled, RecoveryAction::CancelBridge)
+                .unwrap();
+            assert_eq!(
+                bridge.monitor_bridge_status(stalled).unwrap().status,
+                BridgeOperationStatus::Failed
+            );
+        }
     }
 }
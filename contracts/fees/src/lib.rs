@@ -21,6 +21,30 @@ mod propchain_fees {
     const CONGESTION_WINDOW: u32 = 100;
     /// Max fee multiplier from congestion (e.g. 3x base)
     const MAX_CONGESTION_MULTIPLIER: u32 = 300; // 300% of base
+    /// Weight charged to a message with no configured estimate
+    const DEFAULT_MESSAGE_WEIGHT: u64 = 10_000;
+    /// Default band a declared value may sit around the attested valuation (10%)
+    const DEFAULT_VALUATION_TOLERANCE_BP: u32 = 1_000;
+    /// Unrecoverable account burned fees are sent to (no known key)
+    const BURN_ACCOUNT: [u8; 32] = [0u8; 32];
+    /// Upper bound on scheduled config changes awaiting activation
+    const MAX_PENDING_CONFIG_CHANGES: usize = 16;
+    /// Notice required for config changes until the admin sets another (24h in ms)
+    const DEFAULT_CONFIG_CHANGE_DELAY: u64 = 86_400_000;
+    /// Upper bound on fee rule journal entries returned per page
+    const MAX_RULE_JOURNAL_PAGE: u32 = 100;
+
+    /// Every operation with a configurable fee, in schedule order
+    const ALL_OPERATIONS: [FeeOperation; 8] = [
+        FeeOperation::RegisterProperty,
+        FeeOperation::TransferProperty,
+        FeeOperation::UpdateMetadata,
+        FeeOperation::CreateEscrow,
+        FeeOperation::ReleaseEscrow,
+        FeeOperation::PremiumListingBid,
+        FeeOperation::IssueBadge,
+        FeeOperation::OracleUpdate,
+    ];
 
     #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
     #[cfg_attr(
@@ -70,10 +94,14 @@ mod propchain_fees {
         pub end_time: u64,
         pub settled: bool,
         pub fee_paid: u128,
+        /// Listing fee returned to the seller at settlement
+        pub rebate: u128,
+        /// Deadline for the current winner to pay, once the auction awaits payment
+        pub payment_deadline: Option<u64>,
     }
 
     /// Bid in a premium auction
-    #[derive(Debug, Clone, scale::Encode, scale::Decode)]
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
     #[cfg_attr(
         feature = "std",
         derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
@@ -82,6 +110,8 @@ mod propchain_fees {
         pub bidder: AccountId,
         pub amount: u128,
         pub timestamp: u64,
+        /// Held until the bidder pays, withdraws or forfeits it by defaulting
+        pub deposit: u128,
     }
 
     /// Reward record for validators/participants
@@ -94,9 +124,59 @@ mod propchain_fees {
         pub account: AccountId,
         pub amount: u128,
         pub reason: RewardReason,
+        /// Distribution epoch the reward was earned in
+        pub epoch: u64,
+        pub timestamp: u64,
+    }
+
+    /// Linear release of a large validator distribution
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct VestingSchedule {
+        pub epoch: u64,
+        pub total: u128,
+        /// Already moved to claimed rewards
+        pub released: u128,
+        pub start: u64,
+        /// Release period (ms)
+        pub duration: u64,
+    }
+
+    /// Rewards paid out to a participant by one claim
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct RewardClaim {
+        pub amount: u128,
+        /// Part of the amount released from vesting schedules
+        pub vested: u128,
         pub timestamp: u64,
     }
 
+    /// Reward totals of an account over a time range, for accounting
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct RewardStatement {
+        pub account: AccountId,
+        pub from: u64,
+        pub to: u64,
+        /// Reward records in range
+        pub entries: u32,
+        pub earned: u128,
+        pub validator_rewards: u128,
+        pub claimed: u128,
+        /// Still locked in vesting schedules now
+        pub unvested: u128,
+    }
+
     #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(
         feature = "std",
@@ -107,6 +187,7 @@ mod propchain_fees {
         LiquidityProvider,
         PremiumListingFee,
         ParticipationIncentive,
+        ListingRebate,
     }
 
     /// Fee report for transparency and dashboard
@@ -123,6 +204,17 @@ mod propchain_fees {
         pub total_distributed: u128,
         pub operation_count_24h: u64,
         pub premium_auctions_active: u32,
+        pub treasury_deployed: u128,
+        pub strategy_value: u128, // As of the last strategy sync
+        pub realized_yield: u128,
+        pub prepaid_liability: u128, // Unredeemed fee credits
+        pub sponsor_liability: u128, // Unspent sponsorship budgets
+        pub total_sponsored: u128,
+        pub burn_share_bp: u32,
+        pub total_burned: u128,
+        pub pending_burn: u128, // Burn share waiting to reach the existential deposit
+        pub reward_liability: u128, // Pending rewards plus unreleased vesting
+        pub rewards_degraded: bool, // Last distribution was scaled down for lack of funds
         pub timestamp: u64,
     }
 
@@ -141,6 +233,264 @@ mod propchain_fees {
         pub recommendation: String,
     }
 
+    /// Lifecycle of a request-for-quote
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum RfqStatus {
+        Open,
+        Quoted,
+        Accepted,
+        Cancelled,
+    }
+
+    /// Request-for-quote for negotiated bulk pricing
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Rfq {
+        pub requester: AccountId,
+        pub operation: FeeOperation,
+        /// Number of operations the quote should cover
+        pub volume: u32,
+        /// How long the accepted rate stays locked (seconds)
+        pub window_seconds: u64,
+        pub status: RfqStatus,
+        /// Binding fee per operation offered by the quoter
+        pub quoted_fee: u128,
+        pub quoted_by: Option<AccountId>,
+        /// Quote must be accepted before this time
+        pub quote_expires_at: u64,
+        /// Accepted rate applies until this time
+        pub locked_until: u64,
+        /// Operations already charged against this RFQ
+        pub used: u32,
+        pub created_at: u64,
+    }
+
+    /// Operations prepaid at the fee in force when purchased
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct FeeCredit {
+        pub owner: AccountId,
+        pub operation: FeeOperation,
+        pub units: u32,
+        /// Units not yet redeemed
+        pub remaining: u32,
+        /// Fee locked at purchase
+        pub fee_per_unit: u128,
+        pub purchased_at: u64,
+        /// Unredeemed units are forfeited to the treasury after this time
+        pub expires_at: u64,
+    }
+
+    /// Budget a sponsor sets aside to pay fees on behalf of registered accounts
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct Sponsorship {
+        /// Unspent budget
+        pub budget: u128,
+        /// Fees paid from the budget (all time)
+        pub spent: u128,
+        /// Operations sponsored per account, e.g. an account's first 10 (0 = unlimited)
+        pub max_ops_per_account: u32,
+        /// Fees sponsored per account (0 = unlimited)
+        pub max_spend_per_account: u128,
+        pub accounts: u32,
+        pub active: bool,
+    }
+
+    /// What a sponsor has paid for one sponsored account
+    #[derive(Debug, Clone, Default, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct SponsoredUsage {
+        pub ops: u32,
+        pub spent: u128,
+    }
+
+    /// Invocation counters for one message
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct MessageUsage {
+        pub message: String,
+        pub calls: u64,
+        /// Sum of the message's weight estimate over all calls
+        pub total_weight: u64,
+        pub last_called: u64,
+    }
+
+    /// Operation intent waiting for the fee to drop under its bound
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct DeferredIntent {
+        pub owner: AccountId,
+        pub operation: FeeOperation,
+        /// Highest fee the owner accepts
+        pub max_fee: u128,
+        /// Paid to the keeper that executes the intent
+        pub tip: u128,
+        /// Registered contract that performs the operation
+        pub target: AccountId,
+        pub payload: Vec<u8>,
+        /// Value held for fee and tip; the rest is refunded
+        pub deposit: u128,
+        pub created_at: u64,
+    }
+
+    /// How an operation's fee relates to the value it moves
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum FeeMode {
+        /// Dynamic per-operation fee only
+        #[default]
+        Flat,
+        /// Share of the declared value, clamped
+        PercentageOfValue,
+        /// Dynamic fee plus a share of the declared value, clamped
+        Hybrid,
+    }
+
+    /// Value-based fee settings for one operation
+    #[derive(Debug, Clone, PartialEq, Default, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ValueFeeSchedule {
+        pub mode: FeeMode,
+        /// Share of the declared value charged (basis points)
+        pub rate_bp: u32,
+        /// Clamps applied to value-based fees (ignored in flat mode)
+        pub min_fee: u128,
+        pub max_fee: u128,
+    }
+
+    /// Fee config change announced ahead of its activation
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct ConfigChange {
+        pub change_id: u64,
+        /// Operation whose config is replaced; None replaces the default config
+        pub operation: Option<FeeOperation>,
+        pub config: FeeConfig,
+        pub activates_at: u64,
+        pub proposed_by: AccountId,
+        pub proposed_at: u64,
+        /// Reason staged by the proposer, journaled when the change activates
+        pub reason_hash: [u8; 32],
+    }
+
+    /// Fee rule mutation with the values before and after it
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum FeeRuleChange {
+        /// Config of an operation, or the default config when `operation` is None
+        OperationConfig {
+            operation: Option<FeeOperation>,
+            old: Option<FeeConfig>,
+            new: FeeConfig,
+        },
+        FeeMode {
+            operation: FeeOperation,
+            old: ValueFeeSchedule,
+            new: ValueFeeSchedule,
+        },
+        ValuationCheck {
+            old: (Option<AccountId>, u32),
+            new: (Option<AccountId>, u32),
+        },
+        /// (validator share, treasury share) in basis points
+        DistributionRates {
+            old: (u32, u32),
+            new: (u32, u32),
+        },
+        BurnShare {
+            old: u32,
+            new: u32,
+        },
+        ConfigChangeDelay {
+            old: u64,
+            new: u64,
+        },
+        /// (multiple bp, share bp, budget per period, period)
+        RebatePolicy {
+            old: (u32, u32, u128, u64),
+            new: (u32, u32, u128, u64),
+        },
+        /// (validity, per-account cap, total cap)
+        FeeCreditPolicy {
+            old: (u64, u128, u128),
+            new: (u64, u128, u128),
+        },
+        /// (min increment, min increment bp, bid deposit, settlement window)
+        AuctionSafeguards {
+            old: (u128, u32, u128, u64),
+            new: (u128, u32, u128, u64),
+        },
+        DeferralTarget {
+            target: AccountId,
+            old: bool,
+            new: bool,
+        },
+        /// (threshold, duration)
+        VestingPolicy {
+            old: (u128, u64),
+            new: (u128, u64),
+        },
+        MessageWeight {
+            message: String,
+            old: Option<u64>,
+            new: u64,
+        },
+        WeightCapacity {
+            old: u64,
+            new: u64,
+        },
+    }
+
+    /// Append-only fee rule journal entry; entries are never modified or pruned
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub struct FeeRuleEntry {
+        pub index: u64,
+        pub changed_by: AccountId,
+        pub changed_at: u64,
+        pub change: FeeRuleChange,
+        /// Hash of the off-chain justification; zero when none was staged
+        pub reason_hash: [u8; 32],
+    }
+
     #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
     pub enum FeeError {
@@ -152,6 +502,39 @@ mod propchain_fees {
         AlreadySettled,
         InvalidConfig,
         InvalidProperty,
+        RfqNotFound,
+        RfqInvalidState,
+        RfqExpired,
+        RfqVolumeExhausted,
+        IntentNotFound,
+        TargetNotRegistered,
+        InsufficientDeposit,
+        TransferFailed,
+        ValidatorSetManaged,
+        SyncTooSoon,
+        ValidatorSourceUnavailable,
+        SellerNotCompliant,
+        NotPropertyOwner,
+        StrategyNotSet,
+        DeploymentLimitExceeded,
+        StrategyCallFailed,
+        CreditNotFound,
+        CreditExpired,
+        CreditNotExpired,
+        CreditExhausted,
+        CreditCapExceeded,
+        PaymentWindowOpen,
+        PaymentOverdue,
+        DepositLocked,
+        InsufficientPayment,
+        ChangeNotFound,
+        ChangeAlreadyActive,
+        ActivationTooSoon,
+        ChangeMustBeScheduled,
+        SponsorshipInactive,
+        AlreadySponsored,
+        DeclaredValueMismatch,
+        SponsorNotAccepted,
     }
 
     #[ink(storage)]
@@ -175,6 +558,19 @@ mod propchain_fees {
         /// Reward history (for reporting)
         reward_records: Mapping<u64, RewardRecord>,
         reward_record_count: u64,
+        /// Per-account index into reward_records: (account, n) -> record id
+        account_reward_ids: Mapping<(AccountId, u64), u64>,
+        account_reward_count: Mapping<AccountId, u64>,
+        reward_claims: Mapping<(AccountId, u64), RewardClaim>,
+        reward_claim_count: Mapping<AccountId, u64>,
+        /// Incremented by every fee distribution
+        distribution_epoch: u64,
+        /// Validator distributions at or above this vest instead of paying out (0 = off)
+        vesting_threshold: u128,
+        /// Vesting release period (ms)
+        vesting_duration: u64,
+        vesting_schedules: Mapping<(AccountId, u32), VestingSchedule>,
+        vesting_schedule_count: Mapping<AccountId, u32>,
         /// Total fees collected (all time)
         total_fees_collected: u128,
         /// Total distributed to validators/participants
@@ -187,6 +583,126 @@ mod propchain_fees {
         validator_share_bp: u32,
         /// Distribution rate for treasury (rest)
         treasury_share_bp: u32,
+        /// Requests-for-quote: rfq_id -> Rfq
+        rfqs: Mapping<u64, Rfq>,
+        rfq_count: u64,
+        /// Accounts allowed to quote RFQs besides the admin
+        market_makers: Mapping<AccountId, bool>,
+        /// Off-peak deferral queue: intent_id -> intent
+        deferred_intents: Mapping<u64, DeferredIntent>,
+        deferred_count: u64,
+        /// Pending intent ids in submission order
+        deferred_queue: Vec<u64>,
+        /// Contracts allowed to receive deferred executions
+        deferral_targets: Mapping<AccountId, bool>,
+        /// Staking contract that owns the validator set; manual curation when unset
+        validator_source: Option<AccountId>,
+        /// Stake weight per validator (zero for manually added validators)
+        validator_stakes: Mapping<AccountId, u128>,
+        /// Minimum time between pulls from the validator source (ms)
+        validator_sync_interval: u64,
+        validator_synced_at: u64,
+        /// Compliance registry auction sellers must pass (unchecked when unset)
+        compliance_registry: Option<AccountId>,
+        /// Property token contract auction sellers must own the property in
+        property_token: Option<AccountId>,
+        /// Approved yield strategy for idle treasury funds
+        yield_strategy: Option<AccountId>,
+        /// Max share of treasury (held + deployed) that may sit in the strategy
+        max_deploy_bp: u32,
+        /// Principal currently deployed
+        treasury_deployed: u128,
+        /// Position value reported by the strategy at the last sync
+        strategy_value: u128,
+        /// Yield returned to the treasury (all time)
+        realized_yield: u128,
+        /// Principal written off per strategy revoked by an emergency recall
+        strategy_write_offs: Mapping<AccountId, u128>,
+        /// Settlement must exceed min_bid by this multiple to earn a rebate (basis points; 0 = off)
+        rebate_multiple_bp: u32,
+        /// Share of the listing fee rebated (basis points)
+        rebate_share_bp: u32,
+        /// Treasury funds available for rebates per period
+        rebate_budget: u128,
+        rebate_period: u64,
+        rebate_period_start: u64,
+        rebate_spent: u128,
+        /// Prepaid fee credits: credit_id -> credit
+        fee_credits: Mapping<u64, FeeCredit>,
+        fee_credit_count: u64,
+        /// Collectors a credit owner lets redeem on their behalf: (owner, collector)
+        credit_collectors: Mapping<(AccountId, AccountId), bool>,
+        /// How long purchased credits stay redeemable (ms)
+        credit_validity: u64,
+        /// Max unredeemed credit value per account and in total (0 = uncapped)
+        account_credit_cap: u128,
+        total_credit_cap: u128,
+        account_credit_value: Mapping<AccountId, u128>,
+        /// Prepaid value held for unredeemed credits; not part of fee_treasury
+        prepaid_liability: u128,
+        /// Bidders per auction, in order of their first bid
+        auction_bidders: Mapping<u64, Vec<AccountId>>,
+        /// A new bid must beat the current one by the larger of these
+        min_bid_increment: u128,
+        min_bid_increment_bp: u32,
+        /// Deposit each bidder posts with their first bid
+        bid_deposit: u128,
+        /// Time the winner has to pay after settlement (ms; 0 = settle without payment)
+        settlement_window: u64,
+        /// Scheduled config changes, applied once their activation time passes
+        pending_config_changes: Vec<ConfigChange>,
+        config_change_count: u64,
+        /// Minimum notice for config changes (ms; 0 = changes may apply immediately)
+        config_change_delay: u64,
+        /// Shorter notice delay and the time it takes over
+        pending_config_change_delay: Option<(u64, u64)>,
+        /// Fee sponsorships by sponsor
+        sponsorships: Mapping<AccountId, Sponsorship>,
+        /// Sponsor paying each sponsored account's fees
+        account_sponsors: Mapping<AccountId, AccountId>,
+        /// Accounts each sponsor currently pays for
+        sponsored_accounts: Mapping<AccountId, Vec<AccountId>>,
+        /// Sponsor each account has agreed to be sponsored by
+        accepted_sponsors: Mapping<AccountId, AccountId>,
+        /// (sponsor, account) -> fees sponsored so far
+        sponsored_usage: Mapping<(AccountId, AccountId), SponsoredUsage>,
+        /// Unspent sponsorship budgets; not part of fee_treasury
+        sponsor_liability: u128,
+        total_sponsored: u128,
+        /// Rewards owed to participants: pending plus unreleased vesting
+        reward_liability: u128,
+        /// Set while distributions are scaled down to the contract balance
+        rewards_degraded: bool,
+        /// Per-message telemetry, keyed by message name
+        message_usage: Mapping<String, MessageUsage>,
+        /// Messages with recorded usage, in order of first call
+        tracked_messages: Vec<String>,
+        /// Admin-set weight estimates (else DEFAULT_MESSAGE_WEIGHT)
+        message_weights: Mapping<String, u64>,
+        /// Weight recorded in the current congestion window
+        recent_weight: u64,
+        /// Window weight treated as full congestion (0 = ops count only)
+        weight_capacity: u64,
+        /// Value-based fee modes per operation (flat when unset)
+        value_fee_schedules: Mapping<FeeOperation, ValueFeeSchedule>,
+        /// Oracle declared values are checked against (unchecked when unset)
+        valuation_oracle: Option<AccountId>,
+        /// Allowed deviation of a declared value from the attested valuation (basis points)
+        valuation_tolerance_bp: u32,
+        /// Share of each distribution burned before the validator split (basis points)
+        burn_share_bp: u32,
+        /// Governance account controlling the burn share; the admin when unset
+        burn_governor: Option<AccountId>,
+        /// Fees sent to BURN_ACCOUNT (all time)
+        total_burned: u128,
+        /// Burn share carried between distributions until it reaches the
+        /// existential deposit, below which the transfer would be refused
+        pending_burn: u128,
+        /// Fee rule journal, by index; append-only
+        rule_journal: Mapping<u64, FeeRuleEntry>,
+        rule_journal_len: u64,
+        /// Reason hash each account attaches to its next fee rule change
+        staged_reasons: Mapping<AccountId, [u8; 32]>,
     }
 
     #[ink(event)]
@@ -198,6 +714,55 @@ mod propchain_fees {
         timestamp: u64,
     }
 
+    #[ink(event)]
+    pub struct FeeRuleJournaled {
+        #[ink(topic)]
+        index: u64,
+        #[ink(topic)]
+        changed_by: AccountId,
+        reason_hash: [u8; 32],
+    }
+
+    #[ink(event)]
+    pub struct BurnShareUpdated {
+        #[ink(topic)]
+        by: AccountId,
+        burn_share_bp: u32,
+    }
+
+    #[ink(event)]
+    pub struct FeesBurned {
+        #[ink(topic)]
+        epoch: u64,
+        amount: u128,
+        total_burned: u128,
+    }
+
+    /// Validator rewards for an epoch were scaled down to what the balance can honor
+    #[ink(event)]
+    pub struct RewardShortfall {
+        #[ink(topic)]
+        epoch: u64,
+        promised: u128,
+        funded: u128,
+        deficit: u128,
+    }
+
+    #[ink(event)]
+    pub struct ConfigChangeScheduled {
+        #[ink(topic)]
+        change_id: u64,
+        operation: Option<FeeOperation>,
+        base_fee: u128,
+        activates_at: u64,
+    }
+
+    #[ink(event)]
+    pub struct ConfigChangeCancelled {
+        #[ink(topic)]
+        change_id: u64,
+    }
+
     #[ink(event)]
     pub struct PremiumAuctionCreated {
         #[ink(topic)]
@@ -233,6 +798,35 @@ mod propchain_fees {
         timestamp: u64,
     }
 
+    #[ink(event)]
+    pub struct AuctionAwaitingPayment {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        winner: AccountId,
+        amount: u128,
+        payment_deadline: u64,
+    }
+
+    #[ink(event)]
+    pub struct BidDepositForfeited {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        bidder: AccountId,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct ListingFeeRebated {
+        #[ink(topic)]
+        auction_id: u64,
+        #[ink(topic)]
+        seller: AccountId,
+        amount: u128,
+        budget_remaining: u128,
+    }
+
     #[ink(event)]
     pub struct RewardsDistributed {
         #[ink(topic)]
@@ -242,93 +836,530 @@ mod propchain_fees {
         timestamp: u64,
     }
 
-    /// Dynamic fee calculation: base * (1 + congestion_factor + demand_factor)
-    fn compute_dynamic_fee(
-        config: &FeeConfig,
-        congestion_index: u32,
-        demand_factor_bp: u32,
-    ) -> u128 {
-        // Congestion multiplier: 0-100 -> 0% to (MAX_CONGESTION_MULTIPLIER-100)%
-        let congestion_bp = (congestion_index as u128)
-            .saturating_mul(config.congestion_sensitivity as u128)
-            .saturating_mul((MAX_CONGESTION_MULTIPLIER - 100) as u128)
-            / 10_000;
-        let demand_bp = demand_factor_bp.min(5000); // Cap demand at 50%
-        let total_multiplier_bp = 10_000u128
-            .saturating_add(congestion_bp)
-            .saturating_add(demand_bp as u128);
-        let fee = config
-            .base_fee
-            .saturating_mul(total_multiplier_bp)
-            .saturating_div(BASIS_POINTS);
-        fee.clamp(config.min_fee, config.max_fee)
+    #[ink(event)]
+    pub struct RfqPosted {
+        #[ink(topic)]
+        rfq_id: u64,
+        #[ink(topic)]
+        requester: AccountId,
+        operation: FeeOperation,
+        volume: u32,
+        window_seconds: u64,
     }
 
-    impl FeeManager {
-        #[ink(constructor)]
-        pub fn new(base_fee: u128, min_fee: u128, max_fee: u128) -> Self {
-            let caller = Self::env().caller();
-            let timestamp = Self::env().block_timestamp();
-            let default_config = FeeConfig {
-                base_fee,
-                min_fee,
-                max_fee,
-                congestion_sensitivity: 80,
-                demand_factor_bp: 500,
-                last_updated: timestamp,
-            };
-            Self {
-                admin: caller,
-                operation_config: Mapping::default(),
-                default_config,
-                recent_ops_count: 0,
-                last_congestion_reset: timestamp,
-                auctions: Mapping::default(),
-                auction_bids: Mapping::default(),
-                auction_count: 0,
-                fee_treasury: 0,
-                pending_rewards: Mapping::default(),
-                reward_records: Mapping::default(),
-                reward_record_count: 0,
-                total_fees_collected: 0,
-                total_distributed: 0,
-                validators: Mapping::default(),
-                validator_list: Vec::new(),
-                validator_share_bp: 5000, // 50% to validators
-                treasury_share_bp: 5000,  // 50% to treasury
-            }
-        }
+    #[ink(event)]
+    pub struct RfqQuoted {
+        #[ink(topic)]
+        rfq_id: u64,
+        #[ink(topic)]
+        quoted_by: AccountId,
+        fee_per_operation: u128,
+        expires_at: u64,
+    }
 
-        fn ensure_admin(&self) -> Result<(), FeeError> {
-            if self.env().caller() != self.admin {
-                return Err(FeeError::Unauthorized);
-            }
-            Ok(())
-        }
+    #[ink(event)]
+    pub struct RfqAccepted {
+        #[ink(topic)]
+        rfq_id: u64,
+        #[ink(topic)]
+        requester: AccountId,
+        fee_per_operation: u128,
+        locked_until: u64,
+    }
 
-        /// Get config for operation (operation-specific or default)
-        fn get_config(&self, op: FeeOperation) -> FeeConfig {
-            self.operation_config
-                .get(op)
-                .unwrap_or(self.default_config.clone())
-        }
+    #[ink(event)]
+    pub struct RfqFeeCharged {
+        #[ink(topic)]
+        rfq_id: u64,
+        #[ink(topic)]
+        from: AccountId,
+        amount: u128,
+        used: u32,
+    }
 
-        /// Compute current congestion index (0-100) from recent activity
-        fn congestion_index(&self) -> u32 {
-            let now = self.env().block_timestamp();
-            let window_secs = 3600u64; // 1 hour window
-            if now.saturating_sub(self.last_congestion_reset) > window_secs {
-                return 0; // Reset after window
-            }
-            let count = self.recent_ops_count;
-            // Normalize to 0-100: CONGESTION_WINDOW ops = 100
-            (count.saturating_mul(100).saturating_div(CONGESTION_WINDOW)).min(100)
+    #[ink(event)]
+    pub struct ValueFeeScheduleSet {
+        #[ink(topic)]
+        operation: FeeOperation,
+        mode: FeeMode,
+        rate_bp: u32,
+    }
+
+    #[ink(event)]
+    pub struct ValueFeeCharged {
+        #[ink(topic)]
+        operation: FeeOperation,
+        #[ink(topic)]
+        from: AccountId,
+        declared_value: u128,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct YieldStrategyApproved {
+        #[ink(topic)]
+        strategy: Option<AccountId>,
+        max_deploy_bp: u32,
+    }
+
+    #[ink(event)]
+    pub struct TreasuryDeployed {
+        #[ink(topic)]
+        strategy: AccountId,
+        amount: u128,
+        total_deployed: u128,
+    }
+
+    #[ink(event)]
+    pub struct TreasuryRecalled {
+        #[ink(topic)]
+        strategy: AccountId,
+        returned: u128,
+        yield_earned: u128,
+        emergency: bool,
+    }
+
+    #[ink(event)]
+    pub struct StrategyWrittenOff {
+        #[ink(topic)]
+        strategy: AccountId,
+        written_off: u128,
+        unreachable: bool, // The strategy call failed, so nothing came back
+    }
+
+    #[ink(event)]
+    pub struct ValidatorSetSynced {
+        #[ink(topic)]
+        source: AccountId,
+        validators: u32,
+        total_stake: u128,
+        timestamp: u64,
+    }
+
+    #[ink(event)]
+    pub struct FeeCreditsPurchased {
+        #[ink(topic)]
+        credit_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        operation: FeeOperation,
+        units: u32,
+        fee_per_unit: u128,
+        expires_at: u64,
+    }
+
+    #[ink(event)]
+    pub struct FeeCreditRedeemed {
+        #[ink(topic)]
+        credit_id: u64,
+        amount: u128,
+        remaining: u32,
+    }
+
+    #[ink(event)]
+    pub struct FeeCreditForfeited {
+        #[ink(topic)]
+        credit_id: u64,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct SponsorshipFunded {
+        #[ink(topic)]
+        sponsor: AccountId,
+        amount: u128,
+        budget: u128,
+    }
+
+    #[ink(event)]
+    pub struct FeeSponsored {
+        #[ink(topic)]
+        sponsor: AccountId,
+        #[ink(topic)]
+        account: AccountId,
+        operation: FeeOperation,
+        amount: u128,
+    }
+
+    #[ink(event)]
+    pub struct SponsorshipRevoked {
+        #[ink(topic)]
+        sponsor: AccountId,
+        refunded: u128,
+    }
+
+    #[ink(event)]
+    pub struct IntentQueued {
+        #[ink(topic)]
+        intent_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        operation: FeeOperation,
+        max_fee: u128,
+        tip: u128,
+    }
+
+    #[ink(event)]
+    pub struct IntentExecuted {
+        #[ink(topic)]
+        intent_id: u64,
+        #[ink(topic)]
+        keeper: AccountId,
+        fee: u128,
+        tip: u128,
+    }
+
+    #[ink(event)]
+    pub struct IntentFailed {
+        #[ink(topic)]
+        intent_id: u64,
+        refunded: u128,
+    }
+
+    #[ink(event)]
+    pub struct IntentCancelled {
+        #[ink(topic)]
+        intent_id: u64,
+        refunded: u128,
+    }
+
+    /// Dynamic fee calculation: base * (1 + congestion_factor + demand_factor)
+    fn compute_dynamic_fee(
+        config: &FeeConfig,
+        congestion_index: u32,
+        demand_factor_bp: u32,
+    ) -> u128 {
+        // Congestion multiplier: 0-100 -> 0% to (MAX_CONGESTION_MULTIPLIER-100)%
+        let congestion_bp = (congestion_index as u128)
+            .saturating_mul(config.congestion_sensitivity as u128)
+            .saturating_mul((MAX_CONGESTION_MULTIPLIER - 100) as u128)
+            / 10_000;
+        let demand_bp = demand_factor_bp.min(5000); // Cap demand at 50%
+        let total_multiplier_bp = 10_000u128
+            .saturating_add(congestion_bp)
+            .saturating_add(demand_bp as u128);
+        let fee = config
+            .base_fee
+            .saturating_mul(total_multiplier_bp)
+            .saturating_div(BASIS_POINTS);
+        fee.clamp(config.min_fee, config.max_fee)
+    }
+
+    /// Fee for an operation moving `declared_value`, given its dynamic flat fee
+    fn compute_value_fee(
+        schedule: &ValueFeeSchedule,
+        flat_fee: u128,
+        declared_value: u128,
+    ) -> u128 {
+        let proportional = declared_value.saturating_mul(schedule.rate_bp as u128) / BASIS_POINTS;
+        let fee = match schedule.mode {
+            FeeMode::Flat => return flat_fee,
+            FeeMode::PercentageOfValue => proportional,
+            FeeMode::Hybrid => flat_fee.saturating_add(proportional),
+        };
+        fee.clamp(schedule.min_fee, schedule.max_fee)
+    }
+
+    impl FeeManager {
+        #[ink(constructor)]
+        pub fn new(base_fee: u128, min_fee: u128, max_fee: u128) -> Self {
+            let caller = Self::env().caller();
+            let timestamp = Self::env().block_timestamp();
+            let default_config = FeeConfig {
+                base_fee,
+                min_fee,
+                max_fee,
+                congestion_sensitivity: 80,
+                demand_factor_bp: 500,
+                last_updated: timestamp,
+            };
+            Self {
+                admin: caller,
+                operation_config: Mapping::default(),
+                default_config,
+                recent_ops_count: 0,
+                last_congestion_reset: timestamp,
+                auctions: Mapping::default(),
+                auction_bids: Mapping::default(),
+                auction_count: 0,
+                fee_treasury: 0,
+                pending_rewards: Mapping::default(),
+                reward_records: Mapping::default(),
+                reward_record_count: 0,
+                account_reward_ids: Mapping::default(),
+                account_reward_count: Mapping::default(),
+                reward_claims: Mapping::default(),
+                reward_claim_count: Mapping::default(),
+                distribution_epoch: 0,
+                vesting_threshold: 0,
+                vesting_duration: 0,
+                vesting_schedules: Mapping::default(),
+                vesting_schedule_count: Mapping::default(),
+                total_fees_collected: 0,
+                total_distributed: 0,
+                validators: Mapping::default(),
+                validator_list: Vec::new(),
+                validator_share_bp: 5000, // 50% to validators
+                treasury_share_bp: 5000,  // 50% to treasury
+                rfqs: Mapping::default(),
+                rfq_count: 0,
+                market_makers: Mapping::default(),
+                deferred_intents: Mapping::default(),
+                deferred_count: 0,
+                deferred_queue: Vec::new(),
+                deferral_targets: Mapping::default(),
+                validator_source: None,
+                validator_stakes: Mapping::default(),
+                validator_sync_interval: 3_600_000, // 1 hour
+                validator_synced_at: 0,
+                compliance_registry: None,
+                property_token: None,
+                yield_strategy: None,
+                max_deploy_bp: 0,
+                treasury_deployed: 0,
+                strategy_value: 0,
+                realized_yield: 0,
+                strategy_write_offs: Mapping::default(),
+                rebate_multiple_bp: 0,
+                rebate_share_bp: 0,
+                rebate_budget: 0,
+                rebate_period: 2_592_000_000, // 30 days
+                rebate_period_start: timestamp,
+                rebate_spent: 0,
+                fee_credits: Mapping::default(),
+                fee_credit_count: 0,
+                credit_collectors: Mapping::default(),
+                credit_validity: 2_592_000_000, // 30 days
+                account_credit_cap: 0,
+                total_credit_cap: 0,
+                account_credit_value: Mapping::default(),
+                prepaid_liability: 0,
+                auction_bidders: Mapping::default(),
+                min_bid_increment: 0,
+                min_bid_increment_bp: 0,
+                bid_deposit: 0,
+                settlement_window: 0,
+                pending_config_changes: Vec::new(),
+                config_change_count: 0,
+                config_change_delay: DEFAULT_CONFIG_CHANGE_DELAY,
+                pending_config_change_delay: None,
+                sponsorships: Mapping::default(),
+                account_sponsors: Mapping::default(),
+                sponsored_accounts: Mapping::default(),
+                accepted_sponsors: Mapping::default(),
+                sponsored_usage: Mapping::default(),
+                sponsor_liability: 0,
+                total_sponsored: 0,
+                reward_liability: 0,
+                rewards_degraded: false,
+                message_usage: Mapping::default(),
+                tracked_messages: Vec::new(),
+                message_weights: Mapping::default(),
+                recent_weight: 0,
+                weight_capacity: 0,
+                value_fee_schedules: Mapping::default(),
+                valuation_oracle: None,
+                valuation_tolerance_bp: DEFAULT_VALUATION_TOLERANCE_BP,
+                burn_share_bp: 0,
+                burn_governor: None,
+                total_burned: 0,
+                pending_burn: 0,
+                rule_journal: Mapping::default(),
+                rule_journal_len: 0,
+                staged_reasons: Mapping::default(),
+            }
+        }
+
+        fn ensure_burn_authority(&self) -> Result<(), FeeError> {
+            match self.burn_governor {
+                Some(governor) if self.env().caller() != governor => Err(FeeError::Unauthorized),
+                Some(_) => Ok(()),
+                None => self.ensure_admin(),
+            }
+        }
+
+        fn ensure_admin(&self) -> Result<(), FeeError> {
+            if self.env().caller() != self.admin {
+                return Err(FeeError::Unauthorized);
+            }
+            Ok(())
+        }
+
+        fn validate_config(config: &FeeConfig) -> Result<(), FeeError> {
+            if config.min_fee > config.max_fee || config.base_fee < config.min_fee {
+                return Err(FeeError::InvalidConfig);
+            }
+            Ok(())
+        }
+
+        /// Get config for operation (operation-specific or default), including
+        /// scheduled changes whose activation time has passed
+        fn get_config(&self, op: FeeOperation) -> FeeConfig {
+            self.due_config(Some(op))
+                .or_else(|| self.operation_config.get(op))
+                .unwrap_or_else(|| self.current_default())
+        }
+
+        fn current_default(&self) -> FeeConfig {
+            self.due_config(None)
+                .unwrap_or_else(|| self.default_config.clone())
+        }
+
+        /// Latest activated change for a config that has not been written back yet
+        fn due_config(&self, operation: Option<FeeOperation>) -> Option<FeeConfig> {
+            let now = self.env().block_timestamp();
+            self.pending_config_changes
+                .iter()
+                .filter(|change| change.operation == operation && change.activates_at <= now)
+                .max_by_key(|change| (change.activates_at, change.change_id))
+                .map(|change| FeeConfig {
+                    last_updated: change.activates_at,
+                    ..change.config.clone()
+                })
+        }
+
+        /// Write activated changes into storage, in activation order
+        fn apply_due_config_changes(&mut self) -> u32 {
+            let now = self.env().block_timestamp();
+            let (mut due, pending): (Vec<ConfigChange>, Vec<ConfigChange>) = self
+                .pending_config_changes
+                .iter()
+                .cloned()
+                .partition(|change| change.activates_at <= now);
+            if due.is_empty() {
+                return 0;
+            }
+            due.sort_by_key(|change| (change.activates_at, change.change_id));
+            for change in due.iter() {
+                let config = FeeConfig {
+                    last_updated: change.activates_at,
+                    ..change.config.clone()
+                };
+                let old = match change.operation {
+                    Some(operation) => {
+                        let old = self.operation_config.get(operation);
+                        self.operation_config.insert(operation, &config);
+                        old
+                    }
+                    None => Some(core::mem::replace(&mut self.default_config, config.clone())),
+                };
+                self.append_rule_entry(
+                    change.proposed_by,
+                    change.activates_at,
+                    FeeRuleChange::OperationConfig {
+                        operation: change.operation,
+                        old,
+                        new: config.clone(),
+                    },
+                    change.reason_hash,
+                );
+                self.env().emit_event(FeeConfigUpdated {
+                    by: change.proposed_by,
+                    operation: change.operation,
+                    base_fee: config.base_fee,
+                    timestamp: change.activates_at,
+                });
+            }
+            self.pending_config_changes = pending;
+            due.len() as u32
+        }
+
+        /// Journal a change made by the caller now, with their staged reason
+        fn journal_rule_change(&mut self, change: FeeRuleChange) {
+            let caller = self.env().caller();
+            let reason_hash = self.staged_reasons.take(caller).unwrap_or_default();
+            let now = self.env().block_timestamp();
+            self.append_rule_entry(caller, now, change, reason_hash);
+        }
+
+        fn append_rule_entry(
+            &mut self,
+            changed_by: AccountId,
+            changed_at: u64,
+            change: FeeRuleChange,
+            reason_hash: [u8; 32],
+        ) {
+            let index = self.rule_journal_len;
+            self.rule_journal.insert(
+                index,
+                &FeeRuleEntry {
+                    index,
+                    changed_by,
+                    changed_at,
+                    change,
+                    reason_hash,
+                },
+            );
+            self.rule_journal_len = index + 1;
+            self.env().emit_event(FeeRuleJournaled {
+                index,
+                changed_by,
+                reason_hash,
+            });
+        }
+
+        fn ensure_unscheduled_changes_allowed(&self) -> Result<(), FeeError> {
+            if self.current_config_change_delay() > 0 {
+                return Err(FeeError::ChangeMustBeScheduled);
+            }
+            Ok(())
+        }
+
+        /// Compute current congestion index (0-100) from recent activity
+        fn congestion_index(&self) -> u32 {
+            let now = self.env().block_timestamp();
+            let window_secs = 3600u64; // 1 hour window
+            if now.saturating_sub(self.last_congestion_reset) > window_secs {
+                return 0; // Reset after window
+            }
+            let count = self.recent_ops_count;
+            // Normalize to 0-100: CONGESTION_WINDOW ops = 100
+            let by_ops = (count.saturating_mul(100).saturating_div(CONGESTION_WINDOW)).min(100);
+            if self.weight_capacity == 0 {
+                return by_ops;
+            }
+            let by_weight =
+                (self.recent_weight.saturating_mul(100) / self.weight_capacity).min(100);
+            by_ops.max(by_weight as u32)
+        }
+
+        /// Count a message call and its weight estimate towards telemetry and congestion
+        fn record_usage(&mut self, message: &str) {
+            let now = self.env().block_timestamp();
+            let key = String::from(message);
+            let weight = self
+                .message_weights
+                .get(&key)
+                .unwrap_or(DEFAULT_MESSAGE_WEIGHT);
+            let mut usage = match self.message_usage.get(&key) {
+                Some(usage) => usage,
+                None => {
+                    self.tracked_messages.push(key.clone());
+                    MessageUsage {
+                        message: key.clone(),
+                        calls: 0,
+                        total_weight: 0,
+                        last_called: 0,
+                    }
+                }
+            };
+            usage.calls = usage.calls.saturating_add(1);
+            usage.total_weight = usage.total_weight.saturating_add(weight);
+            usage.last_called = now;
+            self.message_usage.insert(&key, &usage);
+
+            if now.saturating_sub(self.last_congestion_reset) > 3600 {
+                self.last_congestion_reset = now;
+                self.recent_ops_count = 0;
+                self.recent_weight = 0;
+            }
+            self.recent_weight = self.recent_weight.saturating_add(weight);
         }
 
         /// Demand factor in basis points (from recent volume)
         fn demand_factor_bp(&self) -> u32 {
             let ci = self.congestion_index();
-            self.default_config
+            self.current_default()
                 .demand_factor_bp
                 .saturating_mul(ci)
                 .saturating_div(100)
@@ -353,7 +1384,14 @@ mod propchain_fees {
             amount: u128,
             from: AccountId,
         ) -> Result<(), FeeError> {
+            self.record_usage("record_fee_collected");
             let _ = from;
+            self.collect_fee(amount);
+            Ok(())
+        }
+
+        fn collect_fee(&mut self, amount: u128) {
+            self.apply_due_config_changes();
             self.recent_ops_count = self
                 .recent_ops_count
                 .saturating_add(1)
@@ -365,64 +1403,427 @@ mod propchain_fees {
             }
             self.fee_treasury = self.fee_treasury.saturating_add(amount);
             self.total_fees_collected = self.total_fees_collected.saturating_add(amount);
-            Ok(())
         }
 
-        // ========== Automated fee adjustment ==========
+        // ========== Value-based fees ==========
 
-        /// Automated fee adjustment based on recent utilization vs target
+        /// Set how `operation` is charged relative to its declared value (admin only)
         #[ink(message)]
-        pub fn update_fee_params(&mut self) -> Result<(), FeeError> {
+        pub fn set_fee_mode(
+            &mut self,
+            operation: FeeOperation,
+            schedule: ValueFeeSchedule,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_fee_mode");
             self.ensure_admin()?;
-            let now = self.env().block_timestamp();
-            let congestion = self.congestion_index();
-            let mut config = self.default_config.clone();
-            if congestion > 70 {
-                config.base_fee = config
-                    .base_fee
-                    .saturating_mul(105)
-                    .saturating_div(100)
-                    .min(config.max_fee);
-            } else if congestion < 30 {
-                config.base_fee = config
-                    .base_fee
-                    .saturating_mul(95)
-                    .saturating_div(100)
-                    .max(config.min_fee);
+            if schedule.rate_bp as u128 > BASIS_POINTS || schedule.min_fee > schedule.max_fee {
+                return Err(FeeError::InvalidConfig);
             }
-            config.last_updated = now;
-            self.default_config = config.clone();
-            self.env().emit_event(FeeConfigUpdated {
-                by: self.env().caller(),
-                operation: None,
-                base_fee: config.base_fee,
-                timestamp: now,
+            let old = self.get_fee_mode(operation);
+            if schedule.mode == FeeMode::Flat {
+                self.value_fee_schedules.remove(operation);
+            } else {
+                self.value_fee_schedules.insert(operation, &schedule);
+            }
+            self.journal_rule_change(FeeRuleChange::FeeMode {
+                operation,
+                old,
+                new: schedule.clone(),
+            });
+            self.env().emit_event(ValueFeeScheduleSet {
+                operation,
+                mode: schedule.mode,
+                rate_bp: schedule.rate_bp,
             });
             Ok(())
         }
 
-        /// Set fee config for an operation (admin)
         #[ink(message)]
-        pub fn set_operation_config(
+        pub fn get_fee_mode(&self, operation: FeeOperation) -> ValueFeeSchedule {
+            self.value_fee_schedules.get(operation).unwrap_or_default()
+        }
+
+        /// Set the oracle declared values are validated against and the allowed
+        /// deviation (admin only)
+        #[ink(message)]
+        pub fn set_valuation_oracle(
             &mut self,
-            operation: FeeOperation,
-            config: FeeConfig,
+            oracle: Option<AccountId>,
+            tolerance_bp: u32,
         ) -> Result<(), FeeError> {
+            self.record_usage("set_valuation_oracle");
             self.ensure_admin()?;
-            if config.min_fee > config.max_fee || config.base_fee < config.min_fee {
+            if tolerance_bp as u128 > BASIS_POINTS {
                 return Err(FeeError::InvalidConfig);
             }
-            self.operation_config.insert(operation, &config);
-            self.env().emit_event(FeeConfigUpdated {
-                by: self.env().caller(),
-                operation: Some(operation),
-                base_fee: config.base_fee,
-                timestamp: self.env().block_timestamp(),
+            self.journal_rule_change(FeeRuleChange::ValuationCheck {
+                old: (self.valuation_oracle, self.valuation_tolerance_bp),
+                new: (oracle, tolerance_bp),
             });
+            self.valuation_oracle = oracle;
+            self.valuation_tolerance_bp = tolerance_bp;
             Ok(())
         }
 
-        // ========== Auction mechanism for premium listings ==========
+        /// Fee for an operation moving `declared_value`, without validation (read-only)
+        #[ink(message)]
+        pub fn calculate_value_fee(&self, operation: FeeOperation, declared_value: u128) -> u128 {
+            let schedule = self.get_fee_mode(operation);
+            compute_value_fee(&schedule, self.calculate_fee(operation), declared_value)
+        }
+
+        /// Fee for an operation on `property_id` after checking the declared value
+        /// against the attested valuation, when one is available
+        #[ink(message)]
+        pub fn quote_value_fee(
+            &self,
+            operation: FeeOperation,
+            property_id: u64,
+            declared_value: u128,
+        ) -> Result<u128, FeeError> {
+            self.validate_declared_value(property_id, declared_value)?;
+            Ok(self.calculate_value_fee(operation, declared_value))
+        }
+
+        /// Record a fee charged on the declared value of an operation on
+        /// `property_id`; returns the fee the calling contract collects
+        #[ink(message)]
+        pub fn record_value_fee_collected(
+            &mut self,
+            operation: FeeOperation,
+            property_id: u64,
+            declared_value: u128,
+            from: AccountId,
+        ) -> Result<u128, FeeError> {
+            self.record_usage("record_value_fee_collected");
+            let amount = self.quote_value_fee(operation, property_id, declared_value)?;
+            self.collect_fee(amount);
+            self.env().emit_event(ValueFeeCharged {
+                operation,
+                from,
+                declared_value,
+                amount,
+            });
+            Ok(amount)
+        }
+
+        /// Declared value must sit within the tolerance band of the oracle's
+        /// valuation; unchecked when no oracle is set or it has no valuation
+        fn validate_declared_value(
+            &self,
+            property_id: u64,
+            declared_value: u128,
+        ) -> Result<(), FeeError> {
+            let Some(attested) = self
+                .valuation_oracle
+                .and_then(|oracle| self.fetch_attested_valuation(oracle, property_id))
+            else {
+                return Ok(());
+            };
+            let tolerance =
+                attested.saturating_mul(self.valuation_tolerance_bp as u128) / BASIS_POINTS;
+            if declared_value.abs_diff(attested) > tolerance {
+                return Err(FeeError::DeclaredValueMismatch);
+            }
+            Ok(())
+        }
+
+        fn fetch_attested_valuation(&self, oracle: AccountId, property_id: u64) -> Option<u128> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::Oracle;
+            let source: ink::contract_ref!(Oracle) = FromAccountId::from_account_id(oracle);
+            match source.call().get_valuation(property_id).try_invoke() {
+                Ok(Ok(Ok(valuation))) => Some(valuation.valuation),
+                _ => None,
+            }
+        }
+
+        // ========== Automated fee adjustment ==========
+
+        /// Automated fee adjustment based on recent utilization vs target
+        #[ink(message)]
+        pub fn update_fee_params(&mut self) -> Result<(), FeeError> {
+            self.record_usage("update_fee_params");
+            self.ensure_admin()?;
+            self.apply_due_config_changes();
+            let now = self.env().block_timestamp();
+            let congestion = self.congestion_index();
+            let mut config = self.default_config.clone();
+            if congestion > 70 {
+                config.base_fee = config
+                    .base_fee
+                    .saturating_mul(105)
+                    .saturating_div(100)
+                    .min(config.max_fee);
+            } else if congestion < 30 {
+                config.base_fee = config
+                    .base_fee
+                    .saturating_mul(95)
+                    .saturating_div(100)
+                    .max(config.min_fee);
+            }
+            config.last_updated = now;
+            let old = core::mem::replace(&mut self.default_config, config.clone());
+            self.journal_rule_change(FeeRuleChange::OperationConfig {
+                operation: None,
+                old: Some(old),
+                new: config.clone(),
+            });
+            self.env().emit_event(FeeConfigUpdated {
+                by: self.env().caller(),
+                operation: None,
+                base_fee: config.base_fee,
+                timestamp: now,
+            });
+            Ok(())
+        }
+
+        /// Set fee config for an operation (admin)
+        #[ink(message)]
+        pub fn set_operation_config(
+            &mut self,
+            operation: FeeOperation,
+            config: FeeConfig,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_operation_config");
+            self.ensure_admin()?;
+            self.ensure_unscheduled_changes_allowed()?;
+            Self::validate_config(&config)?;
+            self.apply_due_config_changes();
+            let old = self.operation_config.get(operation);
+            self.operation_config.insert(operation, &config);
+            self.journal_rule_change(FeeRuleChange::OperationConfig {
+                operation: Some(operation),
+                old,
+                new: config.clone(),
+            });
+            self.env().emit_event(FeeConfigUpdated {
+                by: self.env().caller(),
+                operation: Some(operation),
+                base_fee: config.base_fee,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Set several operation configs at once (admin). Each item is validated;
+        /// the batch is applied only if every item passes, otherwise nothing is
+        /// written. Returns the per-item validation results.
+        #[ink(message)]
+        pub fn set_operation_configs(
+            &mut self,
+            configs: Vec<(FeeOperation, FeeConfig)>,
+        ) -> Result<Vec<Result<(), FeeError>>, FeeError> {
+            self.record_usage("set_operation_configs");
+            self.ensure_admin()?;
+            self.ensure_unscheduled_changes_allowed()?;
+            let results: Vec<Result<(), FeeError>> = configs
+                .iter()
+                .enumerate()
+                .map(|(i, (operation, config))| {
+                    if configs[..i].iter().any(|(earlier, _)| earlier == operation) {
+                        return Err(FeeError::InvalidConfig);
+                    }
+                    Self::validate_config(config)
+                })
+                .collect();
+            if results.iter().any(|result| result.is_err()) {
+                return Ok(results);
+            }
+            self.apply_due_config_changes();
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            let reason_hash = self.staged_reasons.take(caller).unwrap_or_default();
+            for (operation, config) in configs.iter() {
+                let old = self.operation_config.get(*operation);
+                self.operation_config.insert(*operation, config);
+                self.append_rule_entry(
+                    caller,
+                    now,
+                    FeeRuleChange::OperationConfig {
+                        operation: Some(*operation),
+                        old,
+                        new: config.clone(),
+                    },
+                    reason_hash,
+                );
+                self.env().emit_event(FeeConfigUpdated {
+                    by: caller,
+                    operation: Some(*operation),
+                    base_fee: config.base_fee,
+                    timestamp: now,
+                });
+            }
+            Ok(results)
+        }
+
+        /// Effective config of every operation, falling back to the default
+        #[ink(message)]
+        pub fn get_all_operation_configs(&self) -> Vec<(FeeOperation, FeeConfig)> {
+            ALL_OPERATIONS
+                .iter()
+                .map(|op| (*op, self.get_config(*op)))
+                .collect()
+        }
+
+        // ========== Scheduled config changes ==========
+
+        /// Schedule a config change for an operation, or for the default config
+        /// when `operation` is None (admin). Activation must respect the notice delay.
+        #[ink(message)]
+        pub fn schedule_config_change(
+            &mut self,
+            operation: Option<FeeOperation>,
+            config: FeeConfig,
+            activates_at: u64,
+        ) -> Result<u64, FeeError> {
+            self.record_usage("schedule_config_change");
+            self.ensure_admin()?;
+            Self::validate_config(&config)?;
+            let now = self.env().block_timestamp();
+            if activates_at < now.saturating_add(self.current_config_change_delay()) {
+                return Err(FeeError::ActivationTooSoon);
+            }
+            self.apply_due_config_changes();
+            if self.pending_config_changes.len() >= MAX_PENDING_CONFIG_CHANGES {
+                return Err(FeeError::InvalidConfig);
+            }
+            self.config_change_count += 1;
+            let change_id = self.config_change_count;
+            self.env().emit_event(ConfigChangeScheduled {
+                change_id,
+                operation,
+                base_fee: config.base_fee,
+                activates_at,
+            });
+            self.pending_config_changes.push(ConfigChange {
+                change_id,
+                operation,
+                config,
+                activates_at,
+                proposed_by: self.env().caller(),
+                proposed_at: now,
+                reason_hash: self
+                    .staged_reasons
+                    .take(self.env().caller())
+                    .unwrap_or_default(),
+            });
+            Ok(change_id)
+        }
+
+        /// Withdraw a scheduled change before it activates (admin)
+        #[ink(message)]
+        pub fn cancel_config_change(&mut self, change_id: u64) -> Result<(), FeeError> {
+            self.record_usage("cancel_config_change");
+            self.ensure_admin()?;
+            let index = self
+                .pending_config_changes
+                .iter()
+                .position(|change| change.change_id == change_id)
+                .ok_or(FeeError::ChangeNotFound)?;
+            if self.pending_config_changes[index].activates_at <= self.env().block_timestamp() {
+                return Err(FeeError::ChangeAlreadyActive);
+            }
+            self.pending_config_changes.remove(index);
+            self.env().emit_event(ConfigChangeCancelled { change_id });
+            Ok(())
+        }
+
+        /// Write activated changes into storage; anyone may call. Fees already
+        /// follow activated changes without it. Returns the number applied.
+        #[ink(message)]
+        pub fn apply_config_changes(&mut self) -> u32 {
+            self.record_usage("apply_config_changes");
+            self.apply_due_config_changes()
+        }
+
+        /// Changes not yet active, in scheduling order
+        #[ink(message)]
+        pub fn get_pending_config_changes(&self) -> Vec<ConfigChange> {
+            let now = self.env().block_timestamp();
+            self.pending_config_changes
+                .iter()
+                .filter(|change| change.activates_at > now)
+                .cloned()
+                .collect()
+        }
+
+        /// Set the minimum notice for config changes (ms, admin). While non-zero,
+        /// configs can only change through scheduled changes. A longer delay applies
+        /// at once; a shorter one only after the current delay has passed.
+        #[ink(message)]
+        pub fn set_config_change_delay(&mut self, delay: u64) -> Result<(), FeeError> {
+            self.record_usage("set_config_change_delay");
+            self.ensure_admin()?;
+            let current = self.current_config_change_delay();
+            self.journal_rule_change(FeeRuleChange::ConfigChangeDelay {
+                old: current,
+                new: delay,
+            });
+            self.config_change_delay = current;
+            if delay >= current {
+                self.config_change_delay = delay;
+                self.pending_config_change_delay = None;
+            } else {
+                let takes_effect_at = self.env().block_timestamp().saturating_add(current);
+                self.pending_config_change_delay = Some((delay, takes_effect_at));
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_config_change_delay(&self) -> u64 {
+            self.current_config_change_delay()
+        }
+
+        /// A scheduled shorter delay and when it takes over
+        #[ink(message)]
+        pub fn get_pending_config_change_delay(&self) -> Option<(u64, u64)> {
+            self.pending_config_change_delay
+                .filter(|(_, takes_effect_at)| *takes_effect_at > self.env().block_timestamp())
+        }
+
+        fn current_config_change_delay(&self) -> u64 {
+            match self.pending_config_change_delay {
+                Some((delay, takes_effect_at))
+                    if takes_effect_at <= self.env().block_timestamp() =>
+                {
+                    delay
+                }
+                _ => self.config_change_delay,
+            }
+        }
+
+        // ========== Fee rule journal ==========
+
+        /// Stage the hash of an off-chain justification; it is recorded with the
+        /// caller's next fee rule change (or scheduled change) and then cleared
+        #[ink(message)]
+        pub fn set_change_reason(&mut self, reason_hash: [u8; 32]) {
+            self.record_usage("set_change_reason");
+            let caller = self.env().caller();
+            self.staged_reasons.insert(caller, &reason_hash);
+        }
+
+        /// Journal entries from `start`, at most `limit` (capped at
+        /// MAX_RULE_JOURNAL_PAGE). Open to anyone, for independent audit.
+        #[ink(message)]
+        pub fn get_rule_journal(&self, start: u64, limit: u32) -> Vec<FeeRuleEntry> {
+            let end = start
+                .saturating_add(limit.min(MAX_RULE_JOURNAL_PAGE) as u64)
+                .min(self.rule_journal_len);
+            (start..end)
+                .filter_map(|index| self.rule_journal.get(index))
+                .collect()
+        }
+
+        #[ink(message)]
+        pub fn get_rule_journal_len(&self) -> u64 {
+            self.rule_journal_len
+        }
+
+        // ========== Auction mechanism for premium listings ==========
 
         /// Create premium listing auction (pay fee; fee goes to treasury)
         #[ink(message)]
@@ -432,7 +1833,9 @@ mod propchain_fees {
             min_bid: u128,
             duration_seconds: u64,
         ) -> Result<u64, FeeError> {
+            self.record_usage("create_premium_auction");
             let caller = self.env().caller();
+            self.ensure_auction_seller(caller, property_id)?;
             let now = self.env().block_timestamp();
             let fee = self.calculate_fee(FeeOperation::PremiumListingBid);
             if fee > 0 {
@@ -450,6 +1853,8 @@ mod propchain_fees {
                 end_time: now.saturating_add(duration_seconds),
                 settled: false,
                 fee_paid: fee,
+                rebate: 0,
+                payment_deadline: None,
             };
             self.auctions.insert(auction_id, &auction);
             self.env().emit_event(PremiumAuctionCreated {
@@ -463,9 +1868,73 @@ mod propchain_fees {
             Ok(auction_id)
         }
 
-        /// Place or increase bid (bid must be > current_bid and >= min_bid)
+        /// Set the contracts that vet premium auction sellers (admin only); `None`
+        /// turns that check off
         #[ink(message)]
+        pub fn set_auction_checks(
+            &mut self,
+            compliance_registry: Option<AccountId>,
+            property_token: Option<AccountId>,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_auction_checks");
+            self.ensure_admin()?;
+            self.compliance_registry = compliance_registry;
+            self.property_token = property_token;
+            Ok(())
+        }
+
+        /// Seller must be compliant and own the property being auctioned. Each
+        /// check runs only once its contract is configured.
+        fn ensure_auction_seller(
+            &self,
+            seller: AccountId,
+            property_id: u64,
+        ) -> Result<(), FeeError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            if let Some(registry) = self.compliance_registry {
+                use propchain_traits::ComplianceChecker;
+                let checker: ink::contract_ref!(ComplianceChecker) =
+                    FromAccountId::from_account_id(registry);
+                let compliant = checker.call().is_compliant(seller).try_invoke();
+                Self::auction_compliance_verdict(compliant)?;
+            }
+            if let Some(token) = self.property_token {
+                use propchain_traits::PropertyOwnership;
+                let ownership: ink::contract_ref!(PropertyOwnership) =
+                    FromAccountId::from_account_id(token);
+                let owner = ownership.call().property_owner(property_id).try_invoke();
+                Self::auction_ownership_verdict(seller, owner)?;
+            }
+            Ok(())
+        }
+
+        /// Fails closed: a call that traps or cannot be decoded rejects the seller
+        fn auction_compliance_verdict(
+            compliant: ink::env::Result<ink::MessageResult<bool>>,
+        ) -> Result<(), FeeError> {
+            match compliant {
+                Ok(Ok(true)) => Ok(()),
+                _ => Err(FeeError::SellerNotCompliant),
+            }
+        }
+
+        /// Fails closed like `auction_compliance_verdict`
+        fn auction_ownership_verdict(
+            seller: AccountId,
+            owner: ink::env::Result<ink::MessageResult<Option<AccountId>>>,
+        ) -> Result<(), FeeError> {
+            match owner {
+                Ok(Ok(Some(owner))) if owner == seller => Ok(()),
+                _ => Err(FeeError::NotPropertyOwner),
+            }
+        }
+
+        /// Place or increase bid (bid must be >= min_bid and beat current_bid by the
+        /// minimum increment). A bidder's first bid must carry the bid deposit.
+        #[ink(message, payable)]
         pub fn place_bid(&mut self, auction_id: u64, amount: u128) -> Result<(), FeeError> {
+            self.record_usage("place_bid");
             let caller = self.env().caller();
             let now = self.env().block_timestamp();
             let mut auction = self
@@ -484,6 +1953,30 @@ mod propchain_fees {
             if amount <= auction.current_bid {
                 return Err(FeeError::BidTooLow);
             }
+            if auction.current_bidder.is_some() {
+                let increment = self.min_bid_increment.max(
+                    auction
+                        .current_bid
+                        .saturating_mul(self.min_bid_increment_bp as u128)
+                        .saturating_div(BASIS_POINTS),
+                );
+                if amount < auction.current_bid.saturating_add(increment) {
+                    return Err(FeeError::BidTooLow);
+                }
+            }
+            let previous = self.auction_bids.get((auction_id, caller));
+            let deposit = previous
+                .as_ref()
+                .map_or(0, |bid| bid.deposit)
+                .saturating_add(self.env().transferred_value());
+            if deposit < self.bid_deposit {
+                return Err(FeeError::InsufficientDeposit);
+            }
+            if previous.is_none() {
+                let mut bidders = self.auction_bidders.get(auction_id).unwrap_or_default();
+                bidders.push(caller);
+                self.auction_bidders.insert(auction_id, &bidders);
+            }
             let outbid = auction.current_bid;
             auction.current_bid = amount;
             auction.current_bidder = Some(caller);
@@ -494,6 +1987,7 @@ mod propchain_fees {
                     bidder: caller,
                     amount,
                     timestamp: now,
+                    deposit,
                 },
             );
             self.env().emit_event(PremiumAuctionBid {
@@ -502,53 +1996,1139 @@ mod propchain_fees {
                 amount,
                 outbid_previous: outbid,
             });
-            Ok(())
+            Ok(())
+        }
+
+        /// Settle auction after end_time; winner is current_bidder. With a
+        /// settlement window configured the winner must then pay within it.
+        #[ink(message)]
+        pub fn settle_auction(&mut self, auction_id: u64) -> Result<(), FeeError> {
+            self.record_usage("settle_auction");
+            let now = self.env().block_timestamp();
+            let mut auction = self
+                .auctions
+                .get(auction_id)
+                .ok_or(FeeError::AuctionNotFound)?;
+            if auction.settled {
+                return Err(FeeError::AlreadySettled);
+            }
+            if now < auction.end_time {
+                return Err(FeeError::AuctionNotEnded);
+            }
+            if auction.payment_deadline.is_some() {
+                return Err(FeeError::PaymentWindowOpen);
+            }
+            let winner = auction.current_bidder.ok_or(FeeError::AuctionNotFound)?;
+            if self.settlement_window > 0 {
+                self.await_payment(auction_id, &mut auction, winner);
+                return Ok(());
+            }
+            self.finalize_auction(auction_id, auction, winner)
+        }
+
+        /// Winner pays the bid, less their deposit, within the settlement window.
+        /// The full bid goes to the seller; any excess is refunded.
+        #[ink(message, payable)]
+        pub fn pay_auction_settlement(&mut self, auction_id: u64) -> Result<(), FeeError> {
+            self.record_usage("pay_auction_settlement");
+            let caller = self.env().caller();
+            let auction = self
+                .auctions
+                .get(auction_id)
+                .ok_or(FeeError::AuctionNotFound)?;
+            if auction.settled {
+                return Err(FeeError::AlreadySettled);
+            }
+            let deadline = auction.payment_deadline.ok_or(FeeError::AuctionNotEnded)?;
+            if auction.current_bidder != Some(caller) {
+                return Err(FeeError::Unauthorized);
+            }
+            if self.env().block_timestamp() > deadline {
+                return Err(FeeError::PaymentOverdue);
+            }
+            let deposit = self
+                .auction_bids
+                .get((auction_id, caller))
+                .map_or(0, |bid| bid.deposit);
+            let due = auction.current_bid.saturating_sub(deposit);
+            let paid = self.env().transferred_value();
+            if paid < due {
+                return Err(FeeError::InsufficientPayment);
+            }
+            self.remove_bidder(auction_id, caller);
+            self.env()
+                .transfer(auction.seller, auction.current_bid)
+                .map_err(|_| FeeError::TransferFailed)?;
+            if paid > due {
+                self.env()
+                    .transfer(caller, paid - due)
+                    .map_err(|_| FeeError::TransferFailed)?;
+            }
+            self.finalize_auction(auction_id, auction, caller)
+        }
+
+        /// After a missed payment deadline, slash the winner's deposit to the
+        /// treasury and re-award to the highest remaining bid. Callable by anyone.
+        /// Returns the new winner, or None when no bids remain.
+        #[ink(message)]
+        pub fn forfeit_unpaid_auction(
+            &mut self,
+            auction_id: u64,
+        ) -> Result<Option<AccountId>, FeeError> {
+            self.record_usage("forfeit_unpaid_auction");
+            let mut auction = self
+                .auctions
+                .get(auction_id)
+                .ok_or(FeeError::AuctionNotFound)?;
+            if auction.settled {
+                return Err(FeeError::AlreadySettled);
+            }
+            let deadline = auction.payment_deadline.ok_or(FeeError::AuctionNotEnded)?;
+            if self.env().block_timestamp() <= deadline {
+                return Err(FeeError::PaymentWindowOpen);
+            }
+            let defaulter = auction.current_bidder.ok_or(FeeError::AuctionNotFound)?;
+            let forfeited = self.remove_bidder(auction_id, defaulter);
+            self.fee_treasury = self.fee_treasury.saturating_add(forfeited);
+            self.env().emit_event(BidDepositForfeited {
+                auction_id,
+                bidder: defaulter,
+                amount: forfeited,
+            });
+
+            let runner_up = self
+                .auction_bidders
+                .get(auction_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|bidder| self.auction_bids.get((auction_id, bidder)))
+                .max_by_key(|bid| bid.amount);
+            match runner_up {
+                Some(bid) => {
+                    auction.current_bid = bid.amount;
+                    auction.current_bidder = Some(bid.bidder);
+                    self.await_payment(auction_id, &mut auction, bid.bidder);
+                    Ok(Some(bid.bidder))
+                }
+                None => {
+                    // Nobody left to award; the auction closes unsold
+                    auction.current_bid = 0;
+                    auction.current_bidder = None;
+                    auction.payment_deadline = None;
+                    auction.settled = true;
+                    self.auctions.insert(auction_id, &auction);
+                    Ok(None)
+                }
+            }
+        }
+
+        /// Refund a bidder's deposit, giving up their place in re-award order.
+        /// Not available to the current leader or awaiting winner.
+        #[ink(message)]
+        pub fn withdraw_bid_deposit(&mut self, auction_id: u64) -> Result<u128, FeeError> {
+            self.record_usage("withdraw_bid_deposit");
+            let caller = self.env().caller();
+            let auction = self
+                .auctions
+                .get(auction_id)
+                .ok_or(FeeError::AuctionNotFound)?;
+            if !auction.settled && auction.current_bidder == Some(caller) {
+                return Err(FeeError::DepositLocked);
+            }
+            if !self.auction_bids.contains((auction_id, caller)) {
+                return Err(FeeError::AuctionNotFound);
+            }
+            let deposit = self.remove_bidder(auction_id, caller);
+            if deposit > 0 {
+                self.env()
+                    .transfer(caller, deposit)
+                    .map_err(|_| FeeError::TransferFailed)?;
+            }
+            Ok(deposit)
+        }
+
+        /// Configure anti-griefing auction rules (admin only): minimum bid
+        /// increment (absolute and basis points; the larger applies), bidder
+        /// deposit, and the winner's payment window (0 = no payment step)
+        #[ink(message)]
+        pub fn set_auction_safeguards(
+            &mut self,
+            min_increment: u128,
+            min_increment_bp: u32,
+            bid_deposit: u128,
+            settlement_window: u64,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_auction_safeguards");
+            self.ensure_admin()?;
+            if min_increment_bp as u128 > BASIS_POINTS {
+                return Err(FeeError::InvalidConfig);
+            }
+            self.journal_rule_change(FeeRuleChange::AuctionSafeguards {
+                old: (
+                    self.min_bid_increment,
+                    self.min_bid_increment_bp,
+                    self.bid_deposit,
+                    self.settlement_window,
+                ),
+                new: (
+                    min_increment,
+                    min_increment_bp,
+                    bid_deposit,
+                    settlement_window,
+                ),
+            });
+            self.min_bid_increment = min_increment;
+            self.min_bid_increment_bp = min_increment_bp;
+            self.bid_deposit = bid_deposit;
+            self.settlement_window = settlement_window;
+            Ok(())
+        }
+
+        fn await_payment(
+            &mut self,
+            auction_id: u64,
+            auction: &mut PremiumAuction,
+            winner: AccountId,
+        ) {
+            let payment_deadline = self
+                .env()
+                .block_timestamp()
+                .saturating_add(self.settlement_window);
+            auction.payment_deadline = Some(payment_deadline);
+            self.auctions.insert(auction_id, auction);
+            self.env().emit_event(AuctionAwaitingPayment {
+                auction_id,
+                winner,
+                amount: auction.current_bid,
+                payment_deadline,
+            });
+        }
+
+        /// Drops a bidder's record and returns the deposit it held
+        fn remove_bidder(&mut self, auction_id: u64, bidder: AccountId) -> u128 {
+            let mut bidders = self.auction_bidders.get(auction_id).unwrap_or_default();
+            bidders.retain(|b| *b != bidder);
+            self.auction_bidders.insert(auction_id, &bidders);
+            self.auction_bids
+                .take((auction_id, bidder))
+                .map_or(0, |bid| bid.deposit)
+        }
+
+        fn finalize_auction(
+            &mut self,
+            auction_id: u64,
+            mut auction: PremiumAuction,
+            winner: AccountId,
+        ) -> Result<(), FeeError> {
+            let now = self.env().block_timestamp();
+            let amount = auction.current_bid;
+            auction.payment_deadline = None;
+            auction.settled = true;
+            auction.rebate = self.pay_listing_rebate(auction_id, &auction);
+            self.auctions.insert(auction_id, &auction);
+            // fee_paid was already added to fee_treasury at auction creation
+            self.env().emit_event(PremiumAuctionSettled {
+                auction_id,
+                property_id: auction.property_id,
+                winner,
+                amount,
+                timestamp: now,
+            });
+            Ok(())
+        }
+
+        /// Configure listing fee rebates for auctions that settle above
+        /// `multiple_bp` of their min_bid (admin only)
+        #[ink(message)]
+        pub fn set_rebate_policy(
+            &mut self,
+            multiple_bp: u32,
+            share_bp: u32,
+            budget_per_period: u128,
+            period: u64,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_rebate_policy");
+            self.ensure_admin()?;
+            if share_bp as u128 > BASIS_POINTS || period == 0 {
+                return Err(FeeError::InvalidConfig);
+            }
+            self.journal_rule_change(FeeRuleChange::RebatePolicy {
+                old: (
+                    self.rebate_multiple_bp,
+                    self.rebate_share_bp,
+                    self.rebate_budget,
+                    self.rebate_period,
+                ),
+                new: (multiple_bp, share_bp, budget_per_period, period),
+            });
+            self.rebate_multiple_bp = multiple_bp;
+            self.rebate_share_bp = share_bp;
+            self.rebate_budget = budget_per_period;
+            self.rebate_period = period;
+            Ok(())
+        }
+
+        /// Rebate budget left in the current period
+        #[ink(message)]
+        pub fn rebate_budget_remaining(&self) -> u128 {
+            let now = self.env().block_timestamp();
+            if now >= self.rebate_period_start.saturating_add(self.rebate_period) {
+                return self.rebate_budget;
+            }
+            self.rebate_budget.saturating_sub(self.rebate_spent)
+        }
+
+        /// Credit the seller part of the listing fee when the auction cleared
+        /// well above its reserve; returns the rebate
+        fn pay_listing_rebate(&mut self, auction_id: u64, auction: &PremiumAuction) -> u128 {
+            if self.rebate_multiple_bp == 0
+                || auction.current_bid.saturating_mul(BASIS_POINTS)
+                    <= auction
+                        .min_bid
+                        .saturating_mul(self.rebate_multiple_bp as u128)
+            {
+                return 0;
+            }
+            let now = self.env().block_timestamp();
+            if now >= self.rebate_period_start.saturating_add(self.rebate_period) {
+                self.rebate_period_start = now;
+                self.rebate_spent = 0;
+            }
+            let rebate = auction
+                .fee_paid
+                .saturating_mul(self.rebate_share_bp as u128)
+                .saturating_div(BASIS_POINTS)
+                .min(self.rebate_budget.saturating_sub(self.rebate_spent))
+                .min(self.fee_treasury);
+            if rebate == 0 {
+                return 0;
+            }
+            self.fee_treasury = self.fee_treasury.saturating_sub(rebate);
+            self.rebate_spent = self.rebate_spent.saturating_add(rebate);
+            let current = self.pending_rewards.get(auction.seller).unwrap_or(0);
+            self.pending_rewards
+                .insert(auction.seller, &current.saturating_add(rebate));
+            self.record_reward(auction.seller, rebate, RewardReason::ListingRebate);
+            self.total_distributed = self.total_distributed.saturating_add(rebate);
+            self.reward_liability = self.reward_liability.saturating_add(rebate);
+            self.env().emit_event(ListingFeeRebated {
+                auction_id,
+                seller: auction.seller,
+                amount: rebate,
+                budget_remaining: self.rebate_budget.saturating_sub(self.rebate_spent),
+            });
+            rebate
+        }
+
+        #[ink(message)]
+        pub fn get_auction(&self, auction_id: u64) -> Option<PremiumAuction> {
+            self.auctions.get(auction_id)
+        }
+
+        #[ink(message)]
+        pub fn get_auction_bid(&self, auction_id: u64, bidder: AccountId) -> Option<AuctionBid> {
+            self.auction_bids.get((auction_id, bidder))
+        }
+
+        #[ink(message)]
+        pub fn get_auction_count(&self) -> u64 {
+            self.auction_count
+        }
+
+        // ========== RFQ channel for bulk operations ==========
+
+        /// Post a request-for-quote for a volume of operations
+        #[ink(message)]
+        pub fn post_rfq(
+            &mut self,
+            operation: FeeOperation,
+            volume: u32,
+            window_seconds: u64,
+        ) -> Result<u64, FeeError> {
+            self.record_usage("post_rfq");
+            if volume == 0 || window_seconds == 0 {
+                return Err(FeeError::InvalidConfig);
+            }
+            let caller = self.env().caller();
+            self.rfq_count += 1;
+            let rfq_id = self.rfq_count;
+            let rfq = Rfq {
+                requester: caller,
+                operation,
+                volume,
+                window_seconds,
+                status: RfqStatus::Open,
+                quoted_fee: 0,
+                quoted_by: None,
+                quote_expires_at: 0,
+                locked_until: 0,
+                used: 0,
+                created_at: self.env().block_timestamp(),
+            };
+            self.rfqs.insert(rfq_id, &rfq);
+            self.env().emit_event(RfqPosted {
+                rfq_id,
+                requester: caller,
+                operation,
+                volume,
+                window_seconds,
+            });
+            Ok(rfq_id)
+        }
+
+        /// Respond to an RFQ with a binding per-operation fee (admin or market maker)
+        #[ink(message)]
+        pub fn quote_rfq(
+            &mut self,
+            rfq_id: u64,
+            fee_per_operation: u128,
+            valid_for_seconds: u64,
+        ) -> Result<(), FeeError> {
+            self.record_usage("quote_rfq");
+            let caller = self.env().caller();
+            if caller != self.admin && !self.market_makers.get(caller).unwrap_or(false) {
+                return Err(FeeError::Unauthorized);
+            }
+            let mut rfq = self.rfqs.get(rfq_id).ok_or(FeeError::RfqNotFound)?;
+            if rfq.status != RfqStatus::Open && rfq.status != RfqStatus::Quoted {
+                return Err(FeeError::RfqInvalidState);
+            }
+            let expires_at = self
+                .env()
+                .block_timestamp()
+                .saturating_add(valid_for_seconds);
+            rfq.status = RfqStatus::Quoted;
+            rfq.quoted_fee = fee_per_operation;
+            rfq.quoted_by = Some(caller);
+            rfq.quote_expires_at = expires_at;
+            self.rfqs.insert(rfq_id, &rfq);
+            self.env().emit_event(RfqQuoted {
+                rfq_id,
+                quoted_by: caller,
+                fee_per_operation,
+                expires_at,
+            });
+            Ok(())
+        }
+
+        /// Accept the current quote; locks the rate for the RFQ window
+        #[ink(message)]
+        pub fn accept_rfq_quote(&mut self, rfq_id: u64) -> Result<(), FeeError> {
+            self.record_usage("accept_rfq_quote");
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            let mut rfq = self.rfqs.get(rfq_id).ok_or(FeeError::RfqNotFound)?;
+            if rfq.requester != caller {
+                return Err(FeeError::Unauthorized);
+            }
+            if rfq.status != RfqStatus::Quoted {
+                return Err(FeeError::RfqInvalidState);
+            }
+            if now > rfq.quote_expires_at {
+                return Err(FeeError::RfqExpired);
+            }
+            rfq.status = RfqStatus::Accepted;
+            rfq.locked_until = now.saturating_add(rfq.window_seconds);
+            self.rfqs.insert(rfq_id, &rfq);
+            self.env().emit_event(RfqAccepted {
+                rfq_id,
+                requester: caller,
+                fee_per_operation: rfq.quoted_fee,
+                locked_until: rfq.locked_until,
+            });
+            Ok(())
+        }
+
+        /// Cancel an RFQ that has not been accepted (requester or admin)
+        #[ink(message)]
+        pub fn cancel_rfq(&mut self, rfq_id: u64) -> Result<(), FeeError> {
+            self.record_usage("cancel_rfq");
+            let caller = self.env().caller();
+            let mut rfq = self.rfqs.get(rfq_id).ok_or(FeeError::RfqNotFound)?;
+            if caller != rfq.requester && caller != self.admin {
+                return Err(FeeError::Unauthorized);
+            }
+            if rfq.status == RfqStatus::Accepted || rfq.status == RfqStatus::Cancelled {
+                return Err(FeeError::RfqInvalidState);
+            }
+            rfq.status = RfqStatus::Cancelled;
+            self.rfqs.insert(rfq_id, &rfq);
+            Ok(())
+        }
+
+        /// Record a fee charged at an accepted RFQ's locked rate; returns the fee
+        #[ink(message)]
+        pub fn record_rfq_fee_collected(
+            &mut self,
+            rfq_id: u64,
+            from: AccountId,
+        ) -> Result<u128, FeeError> {
+            self.record_usage("record_rfq_fee_collected");
+            let mut rfq = self.rfqs.get(rfq_id).ok_or(FeeError::RfqNotFound)?;
+            if rfq.status != RfqStatus::Accepted {
+                return Err(FeeError::RfqInvalidState);
+            }
+            // The locked rate belongs to the account that requested it
+            if from != rfq.requester {
+                return Err(FeeError::Unauthorized);
+            }
+            if self.env().block_timestamp() > rfq.locked_until {
+                return Err(FeeError::RfqExpired);
+            }
+            if rfq.used >= rfq.volume {
+                return Err(FeeError::RfqVolumeExhausted);
+            }
+            rfq.used += 1;
+            self.rfqs.insert(rfq_id, &rfq);
+            let amount = rfq.quoted_fee;
+            self.collect_fee(amount);
+            self.env().emit_event(RfqFeeCharged {
+                rfq_id,
+                from,
+                amount,
+                used: rfq.used,
+            });
+            Ok(amount)
+        }
+
+        #[ink(message)]
+        pub fn add_market_maker(&mut self, account: AccountId) -> Result<(), FeeError> {
+            self.record_usage("add_market_maker");
+            self.ensure_admin()?;
+            self.market_makers.insert(account, &true);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_rfq(&self, rfq_id: u64) -> Option<Rfq> {
+            self.rfqs.get(rfq_id)
+        }
+
+        // ========== Prepaid fee credits ==========
+
+        /// Prepay `units` operations at the current fee. The transferred value must
+        /// cover the total; any excess is refunded.
+        #[ink(message, payable)]
+        pub fn purchase_fee_credits(
+            &mut self,
+            operation: FeeOperation,
+            units: u32,
+        ) -> Result<u64, FeeError> {
+            self.record_usage("purchase_fee_credits");
+            if units == 0 {
+                return Err(FeeError::InvalidConfig);
+            }
+            let owner = self.env().caller();
+            let fee_per_unit = self.calculate_fee(operation);
+            let cost = fee_per_unit.saturating_mul(units as u128);
+            let paid = self.env().transferred_value();
+            if paid < cost {
+                return Err(FeeError::InsufficientDeposit);
+            }
+            let held = self
+                .account_credit_value
+                .get(owner)
+                .unwrap_or(0)
+                .saturating_add(cost);
+            let liability = self.prepaid_liability.saturating_add(cost);
+            if (self.account_credit_cap > 0 && held > self.account_credit_cap)
+                || (self.total_credit_cap > 0 && liability > self.total_credit_cap)
+            {
+                return Err(FeeError::CreditCapExceeded);
+            }
+            let now = self.env().block_timestamp();
+            self.fee_credit_count += 1;
+            let credit_id = self.fee_credit_count;
+            let credit = FeeCredit {
+                owner,
+                operation,
+                units,
+                remaining: units,
+                fee_per_unit,
+                purchased_at: now,
+                expires_at: now.saturating_add(self.credit_validity),
+            };
+            self.fee_credits.insert(credit_id, &credit);
+            self.account_credit_value.insert(owner, &held);
+            self.prepaid_liability = liability;
+            if paid > cost {
+                self.env()
+                    .transfer(owner, paid - cost)
+                    .map_err(|_| FeeError::TransferFailed)?;
+            }
+            self.env().emit_event(FeeCreditsPurchased {
+                credit_id,
+                owner,
+                operation,
+                units,
+                fee_per_unit,
+                expires_at: credit.expires_at,
+            });
+            Ok(credit_id)
+        }
+
+        /// Allow or revoke a collector redeeming the caller's credits
+        #[ink(message)]
+        pub fn set_credit_collector(&mut self, collector: AccountId, authorized: bool) {
+            self.record_usage("set_credit_collector");
+            let owner = self.env().caller();
+            if authorized {
+                self.credit_collectors.insert((owner, collector), &true);
+            } else {
+                self.credit_collectors.remove((owner, collector));
+            }
+        }
+
+        #[ink(message)]
+        pub fn is_credit_collector(&self, owner: AccountId, collector: AccountId) -> bool {
+            self.credit_collectors
+                .get((owner, collector))
+                .unwrap_or(false)
+        }
+
+        /// Redeem one unit of `from`'s credit in place of charging the current fee;
+        /// moves its prepaid value into the treasury and returns it. Callable by
+        /// `from` or a collector it has authorized.
+        #[ink(message)]
+        pub fn redeem_fee_credit(
+            &mut self,
+            credit_id: u64,
+            from: AccountId,
+        ) -> Result<u128, FeeError> {
+            self.record_usage("redeem_fee_credit");
+            let caller = self.env().caller();
+            if caller != from && !self.is_credit_collector(from, caller) {
+                return Err(FeeError::Unauthorized);
+            }
+            let mut credit = self
+                .fee_credits
+                .get(credit_id)
+                .ok_or(FeeError::CreditNotFound)?;
+            if credit.owner != from {
+                return Err(FeeError::Unauthorized);
+            }
+            if self.env().block_timestamp() > credit.expires_at {
+                return Err(FeeError::CreditExpired);
+            }
+            if credit.remaining == 0 {
+                return Err(FeeError::CreditExhausted);
+            }
+            credit.remaining -= 1;
+            self.fee_credits.insert(credit_id, &credit);
+            let amount = credit.fee_per_unit;
+            self.release_credit_value(from, amount);
+            self.collect_fee(amount);
+            self.env().emit_event(FeeCreditRedeemed {
+                credit_id,
+                amount,
+                remaining: credit.remaining,
+            });
+            Ok(amount)
+        }
+
+        /// Forfeit the unredeemed value of an expired credit to the treasury.
+        /// Callable by anyone; returns the amount forfeited.
+        #[ink(message)]
+        pub fn expire_fee_credit(&mut self, credit_id: u64) -> Result<u128, FeeError> {
+            self.record_usage("expire_fee_credit");
+            let mut credit = self
+                .fee_credits
+                .get(credit_id)
+                .ok_or(FeeError::CreditNotFound)?;
+            if self.env().block_timestamp() <= credit.expires_at {
+                return Err(FeeError::CreditNotExpired);
+            }
+            if credit.remaining == 0 {
+                return Err(FeeError::CreditExhausted);
+            }
+            let amount = credit.fee_per_unit.saturating_mul(credit.remaining as u128);
+            credit.remaining = 0;
+            self.fee_credits.insert(credit_id, &credit);
+            self.release_credit_value(credit.owner, amount);
+            self.fee_treasury = self.fee_treasury.saturating_add(amount);
+            self.total_fees_collected = self.total_fees_collected.saturating_add(amount);
+            self.env()
+                .emit_event(FeeCreditForfeited { credit_id, amount });
+            Ok(amount)
+        }
+
+        /// Configure credit validity (ms) and outstanding value caps (0 = uncapped)
+        #[ink(message)]
+        pub fn set_fee_credit_policy(
+            &mut self,
+            validity: u64,
+            account_cap: u128,
+            total_cap: u128,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_fee_credit_policy");
+            self.ensure_admin()?;
+            if validity == 0 {
+                return Err(FeeError::InvalidConfig);
+            }
+            self.journal_rule_change(FeeRuleChange::FeeCreditPolicy {
+                old: (
+                    self.credit_validity,
+                    self.account_credit_cap,
+                    self.total_credit_cap,
+                ),
+                new: (validity, account_cap, total_cap),
+            });
+            self.credit_validity = validity;
+            self.account_credit_cap = account_cap;
+            self.total_credit_cap = total_cap;
+            Ok(())
+        }
+
+        /// (validity, per-account cap, total cap)
+        #[ink(message)]
+        pub fn get_fee_credit_policy(&self) -> (u64, u128, u128) {
+            (
+                self.credit_validity,
+                self.account_credit_cap,
+                self.total_credit_cap,
+            )
+        }
+
+        #[ink(message)]
+        pub fn get_fee_credit(&self, credit_id: u64) -> Option<FeeCredit> {
+            self.fee_credits.get(credit_id)
+        }
+
+        /// Prepaid value still owed as operations
+        #[ink(message)]
+        pub fn get_prepaid_liability(&self) -> u128 {
+            self.prepaid_liability
+        }
+
+        fn release_credit_value(&mut self, owner: AccountId, amount: u128) {
+            let held = self.account_credit_value.get(owner).unwrap_or(0);
+            self.account_credit_value
+                .insert(owner, &held.saturating_sub(amount));
+            self.prepaid_liability = self.prepaid_liability.saturating_sub(amount);
+        }
+
+        // ========== Fee sponsorship ==========
+
+        /// Fund (or top up) the caller's sponsorship and set its per-account limits
+        #[ink(message, payable)]
+        pub fn fund_sponsorship(
+            &mut self,
+            max_ops_per_account: u32,
+            max_spend_per_account: u128,
+        ) -> Result<u128, FeeError> {
+            self.record_usage("fund_sponsorship");
+            let sponsor = self.env().caller();
+            let amount = self.env().transferred_value();
+            let mut sponsorship = self.sponsorships.get(sponsor).unwrap_or(Sponsorship {
+                budget: 0,
+                spent: 0,
+                max_ops_per_account,
+                max_spend_per_account,
+                accounts: 0,
+                active: true,
+            });
+            if amount == 0 && sponsorship.budget == 0 {
+                return Err(FeeError::InsufficientDeposit);
+            }
+            sponsorship.budget = sponsorship.budget.saturating_add(amount);
+            sponsorship.max_ops_per_account = max_ops_per_account;
+            sponsorship.max_spend_per_account = max_spend_per_account;
+            sponsorship.active = true;
+            self.sponsorships.insert(sponsor, &sponsorship);
+            self.sponsor_liability = self.sponsor_liability.saturating_add(amount);
+            self.env().emit_event(SponsorshipFunded {
+                sponsor,
+                amount,
+                budget: sponsorship.budget,
+            });
+            Ok(sponsorship.budget)
+        }
+
+        /// Agree to have `sponsor` pay the caller's fees; the sponsor still has to
+        /// add the account
+        #[ink(message)]
+        pub fn accept_sponsor(&mut self, sponsor: AccountId) -> Result<(), FeeError> {
+            self.record_usage("accept_sponsor");
+            let account = self.env().caller();
+            self.accepted_sponsors.insert(account, &sponsor);
+            Ok(())
+        }
+
+        /// Stop being sponsored and withdraw any pending acceptance
+        #[ink(message)]
+        pub fn leave_sponsorship(&mut self) -> Result<(), FeeError> {
+            self.record_usage("leave_sponsorship");
+            let account = self.env().caller();
+            self.accepted_sponsors.remove(account);
+            let sponsor = self
+                .account_sponsors
+                .get(account)
+                .ok_or(FeeError::SponsorshipInactive)?;
+            self.unlink_sponsored_account(sponsor, account);
+            Ok(())
+        }
+
+        /// Have the caller's sponsorship pay `account`'s fees. The account must
+        /// have accepted the caller as its sponsor.
+        #[ink(message)]
+        pub fn add_sponsored_account(&mut self, account: AccountId) -> Result<(), FeeError> {
+            self.record_usage("add_sponsored_account");
+            let sponsor = self.env().caller();
+            let mut sponsorship = self
+                .sponsorships
+                .get(sponsor)
+                .filter(|s| s.active)
+                .ok_or(FeeError::SponsorshipInactive)?;
+            if self.account_sponsors.contains(account) {
+                return Err(FeeError::AlreadySponsored);
+            }
+            if self.accepted_sponsors.get(account) != Some(sponsor) {
+                return Err(FeeError::SponsorNotAccepted);
+            }
+            self.account_sponsors.insert(account, &sponsor);
+            let mut sponsored = self.sponsored_accounts.get(sponsor).unwrap_or_default();
+            sponsored.push(account);
+            self.sponsored_accounts.insert(sponsor, &sponsored);
+            sponsorship.accounts += 1;
+            self.sponsorships.insert(sponsor, &sponsorship);
+            Ok(())
+        }
+
+        /// Stop sponsoring `account` (its sponsor only)
+        #[ink(message)]
+        pub fn remove_sponsored_account(&mut self, account: AccountId) -> Result<(), FeeError> {
+            self.record_usage("remove_sponsored_account");
+            let sponsor = self.env().caller();
+            if self.account_sponsors.get(account) != Some(sponsor) {
+                return Err(FeeError::Unauthorized);
+            }
+            self.unlink_sponsored_account(sponsor, account);
+            Ok(())
+        }
+
+        fn unlink_sponsored_account(&mut self, sponsor: AccountId, account: AccountId) {
+            self.account_sponsors.remove(account);
+            let mut sponsored = self.sponsored_accounts.get(sponsor).unwrap_or_default();
+            sponsored.retain(|a| *a != account);
+            self.sponsored_accounts.insert(sponsor, &sponsored);
+            if let Some(mut sponsorship) = self.sponsorships.get(sponsor) {
+                sponsorship.accounts = sponsorship.accounts.saturating_sub(1);
+                self.sponsorships.insert(sponsor, &sponsorship);
+            }
+        }
+
+        /// Revoke the caller's sponsorship and refund its unspent budget. Accounts
+        /// it sponsored pay their own fees from now on.
+        #[ink(message)]
+        pub fn revoke_sponsorship(&mut self) -> Result<u128, FeeError> {
+            self.record_usage("revoke_sponsorship");
+            let sponsor = self.env().caller();
+            let mut sponsorship = self
+                .sponsorships
+                .get(sponsor)
+                .filter(|s| s.active)
+                .ok_or(FeeError::SponsorshipInactive)?;
+            let refunded = sponsorship.budget;
+            for account in self.sponsored_accounts.take(sponsor).unwrap_or_default() {
+                self.account_sponsors.remove(account);
+            }
+            sponsorship.budget = 0;
+            sponsorship.accounts = 0;
+            sponsorship.active = false;
+            self.sponsorships.insert(sponsor, &sponsorship);
+            self.sponsor_liability = self.sponsor_liability.saturating_sub(refunded);
+            if refunded > 0 {
+                self.env()
+                    .transfer(sponsor, refunded)
+                    .map_err(|_| FeeError::TransferFailed)?;
+            }
+            self.env()
+                .emit_event(SponsorshipRevoked { sponsor, refunded });
+            Ok(refunded)
+        }
+
+        /// Draw the current fee for `from`'s operation from its sponsor's budget,
+        /// within the sponsor's per-account limits. Returns the amount covered; the
+        /// calling contract collects the rest. Admin or a registered target only.
+        #[ink(message)]
+        pub fn charge_sponsor(
+            &mut self,
+            operation: FeeOperation,
+            from: AccountId,
+        ) -> Result<u128, FeeError> {
+            self.record_usage("charge_sponsor");
+            let caller = self.env().caller();
+            if caller != self.admin && !self.deferral_targets.get(caller).unwrap_or(false) {
+                return Err(FeeError::Unauthorized);
+            }
+            let fee = self.calculate_fee(operation);
+            let covered = self.draw_sponsorship(operation, from, fee);
+            if covered > 0 {
+                self.collect_fee(covered);
+            }
+            Ok(covered)
+        }
+
+        #[ink(message)]
+        pub fn get_sponsorship(&self, sponsor: AccountId) -> Option<Sponsorship> {
+            self.sponsorships.get(sponsor)
+        }
+
+        #[ink(message)]
+        pub fn get_account_sponsor(&self, account: AccountId) -> Option<AccountId> {
+            self.account_sponsors.get(account)
+        }
+
+        /// Fees a sponsor has paid for one account
+        #[ink(message)]
+        pub fn get_sponsored_usage(
+            &self,
+            sponsor: AccountId,
+            account: AccountId,
+        ) -> SponsoredUsage {
+            self.sponsored_usage
+                .get((sponsor, account))
+                .unwrap_or_default()
+        }
+
+        /// Move up to `fee` from `account`'s sponsor budget into fee revenue; returns
+        /// the amount covered (0 when unsponsored or over the sponsor's limits)
+        fn draw_sponsorship(
+            &mut self,
+            operation: FeeOperation,
+            account: AccountId,
+            fee: u128,
+        ) -> u128 {
+            let Some(sponsor) = self.account_sponsors.get(account) else {
+                return 0;
+            };
+            let Some(mut sponsorship) = self.sponsorships.get(sponsor).filter(|s| s.active) else {
+                return 0;
+            };
+            let mut usage = self
+                .sponsored_usage
+                .get((sponsor, account))
+                .unwrap_or_default();
+            if sponsorship.max_ops_per_account > 0 && usage.ops >= sponsorship.max_ops_per_account {
+                return 0;
+            }
+            let mut amount = fee.min(sponsorship.budget);
+            if sponsorship.max_spend_per_account > 0 {
+                amount = amount.min(
+                    sponsorship
+                        .max_spend_per_account
+                        .saturating_sub(usage.spent),
+                );
+            }
+            if amount == 0 {
+                return 0;
+            }
+            sponsorship.budget -= amount;
+            sponsorship.spent = sponsorship.spent.saturating_add(amount);
+            self.sponsorships.insert(sponsor, &sponsorship);
+            usage.ops += 1;
+            usage.spent = usage.spent.saturating_add(amount);
+            self.sponsored_usage.insert((sponsor, account), &usage);
+            self.sponsor_liability = self.sponsor_liability.saturating_sub(amount);
+            self.total_sponsored = self.total_sponsored.saturating_add(amount);
+            self.env().emit_event(FeeSponsored {
+                sponsor,
+                account,
+                operation,
+                amount,
+            });
+            amount
+        }
+
+        // ========== Off-peak deferral queue ==========
+
+        /// Queue an operation to run once its fee drops to `max_fee` or below.
+        /// The transferred value must cover `max_fee + tip`.
+        #[ink(message, payable)]
+        pub fn enqueue_intent(
+            &mut self,
+            operation: FeeOperation,
+            max_fee: u128,
+            tip: u128,
+            target: AccountId,
+            payload: Vec<u8>,
+        ) -> Result<u64, FeeError> {
+            self.record_usage("enqueue_intent");
+            if !self.deferral_targets.get(target).unwrap_or(false) {
+                return Err(FeeError::TargetNotRegistered);
+            }
+            let deposit = self.env().transferred_value();
+            if deposit < max_fee.saturating_add(tip) {
+                return Err(FeeError::InsufficientDeposit);
+            }
+            let owner = self.env().caller();
+            self.deferred_count += 1;
+            let intent_id = self.deferred_count;
+            let intent = DeferredIntent {
+                owner,
+                operation,
+                max_fee,
+                tip,
+                target,
+                payload,
+                deposit,
+                created_at: self.env().block_timestamp(),
+            };
+            self.deferred_intents.insert(intent_id, &intent);
+            self.deferred_queue.push(intent_id);
+            self.env().emit_event(IntentQueued {
+                intent_id,
+                owner,
+                operation,
+                max_fee,
+                tip,
+            });
+            Ok(intent_id)
         }
 
-        /// Settle auction after end_time; winner is current_bidder
+        /// Cancel a queued intent and refund its deposit (owner only)
         #[ink(message)]
-        pub fn settle_auction(&mut self, auction_id: u64) -> Result<(), FeeError> {
-            let now = self.env().block_timestamp();
-            let mut auction = self
-                .auctions
-                .get(auction_id)
-                .ok_or(FeeError::AuctionNotFound)?;
-            if auction.settled {
-                return Err(FeeError::AlreadySettled);
+        pub fn cancel_intent(&mut self, intent_id: u64) -> Result<(), FeeError> {
+            self.record_usage("cancel_intent");
+            let intent = self
+                .deferred_intents
+                .get(intent_id)
+                .ok_or(FeeError::IntentNotFound)?;
+            if self.env().caller() != intent.owner {
+                return Err(FeeError::Unauthorized);
             }
-            if now < auction.end_time {
-                return Err(FeeError::AuctionNotEnded);
+            self.remove_intent(intent_id);
+            self.env()
+                .transfer(intent.owner, intent.deposit)
+                .map_err(|_| FeeError::TransferFailed)?;
+            self.env().emit_event(IntentCancelled {
+                intent_id,
+                refunded: intent.deposit,
+            });
+            Ok(())
+        }
+
+        /// Keeper entry point: examines up to `limit` queued intents and executes
+        /// those whose current fee is within bound. Returns the number executed.
+        #[ink(message)]
+        pub fn process_queue(&mut self, limit: u32) -> Result<u32, FeeError> {
+            self.record_usage("process_queue");
+            let keeper = self.env().caller();
+            let candidates: Vec<u64> = self
+                .deferred_queue
+                .iter()
+                .take(limit as usize)
+                .copied()
+                .collect();
+            let mut executed = 0u32;
+            for intent_id in candidates {
+                let Some(intent) = self.deferred_intents.get(intent_id) else {
+                    continue;
+                };
+                let fee = self.calculate_fee(intent.operation);
+                if fee > intent.max_fee {
+                    continue;
+                }
+                self.remove_intent(intent_id);
+                if !self.call_deferred_target(&intent) {
+                    self.env()
+                        .transfer(intent.owner, intent.deposit)
+                        .map_err(|_| FeeError::TransferFailed)?;
+                    self.env().emit_event(IntentFailed {
+                        intent_id,
+                        refunded: intent.deposit,
+                    });
+                    continue;
+                }
+                self.collect_fee(fee);
+                // A sponsor's share of the fee goes back to the owner with the refund
+                let sponsored = self.draw_sponsorship(intent.operation, intent.owner, fee);
+                let refund = intent
+                    .deposit
+                    .saturating_sub(fee - sponsored)
+                    .saturating_sub(intent.tip);
+                if intent.tip > 0 {
+                    self.env()
+                        .transfer(keeper, intent.tip)
+                        .map_err(|_| FeeError::TransferFailed)?;
+                }
+                if refund > 0 {
+                    self.env()
+                        .transfer(intent.owner, refund)
+                        .map_err(|_| FeeError::TransferFailed)?;
+                }
+                self.env().emit_event(IntentExecuted {
+                    intent_id,
+                    keeper,
+                    fee,
+                    tip: intent.tip,
+                });
+                executed += 1;
             }
-            let winner = auction.current_bidder.ok_or(FeeError::AuctionNotFound)?;
-            let amount = auction.current_bid;
-            auction.settled = true;
-            self.auctions.insert(auction_id, &auction);
-            // fee_paid was already added to fee_treasury at auction creation
-            self.env().emit_event(PremiumAuctionSettled {
-                auction_id,
-                property_id: auction.property_id,
-                winner,
-                amount,
-                timestamp: now,
+            Ok(executed)
+        }
+
+        /// Allow or disallow a contract as a deferred execution target (admin only)
+        #[ink(message)]
+        pub fn set_deferral_target(
+            &mut self,
+            target: AccountId,
+            allowed: bool,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_deferral_target");
+            self.ensure_admin()?;
+            self.journal_rule_change(FeeRuleChange::DeferralTarget {
+                target,
+                old: self.deferral_targets.get(target).unwrap_or(false),
+                new: allowed,
             });
+            if allowed {
+                self.deferral_targets.insert(target, &true);
+            } else {
+                self.deferral_targets.remove(target);
+            }
             Ok(())
         }
 
         #[ink(message)]
-        pub fn get_auction(&self, auction_id: u64) -> Option<PremiumAuction> {
-            self.auctions.get(auction_id)
+        pub fn get_intent(&self, intent_id: u64) -> Option<DeferredIntent> {
+            self.deferred_intents.get(intent_id)
         }
 
         #[ink(message)]
-        pub fn get_auction_count(&self) -> u64 {
-            self.auction_count
+        pub fn get_deferred_queue(&self) -> Vec<u64> {
+            self.deferred_queue.clone()
+        }
+
+        fn remove_intent(&mut self, intent_id: u64) {
+            self.deferred_intents.remove(intent_id);
+            self.deferred_queue.retain(|id| *id != intent_id);
+        }
+
+        fn call_deferred_target(&self, intent: &DeferredIntent) -> bool {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::DeferredExecutor;
+            let mut executor: ink::contract_ref!(DeferredExecutor) =
+                FromAccountId::from_account_id(intent.target);
+            matches!(
+                executor
+                    .call_mut()
+                    .execute_deferred(intent.owner, intent.operation, intent.payload.clone())
+                    .try_invoke(),
+                Ok(Ok(true))
+            )
         }
 
         // ========== Incentives and distribution ==========
 
         #[ink(message)]
         pub fn add_validator(&mut self, account: AccountId) -> Result<(), FeeError> {
+            self.record_usage("add_validator");
             self.ensure_admin()?;
+            if self.validator_source.is_some() {
+                return Err(FeeError::ValidatorSetManaged);
+            }
             if self.validators.get(account).unwrap_or(false) {
                 return Ok(());
             }
@@ -559,48 +3139,263 @@ mod propchain_fees {
 
         #[ink(message)]
         pub fn remove_validator(&mut self, account: AccountId) -> Result<(), FeeError> {
+            self.record_usage("remove_validator");
             self.ensure_admin()?;
+            if self.validator_source.is_some() {
+                return Err(FeeError::ValidatorSetManaged);
+            }
             self.validators.remove(account);
             self.validator_list.retain(|&a| a != account);
             Ok(())
         }
 
+        /// Hand validator set maintenance to a staking contract, or take it back
+        /// with `None` (admin only)
+        #[ink(message)]
+        pub fn set_validator_source(
+            &mut self,
+            source: Option<AccountId>,
+            sync_interval: u64,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_validator_source");
+            self.ensure_admin()?;
+            self.validator_source = source;
+            self.validator_sync_interval = sync_interval;
+            self.validator_synced_at = 0;
+            Ok(())
+        }
+
+        /// Pull the active validator set from the source (anyone, once per interval)
+        #[ink(message)]
+        pub fn sync_validator_set(&mut self) -> Result<u32, FeeError> {
+            self.record_usage("sync_validator_set");
+            let source = self
+                .validator_source
+                .ok_or(FeeError::ValidatorSourceUnavailable)?;
+            let now = self.env().block_timestamp();
+            if self.validator_synced_at != 0
+                && now
+                    < self
+                        .validator_synced_at
+                        .saturating_add(self.validator_sync_interval)
+            {
+                return Err(FeeError::SyncTooSoon);
+            }
+            let set = self
+                .fetch_validator_set(source)
+                .ok_or(FeeError::ValidatorSourceUnavailable)?;
+            Ok(self.replace_validator_set(source, set))
+        }
+
+        /// Accept a validator set pushed by the source contract itself
+        #[ink(message)]
+        pub fn push_validator_set(
+            &mut self,
+            validators: Vec<(AccountId, u128)>,
+        ) -> Result<u32, FeeError> {
+            self.record_usage("push_validator_set");
+            let caller = self.env().caller();
+            if self.validator_source != Some(caller) {
+                return Err(FeeError::Unauthorized);
+            }
+            Ok(self.replace_validator_set(caller, validators))
+        }
+
+        #[ink(message)]
+        pub fn get_validator_set(&self) -> Vec<(AccountId, u128)> {
+            self.validator_list
+                .iter()
+                .map(|&acc| (acc, self.validator_stakes.get(acc).unwrap_or(0)))
+                .collect()
+        }
+
+        #[ink(message)]
+        pub fn get_validator_source(&self) -> Option<AccountId> {
+            self.validator_source
+        }
+
+        fn fetch_validator_set(&self, source: AccountId) -> Option<Vec<(AccountId, u128)>> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::ValidatorSetProvider;
+            let provider: ink::contract_ref!(ValidatorSetProvider) =
+                FromAccountId::from_account_id(source);
+            provider.call().active_validators().try_invoke().ok()?.ok()
+        }
+
+        fn replace_validator_set(
+            &mut self,
+            source: AccountId,
+            validators: Vec<(AccountId, u128)>,
+        ) -> u32 {
+            for acc in core::mem::take(&mut self.validator_list) {
+                self.validators.remove(acc);
+                self.validator_stakes.remove(acc);
+            }
+            let mut total_stake: u128 = 0;
+            for (acc, stake) in validators {
+                if stake == 0 || self.validators.get(acc).unwrap_or(false) {
+                    continue;
+                }
+                self.validators.insert(acc, &true);
+                self.validator_stakes.insert(acc, &stake);
+                self.validator_list.push(acc);
+                total_stake = total_stake.saturating_add(stake);
+            }
+            let now = self.env().block_timestamp();
+            self.validator_synced_at = now;
+            let count = self.validator_list.len() as u32;
+            self.env().emit_event(ValidatorSetSynced {
+                source,
+                validators: count,
+                total_stake,
+                timestamp: now,
+            });
+            count
+        }
+
         #[ink(message)]
         pub fn set_distribution_rates(
             &mut self,
             validator_share_bp: u32,
             treasury_share_bp: u32,
         ) -> Result<(), FeeError> {
+            self.record_usage("set_distribution_rates");
             self.ensure_admin()?;
             if validator_share_bp.saturating_add(treasury_share_bp) > 10_000 {
                 return Err(FeeError::InvalidConfig);
             }
+            self.journal_rule_change(FeeRuleChange::DistributionRates {
+                old: (self.validator_share_bp, self.treasury_share_bp),
+                new: (validator_share_bp, treasury_share_bp),
+            });
             self.validator_share_bp = validator_share_bp;
             self.treasury_share_bp = treasury_share_bp;
             Ok(())
         }
 
-        /// Distribute accumulated fees: validator share to validators, rest to treasury
+        /// Hand control of the burn share to a governance account, or back to the
+        /// admin with None (admin, or the current governor)
+        #[ink(message)]
+        pub fn set_burn_governor(&mut self, governor: Option<AccountId>) -> Result<(), FeeError> {
+            self.record_usage("set_burn_governor");
+            self.ensure_burn_authority()?;
+            self.burn_governor = governor;
+            Ok(())
+        }
+
+        /// Set the share of each distribution that is burned (burn authority only)
+        #[ink(message)]
+        pub fn set_burn_share(&mut self, burn_share_bp: u32) -> Result<(), FeeError> {
+            self.record_usage("set_burn_share");
+            self.ensure_burn_authority()?;
+            if burn_share_bp as u128 > BASIS_POINTS {
+                return Err(FeeError::InvalidConfig);
+            }
+            self.journal_rule_change(FeeRuleChange::BurnShare {
+                old: self.burn_share_bp,
+                new: burn_share_bp,
+            });
+            self.burn_share_bp = burn_share_bp;
+            self.env().emit_event(BurnShareUpdated {
+                by: self.env().caller(),
+                burn_share_bp,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_burn_settings(&self) -> (u32, Option<AccountId>, u128) {
+            (self.burn_share_bp, self.burn_governor, self.total_burned)
+        }
+
+        /// Distribute accumulated fees: the burn share is sent to BURN_ACCOUNT (or
+        /// carried to the next distribution while below the existential deposit), then
+        /// the validator share of the remainder goes to validators (by stake weight
+        /// when stakes are known, otherwise equally), rest to treasury. When the
+        /// balance cannot back the validator share on top of existing liabilities,
+        /// every validator's reward is scaled down pro rata and the shortfall reported.
         #[ink(message)]
         pub fn distribute_fees(&mut self) -> Result<(), FeeError> {
+            self.record_usage("distribute_fees");
             self.ensure_admin()?;
-            let amount = self.fee_treasury;
-            if amount == 0 {
+            let collected = self.fee_treasury;
+            if collected == 0 {
                 return Ok(());
             }
-            let validator_total = amount
+            let burned = collected
+                .saturating_mul(self.burn_share_bp as u128)
+                .saturating_div(BASIS_POINTS);
+            let burn_due = self.pending_burn.saturating_add(burned);
+            if burn_due > 0 && burn_due >= self.env().minimum_balance() {
+                self.env()
+                    .transfer(AccountId::from(BURN_ACCOUNT), burn_due)
+                    .map_err(|_| FeeError::TransferFailed)?;
+                self.pending_burn = 0;
+                self.total_burned = self.total_burned.saturating_add(burn_due);
+                self.env().emit_event(FeesBurned {
+                    epoch: self.distribution_epoch + 1,
+                    amount: burn_due,
+                    total_burned: self.total_burned,
+                });
+            } else {
+                self.pending_burn = burn_due;
+            }
+            let amount = collected - burned;
+            let promised = amount
                 .saturating_mul(self.validator_share_bp as u128)
                 .saturating_div(BASIS_POINTS);
             let validator_list = self.validator_list.clone();
             let validator_count = validator_list.len() as u32;
+            let validator_total = if validator_count > 0 {
+                promised.min(self.reward_headroom())
+            } else {
+                promised
+            };
+            let total_stake = validator_list
+                .iter()
+                .map(|&acc| self.validator_stakes.get(acc).unwrap_or(0))
+                .fold(0u128, |acc, stake| acc.saturating_add(stake));
+            self.distribution_epoch += 1;
+            self.rewards_degraded = validator_total < promised;
+            if self.rewards_degraded {
+                self.env().emit_event(RewardShortfall {
+                    epoch: self.distribution_epoch,
+                    promised,
+                    funded: validator_total,
+                    deficit: promised - validator_total,
+                });
+            }
             if validator_count > 0 && validator_total > 0 {
-                let per_validator = validator_total.saturating_div(validator_count as u128);
                 for acc in validator_list {
-                    let current = self.pending_rewards.get(acc).unwrap_or(0);
-                    self.pending_rewards
-                        .insert(acc, &current.saturating_add(per_validator));
+                    let per_validator = if total_stake > 0 {
+                        validator_total
+                            .saturating_mul(self.validator_stakes.get(acc).unwrap_or(0))
+                            .saturating_div(total_stake)
+                    } else {
+                        validator_total.saturating_div(validator_count as u128)
+                    };
+                    if self.vesting_threshold > 0 && per_validator >= self.vesting_threshold {
+                        let index = self.vesting_schedule_count.get(acc).unwrap_or(0);
+                        self.vesting_schedules.insert(
+                            (acc, index),
+                            &VestingSchedule {
+                                epoch: self.distribution_epoch,
+                                total: per_validator,
+                                released: 0,
+                                start: self.env().block_timestamp(),
+                                duration: self.vesting_duration,
+                            },
+                        );
+                        self.vesting_schedule_count.insert(acc, &(index + 1));
+                    } else {
+                        let current = self.pending_rewards.get(acc).unwrap_or(0);
+                        self.pending_rewards
+                            .insert(acc, &current.saturating_add(per_validator));
+                    }
                     self.record_reward(acc, per_validator, RewardReason::ValidatorReward);
                     self.total_distributed = self.total_distributed.saturating_add(per_validator);
+                    self.reward_liability = self.reward_liability.saturating_add(per_validator);
                     self.env().emit_event(RewardsDistributed {
                         recipient: acc,
                         amount: per_validator,
@@ -613,6 +3408,193 @@ mod propchain_fees {
             Ok(())
         }
 
+        // ========== Treasury yield strategy ==========
+
+        /// Approve a yield strategy and cap the share of treasury it may hold
+        /// (admin only). A strategy still holding funds must be recalled first.
+        #[ink(message)]
+        pub fn approve_yield_strategy(
+            &mut self,
+            strategy: Option<AccountId>,
+            max_deploy_bp: u32,
+        ) -> Result<(), FeeError> {
+            self.record_usage("approve_yield_strategy");
+            self.ensure_admin()?;
+            if max_deploy_bp as u128 > BASIS_POINTS
+                || (self.treasury_deployed > 0 && strategy != self.yield_strategy)
+            {
+                return Err(FeeError::InvalidConfig);
+            }
+            self.yield_strategy = strategy;
+            self.max_deploy_bp = max_deploy_bp;
+            self.env().emit_event(YieldStrategyApproved {
+                strategy,
+                max_deploy_bp,
+            });
+            Ok(())
+        }
+
+        /// Move `amount` of treasury into the approved strategy, within the cap
+        #[ink(message)]
+        pub fn deploy_treasury(&mut self, amount: u128) -> Result<(), FeeError> {
+            self.record_usage("deploy_treasury");
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::YieldStrategy;
+            self.ensure_admin()?;
+            let strategy = self.yield_strategy.ok_or(FeeError::StrategyNotSet)?;
+            self.record_deployment(amount)?;
+
+            let mut adapter: ink::contract_ref!(YieldStrategy) =
+                FromAccountId::from_account_id(strategy);
+            match adapter
+                .call_mut()
+                .deposit()
+                .transferred_value(amount)
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => {}
+                _ => return Err(FeeError::StrategyCallFailed),
+            }
+            self.env().emit_event(TreasuryDeployed {
+                strategy,
+                amount,
+                total_deployed: self.treasury_deployed,
+            });
+            Ok(())
+        }
+
+        /// Withdraw `amount` from the strategy back into the treasury
+        #[ink(message)]
+        pub fn recall_treasury(&mut self, amount: u128) -> Result<u128, FeeError> {
+            self.record_usage("recall_treasury");
+            self.ensure_admin()?;
+            let strategy = self.yield_strategy.ok_or(FeeError::StrategyNotSet)?;
+            let returned = self.withdraw_from_strategy(strategy, amount)?;
+            Ok(self.record_recall(strategy, returned, false))
+        }
+
+        /// Pull the whole position out and revoke the strategy (admin only). The
+        /// strategy is revoked even when it cannot be reached.
+        #[ink(message)]
+        pub fn emergency_recall(&mut self) -> Result<u128, FeeError> {
+            self.record_usage("emergency_recall");
+            self.ensure_admin()?;
+            let strategy = self.yield_strategy.ok_or(FeeError::StrategyNotSet)?;
+            let returned = self
+                .fetch_strategy_value(strategy)
+                .and_then(|value| self.withdraw_from_strategy(strategy, value));
+            Ok(self.revoke_strategy(strategy, returned.ok()))
+        }
+
+        #[ink(message)]
+        pub fn get_strategy_write_off(&self, strategy: AccountId) -> u128 {
+            self.strategy_write_offs.get(strategy).unwrap_or(0)
+        }
+
+        /// Books what an emergency recall returned (`None` when the strategy call
+        /// failed), writes off the rest and revokes the strategy. Returns the yield.
+        fn revoke_strategy(&mut self, strategy: AccountId, returned: Option<u128>) -> u128 {
+            let yield_earned = match returned {
+                Some(returned) => self.record_recall(strategy, returned, true),
+                None => 0,
+            };
+            let written_off = self.treasury_deployed;
+            if written_off > 0 || returned.is_none() {
+                let total = self.get_strategy_write_off(strategy);
+                self.strategy_write_offs
+                    .insert(strategy, &total.saturating_add(written_off));
+                self.env().emit_event(StrategyWrittenOff {
+                    strategy,
+                    written_off,
+                    unreachable: returned.is_none(),
+                });
+            }
+            self.treasury_deployed = 0;
+            self.strategy_value = 0;
+            self.yield_strategy = None;
+            self.max_deploy_bp = 0;
+            yield_earned
+        }
+
+        /// Refresh the strategy position value shown in the fee report
+        #[ink(message)]
+        pub fn sync_strategy_value(&mut self) -> Result<u128, FeeError> {
+            self.record_usage("sync_strategy_value");
+            let strategy = self.yield_strategy.ok_or(FeeError::StrategyNotSet)?;
+            self.strategy_value = self.fetch_strategy_value(strategy)?;
+            Ok(self.strategy_value)
+        }
+
+        #[ink(message)]
+        pub fn get_yield_strategy(&self) -> Option<AccountId> {
+            self.yield_strategy
+        }
+
+        /// Checks the deployment cap and moves `amount` from held to deployed
+        fn record_deployment(&mut self, amount: u128) -> Result<(), FeeError> {
+            let cap = self
+                .fee_treasury
+                .saturating_add(self.treasury_deployed)
+                .saturating_mul(self.max_deploy_bp as u128)
+                .saturating_div(BASIS_POINTS);
+            let deployed = self.treasury_deployed.saturating_add(amount);
+            if amount == 0 || amount > self.fee_treasury || deployed > cap {
+                return Err(FeeError::DeploymentLimitExceeded);
+            }
+            self.fee_treasury -= amount;
+            self.treasury_deployed = deployed;
+            self.strategy_value = self.strategy_value.saturating_add(amount);
+            Ok(())
+        }
+
+        /// Credits returned funds to the treasury; anything above deployed
+        /// principal counts as yield. Returns the yield realized.
+        fn record_recall(&mut self, strategy: AccountId, returned: u128, emergency: bool) -> u128 {
+            let principal = returned.min(self.treasury_deployed);
+            let yield_earned = returned - principal;
+            self.treasury_deployed -= principal;
+            self.strategy_value = self.strategy_value.saturating_sub(returned);
+            self.fee_treasury = self.fee_treasury.saturating_add(returned);
+            self.realized_yield = self.realized_yield.saturating_add(yield_earned);
+            self.env().emit_event(TreasuryRecalled {
+                strategy,
+                returned,
+                yield_earned,
+                emergency,
+            });
+            yield_earned
+        }
+
+        fn withdraw_from_strategy(
+            &self,
+            strategy: AccountId,
+            amount: u128,
+        ) -> Result<u128, FeeError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::YieldStrategy;
+            let mut adapter: ink::contract_ref!(YieldStrategy) =
+                FromAccountId::from_account_id(strategy);
+            match adapter.call_mut().withdraw(amount).try_invoke() {
+                Ok(Ok(Ok(returned))) => Ok(returned),
+                _ => Err(FeeError::StrategyCallFailed),
+            }
+        }
+
+        fn fetch_strategy_value(&self, strategy: AccountId) -> Result<u128, FeeError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::YieldStrategy;
+            let adapter: ink::contract_ref!(YieldStrategy) =
+                FromAccountId::from_account_id(strategy);
+            let owner = self.env().account_id();
+            match adapter.call().position_value(owner).try_invoke() {
+                Ok(Ok(value)) => Ok(value),
+                _ => Err(FeeError::StrategyCallFailed),
+            }
+        }
+
         fn record_reward(&mut self, account: AccountId, amount: u128, reason: RewardReason) {
             self.reward_record_count += 1;
             self.reward_records.insert(
@@ -621,32 +3603,279 @@ mod propchain_fees {
                     account,
                     amount,
                     reason,
+                    epoch: self.distribution_epoch,
+                    timestamp: self.env().block_timestamp(),
+                },
+            );
+            let index = self.account_reward_count.get(account).unwrap_or(0);
+            self.account_reward_ids
+                .insert((account, index), &self.reward_record_count);
+            self.account_reward_count.insert(account, &(index + 1));
+        }
+
+        /// Set when validator distributions vest: amounts at or above `threshold`
+        /// release linearly over `duration` ms (admin only; threshold 0 disables)
+        #[ink(message)]
+        pub fn set_vesting_policy(
+            &mut self,
+            threshold: u128,
+            duration: u64,
+        ) -> Result<(), FeeError> {
+            self.record_usage("set_vesting_policy");
+            self.ensure_admin()?;
+            if threshold > 0 && duration == 0 {
+                return Err(FeeError::InvalidConfig);
+            }
+            self.journal_rule_change(FeeRuleChange::VestingPolicy {
+                old: (self.vesting_threshold, self.vesting_duration),
+                new: (threshold, duration),
+            });
+            self.vesting_threshold = threshold;
+            self.vesting_duration = duration;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_vesting_policy(&self) -> (u128, u64) {
+            (self.vesting_threshold, self.vesting_duration)
+        }
+
+        #[ink(message)]
+        pub fn get_vesting_schedules(&self, account: AccountId) -> Vec<VestingSchedule> {
+            (0..self.vesting_schedule_count.get(account).unwrap_or(0))
+                .filter_map(|index| self.vesting_schedules.get((account, index)))
+                .collect()
+        }
+
+        /// Vested rewards not yet claimed
+        #[ink(message)]
+        pub fn vested_reward(&self, account: AccountId) -> u128 {
+            let now = self.env().block_timestamp();
+            self.get_vesting_schedules(account)
+                .iter()
+                .map(|schedule| {
+                    Self::vested_amount(schedule, now).saturating_sub(schedule.released)
+                })
+                .fold(0u128, |acc, amount| acc.saturating_add(amount))
+        }
+
+        /// Page of an account's reward records, oldest first
+        #[ink(message)]
+        pub fn get_reward_history(
+            &self,
+            account: AccountId,
+            offset: u64,
+            limit: u32,
+        ) -> Vec<RewardRecord> {
+            let count = self.account_reward_count.get(account).unwrap_or(0);
+            (offset..count)
+                .take(limit as usize)
+                .filter_map(|index| self.account_reward_ids.get((account, index)))
+                .filter_map(|id| self.reward_records.get(id))
+                .collect()
+        }
+
+        /// Page of an account's reward claims, oldest first
+        #[ink(message)]
+        pub fn get_claim_history(
+            &self,
+            account: AccountId,
+            offset: u64,
+            limit: u32,
+        ) -> Vec<RewardClaim> {
+            let count = self.reward_claim_count.get(account).unwrap_or(0);
+            (offset..count)
+                .take(limit as usize)
+                .filter_map(|index| self.reward_claims.get((account, index)))
+                .collect()
+        }
+
+        /// Rewards earned and claimed by `account` with timestamps in `from..=to`
+        #[ink(message)]
+        pub fn get_reward_statement(
+            &self,
+            account: AccountId,
+            from: u64,
+            to: u64,
+        ) -> RewardStatement {
+            let in_range = |timestamp: u64| timestamp >= from && timestamp <= to;
+            let mut statement = RewardStatement {
+                account,
+                from,
+                to,
+                entries: 0,
+                earned: 0,
+                validator_rewards: 0,
+                claimed: 0,
+                unvested: 0,
+            };
+            for index in 0..self.account_reward_count.get(account).unwrap_or(0) {
+                let Some(record) = self
+                    .account_reward_ids
+                    .get((account, index))
+                    .and_then(|id| self.reward_records.get(id))
+                else {
+                    continue;
+                };
+                if !in_range(record.timestamp) {
+                    continue;
+                }
+                statement.entries += 1;
+                statement.earned = statement.earned.saturating_add(record.amount);
+                if record.reason == RewardReason::ValidatorReward {
+                    statement.validator_rewards =
+                        statement.validator_rewards.saturating_add(record.amount);
+                }
+            }
+            for index in 0..self.reward_claim_count.get(account).unwrap_or(0) {
+                if let Some(claim) = self.reward_claims.get((account, index)) {
+                    if in_range(claim.timestamp) {
+                        statement.claimed = statement.claimed.saturating_add(claim.amount);
+                    }
+                }
+            }
+            let now = self.env().block_timestamp();
+            statement.unvested = self
+                .get_vesting_schedules(account)
+                .iter()
+                .map(|schedule| {
+                    schedule
+                        .total
+                        .saturating_sub(Self::vested_amount(schedule, now))
+                })
+                .fold(0u128, |acc, amount| acc.saturating_add(amount));
+            statement
+        }
+
+        fn vested_amount(schedule: &VestingSchedule, now: u64) -> u128 {
+            let elapsed = now.saturating_sub(schedule.start);
+            if elapsed >= schedule.duration {
+                return schedule.total;
+            }
+            schedule
+                .total
+                .saturating_mul(elapsed as u128)
+                .checked_div(schedule.duration as u128)
+                .unwrap_or(schedule.total)
+        }
+
+        /// Move everything vested so far out of the caller's schedules
+        fn release_vested(&mut self, account: AccountId) -> u128 {
+            let now = self.env().block_timestamp();
+            let mut released = 0u128;
+            for index in 0..self.vesting_schedule_count.get(account).unwrap_or(0) {
+                let Some(mut schedule) = self.vesting_schedules.get((account, index)) else {
+                    continue;
+                };
+                let vested = Self::vested_amount(&schedule, now);
+                if vested > schedule.released {
+                    released = released.saturating_add(vested - schedule.released);
+                    schedule.released = vested;
+                    self.vesting_schedules.insert((account, index), &schedule);
+                }
+            }
+            released
+        }
+
+        /// Claim pending rewards plus anything vested for a participant
+        #[ink(message)]
+        pub fn claim_rewards(&mut self) -> Result<u128, FeeError> {
+            self.record_usage("claim_rewards");
+            let caller = self.env().caller();
+            let vested = self.release_vested(caller);
+            let amount = self
+                .pending_rewards
+                .get(caller)
+                .unwrap_or(0)
+                .saturating_add(vested);
+            if amount == 0 {
+                return Ok(0);
+            }
+            self.pending_rewards.remove(caller);
+            self.reward_liability = self.reward_liability.saturating_sub(amount);
+            let index = self.reward_claim_count.get(caller).unwrap_or(0);
+            self.reward_claims.insert(
+                (caller, index),
+                &RewardClaim {
+                    amount,
+                    vested,
                     timestamp: self.env().block_timestamp(),
                 },
             );
+            self.reward_claim_count.insert(caller, &(index + 1));
+            self.env().emit_event(RewardsDistributed {
+                recipient: caller,
+                amount,
+                reason: RewardReason::ValidatorReward,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(amount)
+        }
+
+        #[ink(message)]
+        pub fn pending_reward(&self, account: AccountId) -> u128 {
+            self.pending_rewards.get(account).unwrap_or(0)
+        }
+
+        /// Balance not yet owed to reward holders, credit buyers or sponsors;
+        /// the most a distribution may promise to validators
+        #[ink(message)]
+        pub fn reward_headroom(&self) -> u128 {
+            self.env()
+                .balance()
+                .saturating_sub(self.reward_liability)
+                .saturating_sub(self.prepaid_liability)
+                .saturating_sub(self.sponsor_liability)
+                .saturating_sub(self.pending_burn)
+        }
+
+        // ========== Usage telemetry ==========
+
+        /// Set the weight estimate recorded for each call of a message (admin)
+        #[ink(message)]
+        pub fn set_message_weight(&mut self, message: String, weight: u64) -> Result<(), FeeError> {
+            self.record_usage("set_message_weight");
+            self.ensure_admin()?;
+            self.journal_rule_change(FeeRuleChange::MessageWeight {
+                message: message.clone(),
+                old: self.message_weights.get(&message),
+                new: weight,
+            });
+            self.message_weights.insert(&message, &weight);
+            Ok(())
+        }
+
+        /// Set the window weight at which congestion reads 100 (admin; 0 = ops count only)
+        #[ink(message)]
+        pub fn set_weight_capacity(&mut self, capacity: u64) -> Result<(), FeeError> {
+            self.record_usage("set_weight_capacity");
+            self.ensure_admin()?;
+            self.journal_rule_change(FeeRuleChange::WeightCapacity {
+                old: self.weight_capacity,
+                new: capacity,
+            });
+            self.weight_capacity = capacity;
+            Ok(())
         }
 
-        /// Claim pending rewards for a participant
+        /// Invocation counts and cumulative weight per state-changing message, in
+        /// order of first call
         #[ink(message)]
-        pub fn claim_rewards(&mut self) -> Result<u128, FeeError> {
-            let caller = self.env().caller();
-            let amount = self.pending_rewards.get(caller).unwrap_or(0);
-            if amount == 0 {
-                return Ok(0);
-            }
-            self.pending_rewards.remove(caller);
-            self.env().emit_event(RewardsDistributed {
-                recipient: caller,
-                amount,
-                reason: RewardReason::ValidatorReward,
-                timestamp: self.env().block_timestamp(),
-            });
-            Ok(amount)
+        pub fn get_usage_stats(&self) -> Vec<MessageUsage> {
+            self.tracked_messages
+                .iter()
+                .filter_map(|message| self.message_usage.get(message))
+                .collect()
         }
 
+        /// Weight recorded in the current congestion window and the configured capacity
         #[ink(message)]
-        pub fn pending_reward(&self, account: AccountId) -> u128 {
-            self.pending_rewards.get(account).unwrap_or(0)
+        pub fn get_weight_usage(&self) -> (u64, u64) {
+            let now = self.env().block_timestamp();
+            if now.saturating_sub(self.last_congestion_reset) > 3600 {
+                return (0, self.weight_capacity);
+            }
+            (self.recent_weight, self.weight_capacity)
         }
 
         // ========== Market-based price discovery & transparency ==========
@@ -702,13 +3931,24 @@ mod propchain_fees {
                 }
             }
             FeeReport {
-                config: self.default_config.clone(),
+                config: self.current_default(),
                 congestion_index: self.congestion_index(),
                 recommended_fee: recommended,
                 total_fees_collected: self.total_fees_collected,
                 total_distributed: self.total_distributed,
                 operation_count_24h: self.recent_ops_count as u64,
                 premium_auctions_active: active_auctions,
+                treasury_deployed: self.treasury_deployed,
+                strategy_value: self.strategy_value,
+                realized_yield: self.realized_yield,
+                prepaid_liability: self.prepaid_liability,
+                sponsor_liability: self.sponsor_liability,
+                total_sponsored: self.total_sponsored,
+                burn_share_bp: self.burn_share_bp,
+                total_burned: self.total_burned,
+                pending_burn: self.pending_burn,
+                reward_liability: self.reward_liability,
+                rewards_degraded: self.rewards_degraded,
                 timestamp: now,
             }
         }
@@ -736,7 +3976,7 @@ mod propchain_fees {
 
         #[ink(message)]
         pub fn default_config(&self) -> FeeConfig {
-            self.default_config.clone()
+            self.current_default()
         }
 
         #[ink(message)]
@@ -763,6 +4003,140 @@ mod propchain_fees {
             assert!(fee >= 100 && fee <= 100_000);
         }
 
+        #[ink::test]
+        fn test_operation_configs_batch_is_all_or_nothing() {
+            let mut contract = FeeManager::new(1000, 100, 100_000);
+            contract.config_change_delay = 0;
+            let config = |base_fee: u128, min_fee: u128| FeeConfig {
+                base_fee,
+                min_fee,
+                max_fee: 50_000,
+                congestion_sensitivity: 50,
+                demand_factor_bp: 0,
+                last_updated: 0,
+            };
+
+            let results = contract
+                .set_operation_configs(vec![
+                    (FeeOperation::CreateEscrow, config(2_000, 200)),
+                    (FeeOperation::OracleUpdate, config(100, 200)),
+                    (FeeOperation::CreateEscrow, config(3_000, 200)),
+                ])
+                .expect("admin batch");
+            assert_eq!(
+                results,
+                vec![
+                    Ok(()),
+                    Err(FeeError::InvalidConfig),
+                    Err(FeeError::InvalidConfig)
+                ]
+            );
+            let all = contract.get_all_operation_configs();
+            assert_eq!(all.len(), 8);
+            assert!(all.iter().all(|(_, c)| c.base_fee == 1000));
+
+            let results = contract
+                .set_operation_configs(vec![
+                    (FeeOperation::CreateEscrow, config(2_000, 200)),
+                    (FeeOperation::OracleUpdate, config(300, 200)),
+                ])
+                .expect("admin batch");
+            assert!(results.iter().all(|r| r.is_ok()));
+            let all = contract.get_all_operation_configs();
+            assert!(all.contains(&(FeeOperation::CreateEscrow, config(2_000, 200))));
+            assert!(all.contains(&(FeeOperation::OracleUpdate, config(300, 200))));
+            assert_eq!(all[0].1.base_fee, 1000);
+
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.set_operation_configs(Vec::new()),
+                Err(FeeError::Unauthorized)
+            );
+        }
+
+        #[ink::test]
+        fn test_scheduled_config_changes_activate_at_boundary() {
+            let mut contract = FeeManager::new(1000, 100, 100_000);
+            let config = |base_fee: u128| FeeConfig {
+                base_fee,
+                min_fee: 100,
+                max_fee: 100_000,
+                congestion_sensitivity: 80,
+                demand_factor_bp: 500,
+                last_updated: 0,
+            };
+            assert_eq!(
+                contract.get_config_change_delay(),
+                DEFAULT_CONFIG_CHANGE_DELAY
+            );
+            assert_eq!(
+                contract.set_operation_config(FeeOperation::CreateEscrow, config(2_000)),
+                Err(FeeError::ChangeMustBeScheduled)
+            );
+            // A shorter notice only takes over once the current notice has run
+            contract.set_config_change_delay(3_600_000).unwrap();
+            assert_eq!(
+                contract.get_config_change_delay(),
+                DEFAULT_CONFIG_CHANGE_DELAY
+            );
+            assert_eq!(
+                contract.get_pending_config_change_delay(),
+                Some((3_600_000, DEFAULT_CONFIG_CHANGE_DELAY))
+            );
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(
+                DEFAULT_CONFIG_CHANGE_DELAY,
+            );
+            assert_eq!(contract.get_config_change_delay(), 3_600_000);
+            assert_eq!(contract.get_pending_config_change_delay(), None);
+            let start = DEFAULT_CONFIG_CHANGE_DELAY;
+            assert_eq!(
+                contract.schedule_config_change(
+                    Some(FeeOperation::CreateEscrow),
+                    config(2_000),
+                    start + 3_599_999
+                ),
+                Err(FeeError::ActivationTooSoon)
+            );
+            let escrow_change = contract
+                .schedule_config_change(
+                    Some(FeeOperation::CreateEscrow),
+                    config(2_000),
+                    start + 3_600_000,
+                )
+                .unwrap();
+            let default_change = contract
+                .schedule_config_change(None, config(1_500), start + 7_200_000)
+                .unwrap();
+            assert_eq!(contract.get_pending_config_changes().len(), 2);
+            contract.cancel_config_change(default_change).unwrap();
+            assert_eq!(
+                contract.cancel_config_change(default_change),
+                Err(FeeError::ChangeNotFound)
+            );
+            assert_eq!(contract.calculate_fee(FeeOperation::CreateEscrow), 1000);
+
+            // Fees follow the change as soon as the boundary passes
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(start + 3_600_000);
+            assert_eq!(contract.calculate_fee(FeeOperation::CreateEscrow), 2_000);
+            assert!(contract.get_pending_config_changes().is_empty());
+            assert_eq!(
+                contract.cancel_config_change(escrow_change),
+                Err(FeeError::ChangeAlreadyActive)
+            );
+            assert_eq!(contract.apply_config_changes(), 1);
+            assert_eq!(contract.apply_config_changes(), 0);
+            let all = contract.get_all_operation_configs();
+            assert!(all.contains(&(
+                FeeOperation::CreateEscrow,
+                FeeConfig {
+                    last_updated: start + 3_600_000,
+                    ..config(2_000)
+                }
+            )));
+            assert_eq!(contract.default_config().base_fee, 1000);
+        }
+
         #[ink::test]
         fn test_premium_auction_flow() {
             let mut contract = FeeManager::new(100, 10, 10_000);
@@ -780,6 +4154,585 @@ mod propchain_fees {
             assert_eq!(auction.current_bid, 600);
         }
 
+        #[ink::test]
+        fn test_auction_checks_are_admin_only() {
+            let mut contract = FeeManager::new(100, 10, 10_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.set_auction_checks(Some(accounts.bob), Some(accounts.bob)),
+                Err(FeeError::Unauthorized)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(
+                contract.set_auction_checks(Some(accounts.charlie), Some(accounts.django)),
+                Ok(())
+            );
+            // Unset again, sellers are no longer vetted
+            assert_eq!(contract.set_auction_checks(None, None), Ok(()));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert!(contract.create_premium_auction(7, 500, 3600).is_ok());
+        }
+
+        #[ink::test]
+        fn test_auction_seller_checks_fail_closed() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            assert_eq!(FeeManager::auction_compliance_verdict(Ok(Ok(true))), Ok(()));
+            assert_eq!(
+                FeeManager::auction_compliance_verdict(Ok(Ok(false))),
+                Err(FeeError::SellerNotCompliant)
+            );
+            // A registry that traps or answers garbage rejects the seller
+            assert_eq!(
+                FeeManager::auction_compliance_verdict(Err(ink::env::Error::ReturnError(
+                    ink::env::ReturnErrorCode::CalleeTrapped
+                ))),
+                Err(FeeError::SellerNotCompliant)
+            );
+            assert_eq!(
+                FeeManager::auction_compliance_verdict(Ok(Err(ink::LangError::CouldNotReadInput))),
+                Err(FeeError::SellerNotCompliant)
+            );
+
+            assert_eq!(
+                FeeManager::auction_ownership_verdict(accounts.bob, Ok(Ok(Some(accounts.bob)))),
+                Ok(())
+            );
+            assert_eq!(
+                FeeManager::auction_ownership_verdict(accounts.bob, Ok(Ok(Some(accounts.charlie)))),
+                Err(FeeError::NotPropertyOwner)
+            );
+            assert_eq!(
+                FeeManager::auction_ownership_verdict(accounts.bob, Ok(Ok(None))),
+                Err(FeeError::NotPropertyOwner)
+            );
+            assert_eq!(
+                FeeManager::auction_ownership_verdict(
+                    accounts.bob,
+                    Err(ink::env::Error::ReturnError(
+                        ink::env::ReturnErrorCode::CalleeReverted
+                    ))
+                ),
+                Err(FeeError::NotPropertyOwner)
+            );
+        }
+
+        #[ink::test]
+        fn test_auction_rebate_above_reserve_multiple() {
+            let mut contract = FeeManager::new(100, 10, 10_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract
+                .set_rebate_policy(20_000, 5_000, 30, 86_400_000)
+                .unwrap();
+            let strong = contract.create_premium_auction(1, 500, 3600).unwrap();
+            let weak = contract.create_premium_auction(2, 500, 3600).unwrap();
+            let fee = contract.get_auction(strong).unwrap().fee_paid;
+            contract.place_bid(strong, 1_200).unwrap();
+            contract.place_bid(weak, 900).unwrap();
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(3600);
+            contract.settle_auction(strong).unwrap();
+            contract.settle_auction(weak).unwrap();
+
+            // Only the auction clearing above 2x min_bid earns half its fee back, within budget
+            let expected = (fee / 2).min(30);
+            assert_eq!(contract.get_auction(strong).unwrap().rebate, expected);
+            assert_eq!(contract.get_auction(weak).unwrap().rebate, 0);
+            assert_eq!(contract.pending_reward(accounts.alice), expected);
+            assert_eq!(contract.rebate_budget_remaining(), 30 - expected);
+            assert_eq!(contract.fee_treasury(), fee * 2 - expected);
+        }
+
+        #[ink::test]
+        fn test_auction_increments_and_deposit_slashing_reawards() {
+            let mut contract = FeeManager::new(100, 10, 10_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract
+                .set_auction_safeguards(50, 1_000, 100, 1_000)
+                .unwrap();
+            let auction_id = contract.create_premium_auction(1, 500, 3600).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.place_bid(auction_id, 600),
+                Err(FeeError::InsufficientDeposit)
+            );
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(100);
+            contract.place_bid(auction_id, 600).unwrap();
+            // 10% of 600 beats the absolute increment of 50
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                contract.place_bid(auction_id, 659),
+                Err(FeeError::BidTooLow)
+            );
+            contract.place_bid(auction_id, 660).unwrap();
+            assert_eq!(
+                contract.withdraw_bid_deposit(auction_id),
+                Err(FeeError::DepositLocked)
+            );
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(3600);
+            contract.settle_auction(auction_id).unwrap();
+            let auction = contract.get_auction(auction_id).unwrap();
+            assert!(!auction.settled);
+            assert_eq!(auction.payment_deadline, Some(4600));
+            assert_eq!(
+                contract.forfeit_unpaid_auction(auction_id),
+                Err(FeeError::PaymentWindowOpen)
+            );
+
+            // Charlie never pays: the deposit is slashed and bob inherits the win
+            let treasury = contract.fee_treasury();
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(4601);
+            assert_eq!(
+                contract.forfeit_unpaid_auction(auction_id),
+                Ok(Some(accounts.bob))
+            );
+            assert_eq!(contract.fee_treasury(), treasury + 100);
+            assert_eq!(contract.get_auction_bid(auction_id, accounts.charlie), None);
+            let auction = contract.get_auction(auction_id).unwrap();
+            assert_eq!(auction.current_bid, 600);
+            assert_eq!(auction.payment_deadline, Some(5601));
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                ink::env::test::callee::<ink::env::DefaultEnvironment>(),
+                1_001_000,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(499);
+            assert_eq!(
+                contract.pay_auction_settlement(auction_id),
+                Err(FeeError::InsufficientPayment)
+            );
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(500);
+            contract.pay_auction_settlement(auction_id).unwrap();
+            let auction = contract.get_auction(auction_id).unwrap();
+            assert!(auction.settled);
+            assert_eq!(auction.current_bidder, Some(accounts.bob));
+        }
+
+        #[ink::test]
+        fn test_rfq_locks_quoted_rate() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            let rfq_id = contract
+                .post_rfq(FeeOperation::RegisterProperty, 2, 3600)
+                .expect("post rfq");
+            assert_eq!(
+                contract.quote_rfq(rfq_id, 400, 600),
+                Err(FeeError::Unauthorized)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.quote_rfq(rfq_id, 400, 600).expect("admin quotes");
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.accept_rfq_quote(rfq_id).expect("accept quote");
+
+            assert_eq!(
+                contract.record_rfq_fee_collected(rfq_id, accounts.charlie),
+                Err(FeeError::Unauthorized)
+            );
+            assert_eq!(
+                contract.record_rfq_fee_collected(rfq_id, accounts.bob),
+                Ok(400)
+            );
+            assert_eq!(
+                contract.record_rfq_fee_collected(rfq_id, accounts.bob),
+                Ok(400)
+            );
+            assert_eq!(
+                contract.record_rfq_fee_collected(rfq_id, accounts.bob),
+                Err(FeeError::RfqVolumeExhausted)
+            );
+            assert_eq!(contract.fee_treasury(), 800);
+        }
+
+        #[ink::test]
+        fn test_fee_credits_lock_rate_and_forfeit_on_expiry() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract
+                .set_fee_credit_policy(10_000, 2_500, 0)
+                .expect("admin sets policy");
+            let fee = contract.calculate_fee(FeeOperation::TransferProperty);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(fee);
+            assert_eq!(
+                contract.purchase_fee_credits(FeeOperation::TransferProperty, 2),
+                Err(FeeError::InsufficientDeposit)
+            );
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(fee * 3);
+            assert_eq!(
+                contract.purchase_fee_credits(FeeOperation::TransferProperty, 3),
+                Err(FeeError::CreditCapExceeded)
+            );
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(fee * 2);
+            let credit_id = contract
+                .purchase_fee_credits(FeeOperation::TransferProperty, 2)
+                .expect("credits purchased");
+            assert_eq!(contract.get_prepaid_liability(), fee * 2);
+            assert_eq!(contract.fee_treasury(), 0);
+
+            // Congestion raises the live fee; the credit keeps its purchase rate
+            for _ in 0..50 {
+                contract
+                    .record_fee_collected(FeeOperation::TransferProperty, 0, accounts.bob)
+                    .unwrap();
+            }
+            assert!(contract.calculate_fee(FeeOperation::TransferProperty) > fee);
+            assert_eq!(
+                contract.redeem_fee_credit(credit_id, accounts.charlie),
+                Err(FeeError::Unauthorized)
+            );
+            assert_eq!(contract.redeem_fee_credit(credit_id, accounts.bob), Ok(fee));
+            assert_eq!(contract.fee_treasury(), fee);
+            assert_eq!(
+                contract.expire_fee_credit(credit_id),
+                Err(FeeError::CreditNotExpired)
+            );
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(10_001);
+            assert_eq!(
+                contract.redeem_fee_credit(credit_id, accounts.bob),
+                Err(FeeError::CreditExpired)
+            );
+            assert_eq!(contract.expire_fee_credit(credit_id), Ok(fee));
+            assert_eq!(contract.get_prepaid_liability(), 0);
+            assert_eq!(contract.fee_treasury(), fee * 2);
+            assert_eq!(contract.get_fee_report().prepaid_liability, 0);
+        }
+
+        #[ink::test]
+        fn test_fee_credit_redeemed_only_by_owner_or_collector() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let fee = contract.calculate_fee(FeeOperation::TransferProperty);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(fee * 2);
+            let credit_id = contract
+                .purchase_fee_credits(FeeOperation::TransferProperty, 2)
+                .expect("credits purchased");
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                contract.redeem_fee_credit(credit_id, accounts.bob),
+                Err(FeeError::Unauthorized)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.set_credit_collector(accounts.charlie, true);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.redeem_fee_credit(credit_id, accounts.bob), Ok(fee));
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.set_credit_collector(accounts.charlie, false);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(
+                contract.redeem_fee_credit(credit_id, accounts.bob),
+                Err(FeeError::Unauthorized)
+            );
+        }
+
+        #[ink::test]
+        fn test_sponsor_budget_pays_fees_within_limits() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let fee = contract.calculate_fee(FeeOperation::TransferProperty);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.add_sponsored_account(accounts.charlie),
+                Err(FeeError::SponsorshipInactive)
+            );
+            // Sponsor the first two operations of each account
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(fee * 5);
+            assert_eq!(contract.fund_sponsorship(2, 0), Ok(fee * 5));
+            assert_eq!(
+                contract.add_sponsored_account(accounts.charlie),
+                Err(FeeError::SponsorNotAccepted)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract.accept_sponsor(accounts.bob).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.add_sponsored_account(accounts.charlie).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(fee);
+            contract.fund_sponsorship(0, 0).unwrap();
+            assert_eq!(
+                contract.add_sponsored_account(accounts.charlie),
+                Err(FeeError::AlreadySponsored)
+            );
+
+            assert_eq!(
+                contract.charge_sponsor(FeeOperation::TransferProperty, accounts.charlie),
+                Err(FeeError::Unauthorized)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            let first = contract
+                .charge_sponsor(FeeOperation::TransferProperty, accounts.charlie)
+                .unwrap();
+            assert_eq!(first, fee);
+            let second = contract
+                .charge_sponsor(FeeOperation::TransferProperty, accounts.charlie)
+                .unwrap();
+            assert_eq!(
+                contract.charge_sponsor(FeeOperation::TransferProperty, accounts.charlie),
+                Ok(0)
+            );
+            assert_eq!(
+                contract.charge_sponsor(FeeOperation::TransferProperty, accounts.eve),
+                Ok(0)
+            );
+            let usage = contract.get_sponsored_usage(accounts.bob, accounts.charlie);
+            assert_eq!((usage.ops, usage.spent), (2, first + second));
+            let report = contract.get_fee_report();
+            assert_eq!(report.total_sponsored, first + second);
+            assert_eq!(report.sponsor_liability, fee * 6 - first - second);
+            assert_eq!(contract.fee_treasury(), first + second);
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                ink::env::test::callee::<ink::env::DefaultEnvironment>(),
+                1_000_000_000,
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.revoke_sponsorship(), Ok(fee * 5 - first - second));
+            assert_eq!(
+                contract.revoke_sponsorship(),
+                Err(FeeError::SponsorshipInactive)
+            );
+            assert_eq!(contract.get_fee_report().sponsor_liability, fee);
+            // Revoking releases the accounts so another sponsor can take them on
+            assert_eq!(contract.get_account_sponsor(accounts.charlie), None);
+            assert_eq!(contract.get_sponsorship(accounts.bob).unwrap().accounts, 0);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract.accept_sponsor(accounts.django).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract.add_sponsored_account(accounts.charlie).unwrap();
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            contract.leave_sponsorship().unwrap();
+            assert_eq!(contract.get_account_sponsor(accounts.charlie), None);
+            assert_eq!(
+                contract.get_sponsorship(accounts.django).unwrap().accounts,
+                0
+            );
+            assert_eq!(
+                contract.leave_sponsorship(),
+                Err(FeeError::SponsorshipInactive)
+            );
+        }
+
+        #[ink::test]
+        fn test_rfq_quote_expiry() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let rfq_id = contract
+                .post_rfq(FeeOperation::TransferProperty, 10, 3600)
+                .expect("post rfq");
+            contract.quote_rfq(rfq_id, 250, 60).expect("quote");
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(61);
+            assert_eq!(contract.accept_rfq_quote(rfq_id), Err(FeeError::RfqExpired));
+            assert_eq!(
+                contract.record_rfq_fee_collected(rfq_id, contract.admin()),
+                Err(FeeError::RfqInvalidState)
+            );
+        }
+
+        #[ink::test]
+        fn test_deferred_intent_waits_and_refunds_on_cancel() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let target = accounts.django;
+            assert_eq!(
+                contract.enqueue_intent(
+                    FeeOperation::RegisterProperty,
+                    500,
+                    50,
+                    target,
+                    Vec::new()
+                ),
+                Err(FeeError::TargetNotRegistered)
+            );
+            contract.set_deferral_target(target, true).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(500);
+            assert_eq!(
+                contract.enqueue_intent(
+                    FeeOperation::RegisterProperty,
+                    500,
+                    50,
+                    target,
+                    Vec::new()
+                ),
+                Err(FeeError::InsufficientDeposit)
+            );
+            ink::env::test::set_value_transferred::<ink::env::DefaultEnvironment>(550);
+            let intent_id = contract
+                .enqueue_intent(FeeOperation::RegisterProperty, 500, 50, target, Vec::new())
+                .unwrap();
+
+            // Current fee (1000) is above the bound, so the keeper leaves it queued
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.process_queue(10), Ok(0));
+            assert_eq!(contract.get_deferred_queue(), vec![intent_id]);
+
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                ink::env::test::callee::<ink::env::DefaultEnvironment>(),
+                1_000_550,
+            );
+            assert_eq!(
+                contract.cancel_intent(intent_id),
+                Err(FeeError::Unauthorized)
+            );
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            contract.cancel_intent(intent_id).unwrap();
+            assert!(contract.get_deferred_queue().is_empty());
+            assert_eq!(contract.get_intent(intent_id), None);
+        }
+
+        #[ink::test]
+        fn test_pushed_validator_set_distributes_by_stake() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.add_validator(accounts.eve).unwrap();
+            contract
+                .set_validator_source(Some(accounts.django), 3_600_000)
+                .unwrap();
+            assert_eq!(
+                contract.add_validator(accounts.frank),
+                Err(FeeError::ValidatorSetManaged)
+            );
+            assert_eq!(
+                contract.push_validator_set(vec![(accounts.bob, 100)]),
+                Err(FeeError::Unauthorized)
+            );
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            let count = contract
+                .push_validator_set(vec![
+                    (accounts.bob, 300),
+                    (accounts.charlie, 100),
+                    (accounts.frank, 0),
+                ])
+                .unwrap();
+            assert_eq!(count, 2);
+            assert_eq!(
+                contract.get_validator_set(),
+                vec![(accounts.bob, 300), (accounts.charlie, 100)]
+            );
+
+            // Validator half of 8000 is split 3:1 by stake; the replaced eve gets nothing
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 8000, accounts.bob)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            assert_eq!(contract.pending_reward(accounts.bob), 3000);
+            assert_eq!(contract.pending_reward(accounts.charlie), 1000);
+            assert_eq!(contract.pending_reward(accounts.eve), 0);
+        }
+
+        #[ink::test]
+        fn test_underfunded_distribution_scales_rewards_down() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.add_validator(accounts.bob).unwrap();
+            contract.add_validator(accounts.charlie).unwrap();
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                ink::env::test::callee::<ink::env::DefaultEnvironment>(),
+                6_000_000,
+            );
+
+            // Half of 8M to validators, fully backed by the balance
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 8_000_000, accounts.alice)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            assert_eq!(contract.pending_reward(accounts.bob), 2_000_000);
+            assert_eq!(contract.reward_headroom(), 2_000_000);
+            assert!(!contract.get_fee_report().rewards_degraded);
+
+            // Only 2M of the next 4M can be honored: each validator gets half
+            let emitted = ink::env::test::recorded_events().count();
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 8_000_000, accounts.alice)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            assert_eq!(contract.pending_reward(accounts.bob), 3_000_000);
+            assert_eq!(contract.pending_reward(accounts.charlie), 3_000_000);
+            assert_eq!(contract.reward_headroom(), 0);
+            let report = contract.get_fee_report();
+            assert!(report.rewards_degraded);
+            assert_eq!(report.reward_liability, 6_000_000);
+            assert!(ink::env::test::recorded_events().count() > emitted);
+
+            // Claims release liability and restore full distributions
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.claim_rewards(), Ok(3_000_000));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 2_000_000, accounts.alice)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            assert_eq!(contract.pending_reward(accounts.bob), 500_000);
+            assert!(!contract.get_fee_report().rewards_degraded);
+        }
+
+        #[ink::test]
+        fn test_reward_history_vesting_and_statement() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.add_validator(accounts.bob).unwrap();
+            contract.add_validator(accounts.charlie).unwrap();
+            assert_eq!(
+                contract.set_vesting_policy(3_000, 0),
+                Err(FeeError::InvalidConfig)
+            );
+            contract.set_vesting_policy(3_000, 10_000).unwrap();
+
+            // Epoch 1: 1000 each, paid out directly
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(1_000);
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 4_000, accounts.alice)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            // Epoch 2: 4000 each, over the threshold so it vests
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(2_000);
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 16_000, accounts.alice)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            assert_eq!(contract.pending_reward(accounts.bob), 1_000);
+
+            let history = contract.get_reward_history(accounts.bob, 0, 10);
+            assert_eq!(history.len(), 2);
+            assert_eq!((history[0].epoch, history[0].amount), (1, 1_000));
+            assert_eq!((history[1].epoch, history[1].amount), (2, 4_000));
+            assert_eq!(contract.get_reward_history(accounts.bob, 1, 10).len(), 1);
+
+            // Half way through vesting bob claims the direct reward plus half the schedule
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(7_000);
+            assert_eq!(contract.vested_reward(accounts.bob), 2_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.claim_rewards(), Ok(3_000));
+            assert_eq!(
+                contract.get_vesting_schedules(accounts.bob)[0].released,
+                2_000
+            );
+            let claims = contract.get_claim_history(accounts.bob, 0, 10);
+            assert_eq!((claims[0].amount, claims[0].vested), (3_000, 2_000));
+
+            let statement = contract.get_reward_statement(accounts.bob, 0, 1_500);
+            assert_eq!((statement.entries, statement.earned), (1, 1_000));
+            let statement = contract.get_reward_statement(accounts.bob, 0, 10_000);
+            assert_eq!(statement.earned, 5_000);
+            assert_eq!(statement.validator_rewards, 5_000);
+            assert_eq!(statement.claimed, 3_000);
+            assert_eq!(statement.unvested, 2_000);
+        }
+
         #[ink::test]
         fn test_fee_report() {
             let contract = FeeManager::new(1000, 100, 50_000);
@@ -788,6 +4741,364 @@ mod propchain_fees {
             assert!(report.recommended_fee >= 100);
         }
 
+        #[ink::test]
+        fn test_usage_stats_track_calls_and_feed_congestion() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            for _ in 0..2 {
+                contract
+                    .record_fee_collected(FeeOperation::RegisterProperty, 100, accounts.bob)
+                    .unwrap();
+            }
+            let stats = contract.get_usage_stats();
+            assert_eq!(stats.len(), 1);
+            assert_eq!(stats[0].message, "record_fee_collected");
+            assert_eq!(stats[0].calls, 2);
+            assert_eq!(stats[0].total_weight, 2 * DEFAULT_MESSAGE_WEIGHT);
+            assert_eq!(contract.get_fee_report().congestion_index, 2);
+
+            contract
+                .set_message_weight("record_fee_collected".into(), 500_000)
+                .unwrap();
+            contract.set_weight_capacity(1_000_000).unwrap();
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 100, accounts.bob)
+                .unwrap();
+
+            // 4 default-weight calls plus one heavy call out of 1M capacity
+            assert_eq!(contract.get_weight_usage(), (540_000, 1_000_000));
+            assert_eq!(contract.get_fee_report().congestion_index, 54);
+            let stats = contract.get_usage_stats();
+            assert_eq!(stats.len(), 3);
+            assert_eq!(stats[0].calls, 3);
+            assert_eq!(stats[0].total_weight, 520_000);
+            assert_eq!(stats[1].message, "set_message_weight");
+            assert_eq!(stats[2].message, "set_weight_capacity");
+        }
+
+        #[ink::test]
+        fn test_burn_share_is_destroyed_and_reported() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract.add_validator(accounts.bob).unwrap();
+            contract.set_distribution_rates(5_000, 5_000).unwrap();
+            assert_eq!(
+                contract.set_burn_share(10_001),
+                Err(FeeError::InvalidConfig)
+            );
+            contract.set_burn_share(2_500).unwrap();
+
+            // Governance takes over the burn rate; the admin no longer controls it
+            contract.set_burn_governor(Some(accounts.django)).unwrap();
+            assert_eq!(contract.set_burn_share(0), Err(FeeError::Unauthorized));
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            contract.set_burn_share(2_000).unwrap();
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(
+                ink::env::test::callee::<ink::env::DefaultEnvironment>(),
+                20_000_000,
+            );
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 10_000_000, accounts.bob)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+
+            // 20% burned, validators get half of the remaining 8M
+            let burn_account = AccountId::from(BURN_ACCOUNT);
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(burn_account),
+                Ok(2_000_000)
+            );
+            assert_eq!(contract.pending_reward(accounts.bob), 4_000_000);
+            let report = contract.get_fee_report();
+            assert_eq!(report.burn_share_bp, 2_000);
+            assert_eq!(report.total_burned, 2_000_000);
+            assert_eq!(
+                contract.get_burn_settings(),
+                (2_000, Some(accounts.django), 2_000_000)
+            );
+
+            // A burn share below the existential deposit is carried, not refused
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 1_000_000, accounts.bob)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(burn_account),
+                Ok(2_000_000)
+            );
+            let report = contract.get_fee_report();
+            assert_eq!(
+                (report.total_burned, report.pending_burn),
+                (2_000_000, 200_000)
+            );
+            assert_eq!(contract.pending_reward(accounts.bob), 4_400_000);
+
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 4_000_000, accounts.bob)
+                .unwrap();
+            contract.distribute_fees().unwrap();
+            assert_eq!(
+                ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(burn_account),
+                Ok(3_000_000)
+            );
+            let report = contract.get_fee_report();
+            assert_eq!((report.total_burned, report.pending_burn), (3_000_000, 0));
+        }
+
+        #[ink::test]
+        fn test_fee_rule_journal_records_every_change() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut contract = FeeManager::new(1000, 100, 100_000);
+            contract.config_change_delay = 0;
+            let config = FeeConfig {
+                base_fee: 2_000,
+                min_fee: 100,
+                max_fee: 50_000,
+                congestion_sensitivity: 50,
+                demand_factor_bp: 0,
+                last_updated: 0,
+            };
+
+            contract.set_change_reason([7u8; 32]);
+            contract
+                .set_operation_config(FeeOperation::CreateEscrow, config.clone())
+                .unwrap();
+            contract.set_distribution_rates(6_000, 4_000).unwrap();
+            contract.set_config_change_delay(1_000).unwrap();
+            let scheduled = FeeConfig {
+                base_fee: 3_000,
+                ..config.clone()
+            };
+            contract.set_change_reason([9u8; 32]);
+            contract
+                .schedule_config_change(Some(FeeOperation::CreateEscrow), scheduled.clone(), 5_000)
+                .unwrap();
+            assert_eq!(contract.get_rule_journal_len(), 3);
+
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(5_000);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.apply_config_changes(), 1);
+            assert_eq!(contract.get_rule_journal_len(), 4);
+
+            let journal = contract.get_rule_journal(0, 10);
+            assert_eq!(journal.len(), 4);
+            assert_eq!(journal[0].changed_by, accounts.alice);
+            assert_eq!(journal[0].reason_hash, [7u8; 32]);
+            assert_eq!(
+                journal[0].change,
+                FeeRuleChange::OperationConfig {
+                    operation: Some(FeeOperation::CreateEscrow),
+                    old: None,
+                    new: config.clone(),
+                }
+            );
+            assert_eq!(journal[1].reason_hash, [0u8; 32]);
+            assert_eq!(
+                journal[1].change,
+                FeeRuleChange::DistributionRates {
+                    old: (5_000, 5_000),
+                    new: (6_000, 4_000),
+                }
+            );
+            assert_eq!(
+                journal[2].change,
+                FeeRuleChange::ConfigChangeDelay { old: 0, new: 1_000 }
+            );
+            // Activation is attributed to the proposer, with the reason staged when scheduling
+            assert_eq!(journal[3].changed_by, accounts.alice);
+            assert_eq!(journal[3].changed_at, 5_000);
+            assert_eq!(journal[3].reason_hash, [9u8; 32]);
+            assert_eq!(
+                journal[3].change,
+                FeeRuleChange::OperationConfig {
+                    operation: Some(FeeOperation::CreateEscrow),
+                    old: Some(config),
+                    new: FeeConfig {
+                        last_updated: 5_000,
+                        ..scheduled
+                    },
+                }
+            );
+
+            let page = contract.get_rule_journal(2, 1);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].index, 2);
+            assert!(contract.get_rule_journal(4, 10).is_empty());
+
+            // Policy setters outside fee configs are journaled too
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            contract.set_vesting_policy(1_000, 60_000).unwrap();
+            contract.set_deferral_target(accounts.django, true).unwrap();
+            contract.set_weight_capacity(500).unwrap();
+            let journal = contract.get_rule_journal(4, 10);
+            assert_eq!(
+                journal[0].change,
+                FeeRuleChange::VestingPolicy {
+                    old: (0, 0),
+                    new: (1_000, 60_000),
+                }
+            );
+            assert_eq!(
+                journal[1].change,
+                FeeRuleChange::DeferralTarget {
+                    target: accounts.django,
+                    old: false,
+                    new: true,
+                }
+            );
+            assert_eq!(
+                journal[2].change,
+                FeeRuleChange::WeightCapacity { old: 0, new: 500 }
+            );
+        }
+
+        #[ink::test]
+        fn test_value_based_fee_modes_clamp_and_charge() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let schedule =
+                |mode: FeeMode, rate_bp: u32, min_fee: u128, max_fee: u128| ValueFeeSchedule {
+                    mode,
+                    rate_bp,
+                    min_fee,
+                    max_fee,
+                };
+            // Flat by default: the declared value is ignored
+            assert_eq!(
+                contract.calculate_value_fee(FeeOperation::RegisterProperty, 10_000_000),
+                1000
+            );
+
+            contract
+                .set_fee_mode(
+                    FeeOperation::RegisterProperty,
+                    schedule(FeeMode::PercentageOfValue, 50, 500, 20_000),
+                )
+                .unwrap();
+            let register = |contract: &FeeManager, value: u128| {
+                contract.calculate_value_fee(FeeOperation::RegisterProperty, value)
+            };
+            assert_eq!(register(&contract, 10_000), 500);
+            assert_eq!(register(&contract, 1_000_000), 5_000);
+            assert_eq!(register(&contract, 100_000_000), 20_000);
+
+            contract
+                .set_fee_mode(
+                    FeeOperation::TransferProperty,
+                    schedule(FeeMode::Hybrid, 10, 0, 10_000),
+                )
+                .unwrap();
+            assert_eq!(
+                contract.calculate_value_fee(FeeOperation::TransferProperty, 1_000_000),
+                2_000
+            );
+
+            assert_eq!(
+                contract.set_fee_mode(
+                    FeeOperation::CreateEscrow,
+                    schedule(FeeMode::Hybrid, 10_001, 0, 10_000)
+                ),
+                Err(FeeError::InvalidConfig)
+            );
+            assert_eq!(
+                contract.set_fee_mode(
+                    FeeOperation::CreateEscrow,
+                    schedule(FeeMode::PercentageOfValue, 10, 500, 100)
+                ),
+                Err(FeeError::InvalidConfig)
+            );
+            assert_eq!(
+                contract.set_valuation_oracle(Some(accounts.django), 10_001),
+                Err(FeeError::InvalidConfig)
+            );
+
+            // No oracle configured: the declared value is taken as is
+            assert_eq!(
+                contract.record_value_fee_collected(
+                    FeeOperation::RegisterProperty,
+                    1,
+                    1_000_000,
+                    accounts.bob
+                ),
+                Ok(5_000)
+            );
+            assert_eq!(contract.fee_treasury(), 5_000);
+
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.set_fee_mode(FeeOperation::RegisterProperty, ValueFeeSchedule::default()),
+                Err(FeeError::Unauthorized)
+            );
+            assert_eq!(
+                contract.get_fee_mode(FeeOperation::RegisterProperty).mode,
+                FeeMode::PercentageOfValue
+            );
+        }
+
+        #[ink::test]
+        fn test_treasury_deployment_cap_and_yield_accounting() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 10_000, accounts.bob)
+                .unwrap();
+            assert_eq!(
+                contract.deploy_treasury(1_000),
+                Err(FeeError::StrategyNotSet)
+            );
+            assert_eq!(
+                contract.approve_yield_strategy(Some(accounts.frank), 10_001),
+                Err(FeeError::InvalidConfig)
+            );
+            contract
+                .approve_yield_strategy(Some(accounts.frank), 3_000)
+                .unwrap();
+
+            // At most 30% of the 10_000 treasury may be deployed
+            assert_eq!(
+                contract.record_deployment(3_001),
+                Err(FeeError::DeploymentLimitExceeded)
+            );
+            contract.record_deployment(3_000).unwrap();
+            assert_eq!(contract.fee_treasury(), 7_000);
+            assert_eq!(
+                contract.approve_yield_strategy(Some(accounts.eve), 3_000),
+                Err(FeeError::InvalidConfig)
+            );
+
+            // 3_150 comes back: 3_000 principal plus 150 yield
+            assert_eq!(contract.record_recall(accounts.frank, 3_150, false), 150);
+            let report = contract.get_fee_report();
+            assert_eq!(report.treasury_deployed, 0);
+            assert_eq!(report.realized_yield, 150);
+            assert_eq!(contract.fee_treasury(), 10_150);
+        }
+
+        #[ink::test]
+        fn test_unreachable_strategy_is_revoked_and_written_off() {
+            let mut contract = FeeManager::new(1000, 100, 50_000);
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            contract
+                .record_fee_collected(FeeOperation::RegisterProperty, 10_000, accounts.bob)
+                .unwrap();
+            contract
+                .approve_yield_strategy(Some(accounts.frank), 3_000)
+                .unwrap();
+            contract.record_deployment(3_000).unwrap();
+
+            // The strategy call failed: nothing returns, the position is written off
+            assert_eq!(contract.revoke_strategy(accounts.frank, None), 0);
+            assert_eq!(contract.get_yield_strategy(), None);
+            assert_eq!(contract.get_strategy_write_off(accounts.frank), 3_000);
+            let report = contract.get_fee_report();
+            assert_eq!(report.treasury_deployed, 0);
+            assert_eq!(report.strategy_value, 0);
+            assert_eq!(contract.fee_treasury(), 7_000);
+            assert_eq!(contract.emergency_recall(), Err(FeeError::StrategyNotSet));
+        }
+
         #[ink::test]
         fn test_fee_estimate_recommendation() {
             let contract = FeeManager::new(1000, 100, 50_000);
@@ -13,6 +13,31 @@ use ink::storage::Mapping;
 mod propchain_insurance {
     use super::*;
     use ink::prelude::{string::String, vec::Vec};
+    use propchain_traits::BridgeHookError;
+
+    /// Reputation a newly bonded assessor starts with
+    const INITIAL_ASSESSOR_REPUTATION: u32 = 5_000;
+    const MAX_ASSESSOR_REPUTATION: u32 = 10_000;
+    /// Reputation regained when a disputed decision is upheld
+    const UPHELD_REPUTATION_REWARD: u32 = 100;
+    /// Settled claims at which a recalibrated model is fully credible (confidence 100)
+    const FULL_CREDIBILITY_CLAIMS: u64 = 100;
+    /// Fraud score at or above which a third-party claim is decided by the admin only
+    const DEFAULT_LIABILITY_FRAUD_THRESHOLD: u32 = 60;
+    /// Most policies a coordinated incident claim may span
+    const MAX_COORDINATED_CLAIMS: usize = 8;
+    /// Longest accepted locale tag (BCP 47)
+    const MAX_LOCALE_LEN: usize = 35;
+    /// How long before a treaty's end the reinsurer may propose renewal terms (30 days)
+    pub const TREATY_RENEWAL_WINDOW: u64 = 30 * 86_400;
+    /// NAV points kept per pool; older points are overwritten
+    const MAX_NAV_HISTORY: u32 = 100;
+    /// Fixed-point scale of NAV per LP unit (NAV_PRECISION = par)
+    pub const NAV_PRECISION: u128 = 1_000_000_000_000;
+    /// Largest share of each loss a policyholder may retain as co-pay (50%)
+    pub const MAX_COPAY_BPS: u32 = 5_000;
+    /// How long a guardian's payout pause confirmation counts towards the quorum (1 hour)
+    pub const PAUSE_CONFIRMATION_TTL: u64 = 3_600;
 
     // =========================================================================
     // ERROR TYPES
@@ -40,6 +65,52 @@ mod propchain_insurance {
         CooldownPeriodActive,
         PropertyNotInsurable,
         DuplicateClaim,
+        ClaimNotOverdue,
+        SurplusSharingNotConfigured,
+        PayoutModeLocked,
+        EvidenceNotFound,
+        PoolClosing,
+        ReserveRequired,
+        IncidentNotFound,
+        LienNotFound,
+        LienOutstanding,
+        SnapshotTooSoon,
+        DocumentNotFound,
+        ComplianceSourceUnavailable,
+        RiskSourceUnavailable,
+        InsufficientStake,
+        AssessmentsPending,
+        ClaimNotDisputable,
+        DomainPaused,
+        TemplateNotFound,
+        TemplateSuperseded,
+        TemplateViolation,
+        NoticeNotFound,
+        NoticeAlreadyAcknowledged,
+        EsgTierTooLow,
+        LevyScheduleInvalid,
+        DenominationMismatch,
+        AssetNotRegistered,
+        AssetSwapNotFound,
+        ModelNotPendingReview,
+        CoverageRequirementNotFound,
+        WaitingPeriodActive,
+        ThirdPartyOnly,
+        LiabilityClaimNotFound,
+        TermsNotFound,
+        TermsMismatch,
+        TermsAlreadyAcknowledged,
+        TermsNotAcknowledged,
+        CoordinationNotFound,
+        RecoveryExceedsLoss,
+        RenewalWindowClosed,
+        RenewalNotFound,
+        UnderwritingDeclined,
+        NoAdoptedModel,
+        LossNotAssessed,
+        ExpenseCapExceeded,
+        DocumentMismatch,
+        PayoutTargetMismatch,
     }
 
     // =========================================================================
@@ -62,6 +133,7 @@ mod propchain_insurance {
         Cancelled,
         Claimed,
         Suspended,
+        Portable, // Property token bridged away; honored by the destination deployment
     }
 
     #[derive(
@@ -122,6 +194,26 @@ mod propchain_insurance {
         VeryHigh,
     }
 
+    /// Where approved claim funds for a policy are sent
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PayoutMode {
+        Policyholder,
+        // Deposit into the PropertyToken dividend pool of the insured token
+        TokenDividends {
+            token_contract: AccountId,
+            token_id: u64,
+        },
+    }
+
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
@@ -142,6 +234,27 @@ mod propchain_insurance {
         pub claims_count: u32,
         pub total_claimed: u128,
         pub metadata_url: String,
+        pub payout_mode: PayoutMode,
+        pub waiting_period_ends: u64, // Incidents of the insured peril before this are not covered
+        pub copay_bps: u32, // Share of each loss after the deductible borne by the insured
+        pub cover_since: u64, // Start of uninterrupted cover; waiting periods run from here
+    }
+
+    /// Policy carried over from an existing book; no premium is charged on import
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PolicyImport {
+        pub property_id: u64,
+        pub policyholder: AccountId,
+        pub coverage_type: CoverageType,
+        pub coverage_amount: u128,
+        pub premium_amount: u128, // Premium recorded by the previous insurer
+        pub deductible: u128,
+        pub start_time: u64, // Historical start, may predate the contract
+        pub end_time: u64,
+        pub pool_id: u64,
+        pub prior_claims_count: u32,
+        pub metadata_url: String,
     }
 
     #[derive(
@@ -162,6 +275,46 @@ mod propchain_insurance {
         pub payout_amount: u128,
         pub assessor: Option<AccountId>,
         pub rejection_reason: String,
+        pub review_deadline: Option<u64>, // SLA deadline for a decision, if the pool has one
+        pub escalated: bool,
+        pub incident_id: u64,
+    }
+
+    /// (property_id, peril, date window) an incident is registered under
+    pub type IncidentKey = (u64, CoverageType, u64);
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Incident {
+        pub incident_id: u64,
+        pub property_id: u64,
+        pub peril: CoverageType,
+        pub occurred_at: u64,
+        pub reported_by: AccountId,
+        pub reported_at: u64,
+        pub claim_ids: Vec<u64>,
+        pub flagged: bool, // Set on suspicious repeat filings
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct EvidenceCommitment {
+        pub content_hash: Hash,         // Blake2x256 of the evidence content
+        pub encrypted_pointer: Vec<u8>, // Location of the content, encrypted for assessors
+        pub committed_at: u64,
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct EvidenceAccess {
+        pub accessor: AccountId,
+        pub accessed_at: u64,
     }
 
     #[derive(
@@ -183,6 +336,90 @@ mod propchain_insurance {
         pub is_active: bool,
     }
 
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ClaimSlaConfig {
+        pub review_period: u64,     // Seconds allowed between submission and decision
+        pub compensation_rate: u32, // Credit owed on breach (basis points of claim amount)
+    }
+
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ClaimSlaStats {
+        pub claims_tracked: u64,
+        pub decided_within_sla: u64,
+        pub breaches: u64,
+        pub total_compensation: u128,
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SurplusSharingConfig {
+        pub max_loss_ratio: u32, // Loss ratio at or below which surplus is shared (basis points)
+        pub share_rate: u32, // Portion of period surplus returned to policyholders (basis points)
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SurplusPeriod {
+        pub started_at: u64,
+        pub premiums_at_start: u128, // Pool premium total when the period opened
+        pub claims_at_start: u128,   // Pool claims-paid total when the period opened
+    }
+
+    /// (policyholder, premium paid) for a policy sharing in a surplus period
+    pub type SurplusShareBasis = (AccountId, u128);
+
+    /// A surplus period close in progress: eligible policies are tallied first, then
+    /// the pot reserved from pool capital is credited to them
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SurplusClose {
+        pub closed_at: u64,
+        pub premiums: u128,
+        pub claims_paid: u128,
+        pub loss_ratio: u32,
+        pub policy_count: u32, // Pool policies as of the close
+        pub scanned: u32,
+        pub eligible_policies: u32,
+        pub eligible_premiums: u128,
+        pub pot: u128, // Reserved once every policy is scanned
+        pub credited: u32,
+        pub distributed: u128,
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SurplusPeriodReport {
+        pub pool_id: u64,
+        pub period: u32,
+        pub started_at: u64,
+        pub closed_at: u64,
+        pub premiums: u128,
+        pub claims_paid: u128,
+        pub loss_ratio: u32, // Basis points
+        pub distributed: u128,
+        pub eligible_policies: u32,
+    }
+
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
@@ -210,6 +447,97 @@ mod propchain_insurance {
         pub annual_premium: u128,     // Final annual premium
         pub monthly_premium: u128,    // Monthly equivalent
         pub deductible: u128,
+        pub mitigation_discount: u32, // Basis points taken off the annual premium
+        pub green_discount: u32,      // Basis points taken off for the property's ESG tier
+        pub seasonal_factor: u32,     // Basis points (10_000 = 1.0x) for the quote month
+        pub seasonal_curve_version: u32, // 0 = no curve published
+        pub copay_bps: u32, // Basis points of each loss retained; taken off the premium pro rata
+    }
+
+    /// Monthly risk adjustment for a coverage type; superseded curves are kept
+    /// so past quotes can be reproduced
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct SeasonalCurve {
+        pub version: u32,
+        pub monthly_factors: Vec<u32>, // Basis points, January first
+        pub effective_from: u64,
+        pub published_by: AccountId,
+    }
+
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum MitigationMeasure {
+        FloodBarrier,
+        FireSuppression,
+        StormShutters,
+        SeismicRetrofit,
+        SecuritySystem,
+    }
+
+    /// Evidence of a mitigation measure; earns discounts once a notary verifies it
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct MitigationDocument {
+        pub document_id: u64,
+        pub property_id: u64,
+        pub measure: MitigationMeasure,
+        pub document_hash: Hash,
+        pub submitted_by: AccountId,
+        pub submitted_at: u64,
+        pub valid_until: u64, // Discount lapses with the document
+        pub verified_by: Option<AccountId>,
+    }
+
+    /// Green tier derived from a property's verified ESG attributes; higher is greener
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        PartialOrd,
+        Ord,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum EsgTier {
+        Unrated,
+        Bronze,
+        Silver,
+        Gold,
+    }
+
+    /// ESG attributes attested for a property; the tier counts once a notary verifies it
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct EsgProfile {
+        pub property_id: u64,
+        pub energy_rating: u8, // 1 = A (best) .. 7 = G
+        pub materials_certified: bool,
+        pub tier: EsgTier,
+        pub document_hash: Hash,
+        pub submitted_by: AccountId,
+        pub submitted_at: u64,
+        pub valid_until: u64,
+        pub verified_by: Option<AccountId>,
     }
 
     #[derive(
@@ -228,6 +556,33 @@ mod propchain_insurance {
         pub is_active: bool,
         pub total_ceded_premiums: u128,
         pub total_recoveries: u128,
+        pub limit_utilized: u128, // Recoveries charged against the current layer limit
+        pub reinstatements: u32,  // Times the layer limit may be restored once exhausted
+        pub reinstatements_used: u32,
+    }
+
+    /// Terms a reinsurer offers for the treaty period following an agreement's end
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TreatyRenewalProposal {
+        pub agreement_id: u64,
+        pub coverage_limit: u128,
+        pub retention_limit: u128,
+        pub premium_ceded_rate: u32,
+        pub coverage_types: Vec<CoverageType>,
+        pub duration_seconds: u64,
+        pub proposed_at: u64,
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LayerAllocation {
+        pub agreement_id: u64,
+        pub recovery: u128,
     }
 
     #[derive(
@@ -259,6 +614,27 @@ mod propchain_insurance {
         pub data_points: u32,
     }
 
+    /// Running loss experience for a coverage type, fed as policies are written
+    /// and claims settle
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LossExperience {
+        pub policies_written: u64,
+        pub premiums_written: u128,
+        pub claims_settled: u64,
+        pub claims_approved: u64,
+        pub total_payout: u128,
+        pub calibrated_claims: u64, // claims_settled at the last recalibration
+    }
+
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
@@ -273,6 +649,100 @@ mod propchain_insurance {
         pub min_risk_score: u32,
     }
 
+    /// A pool underwriting criterion checkable against on-chain data
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum UnderwritingCriterion {
+        MinRiskScore,      // Overall assessment score floor
+        MinPropertyValue,  // Checked against the property's appraised value
+        MaxPropertyValue,  // Checked against the property's appraised value
+        MaxPreviousClaims, // Claims filed on the property's earlier policies
+        SafetyFeatures,    // At least one verified, unexpired mitigation measure
+        MaxPropertyAge,    // Whole years since the property was built
+        ExcludedLocation,  // Property location is not on the pool's exclusion list
+    }
+
+    /// Recorded facts about a property that underwriting criteria are checked against
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PropertyProfile {
+        pub built_at: u64, // Completion timestamp
+        pub appraised_value: u128,
+        pub location: String,
+    }
+
+    /// Outcome of one criterion on an underwriting worksheet
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CriterionResult {
+        pub criterion: UnderwritingCriterion,
+        pub threshold: u128,
+        pub observed: u128,
+        pub passed: bool,
+        pub overridden: bool, // Failed, but waived by an approved override
+    }
+
+    /// (pool_id, property_id) an application is made under
+    pub type ApplicationKey = (u64, u64);
+
+    /// Manual waiver of a criterion for one property's application to a pool
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct UnderwritingOverride {
+        pub criterion: UnderwritingCriterion,
+        pub approver: AccountId,
+        pub reason: String,
+        pub approved_at: u64,
+    }
+
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum UnderwritingDecision {
+        Approved,
+        ApprovedWithOverrides,
+    }
+
+    /// Justification for an underwriting decision, kept per issued policy
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct UnderwritingWorksheet {
+        pub policy_id: u64,
+        pub pool_id: u64,
+        pub property_id: u64,
+        pub criteria: UnderwritingCriteria, // As configured when the decision was made
+        pub assessment: RiskAssessment,     // Assessment the decision relied on
+        pub results: Vec<CriterionResult>,
+        pub overrides: Vec<UnderwritingOverride>,
+        pub decision: UnderwritingDecision,
+        pub decided_at: u64,
+    }
+
     #[derive(
         Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
     )]
@@ -281,1731 +751,12113 @@ mod propchain_insurance {
         pub provider: AccountId,
         pub pool_id: u64,
         pub deposited_amount: u128,
+        pub lp_units: u128, // Minted at the pool's NAV per unit when depositing
         pub share_percentage: u32, // In basis points (10000 = 100%)
         pub deposited_at: u64,
         pub last_reward_claim: u64,
         pub accumulated_rewards: u128,
     }
 
-    // =========================================================================
-    // STORAGE
-    // =========================================================================
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum RunOffStatus {
+        Closing,
+        Closed,
+    }
 
-    #[ink(storage)]
-    pub struct PropertyInsurance {
-        admin: AccountId,
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PoolRunOff {
+        pub pool_id: u64,
+        pub status: RunOffStatus,
+        pub initiated_at: u64,
+        pub remaining_policies: u64,
+        pub remaining_exposure: u128, // Unclaimed coverage still in force
+        pub closed_at: Option<u64>,
+        pub final_surplus: u128, // Capital returned to LPs on closure
+    }
 
-        // Policies
-        policies: Mapping<u64, InsurancePolicy>,
-        policy_count: u64,
-        policyholder_policies: Mapping<AccountId, Vec<u64>>,
-        property_policies: Mapping<u64, Vec<u64>>,
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum LienStatus {
+        Active,
+        Defaulted,
+        Released,
+        Settled,
+    }
 
-        // Claims
-        claims: Mapping<u64, InsuranceClaim>,
-        claim_count: u64,
-        policy_claims: Mapping<u64, Vec<u64>>,
-
-        // Risk Pools
-        pools: Mapping<u64, RiskPool>,
-        pool_count: u64,
+    /// Coverage requested for a new policy
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PolicyTerms {
+        pub property_id: u64,
+        pub coverage_type: CoverageType,
+        pub coverage_amount: u128,
+        pub pool_id: u64,
+        pub duration_seconds: u64,
+        pub metadata_url: String,
+        pub template_id: u64, // Jurisdiction template the terms must meet
+        pub copay_bps: Option<u32>,
+    }
 
-        // Risk Assessments
-        risk_assessments: Mapping<u64, RiskAssessment>,
+    /// Premium paid by a lender on a policyholder's behalf, secured on the policy
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PremiumLien {
+        pub policy_id: u64,
+        pub lender: AccountId,
+        pub borrower: AccountId,
+        pub financed_amount: u128,
+        pub financed_at: u64,
+        pub status: LienStatus,
+        pub refund_paid: u128, // Unearned premium returned to the lender on default
+    }
 
-        // Reinsurance
-        reinsurance_agreements: Mapping<u64, ReinsuranceAgreement>,
-        reinsurance_count: u64,
+    /// Lender covenant that a collateralized property stays insured for at least
+    /// `min_coverage` of unclaimed in-force cover
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CoverageRequirement {
+        pub lender: AccountId,
+        pub min_coverage: u128,
+        pub set_at: u64,
+        pub shortfall_since: Option<u64>, // set while in-force cover is below the minimum
+    }
 
-        // Insurance Tokens (secondary market)
-        insurance_tokens: Mapping<u64, InsuranceToken>,
-        token_count: u64,
-        token_listings: Vec<u64>, // Tokens listed for sale
+    /// A property's in-force cover measured against its lender requirement
+    #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CoverageCheck {
+        pub property_id: u64,
+        pub lender: AccountId,
+        pub required: u128,
+        pub in_force: u128,
+        pub satisfied: bool,
+        pub shortfall_since: Option<u64>,
+    }
 
-        // Actuarial Models
-        actuarial_models: Mapping<u64, ActuarialModel>,
-        model_count: u64,
+    /// Linked claims opened across one holder's policies for a single incident.
+    /// Payouts across the linked claims never exceed the declared loss, which an
+    /// assessor bounds before any linked claim is approved.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ClaimCoordination {
+        pub coordination_id: u64,
+        pub incident_id: u64,
+        pub claimant: AccountId,
+        pub total_loss: u128,
+        pub assessed_loss: Option<u128>,
+        pub claim_ids: Vec<u64>,
+        pub total_paid: u128,
+        pub opened_at: u64,
+        pub settled_at: Option<u64>,
+    }
 
-        // Underwriting
-        underwriting_criteria: Mapping<u64, UnderwritingCriteria>, // pool_id -> criteria
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CoordinatedClaimLine {
+        pub claim_id: u64,
+        pub policy_id: u64,
+        pub coverage_type: CoverageType,
+        pub claimed: u128,
+        pub paid: u128,
+        pub status: ClaimStatus,
+    }
 
-        // Liquidity providers
-        liquidity_providers: Mapping<(u64, AccountId), PoolLiquidityProvider>,
-        pool_providers: Mapping<u64, Vec<AccountId>>,
+    /// Consolidated view of a coordinated claim, one line per linked claim
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CoordinatedClaimStatement {
+        pub coordination_id: u64,
+        pub incident_id: u64,
+        pub claimant: AccountId,
+        pub total_loss: u128,
+        pub lines: Vec<CoordinatedClaimLine>,
+        pub total_claimed: u128,
+        pub total_paid: u128,
+        pub settled_at: Option<u64>,
+    }
 
-        // Oracle addresses
-        authorized_oracles: Mapping<AccountId, bool>,
+    /// Claim filed by an injured third party against a policy's liability cover
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ThirdPartyClaim {
+        pub claim_id: u64, // Shares the id sequence of policyholder claims
+        pub policy_id: u64,
+        pub incident_id: u64,
+        pub claimant: AccountId,
+        pub claim_amount: u128,
+        pub description: String,
+        pub evidence_url: String,
+        pub status: ClaimStatus,
+        pub fraud_score: u32, // 0-100, scored at filing
+        pub contested: bool,
+        pub contest_reason: String,
+        pub submitted_at: u64,
+        pub processed_at: Option<u64>,
+        pub payout_amount: u128,
+        pub assessor: Option<AccountId>,
+        pub rejection_reason: String,
+    }
 
-        // Assessors
-        authorized_assessors: Mapping<AccountId, bool>,
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LiabilityClaimStats {
+        pub filed: u64,
+        pub contested: u64,
+        pub flagged: u64, // Scored at or above the fraud threshold
+        pub approved: u64,
+        pub rejected: u64,
+        pub total_paid: u128,
+    }
 
-        // Claim cooldown: property_id -> last_claim_timestamp
-        claim_cooldowns: Mapping<u64, u64>,
+    /// (coverage type, region, period) a claims statistics cell is kept under
+    pub type StatisticsKey = (CoverageType, String, u64);
 
-        // Platform settings
-        platform_fee_rate: u32,     // Basis points (e.g. 200 = 2%)
-        claim_cooldown_period: u64, // In seconds
-        min_pool_capital: u128,
+    /// Running totals for one (coverage type, region, period) statistics cell
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ClaimsCell {
+        pub policies_written: u64,
+        pub claims_settled: u64,
+        pub claims_approved: u64,
+        pub total_payout: u128,
     }
 
-    // =========================================================================
-    // EVENTS
-    // =========================================================================
+    /// Public, anonymized claims experience for a statistics cell
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ClaimsStatistics {
+        pub policies_written: u64,
+        pub claims_settled: u64,
+        pub claims_approved: u64,
+        pub frequency_bps: u32, // Claims settled per policy written, basis points
+        pub average_severity: u128, // Mean payout of approved claims
+    }
 
-    #[ink(event)]
-    pub struct PolicyCreated {
-        #[ink(topic)]
-        policy_id: u64,
-        #[ink(topic)]
-        policyholder: AccountId,
-        #[ink(topic)]
-        property_id: u64,
-        coverage_type: CoverageType,
-        coverage_amount: u128,
-        premium_amount: u128,
-        start_time: u64,
-        end_time: u64,
+    /// Loss-adjustment cost charged to a claim and paid from its pool
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct AdjusterExpense {
+        pub expense_id: u64,
+        pub claim_id: u64,
+        pub pool_id: u64,
+        pub payee: AccountId,
+        pub amount: u128,
+        pub reason_hash: Hash,
+        pub recorded_by: AccountId,
+        pub recorded_at: u64,
     }
 
-    #[ink(event)]
-    pub struct PolicyCancelled {
-        #[ink(topic)]
-        policy_id: u64,
-        #[ink(topic)]
-        policyholder: AccountId,
-        cancelled_at: u64,
+    /// Underwriting ratios of a pool since inception, in basis points of premiums
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PoolKpis {
+        pub pool_id: u64,
+        pub premiums_collected: u128,
+        pub claims_paid: u128,
+        pub adjuster_expenses: u128,
+        pub loss_ratio: u32,
+        pub expense_ratio: u32,
+        pub combined_ratio: u32, // Loss ratio plus expense ratio
     }
 
-    #[ink(event)]
-    pub struct ClaimSubmitted {
-        #[ink(topic)]
-        claim_id: u64,
-        #[ink(topic)]
-        policy_id: u64,
-        #[ink(topic)]
-        claimant: AccountId,
-        claim_amount: u128,
-        submitted_at: u64,
+    /// Non-binding estimate of what a claim filed now would pay out
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ClaimSimulation {
+        pub policy_id: u64,
+        pub claim_amount: u128,
+        pub deductible: u128,
+        pub copay_amount: u128, // Co-pay borne by the policyholder after the deductible
+        pub coverage_remaining: u128,
+        pub incident_cap: u128,    // Per-incident cap for a fresh incident
+        pub expected_payout: u128, // After deductible, co-pay, coverage and incident caps
+        pub reinsurance_recovery: u128,
+        pub reinsurance_layers: Vec<LayerAllocation>,
+        pub pool_available: u128,
+        pub liquidity_shortfall: u128, // Extra capital the pool needs before paying out
+        pub eligible: bool,            // A claim for this amount would be accepted for filing now
+        pub binding: bool,             // Always false: the assessor's decision governs the payout
+        pub simulated_at: u64,
     }
 
-    #[ink(event)]
-    pub struct ClaimApproved {
-        #[ink(topic)]
-        claim_id: u64,
-        #[ink(topic)]
-        policy_id: u64,
-        payout_amount: u128,
-        approved_by: AccountId,
-        timestamp: u64,
+    /// Regulator view of a pool's in-force book
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ExposureReport {
+        pub pool_id: u64,
+        pub generated_at: u64,
+        pub total_policies: u64,
+        pub policies_in_force: u64,
+        pub total_insured_value: u128,
+        pub insured_by_coverage: Vec<(CoverageType, u128)>,
+        pub insured_by_region: Vec<(String, u128)>, // "" for properties without a region
+        pub insured_by_esg_tier: Vec<(EsgTier, u128)>,
+        pub total_capital: u128,
+        pub available_capital: u128,
+        pub open_claims_reserve: u128,
+        pub reinsurance_attachment: Option<u128>, // Lowest retention of covering layers
+        pub reinsurance_capacity: u128,
+        pub solvency_ratio: u32, // (capital + reinsurance) / insured value, basis points
+        pub uncovered_layers: Vec<u64>, // Lapsed treaties on the pool's peril with no renewal
     }
 
-    #[ink(event)]
-    pub struct ClaimRejected {
-        #[ink(topic)]
-        claim_id: u64,
-        #[ink(topic)]
-        policy_id: u64,
-        reason: String,
-        rejected_by: AccountId,
-        timestamp: u64,
+    /// Bond and track record of a staked claims assessor
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct AssessorStats {
+        pub stake: u128,
+        pub reputation: u32, // 0-10_000; weights random claim assignment
+        pub assignments: u64,
+        pub open_assignments: u32,
+        pub decisions: u64,
+        pub disputes_upheld: u64,
+        pub overturned: u64,
+        pub slashed_total: u128,
     }
 
-    #[ink(event)]
-    pub struct PayoutExecuted {
-        #[ink(topic)]
-        claim_id: u64,
-        #[ink(topic)]
-        recipient: AccountId,
-        amount: u128,
-        timestamp: u64,
+    /// Area of the contract that can be paused independently
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PauseDomain {
+        NewPolicies,
+        ClaimsIntake,
+        Payouts,
+        SecondaryMarket,
     }
 
-    #[ink(event)]
-    pub struct PoolCapitalized {
-        #[ink(topic)]
-        pool_id: u64,
-        #[ink(topic)]
-        provider: AccountId,
-        amount: u128,
-        timestamp: u64,
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ExposureSnapshot {
+        pub report_hash: Hash, // Blake2x256 of the SCALE-encoded report
+        pub taken_at: u64,
+        pub policies_in_force: u64,
+        pub total_insured_value: u128,
+        pub solvency_ratio: u32,
     }
 
-    #[ink(event)]
-    pub struct ReinsuranceActivated {
-        #[ink(topic)]
-        claim_id: u64,
-        agreement_id: u64,
-        recovery_amount: u128,
-        timestamp: u64,
+    /// Catastrophe scenario for a stress test. Multipliers shock the adopted
+    /// actuarial model's loss frequency and severity (10_000 = 1.0x).
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct StressScenario {
+        pub name: String,
+        pub frequency_multiplier: u32,
+        pub severity_multiplier: u32,
     }
 
-    #[ink(event)]
-    pub struct InsuranceTokenMinted {
-        #[ink(topic)]
-        token_id: u64,
-        #[ink(topic)]
-        policy_id: u64,
-        #[ink(topic)]
-        owner: AccountId,
-        face_value: u128,
+    /// Outcome of a scenario run against a pool's in-force book, kept for filings
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct StressTestResult {
+        pub pool_id: u64,
+        pub scenario: StressScenario,
+        pub model_id: u64, // Adopted model the shocks were applied to
+        pub run_at: u64,
+        pub policies_in_force: u64,
+        pub exposure: u128,         // Remaining cover of in-force policies
+        pub shocked_frequency: u32, // Share of policies suffering a loss (basis points)
+        pub shocked_severity: u128, // Loss per affected policy, before capping at its cover
+        pub projected_losses: u128, // Gross of reinsurance
+        pub reinsurance_recoveries: u128,
+        pub net_losses: u128,
+        pub post_shock_capital: u128, // Available capital less net losses
+        pub post_shock_solvency_ratio: u32, // (capital + reinsurance left) / exposure, basis points
+        pub solvent: bool,            // Capital covers the net losses
     }
 
-    #[ink(event)]
-    pub struct InsuranceTokenTransferred {
-        #[ink(topic)]
-        token_id: u64,
-        #[ink(topic)]
-        from: AccountId,
-        #[ink(topic)]
-        to: AccountId,
-        price: u128,
+    /// Net asset value of a pool attributable to its liquidity providers. Deposits
+    /// mint LP units at the NAV per unit, so earlier providers are not diluted.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PoolNav {
+        pub pool_id: u64,
+        pub computed_at: u64,
+        pub capital: u128,     // Available capital
+        pub receivables: u128, // Reinsurance recoveries allocated to paid claims
+        pub reserves: u128,    // Amounts of open claims
+        pub nav: u128,         // capital + receivables - reserves, floored at zero
+        pub lp_units: u128,
+        pub nav_per_unit: u128, // Scaled by NAV_PRECISION; par while no units exist
     }
 
-    #[ink(event)]
-    pub struct RiskAssessmentUpdated {
-        #[ink(topic)]
-        property_id: u64,
-        overall_score: u32,
-        risk_level: RiskLevel,
-        timestamp: u64,
+    /// Point of a pool's NAV series
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct NavPoint {
+        pub timestamp: u64,
+        pub nav: u128,
+        pub lp_units: u128,
+        pub nav_per_unit: u128,
     }
 
-    // =========================================================================
-    // IMPLEMENTATION
-    // =========================================================================
+    /// Proof of insurance for lenders and escrow agents. The content hash covers
+    /// the SCALE encoding of this record.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CoverageCertificate {
+        pub certificate_id: u64,
+        pub policy_id: u64,
+        pub property_id: u64,
+        pub policyholder: AccountId,
+        pub coverage_type: CoverageType,
+        pub coverage_amount: u128,
+        pub remaining_coverage: u128,
+        pub deductible: u128,
+        pub start_time: u64,
+        pub end_time: u64,
+        pub status: PolicyStatus, // At issue
+        pub issued_at: u64,
+    }
 
-    impl PropertyInsurance {
-        #[ink(constructor)]
-        pub fn new(admin: AccountId) -> Self {
-            Self {
-                admin,
-                policies: Mapping::default(),
-                policy_count: 0,
-                policyholder_policies: Mapping::default(),
-                property_policies: Mapping::default(),
-                claims: Mapping::default(),
-                claim_count: 0,
-                policy_claims: Mapping::default(),
-                pools: Mapping::default(),
-                pool_count: 0,
-                risk_assessments: Mapping::default(),
-                reinsurance_agreements: Mapping::default(),
-                reinsurance_count: 0,
-                insurance_tokens: Mapping::default(),
-                token_count: 0,
-                token_listings: Vec::new(),
-                actuarial_models: Mapping::default(),
-                model_count: 0,
-                underwriting_criteria: Mapping::default(),
-                liquidity_providers: Mapping::default(),
-                pool_providers: Mapping::default(),
-                authorized_oracles: Mapping::default(),
-                authorized_assessors: Mapping::default(),
-                claim_cooldowns: Mapping::default(),
-                platform_fee_rate: 200,            // 2%
-                claim_cooldown_period: 2_592_000,  // 30 days in seconds
-                min_pool_capital: 100_000_000_000, // Minimum pool capital
-            }
-        }
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum NoticeKind {
+        Renewal,
+        Lapse,
+        ClaimDecision,
+        Cancellation,
+        Other,
+        ThirdPartyClaim,
+    }
 
-        // =====================================================================
-        // POOL MANAGEMENT
-        // =====================================================================
+    /// Regulatory notice sent to a policyholder; the full text lives off-chain
+    /// and is pinned by `content_hash`
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PolicyNotice {
+        pub notice_id: u64,
+        pub policy_id: u64,
+        pub policyholder: AccountId,
+        pub kind: NoticeKind,
+        pub content_hash: Hash,
+        pub issued_at: u64,
+        pub acknowledged_at: Option<u64>,
+    }
 
-        /// Create a new risk pool (admin only)
-        #[ink(message)]
-        pub fn create_risk_pool(
-            &mut self,
-            name: String,
-            coverage_type: CoverageType,
-            max_coverage_ratio: u32,
-            reinsurance_threshold: u128,
-        ) -> Result<u64, InsuranceError> {
-            self.ensure_admin()?;
+    /// (policy_id, locale, version)
+    pub type DocumentKey = (u64, String, u32);
 
-            let pool_id = self.pool_count + 1;
-            self.pool_count = pool_id;
+    /// One version of a policy's terms in a given locale; the text lives
+    /// off-chain at `uri` and is pinned by `document_hash`
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LocalizedDocument {
+        pub policy_id: u64,
+        pub locale: String,
+        pub version: u32,
+        pub document_hash: Hash,
+        pub uri: String,
+        pub effective_from: u64,
+        pub published_at: u64,
+    }
 
-            let pool = RiskPool {
-                pool_id,
-                name,
-                coverage_type,
-                total_capital: 0,
-                available_capital: 0,
-                total_premiums_collected: 0,
-                total_claims_paid: 0,
-                active_policies: 0,
-                max_coverage_ratio,
-                reinsurance_threshold,
-                created_at: self.env().block_timestamp(),
-                is_active: true,
-            };
+    /// Localized terms the policyholder accepted, referenced in disputes
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct TermsAcceptance {
+        pub locale: String,
+        pub version: u32,
+        pub document_hash: Hash,
+        pub acknowledged_at: u64,
+    }
 
-            self.pools.insert(&pool_id, &pool);
-            Ok(pool_id)
-        }
+    /// Coverage carried by a policy whose property token bridged to another chain.
+    /// The attestation hash covers the SCALE encoding of this record.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PortableCoverage {
+        pub policy_id: u64,
+        pub property_id: u64,
+        pub policyholder: AccountId,
+        pub coverage_type: CoverageType,
+        pub remaining_coverage: u128,
+        pub deductible: u128,
+        pub end_time: u64,
+        pub destination_chain: u64,
+        pub bridge_transaction: Hash,
+        pub ported_at: u64,
+    }
 
-        /// Provide liquidity to a pool
-        #[ink(message, payable)]
-        pub fn provide_pool_liquidity(&mut self, pool_id: u64) -> Result<(), InsuranceError> {
-            let caller = self.env().caller();
-            let amount = self.env().transferred_value();
+    /// Regulatory terms a jurisdiction imposes on new policies. Templates are
+    /// immutable; registering again for a jurisdiction supersedes the old version.
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PolicyTemplate {
+        pub template_id: u64,
+        pub jurisdiction: String,
+        pub version: u32,
+        pub min_coverage: u128,
+        pub mandatory_perils: Vec<CoverageType>, // Each must be covered by the policy
+        pub max_deductible: u128,                // 0 = no cap
+        pub cancellation_notice: u64,            // In seconds, for insurer cancellations
+        pub created_at: u64,
+    }
 
-            let mut pool = self
-                .pools
-                .get(&pool_id)
-                .ok_or(InsuranceError::PoolNotFound)?;
-            if !pool.is_active {
-                return Err(InsuranceError::PoolNotFound);
-            }
+    /// One premium tax or regulatory levy a jurisdiction charges on collected premiums
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LevyRate {
+        pub name: String,
+        pub rate_bp: u32,         // Basis points of the premium paid
+        pub recipient: AccountId, // Levy account the charge is remitted to
+    }
 
-            pool.total_capital += amount;
-            pool.available_capital += amount;
-            self.pools.insert(&pool_id, &pool);
+    /// Asset a pool's premiums, claims and LP capital are held in
+    #[derive(
+        Debug,
+        Clone,
+        Copy,
+        Default,
+        PartialEq,
+        Eq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Denomination {
+        #[default]
+        Native,
+        Asset(AccountId), // Registered PSP22 stablecoin
+    }
 
-            // Update liquidity provider record
-            let key = (pool_id, caller);
-            let mut provider =
-                self.liquidity_providers
-                    .get(&key)
-                    .unwrap_or(PoolLiquidityProvider {
-                        provider: caller,
-                        pool_id,
-                        deposited_amount: 0,
-                        share_percentage: 0,
-                        deposited_at: self.env().block_timestamp(),
-                        last_reward_claim: self.env().block_timestamp(),
-                        accumulated_rewards: 0,
-                    });
-            provider.deposited_amount += amount;
-            self.liquidity_providers.insert(&key, &provider);
+    /// Admin-approved conversion of treasury funds between assets, settled by
+    /// the named counterparty
+    #[derive(
+        Debug, Clone, PartialEq, scale::Encode, scale::Decode, ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct AssetSwap {
+        pub swap_id: u64,
+        pub counterparty: AccountId,
+        pub sell: Denomination,
+        pub sell_amount: u128,
+        pub buy: Denomination,
+        pub buy_amount: u128,
+        pub approved_at: u64,
+        pub executed: bool,
+    }
 
-            // Track providers per pool
-            let mut providers = self.pool_providers.get(&pool_id).unwrap_or_default();
-            if !providers.contains(&caller) {
-                providers.push(caller);
-                self.pool_providers.insert(&pool_id, &providers);
-            }
+    /// (jurisdiction, statistics period, asset) a levy report is kept under
+    pub type LevyReportKey = (String, u64, Denomination);
 
-            self.env().emit_event(PoolCapitalized {
-                pool_id,
-                provider: caller,
-                amount,
-                timestamp: self.env().block_timestamp(),
-            });
+    /// Levies collected in one jurisdiction over one statistics period, for filings
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LevyReport {
+        pub policies: u32,
+        pub premium_base: u128,
+        pub levies: Vec<(String, u128)>, // Per levy name
+        pub total_levied: u128,
+    }
 
-            Ok(())
-        }
+    /// Work done by one `run_maintenance` call
+    #[derive(
+        Debug,
+        Clone,
+        Default,
+        PartialEq,
+        scale::Encode,
+        scale::Decode,
+        ink::storage::traits::StorageLayout,
+    )]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct MaintenanceReport {
+        pub claims_examined: u32,
+        pub claims_escalated: u32,
+        pub policies_examined: u32,
+        pub policies_expired: u32,
+        pub policies_suspended: u32,
+        pub reward_paid: u128,
+    }
 
-        // =====================================================================
-        // RISK ASSESSMENT
-        // =====================================================================
+    // =========================================================================
+    // STORAGE
+    // =========================================================================
 
-        /// Submit or update risk assessment for a property (oracle/admin)
-        #[ink(message)]
-        pub fn update_risk_assessment(
-            &mut self,
-            property_id: u64,
-            location_score: u32,
-            construction_score: u32,
-            age_score: u32,
-            claims_history_score: u32,
-            valid_for_seconds: u64,
-        ) -> Result<(), InsuranceError> {
-            let caller = self.env().caller();
-            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
-                return Err(InsuranceError::Unauthorized);
-            }
+    #[ink(storage)]
+    pub struct PropertyInsurance {
+        admin: AccountId,
 
-            let overall = (location_score
-                .saturating_add(construction_score)
-                .saturating_add(age_score)
-                .saturating_add(claims_history_score))
-                / 4;
+        // Policies
+        policies: Mapping<u64, InsurancePolicy>,
+        policy_count: u64,
+        policyholder_policies: Mapping<AccountId, Vec<u64>>,
+        property_policies: Mapping<u64, Vec<u64>>,
+        pool_policies: Mapping<u64, Vec<u64>>,
+        pool_runoffs: Mapping<u64, PoolRunOff>,
+
+        // Coverage certificates
+        certificates: Mapping<u64, CoverageCertificate>,
+        certificate_hashes: Mapping<u64, Hash>,
+        certificate_count: u64,
+
+        // Jurisdiction policy templates
+        policy_templates: Mapping<u64, PolicyTemplate>,
+        policy_template_count: u64,
+        jurisdiction_templates: Mapping<String, u64>, // jurisdiction -> current template
+        policy_template_ids: Mapping<u64, u64>,       // policy_id -> template at issue
+
+        // Cross-chain portability
+        bridge_hook_source: Option<AccountId>, // PropertyToken contract reporting bridges
+        portable_coverage: Mapping<u64, PortableCoverage>,
+        portability_attestations: Mapping<u64, Hash>, // Removed when the token returns
+
+        // Policyholder communications
+        notices: Mapping<u64, PolicyNotice>,
+        notice_count: u64,
+        policy_notices: Mapping<u64, Vec<u64>>,
+        unacknowledged_notices: Mapping<AccountId, Vec<u64>>,
+
+        // Localized policy documents
+        policy_documents: Mapping<DocumentKey, LocalizedDocument>,
+        document_versions: Mapping<(u64, String), u32>, // Latest version per locale
+        policy_locales: Mapping<u64, Vec<String>>,
+        accepted_terms: Mapping<u64, TermsAcceptance>,
+
+        // Book migration
+        imported_policies: Mapping<u64, bool>,
+        imported_policy_count: u64,
+        max_import_batch: u32,
+
+        // Premium financing
+        authorized_lenders: Mapping<AccountId, bool>,
+        premium_liens: Mapping<u64, PremiumLien>, // policy_id -> lien
+        coverage_requirements: Mapping<u64, CoverageRequirement>, // property_id -> covenant
 
-            let risk_level = Self::score_to_risk_level(overall);
+        // Claims
+        claims: Mapping<u64, InsuranceClaim>,
+        claim_count: u64,
+        policy_claims: Mapping<u64, Vec<u64>>,
 
-            let now = self.env().block_timestamp();
-            let assessment = RiskAssessment {
-                property_id,
-                location_risk_score: location_score,
-                construction_risk_score: construction_score,
-                age_risk_score: age_score,
-                claims_history_score,
-                overall_risk_score: overall,
-                risk_level: risk_level.clone(),
-                assessed_at: now,
-                valid_until: now.saturating_add(valid_for_seconds),
-            };
+        // Adjuster expenses
+        adjuster_expenses: Mapping<u64, AdjusterExpense>,
+        adjuster_expense_count: u64,
+        claim_expenses: Mapping<u64, Vec<u64>>, // claim_id -> expense ids
+        claim_expense_totals: Mapping<u64, u128>,
+        adjuster_expense_caps: Mapping<u64, u128>, // pool_id -> per-claim cap for adjusters
+        pool_expenses: Mapping<u64, u128>,
+        period_expenses: Mapping<(u64, u64), u128>, // (pool_id, statistics period) -> total
+
+        // Private claim evidence
+        claim_evidence: Mapping<u64, Vec<EvidenceCommitment>>,
+        evidence_grants: Mapping<(u64, AccountId), u64>, // (claim_id, assessor) -> granted_at
+        evidence_access_log: Mapping<u64, Vec<EvidenceAccess>>,
+
+        // Third-party liability claims
+        liability_claims: Mapping<u64, ThirdPartyClaim>,
+        policy_liability_claims: Mapping<u64, Vec<u64>>,
+        liability_filings: Mapping<AccountId, u32>, // Liability claims filed per claimant
+        liability_stats: LiabilityClaimStats,
+        liability_fraud_threshold: u32,
 
-            self.risk_assessments.insert(&property_id, &assessment);
+        // Risk Pools
+        pools: Mapping<u64, RiskPool>,
+        pool_count: u64,
 
-            self.env().emit_event(RiskAssessmentUpdated {
-                property_id,
-                overall_score: overall,
-                risk_level,
-                timestamp: now,
-            });
+        // Risk Assessments
+        risk_assessments: Mapping<u64, RiskAssessment>,
+        property_regions: Mapping<u64, String>,
+
+        // Mitigation credits
+        mitigation_documents: Mapping<u64, MitigationDocument>,
+        mitigation_document_count: u64,
+        property_mitigations: Mapping<u64, Vec<u64>>,
+        mitigation_discounts: Mapping<(CoverageType, MitigationMeasure), u32>, // basis points
+        authorized_notaries: Mapping<AccountId, bool>,
+
+        // Green-discount program
+        esg_profiles: Mapping<u64, EsgProfile>, // property_id -> latest attestation
+        green_discounts: Mapping<EsgTier, u32>, // basis points
+        pool_esg_filters: Mapping<u64, EsgTier>, // pool_id -> minimum tier underwritten
+
+        // Open claims statistics, keyed by (coverage, region, period)
+        claims_statistics: Mapping<StatisticsKey, ClaimsCell>,
+        statistics_period: u64, // In seconds
+        min_cell_count: u32,    // Cells with fewer settled claims are not published
+
+        // Compliance-driven suspension
+        compliance_source: Option<AccountId>,
+        compliance_grace_period: u64,              // In seconds
+        compliance_lapses: Mapping<u64, u64>,      // property_id -> first seen non-compliant
+        compliance_suspensions: Mapping<u64, u64>, // policy_id -> suspended at
+
+        // Regulatory exposure snapshots
+        exposure_snapshots: Mapping<(u64, u32), ExposureSnapshot>,
+        exposure_snapshot_count: Mapping<u64, u32>,
+        exposure_snapshot_interval: u64, // In seconds
+        stress_tests: Mapping<(u64, u32), StressTestResult>,
+        stress_test_count: Mapping<u64, u32>,
+        nav_history: Mapping<(u64, u32), NavPoint>, // ring buffer per pool
+        nav_history_count: Mapping<u64, u32>,       // points ever recorded per pool
+        pool_claim_reserves: Mapping<u64, u128>,    // amounts of open claims per pool
+        pool_receivables: Mapping<u64, u128>,       // recoveries allocated to paid claims
+        pool_lp_units: Mapping<u64, u128>,
 
-            Ok(())
-        }
+        // Reinsurance
+        reinsurance_agreements: Mapping<u64, ReinsuranceAgreement>,
+        reinsurance_count: u64,
+        claim_reinsurance_allocations: Mapping<u64, Vec<LayerAllocation>>,
+        treaty_renewals: Mapping<u64, TreatyRenewalProposal>, // agreement -> pending terms
+        treaty_successors: Mapping<u64, u64>,                 // agreement -> renewed agreement
 
-        /// Calculate premium for a policy
-        #[ink(message)]
-        pub fn calculate_premium(
-            &self,
-            property_id: u64,
-            coverage_amount: u128,
-            coverage_type: CoverageType,
-        ) -> Result<PremiumCalculation, InsuranceError> {
-            let assessment = self
-                .risk_assessments
-                .get(&property_id)
-                .ok_or(InsuranceError::PropertyNotInsurable)?;
+        // Insurance Tokens (secondary market)
+        insurance_tokens: Mapping<u64, InsuranceToken>,
+        token_count: u64,
+        token_listings: Vec<u64>, // Tokens listed for sale
 
-            // Base rate in basis points: 150 = 1.50%
-            let base_rate: u32 = 150;
+        // Actuarial Models
+        actuarial_models: Mapping<u64, ActuarialModel>,
+        model_count: u64,
+        loss_experience: Mapping<CoverageType, LossExperience>,
+        adopted_models: Mapping<CoverageType, u64>, // model the pricing engine uses
+        models_pending_review: Mapping<u64, bool>,
+        recalibration_shift_limit: u32, // bps parameter shift that needs review
+        target_loss_ratio: u32,         // bps loss ratio adopted models price towards
 
-            // Risk multiplier based on score (100 = 1.0x, 200 = 2.0x)
-            let risk_multiplier = self.risk_score_to_multiplier(assessment.overall_risk_score);
+        // Underwriting
+        underwriting_criteria: Mapping<u64, UnderwritingCriteria>, // pool_id -> criteria
+        underwriting_overrides: Mapping<ApplicationKey, Vec<UnderwritingOverride>>,
+        underwriting_worksheets: Mapping<u64, UnderwritingWorksheet>, // policy_id -> worksheet
 
-            // Coverage type multiplier
-            let coverage_multiplier = Self::coverage_type_multiplier(&coverage_type);
+        // Liquidity providers
+        liquidity_providers: Mapping<(u64, AccountId), PoolLiquidityProvider>,
+        pool_providers: Mapping<u64, Vec<AccountId>>,
 
-            // Annual premium = coverage * base_rate * risk_mult * coverage_mult / 1_000_000
-            let annual_premium = coverage_amount
-                .saturating_mul(base_rate as u128)
-                .saturating_mul(risk_multiplier as u128)
-                .saturating_mul(coverage_multiplier as u128)
-                / 1_000_000_000_000u128; // 3 basis point divisors × 10000 each
+        // Oracle addresses
+        authorized_oracles: Mapping<AccountId, bool>,
+        risk_score_source: Option<AccountId>, // Valuation engine deriving risk scores
 
-            let monthly_premium = annual_premium / 12;
+        // Seasonal pricing: (coverage type, version) -> curve
+        seasonal_curves: Mapping<(CoverageType, u32), SeasonalCurve>,
+        seasonal_curve_versions: Mapping<CoverageType, u32>,
 
-            // Deductible: 5% of coverage_amount, scaled by risk
-            let deductible = coverage_amount
-                .saturating_mul(500u128)
-                .saturating_mul(risk_multiplier as u128)
-                / 10_000_000u128;
+        // Assessors
+        authorized_assessors: Mapping<AccountId, bool>,
+        assessor_stats: Mapping<AccountId, AssessorStats>,
+        assessor_pool: Vec<AccountId>, // Bonded assessors eligible for assignment
+        claim_assignments: Mapping<u64, AccountId>,
+        assessor_conflicts: Mapping<(AccountId, AccountId), bool>, // (assessor, party)
+        min_assessor_stake: u128,
+        assessor_slash_rate: u32, // Basis points of stake slashed per overturned decision
+        overturn_reputation_penalty: u32,
 
-            Ok(PremiumCalculation {
-                base_rate,
-                risk_multiplier,
-                coverage_multiplier,
-                annual_premium,
-                monthly_premium,
-                deductible,
-            })
-        }
+        // Claim cooldown: property_id -> last_claim_timestamp
+        claim_cooldowns: Mapping<u64, u64>,
 
-        // =====================================================================
-        // POLICY MANAGEMENT
-        // =====================================================================
+        // Claim SLAs
+        claim_sla_configs: Mapping<u64, ClaimSlaConfig>, // pool_id -> SLA
+        claim_sla_stats: Mapping<u64, ClaimSlaStats>,    // pool_id -> stats
+        escalated_claims: Mapping<u64, u64>,             // Admin review queue: slot -> claim_id
+        escalation_slots: Mapping<u64, u64>,             // claim_id -> slot in the queue
+        escalated_claim_count: u64,
+        sla_credits: Mapping<AccountId, u128>,
+
+        // Surplus sharing
+        surplus_configs: Mapping<u64, SurplusSharingConfig>, // pool_id -> config
+        surplus_periods: Mapping<u64, SurplusPeriod>,        // pool_id -> open period
+        surplus_period_count: Mapping<u64, u32>,
+        surplus_reports: Mapping<(u64, u32), SurplusPeriodReport>,
+        surplus_credits: Mapping<AccountId, u128>,
+        surplus_closes: Mapping<u64, SurplusClose>, // pool_id -> close in progress
+        surplus_eligible: Mapping<(u64, u32), SurplusShareBasis>, // (pool, index)
+
+        // Incident registry
+        incidents: Mapping<u64, Incident>,
+        incident_count: u64,
+        incident_index: Mapping<IncidentKey, u64>, // (property, peril, window) -> incident
+        incident_payouts: Mapping<(u64, u64), u128>, // (incident, policy) -> paid
+        incident_window: u64,                      // In seconds
+        incident_payout_limit: u32, // Max paid per incident per policy (basis points of coverage)
+        claim_coordinations: Mapping<u64, ClaimCoordination>,
+        coordination_count: u64,
+        claim_coordination: Mapping<u64, u64>, // claim -> coordination
+        incident_coordinations: Mapping<(u64, AccountId), u64>, // (incident, holder) -> coordination
+
+        // Emergency pause
+        paused_domains: Mapping<PauseDomain, bool>,
+        guardians: Vec<AccountId>,
+        guardian_threshold: u32, // Confirmations needed to pause payouts
+        payout_pause_confirmations: Vec<(AccountId, u64)>, // (guardian, confirmed at)
+        payout_velocity_limit: u128, // Max paid per window before payouts auto-pause; 0 = off
+        payout_velocity_window: u64, // In seconds
+        payout_window_start: u64,
+        payout_window_total: u128,
+
+        // Keeper maintenance
+        maintenance_claim_cursor: u64,  // Next claim id to examine
+        maintenance_policy_cursor: u64, // Next policy id to examine
+        maintenance_reward: u128,       // Paid per unit of work performed
+        maintenance_fund: u128,         // Balance set aside for keeper rewards
+
+        // Premium taxes and regulatory levies
+        levy_schedules: Mapping<String, Vec<LevyRate>>, // jurisdiction -> levies charged
+        levy_jurisdictions: Vec<String>,                // jurisdictions with a schedule
+        property_jurisdictions: Mapping<u64, String>,   // property_id -> where it is taxed
+        property_profiles: Mapping<u64, PropertyProfile>,
+        levy_balances: Mapping<AccountId, u128>, // Remittable to each levy account
+        levy_reports: Mapping<LevyReportKey, LevyReport>,
+
+        // Pool denominations and per-asset accounting
+        denomination_assets: Mapping<AccountId, String>, // Registered PSP22 token -> symbol
+        pool_denominations: Mapping<u64, Denomination>,  // Absent = native
+        asset_treasuries: Mapping<Denomination, u128>,   // Platform fees held in each asset
+        asset_credits: Mapping<(AccountId, AccountId), u128>, // (account, token) -> withdrawable
+        asset_swaps: Mapping<u64, AssetSwap>,
+        asset_swap_count: u64,
 
-        /// Create an insurance policy (policyholder pays premium)
-        #[ink(message, payable)]
-        pub fn create_policy(
-            &mut self,
-            property_id: u64,
-            coverage_type: CoverageType,
-            coverage_amount: u128,
-            pool_id: u64,
-            duration_seconds: u64,
-            metadata_url: String,
-        ) -> Result<u64, InsuranceError> {
-            let caller = self.env().caller();
-            let paid = self.env().transferred_value();
-            let now = self.env().block_timestamp();
+        // Platform settings
+        platform_fee_rate: u32,     // Basis points (e.g. 200 = 2%)
+        claim_cooldown_period: u64, // In seconds
+        min_pool_capital: u128,
+        waiting_periods: Mapping<CoverageType, u64>, // Seconds from policy start before cover applies
+    }
 
-            // Validate pool
-            let mut pool = self
-                .pools
-                .get(&pool_id)
-                .ok_or(InsuranceError::PoolNotFound)?;
-            if !pool.is_active {
-                return Err(InsuranceError::PoolNotFound);
-            }
+    // =========================================================================
+    // EVENTS
+    // =========================================================================
 
-            // Check pool has enough capital for coverage
-            let max_exposure = pool
-                .available_capital
-                .saturating_mul(pool.max_coverage_ratio as u128)
-                / 10_000;
-            if coverage_amount > max_exposure {
-                return Err(InsuranceError::InsufficientPoolFunds);
-            }
+    #[ink(event)]
+    pub struct PolicyImported {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        policyholder: AccountId,
+        #[ink(topic)]
+        property_id: u64,
+        premium_amount: u128,
+        start_time: u64,
+        end_time: u64,
+        prior_claims_count: u32,
+    }
 
-            // Get risk assessment
-            let assessment = self
-                .risk_assessments
-                .get(&property_id)
-                .ok_or(InsuranceError::PropertyNotInsurable)?;
+    #[ink(event)]
+    pub struct AdjusterExpenseRecorded {
+        #[ink(topic)]
+        expense_id: u64,
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        payee: AccountId,
+        amount: u128,
+        reason_hash: Hash,
+    }
 
-            // Check assessment is still valid
-            if now > assessment.valid_until {
-                return Err(InsuranceError::PropertyNotInsurable);
-            }
+    #[ink(event)]
+    pub struct PolicyTemplateRegistered {
+        #[ink(topic)]
+        template_id: u64,
+        jurisdiction: String,
+        version: u32,
+    }
 
-            // Calculate required premium
-            let calc =
-                self.calculate_premium(property_id, coverage_amount, coverage_type.clone())?;
-            if paid < calc.annual_premium {
-                return Err(InsuranceError::InsufficientPremium);
-            }
+    #[ink(event)]
+    pub struct LevyScheduleSet {
+        #[ink(topic)]
+        jurisdiction: String,
+        total_rate_bp: u32,
+    }
 
-            // Platform fee
-            let fee = paid.saturating_mul(self.platform_fee_rate as u128) / 10_000;
-            let pool_share = paid.saturating_sub(fee);
+    #[ink(event)]
+    pub struct CertificateIssued {
+        #[ink(topic)]
+        certificate_id: u64,
+        #[ink(topic)]
+        policy_id: u64,
+        content_hash: Hash,
+        valid_until: u64,
+    }
 
-            // Update pool
-            pool.total_premiums_collected += pool_share;
-            pool.available_capital += pool_share;
-            pool.active_policies += 1;
-            self.pools.insert(&pool_id, &pool);
+    #[ink(event)]
+    pub struct PolicyCreated {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        policyholder: AccountId,
+        #[ink(topic)]
+        property_id: u64,
+        coverage_type: CoverageType,
+        coverage_amount: u128,
+        premium_amount: u128,
+        levies: Vec<(String, u128)>, // Premium taxes withheld, by levy name
+        start_time: u64,
+        end_time: u64,
+        waiting_period_ends: u64,
+    }
 
-            // Create policy
-            let policy_id = self.policy_count + 1;
-            self.policy_count = policy_id;
+    #[ink(event)]
+    pub struct WaitingPeriodSet {
+        #[ink(topic)]
+        coverage_type: CoverageType,
+        period_seconds: u64,
+    }
 
-            let policy = InsurancePolicy {
-                policy_id,
-                property_id,
-                policyholder: caller,
-                coverage_type: coverage_type.clone(),
-                coverage_amount,
-                premium_amount: paid,
-                deductible: calc.deductible,
-                start_time: now,
-                end_time: now.saturating_add(duration_seconds),
-                status: PolicyStatus::Active,
-                risk_level: assessment.risk_level,
-                pool_id,
-                claims_count: 0,
-                total_claimed: 0,
-                metadata_url,
-            };
+    #[ink(event)]
+    pub struct PolicyCancelled {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        policyholder: AccountId,
+        cancelled_at: u64,
+    }
 
-            self.policies.insert(&policy_id, &policy);
+    #[ink(event)]
+    pub struct ClaimSubmitted {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        claimant: AccountId,
+        claim_amount: u128,
+        submitted_at: u64,
+    }
 
-            let mut ph_policies = self.policyholder_policies.get(&caller).unwrap_or_default();
-            ph_policies.push(policy_id);
-            self.policyholder_policies.insert(&caller, &ph_policies);
+    #[ink(event)]
+    pub struct CoordinatedClaimOpened {
+        #[ink(topic)]
+        coordination_id: u64,
+        #[ink(topic)]
+        incident_id: u64,
+        claimant: AccountId,
+        total_loss: u128,
+        claim_ids: Vec<u64>,
+    }
 
-            let mut prop_policies = self.property_policies.get(&property_id).unwrap_or_default();
-            prop_policies.push(policy_id);
-            self.property_policies.insert(&property_id, &prop_policies);
+    #[ink(event)]
+    pub struct CoordinatedClaimSettled {
+        #[ink(topic)]
+        coordination_id: u64,
+        #[ink(topic)]
+        incident_id: u64,
+        total_paid: u128,
+        statement_hash: Hash,
+    }
 
-            // Mint insurance token
-            self.internal_mint_token(policy_id, caller, coverage_amount)?;
+    #[ink(event)]
+    pub struct LiabilityClaimFiled {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        policyholder: AccountId,
+        claimant: AccountId,
+        claim_amount: u128,
+        fraud_score: u32,
+    }
 
-            self.env().emit_event(PolicyCreated {
-                policy_id,
-                policyholder: caller,
-                property_id,
-                coverage_type,
-                coverage_amount,
-                premium_amount: paid,
-                start_time: now,
-                end_time: now.saturating_add(duration_seconds),
-            });
+    #[ink(event)]
+    pub struct LiabilityClaimContested {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        policyholder: AccountId,
+        reason: String,
+    }
 
-            Ok(policy_id)
-        }
+    #[ink(event)]
+    pub struct LiabilityClaimDecided {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        claimant: AccountId,
+        approved: bool,
+        payout_amount: u128,
+        decided_by: AccountId,
+    }
 
-        /// Cancel an active policy (policyholder or admin)
-        #[ink(message)]
-        pub fn cancel_policy(&mut self, policy_id: u64) -> Result<(), InsuranceError> {
-            let caller = self.env().caller();
-            let mut policy = self
-                .policies
-                .get(&policy_id)
-                .ok_or(InsuranceError::PolicyNotFound)?;
+    #[ink(event)]
+    pub struct IncidentReported {
+        #[ink(topic)]
+        incident_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        peril: CoverageType,
+        occurred_at: u64,
+    }
 
-            if caller != policy.policyholder && caller != self.admin {
-                return Err(InsuranceError::Unauthorized);
-            }
+    #[ink(event)]
+    pub struct SuspiciousFilingFlagged {
+        #[ink(topic)]
+        incident_id: u64,
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        claimant: AccountId,
+    }
 
-            if policy.status != PolicyStatus::Active {
-                return Err(InsuranceError::PolicyInactive);
-            }
+    #[ink(event)]
+    pub struct EvidenceCommitted {
+        #[ink(topic)]
+        claim_id: u64,
+        content_hash: Hash,
+        index: u32,
+    }
 
-            policy.status = PolicyStatus::Cancelled;
-            self.policies.insert(&policy_id, &policy);
+    #[ink(event)]
+    pub struct EvidenceAccessGranted {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        assessor: AccountId,
+        granted_by: AccountId,
+    }
 
-            // Reduce pool active count
-            if let Some(mut pool) = self.pools.get(&policy.pool_id) {
-                if pool.active_policies > 0 {
-                    pool.active_policies -= 1;
-                }
-                self.pools.insert(&policy.pool_id, &pool);
-            }
+    #[ink(event)]
+    pub struct EvidenceAccessed {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        accessor: AccountId,
+        timestamp: u64,
+    }
 
-            self.env().emit_event(PolicyCancelled {
-                policy_id,
-                policyholder: policy.policyholder,
-                cancelled_at: self.env().block_timestamp(),
-            });
+    #[ink(event)]
+    pub struct ClaimApproved {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        policy_id: u64,
+        payout_amount: u128,
+        approved_by: AccountId,
+        timestamp: u64,
+    }
 
-            Ok(())
-        }
+    #[ink(event)]
+    pub struct ClaimRejected {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        policy_id: u64,
+        reason: String,
+        rejected_by: AccountId,
+        timestamp: u64,
+    }
 
-        // =====================================================================
-        // CLAIMS PROCESSING
-        // =====================================================================
+    #[ink(event)]
+    pub struct AssessorBonded {
+        #[ink(topic)]
+        assessor: AccountId,
+        amount: u128,
+        total_stake: u128,
+    }
 
-        /// Submit an insurance claim
-        #[ink(message)]
-        pub fn submit_claim(
-            &mut self,
-            policy_id: u64,
-            claim_amount: u128,
-            description: String,
-            evidence_url: String,
-        ) -> Result<u64, InsuranceError> {
-            let caller = self.env().caller();
-            let now = self.env().block_timestamp();
+    #[ink(event)]
+    pub struct ClaimAssigned {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        assessor: AccountId,
+    }
 
-            let mut policy = self
-                .policies
-                .get(&policy_id)
-                .ok_or(InsuranceError::PolicyNotFound)?;
+    #[ink(event)]
+    pub struct AssessorRecused {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        assessor: AccountId,
+    }
 
-            if policy.policyholder != caller {
-                return Err(InsuranceError::Unauthorized);
-            }
-            if policy.status != PolicyStatus::Active {
-                return Err(InsuranceError::PolicyInactive);
-            }
-            if now > policy.end_time {
-                return Err(InsuranceError::PolicyExpired);
-            }
+    #[ink(event)]
+    pub struct ClaimDisputed {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        assessor: AccountId,
+        disputed_by: AccountId,
+    }
 
-            // Check claim amount doesn't exceed remaining coverage
-            let remaining = policy.coverage_amount.saturating_sub(policy.total_claimed);
-            if claim_amount > remaining {
-                return Err(InsuranceError::ClaimExceedsCoverage);
-            }
+    #[ink(event)]
+    pub struct DisputeResolved {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        assessor: AccountId,
+        overturned: bool,
+        slashed: u128,
+        reputation: u32,
+    }
 
-            // Cooldown check
-            let last_claim = self.claim_cooldowns.get(&policy.property_id).unwrap_or(0);
-            if now.saturating_sub(last_claim) < self.claim_cooldown_period {
-                return Err(InsuranceError::CooldownPeriodActive);
-            }
+    #[ink(event)]
+    pub struct ClaimEscalated {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        pool_id: u64,
+        deadline: u64,
+        compensation: u128,
+        timestamp: u64,
+    }
 
-            let claim_id = self.claim_count + 1;
-            self.claim_count = claim_id;
+    #[ink(event)]
+    pub struct SurplusDistributed {
+        #[ink(topic)]
+        pool_id: u64,
+        period: u32,
+        loss_ratio: u32,
+        distributed: u128,
+        eligible_policies: u32,
+        timestamp: u64,
+    }
 
-            let claim = InsuranceClaim {
-                claim_id,
-                policy_id,
-                claimant: caller,
-                claim_amount,
-                description,
-                evidence_url,
-                oracle_report_url: String::new(),
-                status: ClaimStatus::Pending,
-                submitted_at: now,
-                processed_at: None,
-                payout_amount: 0,
-                assessor: None,
-                rejection_reason: String::new(),
-            };
+    #[ink(event)]
+    pub struct PayoutExecuted {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        recipient: AccountId,
+        amount: u128,
+        deductible_applied: u128,
+        copay_amount: u128,
+        timestamp: u64,
+    }
 
-            self.claims.insert(&claim_id, &claim);
+    #[ink(event)]
+    pub struct PayoutRoutedToShareholders {
+        #[ink(topic)]
+        claim_id: u64,
+        #[ink(topic)]
+        token_contract: AccountId,
+        token_id: u64,
+        amount: u128,
+    }
 
-            let mut policy_claims = self.policy_claims.get(&policy_id).unwrap_or_default();
-            policy_claims.push(claim_id);
-            self.policy_claims.insert(&policy_id, &policy_claims);
+    #[ink(event)]
+    pub struct PoolCapitalized {
+        #[ink(topic)]
+        pool_id: u64,
+        #[ink(topic)]
+        provider: AccountId,
+        amount: u128,
+        timestamp: u64,
+    }
 
-            policy.claims_count += 1;
-            self.policies.insert(&policy_id, &policy);
+    #[ink(event)]
+    pub struct ReinsuranceActivated {
+        #[ink(topic)]
+        claim_id: u64,
+        agreement_id: u64,
+        recovery_amount: u128,
+        timestamp: u64,
+    }
 
-            self.env().emit_event(ClaimSubmitted {
-                claim_id,
-                policy_id,
-                claimant: caller,
-                claim_amount,
-                submitted_at: now,
-            });
+    #[ink(event)]
+    pub struct TreatyRenewalProposed {
+        #[ink(topic)]
+        agreement_id: u64,
+        #[ink(topic)]
+        reinsurer: AccountId,
+        coverage_limit: u128,
+        retention_limit: u128,
+        premium_ceded_rate: u32,
+    }
 
-            Ok(claim_id)
-        }
+    #[ink(event)]
+    pub struct TreatyRenewalResolved {
+        #[ink(topic)]
+        agreement_id: u64,
+        renewed_agreement_id: Option<u64>, // None when the terms were rejected
+    }
 
-        /// Assessor reviews a claim and either approves or rejects it
-        #[ink(message)]
-        pub fn process_claim(
-            &mut self,
-            claim_id: u64,
-            approved: bool,
-            oracle_report_url: String,
-            rejection_reason: String,
-        ) -> Result<(), InsuranceError> {
-            let caller = self.env().caller();
+    #[ink(event)]
+    pub struct UnderwritingOverrideApproved {
+        #[ink(topic)]
+        pool_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        criterion: UnderwritingCriterion,
+        approver: AccountId,
+    }
 
-            if caller != self.admin && !self.authorized_assessors.get(&caller).unwrap_or(false) {
-                return Err(InsuranceError::Unauthorized);
-            }
+    #[ink(event)]
+    pub struct InsuranceTokenMinted {
+        #[ink(topic)]
+        token_id: u64,
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        owner: AccountId,
+        face_value: u128,
+    }
 
-            let mut claim = self
-                .claims
-                .get(&claim_id)
-                .ok_or(InsuranceError::ClaimNotFound)?;
-            if claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview {
-                return Err(InsuranceError::ClaimAlreadyProcessed);
-            }
+    #[ink(event)]
+    pub struct InsuranceTokenTransferred {
+        #[ink(topic)]
+        token_id: u64,
+        #[ink(topic)]
+        from: AccountId,
+        #[ink(topic)]
+        to: AccountId,
+        price: u128,
+    }
 
-            let now = self.env().block_timestamp();
-            claim.assessor = Some(caller);
-            claim.oracle_report_url = oracle_report_url;
-            claim.processed_at = Some(now);
+    #[ink(event)]
+    pub struct RiskAssessmentUpdated {
+        #[ink(topic)]
+        property_id: u64,
+        overall_score: u32,
+        risk_level: RiskLevel,
+        timestamp: u64,
+    }
 
-            if approved {
-                let policy = self
-                    .policies
-                    .get(&claim.policy_id)
-                    .ok_or(InsuranceError::PolicyNotFound)?;
+    #[ink(event)]
+    pub struct PoolRunOffStarted {
+        #[ink(topic)]
+        pool_id: u64,
+        remaining_policies: u64,
+        remaining_exposure: u128,
+        timestamp: u64,
+    }
 
-                // Apply deductible
-                let payout = if claim.claim_amount > policy.deductible {
-                    claim.claim_amount.saturating_sub(policy.deductible)
-                } else {
-                    0
-                };
+    #[ink(event)]
+    pub struct ActuarialModelRecalibrated {
+        #[ink(topic)]
+        model_id: u64,
+        coverage_type: CoverageType,
+        parameter_shift: u32,
+        pending_review: bool,
+    }
 
-                claim.payout_amount = payout;
-                claim.status = ClaimStatus::Approved;
-                self.claims.insert(&claim_id, &claim);
+    #[ink(event)]
+    pub struct ActuarialModelAdopted {
+        #[ink(topic)]
+        model_id: u64,
+        coverage_type: CoverageType,
+    }
 
-                // Execute payout
-                self.execute_payout(claim_id, claim.policy_id, claim.claimant, payout)?;
+    #[ink(event)]
+    pub struct PoolDenominationSet {
+        #[ink(topic)]
+        pool_id: u64,
+        denomination: Denomination,
+    }
 
-                self.env().emit_event(ClaimApproved {
-                    claim_id,
-                    policy_id: claim.policy_id,
-                    payout_amount: payout,
-                    approved_by: caller,
-                    timestamp: now,
-                });
-            } else {
-                claim.status = ClaimStatus::Rejected;
-                claim.rejection_reason = rejection_reason.clone();
-                self.claims.insert(&claim_id, &claim);
+    #[ink(event)]
+    pub struct AssetSwapExecuted {
+        #[ink(topic)]
+        swap_id: u64,
+        #[ink(topic)]
+        counterparty: AccountId,
+        sell: Denomination,
+        sell_amount: u128,
+        buy: Denomination,
+        buy_amount: u128,
+    }
 
-                self.env().emit_event(ClaimRejected {
-                    claim_id,
-                    policy_id: claim.policy_id,
-                    reason: rejection_reason,
-                    rejected_by: caller,
-                    timestamp: now,
-                });
-            }
+    #[ink(event)]
+    pub struct RunOffCapitalWithdrawn {
+        #[ink(topic)]
+        pool_id: u64,
+        #[ink(topic)]
+        provider: AccountId,
+        amount: u128,
+        remaining_reserve: u128,
+    }
 
-            Ok(())
-        }
+    #[ink(event)]
+    pub struct PoolClosed {
+        #[ink(topic)]
+        pool_id: u64,
+        final_surplus: u128,
+        providers: u32,
+        timestamp: u64,
+    }
 
-        // =====================================================================
-        // REINSURANCE
-        // =====================================================================
+    #[ink(event)]
+    pub struct PremiumFinanced {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+        #[ink(topic)]
+        borrower: AccountId,
+        amount: u128,
+    }
 
-        /// Register a reinsurance agreement (admin only)
-        #[ink(message)]
-        pub fn register_reinsurance(
-            &mut self,
-            reinsurer: AccountId,
-            coverage_limit: u128,
-            retention_limit: u128,
-            premium_ceded_rate: u32,
-            coverage_types: Vec<CoverageType>,
-            duration_seconds: u64,
-        ) -> Result<u64, InsuranceError> {
-            self.ensure_admin()?;
+    #[ink(event)]
+    pub struct FinancingDefaultReported {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+        timestamp: u64,
+    }
 
-            let now = self.env().block_timestamp();
-            let agreement_id = self.reinsurance_count + 1;
-            self.reinsurance_count = agreement_id;
+    #[ink(event)]
+    pub struct PremiumLienReleased {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+    }
 
-            let agreement = ReinsuranceAgreement {
-                agreement_id,
-                reinsurer,
-                coverage_limit,
-                retention_limit,
-                premium_ceded_rate,
-                coverage_types,
-                start_time: now,
-                end_time: now.saturating_add(duration_seconds),
-                is_active: true,
-                total_ceded_premiums: 0,
-                total_recoveries: 0,
-            };
+    #[ink(event)]
+    pub struct CoverageRequirementSet {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+        min_coverage: u128,
+    }
 
-            self.reinsurance_agreements
-                .insert(&agreement_id, &agreement);
-            Ok(agreement_id)
-        }
+    #[ink(event)]
+    pub struct CoverageRequirementRemoved {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+    }
 
-        // =====================================================================
-        // INSURANCE TOKENIZATION & SECONDARY MARKET
-        // =====================================================================
+    #[ink(event)]
+    pub struct CoverageShortfall {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+        required: u128,
+        in_force: u128,
+        timestamp: u64,
+    }
 
-        /// List an insurance token for sale on the secondary market
-        #[ink(message)]
-        pub fn list_token_for_sale(
-            &mut self,
-            token_id: u64,
-            price: u128,
-        ) -> Result<(), InsuranceError> {
-            let caller = self.env().caller();
-            let mut token = self
-                .insurance_tokens
-                .get(&token_id)
-                .ok_or(InsuranceError::TokenNotFound)?;
+    #[ink(event)]
+    pub struct CoverageRestored {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+        in_force: u128,
+        timestamp: u64,
+    }
 
-            if token.owner != caller {
-                return Err(InsuranceError::Unauthorized);
-            }
-            if !token.is_tradeable {
-                return Err(InsuranceError::InvalidParameters);
-            }
+    #[ink(event)]
+    pub struct FinancedPolicyCancelled {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        lender: AccountId,
+        unearned_refund: u128,
+        timestamp: u64,
+    }
 
-            token.listed_price = Some(price);
-            self.insurance_tokens.insert(&token_id, &token);
+    #[ink(event)]
+    pub struct MitigationDocumentAttached {
+        #[ink(topic)]
+        document_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        measure: MitigationMeasure,
+        valid_until: u64,
+    }
 
-            if !self.token_listings.contains(&token_id) {
-                self.token_listings.push(token_id);
-            }
+    #[ink(event)]
+    pub struct MitigationDocumentVerified {
+        #[ink(topic)]
+        document_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        notary: AccountId,
+    }
 
-            Ok(())
-        }
+    #[ink(event)]
+    pub struct EsgProfileAttached {
+        #[ink(topic)]
+        property_id: u64,
+        tier: EsgTier,
+        valid_until: u64,
+    }
 
-        /// Purchase an insurance token from the secondary market
-        #[ink(message, payable)]
-        pub fn purchase_token(&mut self, token_id: u64) -> Result<(), InsuranceError> {
-            let caller = self.env().caller();
-            let paid = self.env().transferred_value();
+    #[ink(event)]
+    pub struct EsgProfileVerified {
+        #[ink(topic)]
+        property_id: u64,
+        #[ink(topic)]
+        notary: AccountId,
+        tier: EsgTier,
+    }
 
-            let mut token = self
-                .insurance_tokens
-                .get(&token_id)
-                .ok_or(InsuranceError::TokenNotFound)?;
-            let price = token
-                .listed_price
-                .ok_or(InsuranceError::InvalidParameters)?;
+    #[ink(event)]
+    pub struct PropertyComplianceLapsed {
+        #[ink(topic)]
+        property_id: u64,
+        grace_ends: u64,
+    }
 
-            if paid < price {
-                return Err(InsuranceError::InsufficientPremium);
-            }
+    #[ink(event)]
+    pub struct PolicyPorted {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        destination_chain: u64,
+        attestation: Hash,
+    }
 
-            let seller = token.owner;
-            let old_owner = seller;
+    #[ink(event)]
+    pub struct PolicyReturned {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        returned_at: u64,
+    }
 
-            // Transfer the policy to the buyer
-            let policy = self
-                .policies
-                .get(&token.policy_id)
-                .ok_or(InsuranceError::PolicyNotFound)?;
-            if policy.status != PolicyStatus::Active {
-                return Err(InsuranceError::PolicyInactive);
-            }
+    #[ink(event)]
+    pub struct NoticeIssued {
+        #[ink(topic)]
+        notice_id: u64,
+        #[ink(topic)]
+        policy_id: u64,
+        kind: NoticeKind,
+        content_hash: Hash,
+    }
 
-            // Update policy policyholder
-            let mut updated_policy = policy;
-            updated_policy.policyholder = caller;
-            self.policies.insert(&token.policy_id, &updated_policy);
+    #[ink(event)]
+    pub struct NoticeAcknowledged {
+        #[ink(topic)]
+        notice_id: u64,
+        #[ink(topic)]
+        policyholder: AccountId,
+        acknowledged_at: u64,
+    }
 
-            // Update ownership tracking
-            let mut seller_policies = self.policyholder_policies.get(&seller).unwrap_or_default();
-            seller_policies.retain(|&p| p != token.policy_id);
-            self.policyholder_policies.insert(&seller, &seller_policies);
+    #[ink(event)]
+    pub struct PolicyDocumentPublished {
+        #[ink(topic)]
+        policy_id: u64,
+        locale: String,
+        version: u32,
+        document_hash: Hash,
+        effective_from: u64,
+    }
 
-            let mut buyer_policies = self.policyholder_policies.get(&caller).unwrap_or_default();
-            buyer_policies.push(token.policy_id);
-            self.policyholder_policies.insert(&caller, &buyer_policies);
+    #[ink(event)]
+    pub struct PolicyTermsAcknowledged {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        policyholder: AccountId,
+        locale: String,
+        version: u32,
+        document_hash: Hash,
+    }
 
-            // Update token
-            token.owner = caller;
-            token.listed_price = None;
-            self.insurance_tokens.insert(&token_id, &token);
+    #[ink(event)]
+    pub struct PolicySuspended {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        suspended_at: u64,
+    }
 
-            // Remove from listings
-            self.token_listings.retain(|&t| t != token_id);
+    #[ink(event)]
+    pub struct PolicyReinstated {
+        #[ink(topic)]
+        policy_id: u64,
+        #[ink(topic)]
+        property_id: u64,
+        extended_by: u64,
+    }
 
-            self.env().emit_event(InsuranceTokenTransferred {
-                token_id,
-                from: old_owner,
-                to: caller,
-                price: paid,
-            });
+    #[ink(event)]
+    pub struct DomainPauseChanged {
+        #[ink(topic)]
+        domain: PauseDomain,
+        #[ink(topic)]
+        changed_by: AccountId,
+        paused: bool,
+    }
 
-            Ok(())
+    #[ink(event)]
+    pub struct PayoutPauseConfirmed {
+        #[ink(topic)]
+        guardian: AccountId,
+        confirmations: u32,
+        required: u32,
+    }
+
+    #[ink(event)]
+    pub struct MaintenanceRun {
+        #[ink(topic)]
+        keeper: AccountId,
+        claims_escalated: u32,
+        policies_expired: u32,
+        policies_suspended: u32,
+        reward_paid: u128,
+    }
+
+    #[ink(event)]
+    pub struct CircuitBreakerTripped {
+        #[ink(topic)]
+        domain: PauseDomain,
+        observed: u128,
+        limit: u128,
+        timestamp: u64,
+    }
+
+    #[ink(event)]
+    pub struct ExposureSnapshotTaken {
+        #[ink(topic)]
+        pool_id: u64,
+        #[ink(topic)]
+        report_hash: Hash,
+        index: u32,
+        solvency_ratio: u32,
+        timestamp: u64,
+    }
+
+    #[ink(event)]
+    pub struct StressTestRun {
+        #[ink(topic)]
+        pool_id: u64,
+        index: u32,
+        projected_losses: u128,
+        net_losses: u128,
+        solvent: bool,
+    }
+
+    // =========================================================================
+    // IMPLEMENTATION
+    // =========================================================================
+
+    impl PropertyInsurance {
+        #[ink(constructor)]
+        pub fn new(admin: AccountId) -> Self {
+            Self {
+                admin,
+                policies: Mapping::default(),
+                policy_count: 0,
+                policyholder_policies: Mapping::default(),
+                property_policies: Mapping::default(),
+                pool_policies: Mapping::default(),
+                pool_runoffs: Mapping::default(),
+                certificates: Mapping::default(),
+                certificate_hashes: Mapping::default(),
+                certificate_count: 0,
+                policy_templates: Mapping::default(),
+                policy_template_count: 0,
+                jurisdiction_templates: Mapping::default(),
+                policy_template_ids: Mapping::default(),
+                bridge_hook_source: None,
+                portable_coverage: Mapping::default(),
+                portability_attestations: Mapping::default(),
+                notices: Mapping::default(),
+                notice_count: 0,
+                policy_notices: Mapping::default(),
+                unacknowledged_notices: Mapping::default(),
+                policy_documents: Mapping::default(),
+                document_versions: Mapping::default(),
+                policy_locales: Mapping::default(),
+                accepted_terms: Mapping::default(),
+                imported_policies: Mapping::default(),
+                imported_policy_count: 0,
+                max_import_batch: 50,
+                authorized_lenders: Mapping::default(),
+                coverage_requirements: Mapping::default(),
+                premium_liens: Mapping::default(),
+                claims: Mapping::default(),
+                adjuster_expenses: Mapping::default(),
+                adjuster_expense_count: 0,
+                claim_expenses: Mapping::default(),
+                claim_expense_totals: Mapping::default(),
+                adjuster_expense_caps: Mapping::default(),
+                pool_expenses: Mapping::default(),
+                period_expenses: Mapping::default(),
+                claim_count: 0,
+                policy_claims: Mapping::default(),
+                claim_evidence: Mapping::default(),
+                evidence_grants: Mapping::default(),
+                evidence_access_log: Mapping::default(),
+                pools: Mapping::default(),
+                pool_count: 0,
+                risk_assessments: Mapping::default(),
+                property_regions: Mapping::default(),
+                mitigation_documents: Mapping::default(),
+                mitigation_document_count: 0,
+                property_mitigations: Mapping::default(),
+                mitigation_discounts: Mapping::default(),
+                authorized_notaries: Mapping::default(),
+                esg_profiles: Mapping::default(),
+                green_discounts: Mapping::default(),
+                pool_esg_filters: Mapping::default(),
+                liability_claims: Mapping::default(),
+                policy_liability_claims: Mapping::default(),
+                liability_filings: Mapping::default(),
+                liability_stats: LiabilityClaimStats::default(),
+                liability_fraud_threshold: DEFAULT_LIABILITY_FRAUD_THRESHOLD,
+                claims_statistics: Mapping::default(),
+                statistics_period: 2_592_000, // 30 days
+                min_cell_count: 5,
+                compliance_source: None,
+                compliance_grace_period: 604_800, // 7 days
+                compliance_lapses: Mapping::default(),
+                compliance_suspensions: Mapping::default(),
+                exposure_snapshots: Mapping::default(),
+                exposure_snapshot_count: Mapping::default(),
+                exposure_snapshot_interval: 86_400, // Daily
+                stress_tests: Mapping::default(),
+                stress_test_count: Mapping::default(),
+                nav_history: Mapping::default(),
+                nav_history_count: Mapping::default(),
+                pool_claim_reserves: Mapping::default(),
+                pool_receivables: Mapping::default(),
+                pool_lp_units: Mapping::default(),
+                reinsurance_agreements: Mapping::default(),
+                reinsurance_count: 0,
+                claim_reinsurance_allocations: Mapping::default(),
+                treaty_renewals: Mapping::default(),
+                treaty_successors: Mapping::default(),
+                insurance_tokens: Mapping::default(),
+                token_count: 0,
+                token_listings: Vec::new(),
+                actuarial_models: Mapping::default(),
+                model_count: 0,
+                loss_experience: Mapping::default(),
+                adopted_models: Mapping::default(),
+                models_pending_review: Mapping::default(),
+                recalibration_shift_limit: 2_000, // 20%
+                target_loss_ratio: 6_000,         // 60%
+                underwriting_criteria: Mapping::default(),
+                underwriting_overrides: Mapping::default(),
+                underwriting_worksheets: Mapping::default(),
+                liquidity_providers: Mapping::default(),
+                pool_providers: Mapping::default(),
+                authorized_oracles: Mapping::default(),
+                risk_score_source: None,
+                seasonal_curves: Mapping::default(),
+                seasonal_curve_versions: Mapping::default(),
+                authorized_assessors: Mapping::default(),
+                assessor_stats: Mapping::default(),
+                assessor_pool: Vec::new(),
+                claim_assignments: Mapping::default(),
+                assessor_conflicts: Mapping::default(),
+                min_assessor_stake: 1,
+                assessor_slash_rate: 1_000,         // 10%
+                overturn_reputation_penalty: 1_000, // 10 points of 100
+                claim_cooldowns: Mapping::default(),
+                claim_sla_configs: Mapping::default(),
+                claim_sla_stats: Mapping::default(),
+                escalated_claims: Mapping::default(),
+                escalation_slots: Mapping::default(),
+                escalated_claim_count: 0,
+                sla_credits: Mapping::default(),
+                surplus_configs: Mapping::default(),
+                surplus_periods: Mapping::default(),
+                surplus_period_count: Mapping::default(),
+                surplus_reports: Mapping::default(),
+                surplus_credits: Mapping::default(),
+                surplus_closes: Mapping::default(),
+                surplus_eligible: Mapping::default(),
+                incidents: Mapping::default(),
+                incident_count: 0,
+                incident_index: Mapping::default(),
+                incident_payouts: Mapping::default(),
+                claim_coordinations: Mapping::default(),
+                coordination_count: 0,
+                claim_coordination: Mapping::default(),
+                incident_coordinations: Mapping::default(),
+                incident_window: 604_800, // 7 days in seconds
+                incident_payout_limit: 10_000,
+                paused_domains: Mapping::default(),
+                guardians: Vec::new(),
+                guardian_threshold: 1,
+                payout_pause_confirmations: Vec::new(),
+                payout_velocity_limit: 0,
+                payout_velocity_window: 86_400, // Daily
+                payout_window_start: 0,
+                payout_window_total: 0,
+                maintenance_claim_cursor: 1,
+                maintenance_policy_cursor: 1,
+                maintenance_reward: 0,
+                maintenance_fund: 0,
+                levy_schedules: Mapping::default(),
+                levy_jurisdictions: Vec::new(),
+                property_jurisdictions: Mapping::default(),
+                property_profiles: Mapping::default(),
+                levy_balances: Mapping::default(),
+                levy_reports: Mapping::default(),
+                denomination_assets: Mapping::default(),
+                pool_denominations: Mapping::default(),
+                asset_treasuries: Mapping::default(),
+                asset_credits: Mapping::default(),
+                asset_swaps: Mapping::default(),
+                asset_swap_count: 0,
+                platform_fee_rate: 200,            // 2%
+                claim_cooldown_period: 2_592_000,  // 30 days in seconds
+                min_pool_capital: 100_000_000_000, // Minimum pool capital
+                waiting_periods: Mapping::default(),
+            }
         }
 
         // =====================================================================
-        // ACTUARIAL MODELING
+        // POOL MANAGEMENT
         // =====================================================================
 
-        /// Update actuarial model (admin/authorized oracle)
+        /// Create a new risk pool (admin only)
         #[ink(message)]
-        pub fn update_actuarial_model(
+        pub fn create_risk_pool(
             &mut self,
+            name: String,
             coverage_type: CoverageType,
-            loss_frequency: u32,
-            average_loss_severity: u128,
-            expected_loss_ratio: u32,
-            confidence_level: u32,
-            data_points: u32,
+            max_coverage_ratio: u32,
+            reinsurance_threshold: u128,
         ) -> Result<u64, InsuranceError> {
-            let caller = self.env().caller();
-            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
-                return Err(InsuranceError::Unauthorized);
-            }
+            self.ensure_admin()?;
 
-            let model_id = self.model_count + 1;
-            self.model_count = model_id;
+            let pool_id = self.pool_count + 1;
+            self.pool_count = pool_id;
 
-            let model = ActuarialModel {
-                model_id,
+            let pool = RiskPool {
+                pool_id,
+                name,
                 coverage_type,
-                loss_frequency,
-                average_loss_severity,
-                expected_loss_ratio,
-                confidence_level,
-                last_updated: self.env().block_timestamp(),
-                data_points,
+                total_capital: 0,
+                available_capital: 0,
+                total_premiums_collected: 0,
+                total_claims_paid: 0,
+                active_policies: 0,
+                max_coverage_ratio,
+                reinsurance_threshold,
+                created_at: self.env().block_timestamp(),
+                is_active: true,
             };
 
-            self.actuarial_models.insert(&model_id, &model);
-            Ok(model_id)
+            self.pools.insert(&pool_id, &pool);
+            Ok(pool_id)
         }
 
-        // =====================================================================
-        // UNDERWRITING
-        // =====================================================================
+        /// Provide liquidity to a natively denominated pool
+        #[ink(message, payable)]
+        pub fn provide_pool_liquidity(&mut self, pool_id: u64) -> Result<(), InsuranceError> {
+            if self.pool_denomination(pool_id) != Denomination::Native {
+                return Err(InsuranceError::DenominationMismatch);
+            }
+            let caller = self.env().caller();
+            let amount = self.env().transferred_value();
+            self.add_pool_liquidity(caller, pool_id, amount)
+        }
 
-        /// Set underwriting criteria for a pool (admin only)
+        /// Provide liquidity to a stablecoin pool; the pool's token is pulled from
+        /// the caller, who must have approved this contract for `amount`
         #[ink(message)]
-        pub fn set_underwriting_criteria(
+        pub fn provide_pool_liquidity_in_asset(
             &mut self,
             pool_id: u64,
-            max_property_age_years: u32,
-            min_property_value: u128,
-            max_property_value: u128,
-            required_safety_features: bool,
-            max_previous_claims: u32,
-            min_risk_score: u32,
+            amount: u128,
         ) -> Result<(), InsuranceError> {
-            self.ensure_admin()?;
-            self.pools
+            let Denomination::Asset(token) = self.pool_denomination(pool_id) else {
+                return Err(InsuranceError::DenominationMismatch);
+            };
+            let caller = self.env().caller();
+            self.pull_asset(token, caller, amount)?;
+            self.add_pool_liquidity(caller, pool_id, amount)
+        }
+
+        fn add_pool_liquidity(
+            &mut self,
+            caller: AccountId,
+            pool_id: u64,
+            amount: u128,
+        ) -> Result<(), InsuranceError> {
+            let mut pool = self
+                .pools
                 .get(&pool_id)
                 .ok_or(InsuranceError::PoolNotFound)?;
+            if self.pool_runoffs.contains(&pool_id) {
+                return Err(InsuranceError::PoolClosing);
+            }
+            if !pool.is_active {
+                return Err(InsuranceError::PoolNotFound);
+            }
+            let units = amount
+                .saturating_mul(NAV_PRECISION)
+                .checked_div(self.get_nav(pool_id)?.nav_per_unit)
+                .ok_or(InsuranceError::InsufficientPoolFunds)?;
 
-            let criteria = UnderwritingCriteria {
-                max_property_age_years,
-                min_property_value,
-                max_property_value,
-                excluded_locations: Vec::new(),
-                required_safety_features,
-                max_previous_claims,
-                min_risk_score,
-            };
+            pool.total_capital += amount;
+            pool.available_capital += amount;
+            self.pools.insert(&pool_id, &pool);
+            let pool_units = self.pool_lp_units.get(&pool_id).unwrap_or(0);
+            self.pool_lp_units
+                .insert(&pool_id, &pool_units.saturating_add(units));
 
-            self.underwriting_criteria.insert(&pool_id, &criteria);
-            Ok(())
-        }
-
-        // =====================================================================
-        // ADMIN / AUTHORITY MANAGEMENT
-        // =====================================================================
-
-        /// Authorize an oracle address
-        #[ink(message)]
-        pub fn authorize_oracle(&mut self, oracle: AccountId) -> Result<(), InsuranceError> {
-            self.ensure_admin()?;
-            self.authorized_oracles.insert(&oracle, &true);
-            Ok(())
-        }
-
-        /// Authorize a claims assessor
-        #[ink(message)]
-        pub fn authorize_assessor(&mut self, assessor: AccountId) -> Result<(), InsuranceError> {
-            self.ensure_admin()?;
-            self.authorized_assessors.insert(&assessor, &true);
-            Ok(())
-        }
+            // Update liquidity provider record
+            let key = (pool_id, caller);
+            let mut provider =
+                self.liquidity_providers
+                    .get(&key)
+                    .unwrap_or(PoolLiquidityProvider {
+                        provider: caller,
+                        pool_id,
+                        deposited_amount: 0,
+                        lp_units: 0,
+                        share_percentage: 0,
+                        deposited_at: self.env().block_timestamp(),
+                        last_reward_claim: self.env().block_timestamp(),
+                        accumulated_rewards: 0,
+                    });
+            provider.deposited_amount += amount;
+            provider.lp_units = provider.lp_units.saturating_add(units);
+            self.liquidity_providers.insert(&key, &provider);
+            self.record_nav(pool_id);
 
-        /// Update platform fee rate (admin only)
-        #[ink(message)]
-        pub fn set_platform_fee_rate(&mut self, rate: u32) -> Result<(), InsuranceError> {
-            self.ensure_admin()?;
-            if rate > 1000 {
-                return Err(InsuranceError::InvalidParameters); // Max 10%
+            // Track providers per pool
+            let mut providers = self.pool_providers.get(&pool_id).unwrap_or_default();
+            if !providers.contains(&caller) {
+                providers.push(caller);
+                self.pool_providers.insert(&pool_id, &providers);
             }
-            self.platform_fee_rate = rate;
-            Ok(())
-        }
 
-        /// Update claim cooldown period (admin only)
-        #[ink(message)]
-        pub fn set_claim_cooldown(&mut self, period_seconds: u64) -> Result<(), InsuranceError> {
-            self.ensure_admin()?;
-            self.claim_cooldown_period = period_seconds;
+            self.env().emit_event(PoolCapitalized {
+                pool_id,
+                provider: caller,
+                amount,
+                timestamp: self.env().block_timestamp(),
+            });
+
             Ok(())
         }
 
         // =====================================================================
-        // QUERIES
+        // POOL RUN-OFF
         // =====================================================================
 
-        /// Get policy details
-        #[ink(message)]
-        pub fn get_policy(&self, policy_id: u64) -> Option<InsurancePolicy> {
-            self.policies.get(&policy_id)
-        }
-
-        /// Get claim details
+        /// Put a pool into run-off (admin only). No new policies or capital are
+        /// accepted; existing policies run to expiry.
         #[ink(message)]
-        pub fn get_claim(&self, claim_id: u64) -> Option<InsuranceClaim> {
-            self.claims.get(&claim_id)
-        }
+        pub fn begin_pool_runoff(&mut self, pool_id: u64) -> Result<PoolRunOff, InsuranceError> {
+            self.ensure_admin()?;
+            if !self.pools.contains(&pool_id) {
+                return Err(InsuranceError::PoolNotFound);
+            }
+            if self.pool_runoffs.contains(&pool_id) {
+                return Err(InsuranceError::PoolClosing);
+            }
+            let now = self.env().block_timestamp();
+            let (remaining_policies, remaining_exposure) = self.pool_exposure(pool_id, now);
+            let runoff = PoolRunOff {
+                pool_id,
+                status: RunOffStatus::Closing,
+                initiated_at: now,
+                remaining_policies,
+                remaining_exposure,
+                closed_at: None,
+                final_surplus: 0,
+            };
+            self.pool_runoffs.insert(&pool_id, &runoff);
 
-        /// Get pool details
-        #[ink(message)]
-        pub fn get_pool(&self, pool_id: u64) -> Option<RiskPool> {
-            self.pools.get(&pool_id)
-        }
+            self.env().emit_event(PoolRunOffStarted {
+                pool_id,
+                remaining_policies,
+                remaining_exposure,
+                timestamp: now,
+            });
 
-        /// Get risk assessment for a property
-        #[ink(message)]
-        pub fn get_risk_assessment(&self, property_id: u64) -> Option<RiskAssessment> {
-            self.risk_assessments.get(&property_id)
+            Ok(runoff)
         }
 
-        /// Get all policies for a policyholder
+        /// Expire ended policies and, once nothing remains in force, close the pool
+        /// and credit leftover capital to LPs pro-rata to their units. Callable by anyone.
         #[ink(message)]
-        pub fn get_policyholder_policies(&self, holder: AccountId) -> Vec<u64> {
-            self.policyholder_policies.get(&holder).unwrap_or_default()
-        }
+        pub fn advance_pool_runoff(&mut self, pool_id: u64) -> Result<PoolRunOff, InsuranceError> {
+            let mut runoff = self
+                .pool_runoffs
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if runoff.status == RunOffStatus::Closed {
+                return Ok(runoff);
+            }
+            let mut pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            let now = self.env().block_timestamp();
 
-        /// Get all policy IDs for a property
-        #[ink(message)]
-        pub fn get_property_policies(&self, property_id: u64) -> Vec<u64> {
-            self.property_policies.get(&property_id).unwrap_or_default()
-        }
+            for policy_id in self.pool_policies.get(&pool_id).unwrap_or_default() {
+                let Some(mut policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                if policy.status == PolicyStatus::Active && now > policy.end_time {
+                    policy.status = PolicyStatus::Expired;
+                    self.policies.insert(&policy_id, &policy);
+                    pool.active_policies = pool.active_policies.saturating_sub(1);
+                }
+            }
 
-        /// Get all claims for a policy
-        #[ink(message)]
-        pub fn get_policy_claims(&self, policy_id: u64) -> Vec<u64> {
-            self.policy_claims.get(&policy_id).unwrap_or_default()
-        }
+            let (remaining_policies, remaining_exposure) = self.pool_exposure(pool_id, now);
+            runoff.remaining_policies = remaining_policies;
+            runoff.remaining_exposure = remaining_exposure;
+
+            if remaining_policies == 0 && self.pool_claim_reserves.get(&pool_id).unwrap_or(0) == 0 {
+                let surplus = pool.available_capital;
+                let providers = self.pool_providers.get(&pool_id).unwrap_or_default();
+                let total_units = self.pool_lp_units.take(&pool_id).unwrap_or(0);
+                let mut distributed: u128 = 0;
+                for provider in providers.iter() {
+                    let key = (pool_id, *provider);
+                    let Some(mut lp) = self.liquidity_providers.get(&key) else {
+                        continue;
+                    };
+                    let share = surplus
+                        .saturating_mul(lp.lp_units)
+                        .checked_div(total_units)
+                        .unwrap_or(0);
+                    if share > 0 {
+                        let credit = self.surplus_credits.get(provider).unwrap_or(0);
+                        self.surplus_credits
+                            .insert(provider, &credit.saturating_add(share));
+                        distributed = distributed.saturating_add(share);
+                    }
+                    lp.deposited_amount = 0;
+                    lp.lp_units = 0;
+                    self.liquidity_providers.insert(&key, &lp);
+                }
 
-        /// Get insurance token details
-        #[ink(message)]
-        pub fn get_token(&self, token_id: u64) -> Option<InsuranceToken> {
-            self.insurance_tokens.get(&token_id)
-        }
+                pool.available_capital = surplus.saturating_sub(distributed);
+                pool.total_capital = pool.total_capital.saturating_sub(distributed);
+                pool.is_active = false;
+                runoff.status = RunOffStatus::Closed;
+                runoff.closed_at = Some(now);
+                runoff.final_surplus = distributed;
+
+                self.env().emit_event(PoolClosed {
+                    pool_id,
+                    final_surplus: distributed,
+                    providers: providers.len() as u32,
+                    timestamp: now,
+                });
+            }
 
-        /// Get all token listings on the secondary market
-        #[ink(message)]
-        pub fn get_token_listings(&self) -> Vec<u64> {
-            self.token_listings.clone()
+            self.pools.insert(&pool_id, &pool);
+            self.pool_runoffs.insert(&pool_id, &runoff);
+            self.record_nav(pool_id);
+            Ok(runoff)
         }
 
-        /// Get actuarial model
+        /// Withdraw LP capital from a pool in run-off, keeping enough in the pool
+        /// to cover the remaining exposure
         #[ink(message)]
-        pub fn get_actuarial_model(&self, model_id: u64) -> Option<ActuarialModel> {
-            self.actuarial_models.get(&model_id)
-        }
+        pub fn withdraw_runoff_capital(
+            &mut self,
+            pool_id: u64,
+            amount: u128,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let runoff = self
+                .pool_runoffs
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if runoff.status != RunOffStatus::Closing {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let key = (pool_id, caller);
+            let mut lp = self
+                .liquidity_providers
+                .get(&key)
+                .ok_or(InsuranceError::Unauthorized)?;
+            if amount == 0 || amount > lp.deposited_amount {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let mut pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            let (_, reserve) = self.pool_exposure(pool_id, self.env().block_timestamp());
+            if pool.available_capital.saturating_sub(amount) < reserve {
+                return Err(InsuranceError::ReserveRequired);
+            }
 
-        /// Get reinsurance agreement
-        #[ink(message)]
-        pub fn get_reinsurance_agreement(&self, agreement_id: u64) -> Option<ReinsuranceAgreement> {
-            self.reinsurance_agreements.get(&agreement_id)
-        }
+            // Burn units in proportion to the part of the deposit withdrawn
+            let burned = lp
+                .lp_units
+                .saturating_mul(amount)
+                .checked_div(lp.deposited_amount)
+                .unwrap_or(0);
+            lp.deposited_amount -= amount;
+            lp.lp_units = lp.lp_units.saturating_sub(burned);
+            self.liquidity_providers.insert(&key, &lp);
+            let pool_units = self.pool_lp_units.get(&pool_id).unwrap_or(0);
+            self.pool_lp_units
+                .insert(&pool_id, &pool_units.saturating_sub(burned));
+            pool.available_capital -= amount;
+            pool.total_capital = pool.total_capital.saturating_sub(amount);
+            self.pools.insert(&pool_id, &pool);
+            self.record_nav(pool_id);
 
-        /// Get underwriting criteria for a pool
-        #[ink(message)]
-        pub fn get_underwriting_criteria(&self, pool_id: u64) -> Option<UnderwritingCriteria> {
-            self.underwriting_criteria.get(&pool_id)
-        }
+            self.pay_from_pool(pool_id, caller, amount)?;
 
-        /// Get liquidity provider info
-        #[ink(message)]
-        pub fn get_liquidity_provider(
-            &self,
-            pool_id: u64,
-            provider: AccountId,
-        ) -> Option<PoolLiquidityProvider> {
-            self.liquidity_providers.get(&(pool_id, provider))
-        }
+            self.env().emit_event(RunOffCapitalWithdrawn {
+                pool_id,
+                provider: caller,
+                amount,
+                remaining_reserve: reserve,
+            });
 
-        /// Get total policies count
-        #[ink(message)]
-        pub fn get_policy_count(&self) -> u64 {
-            self.policy_count
+            Ok(())
         }
 
-        /// Get total claims count
         #[ink(message)]
-        pub fn get_claim_count(&self) -> u64 {
-            self.claim_count
+        pub fn get_pool_runoff(&self, pool_id: u64) -> Option<PoolRunOff> {
+            self.pool_runoffs.get(&pool_id)
         }
 
-        /// Get admin address
+        /// Unclaimed coverage of policies still in force; the reserve a run-off
+        /// pool must hold
         #[ink(message)]
-        pub fn get_admin(&self) -> AccountId {
-            self.admin
+        pub fn get_pool_remaining_exposure(&self, pool_id: u64) -> u128 {
+            self.pool_exposure(pool_id, self.env().block_timestamp()).1
         }
 
         // =====================================================================
-        // INTERNAL HELPERS
+        // RISK ASSESSMENT
         // =====================================================================
 
-        fn ensure_admin(&self) -> Result<(), InsuranceError> {
-            if self.env().caller() != self.admin {
+        /// Submit or update risk assessment for a property (oracle/admin)
+        #[ink(message)]
+        pub fn update_risk_assessment(
+            &mut self,
+            property_id: u64,
+            location_score: u32,
+            construction_score: u32,
+            age_score: u32,
+            claims_history_score: u32,
+            valid_for_seconds: u64,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
                 return Err(InsuranceError::Unauthorized);
             }
+            self.apply_risk_assessment(
+                property_id,
+                location_score,
+                construction_score,
+                age_score,
+                claims_history_score,
+                valid_for_seconds,
+            );
             Ok(())
         }
 
-        fn score_to_risk_level(score: u32) -> RiskLevel {
-            match score {
-                0..=20 => RiskLevel::VeryHigh,
-                21..=40 => RiskLevel::High,
-                41..=60 => RiskLevel::Medium,
-                61..=80 => RiskLevel::Low,
-                _ => RiskLevel::VeryLow,
-            }
+        /// Set the valuation engine risk scores are imported from (admin)
+        #[ink(message)]
+        pub fn set_risk_score_source(
+            &mut self,
+            source: Option<AccountId>,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.risk_score_source = source;
+            Ok(())
         }
 
-        fn risk_score_to_multiplier(&self, score: u32) -> u32 {
-            // score 0-100: higher score = lower risk = lower multiplier
-            // Range: 400 (very high risk) to 80 (very low risk)
-            match score {
-                0..=20 => 400,
-                21..=40 => 250,
-                41..=60 => 150,
-                61..=80 => 110,
-                _ => 80,
-            }
+        #[ink(message)]
+        pub fn get_risk_score_source(&self) -> Option<AccountId> {
+            self.risk_score_source
         }
 
-        fn coverage_type_multiplier(coverage_type: &CoverageType) -> u32 {
-            match coverage_type {
-                CoverageType::Fire => 100,
-                CoverageType::Theft => 80,
-                CoverageType::Flood => 150,
-                CoverageType::Earthquake => 200,
-                CoverageType::LiabilityDamage => 120,
-                CoverageType::NaturalDisaster => 180,
-                CoverageType::Comprehensive => 250,
+        /// Refresh a property's risk assessment from the valuation engine's derived
+        /// scores (admin/oracle). The engine must itself be an authorized oracle.
+        #[ink(message)]
+        pub fn import_risk_assessment(
+            &mut self,
+            property_id: u64,
+            valid_for_seconds: u64,
+        ) -> Result<(), InsuranceError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::RiskScoreSource;
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let source = self
+                .risk_score_source
+                .ok_or(InsuranceError::RiskSourceUnavailable)?;
+            if !self.authorized_oracles.get(&source).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
             }
+            let engine: ink::contract_ref!(RiskScoreSource) =
+                FromAccountId::from_account_id(source);
+            let scores = match engine.call().derive_risk_scores(property_id).try_invoke() {
+                Ok(Ok(Some(scores))) => scores,
+                _ => return Err(InsuranceError::RiskSourceUnavailable),
+            };
+            self.apply_risk_assessment(
+                property_id,
+                scores.location_score,
+                scores.construction_score,
+                scores.age_score,
+                scores.claims_history_score,
+                valid_for_seconds,
+            );
+            Ok(())
         }
 
-        fn internal_mint_token(
+        fn apply_risk_assessment(
             &mut self,
-            policy_id: u64,
-            owner: AccountId,
-            face_value: u128,
-        ) -> Result<u64, InsuranceError> {
-            let token_id = self.token_count + 1;
-            self.token_count = token_id;
+            property_id: u64,
+            location_score: u32,
+            construction_score: u32,
+            age_score: u32,
+            claims_history_score: u32,
+            valid_for_seconds: u64,
+        ) {
+            let overall = (location_score
+                .saturating_add(construction_score)
+                .saturating_add(age_score)
+                .saturating_add(claims_history_score))
+                / 4;
 
-            let token = InsuranceToken {
-                token_id,
-                policy_id,
-                owner,
-                face_value,
-                is_tradeable: true,
-                created_at: self.env().block_timestamp(),
-                listed_price: None,
+            let risk_level = Self::score_to_risk_level(overall);
+
+            let now = self.env().block_timestamp();
+            let assessment = RiskAssessment {
+                property_id,
+                location_risk_score: location_score,
+                construction_risk_score: construction_score,
+                age_risk_score: age_score,
+                claims_history_score,
+                overall_risk_score: overall,
+                risk_level: risk_level.clone(),
+                assessed_at: now,
+                valid_until: now.saturating_add(valid_for_seconds),
             };
 
-            self.insurance_tokens.insert(&token_id, &token);
+            self.risk_assessments.insert(&property_id, &assessment);
 
-            self.env().emit_event(InsuranceTokenMinted {
-                token_id,
-                policy_id,
-                owner,
-                face_value,
+            self.env().emit_event(RiskAssessmentUpdated {
+                property_id,
+                overall_score: overall,
+                risk_level,
+                timestamp: now,
             });
+        }
 
-            Ok(token_id)
+        /// Calculate premium for a policy
+        #[ink(message)]
+        pub fn calculate_premium(
+            &self,
+            property_id: u64,
+            coverage_amount: u128,
+            coverage_type: CoverageType,
+        ) -> Result<PremiumCalculation, InsuranceError> {
+            self.calculate_premium_with_copay(property_id, coverage_amount, coverage_type, 0)
         }
 
-        fn execute_payout(
+        /// Calculate premium for a policy whose holder retains `copay_bps` of each
+        /// loss after the deductible; the premium falls by the same share
+        #[ink(message)]
+        pub fn calculate_premium_with_copay(
+            &self,
+            property_id: u64,
+            coverage_amount: u128,
+            coverage_type: CoverageType,
+            copay_bps: u32,
+        ) -> Result<PremiumCalculation, InsuranceError> {
+            if copay_bps > MAX_COPAY_BPS {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let assessment = self
+                .risk_assessments
+                .get(&property_id)
+                .ok_or(InsuranceError::PropertyNotInsurable)?;
+
+            // Base rate in basis points: 150 = 1.50%, scaled by the adopted model's
+            // expected loss ratio against the target (between 0.5x and 2x)
+            let base_rate: u32 = match self
+                .adopted_models
+                .get(&coverage_type)
+                .and_then(|model_id| self.actuarial_models.get(&model_id))
+            {
+                Some(model) => {
+                    let factor = (model.expected_loss_ratio as u64 * 10_000)
+                        .checked_div(self.target_loss_ratio as u64)
+                        .unwrap_or(10_000)
+                        .clamp(5_000, 20_000);
+                    (150 * factor / 10_000) as u32
+                }
+                None => 150,
+            };
+
+            // Risk multiplier based on score (100 = 1.0x, 200 = 2.0x)
+            let risk_multiplier = self.risk_score_to_multiplier(assessment.overall_risk_score);
+
+            // Coverage type multiplier
+            let coverage_multiplier = Self::coverage_type_multiplier(&coverage_type);
+
+            // Annual premium = coverage * base_rate * risk_mult * coverage_mult / 1_000_000
+            let gross_premium = coverage_amount
+                .saturating_mul(base_rate as u128)
+                .saturating_mul(risk_multiplier as u128)
+                .saturating_mul(coverage_multiplier as u128)
+                / 1_000_000_000_000u128; // 3 basis point divisors × 10000 each
+
+            // Seasonal adjustment for the month the quote is made in
+            let seasonal_curve_version = self
+                .seasonal_curve_versions
+                .get(&coverage_type)
+                .unwrap_or(0);
+            let seasonal_factor = self.seasonal_factor_at(
+                &coverage_type,
+                seasonal_curve_version,
+                self.env().block_timestamp(),
+            );
+            let gross_premium = gross_premium.saturating_mul(seasonal_factor as u128) / 10_000;
+
+            // Verified, unexpired mitigation measures discount the premium
+            let mitigation_discount = self.get_mitigation_discount(property_id, coverage_type);
+            let annual_premium = gross_premium
+                .saturating_mul(10_000u128.saturating_sub(mitigation_discount as u128))
+                / 10_000;
+
+            // Green-discount schedule for the property's verified ESG tier
+            let green_discount = self.get_green_discount(property_id);
+            let annual_premium = annual_premium
+                .saturating_mul(10_000u128.saturating_sub(green_discount as u128))
+                / 10_000;
+
+            // The insurer's expected share of each loss shrinks with the co-pay
+            let annual_premium = annual_premium
+                .saturating_mul(10_000u128.saturating_sub(copay_bps as u128))
+                / 10_000;
+
+            let monthly_premium = annual_premium / 12;
+
+            // Deductible: 5% of coverage_amount, scaled by risk
+            let deductible = coverage_amount
+                .saturating_mul(500u128)
+                .saturating_mul(risk_multiplier as u128)
+                / 10_000_000u128;
+
+            Ok(PremiumCalculation {
+                base_rate,
+                risk_multiplier,
+                coverage_multiplier,
+                annual_premium,
+                monthly_premium,
+                deductible,
+                mitigation_discount,
+                green_discount,
+                seasonal_factor,
+                seasonal_curve_version,
+                copay_bps,
+            })
+        }
+
+        // =====================================================================
+        // SEASONAL RISK CURVES
+        // =====================================================================
+
+        /// Publish a new monthly adjustment curve for a coverage type (admin/oracle).
+        /// Factors are basis points, January first; returns the curve version.
+        #[ink(message)]
+        pub fn set_seasonal_curve(
             &mut self,
-            claim_id: u64,
-            policy_id: u64,
-            recipient: AccountId,
-            amount: u128,
-        ) -> Result<(), InsuranceError> {
-            if amount == 0 {
-                return Ok(());
+            coverage_type: CoverageType,
+            monthly_factors: Vec<u32>,
+        ) -> Result<u32, InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
             }
+            if monthly_factors.len() != 12
+                || monthly_factors
+                    .iter()
+                    .any(|factor| *factor == 0 || *factor > 50_000)
+            {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let version = self
+                .seasonal_curve_versions
+                .get(&coverage_type)
+                .unwrap_or(0)
+                + 1;
+            self.seasonal_curves.insert(
+                &(coverage_type.clone(), version),
+                &SeasonalCurve {
+                    version,
+                    monthly_factors,
+                    effective_from: self.env().block_timestamp(),
+                    published_by: caller,
+                },
+            );
+            self.seasonal_curve_versions
+                .insert(&coverage_type, &version);
+            Ok(version)
+        }
 
-            let mut policy = self
-                .policies
-                .get(&policy_id)
-                .ok_or(InsuranceError::PolicyNotFound)?;
-            let mut pool = self
-                .pools
-                .get(&policy.pool_id)
-                .ok_or(InsuranceError::PoolNotFound)?;
+        /// Curve `version` for a coverage type, current or superseded
+        #[ink(message)]
+        pub fn get_seasonal_curve(
+            &self,
+            coverage_type: CoverageType,
+            version: u32,
+        ) -> Option<SeasonalCurve> {
+            self.seasonal_curves.get(&(coverage_type, version))
+        }
 
-            // Check if reinsurance is needed
-            let use_reinsurance = amount > pool.reinsurance_threshold;
+        #[ink(message)]
+        pub fn get_seasonal_curve_version(&self, coverage_type: CoverageType) -> u32 {
+            self.seasonal_curve_versions
+                .get(&coverage_type)
+                .unwrap_or(0)
+        }
 
-            if use_reinsurance {
-                // Try to recover excess from reinsurance
-                self.try_reinsurance_recovery(claim_id, policy_id, amount)?;
+        /// Factor curve `version` applies at `timestamp` (10_000 without a curve);
+        /// reproduces the seasonal component of a past quote
+        #[ink(message)]
+        pub fn get_seasonal_factor(
+            &self,
+            coverage_type: CoverageType,
+            version: u32,
+            timestamp: u64,
+        ) -> u32 {
+            self.seasonal_factor_at(&coverage_type, version, timestamp)
+        }
+
+        fn seasonal_factor_at(
+            &self,
+            coverage_type: &CoverageType,
+            version: u32,
+            timestamp: u64,
+        ) -> u32 {
+            self.seasonal_curves
+                .get(&(coverage_type.clone(), version))
+                .and_then(|curve| {
+                    curve
+                        .monthly_factors
+                        .get(Self::month_of(timestamp))
+                        .copied()
+                })
+                .unwrap_or(10_000)
+        }
+
+        /// Zero-based calendar month (UTC) of a timestamp in seconds
+        fn month_of(timestamp: u64) -> usize {
+            // Days-to-civil conversion; the computed year starts in March
+            let z = timestamp / 86_400 + 719_468;
+            let doe = z % 146_097;
+            let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+            let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+            let march_based = (5 * doy + 2) / 153;
+            ((march_based + 2) % 12) as usize
+        }
+
+        // =====================================================================
+        // MITIGATION CREDITS
+        // =====================================================================
+
+        /// Attach evidence of a mitigation measure for a property; it earns a
+        /// discount once a notary verifies it
+        #[ink(message)]
+        pub fn attach_mitigation_document(
+            &mut self,
+            property_id: u64,
+            measure: MitigationMeasure,
+            document_hash: Hash,
+            valid_until: u64,
+        ) -> Result<u64, InsuranceError> {
+            let now = self.env().block_timestamp();
+            if valid_until <= now {
+                return Err(InsuranceError::InvalidParameters);
             }
+            let document_id = self.mitigation_document_count + 1;
+            self.mitigation_document_count = document_id;
+            self.mitigation_documents.insert(
+                &document_id,
+                &MitigationDocument {
+                    document_id,
+                    property_id,
+                    measure,
+                    document_hash,
+                    submitted_by: self.env().caller(),
+                    submitted_at: now,
+                    valid_until,
+                    verified_by: None,
+                },
+            );
+            let mut docs = self
+                .property_mitigations
+                .get(&property_id)
+                .unwrap_or_default();
+            docs.push(document_id);
+            self.property_mitigations.insert(&property_id, &docs);
 
-            if pool.available_capital < amount {
-                return Err(InsuranceError::InsufficientPoolFunds);
+            self.env().emit_event(MitigationDocumentAttached {
+                document_id,
+                property_id,
+                measure,
+                valid_until,
+            });
+            Ok(document_id)
+        }
+
+        /// Notary attests that a mitigation document is genuine
+        #[ink(message)]
+        pub fn verify_mitigation_document(
+            &mut self,
+            document_id: u64,
+        ) -> Result<(), InsuranceError> {
+            let notary = self.env().caller();
+            if !self.authorized_notaries.get(&notary).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
             }
+            let mut doc = self
+                .mitigation_documents
+                .get(&document_id)
+                .ok_or(InsuranceError::DocumentNotFound)?;
+            if doc.verified_by.is_some() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            doc.verified_by = Some(notary);
+            self.mitigation_documents.insert(&document_id, &doc);
 
-            pool.available_capital = pool.available_capital.saturating_sub(amount);
-            pool.total_claims_paid += amount;
-            self.pools.insert(&policy.pool_id, &pool);
+            self.env().emit_event(MitigationDocumentVerified {
+                document_id,
+                property_id: doc.property_id,
+                notary,
+            });
+            Ok(())
+        }
 
-            // Update policy
-            policy.total_claimed += amount;
-            if policy.total_claimed >= policy.coverage_amount {
-                policy.status = PolicyStatus::Claimed;
+        /// Set the premium discount a measure earns for a coverage type (admin)
+        #[ink(message)]
+        pub fn set_mitigation_discount(
+            &mut self,
+            coverage_type: CoverageType,
+            measure: MitigationMeasure,
+            discount_bps: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if discount_bps > 10_000 {
+                return Err(InsuranceError::InvalidParameters);
             }
-            self.policies.insert(&policy_id, &policy);
+            self.mitigation_discounts
+                .insert(&(coverage_type, measure), &discount_bps);
+            Ok(())
+        }
 
-            // Update cooldown
-            self.claim_cooldowns
-                .insert(&policy.property_id, &self.env().block_timestamp());
+        /// Combined discount (basis points) a property currently earns for a
+        /// coverage type; each measure counts once and the total is capped at 50%
+        #[ink(message)]
+        pub fn get_mitigation_discount(
+            &self,
+            property_id: u64,
+            coverage_type: CoverageType,
+        ) -> u32 {
+            let now = self.env().block_timestamp();
+            let mut measures: Vec<MitigationMeasure> = Vec::new();
+            let mut discount: u32 = 0;
+            for document_id in self
+                .property_mitigations
+                .get(&property_id)
+                .unwrap_or_default()
+            {
+                let Some(doc) = self.mitigation_documents.get(&document_id) else {
+                    continue;
+                };
+                if doc.verified_by.is_none()
+                    || now > doc.valid_until
+                    || measures.contains(&doc.measure)
+                {
+                    continue;
+                }
+                measures.push(doc.measure);
+                discount = discount.saturating_add(
+                    self.mitigation_discounts
+                        .get(&(coverage_type.clone(), doc.measure))
+                        .unwrap_or(0),
+                );
+            }
+            discount.min(5_000)
+        }
 
-            // Update claim status
-            if let Some(mut claim) = self.claims.get(&claim_id) {
-                claim.status = ClaimStatus::Paid;
-                self.claims.insert(&claim_id, &claim);
+        #[ink(message)]
+        pub fn get_mitigation_document(&self, document_id: u64) -> Option<MitigationDocument> {
+            self.mitigation_documents.get(&document_id)
+        }
+
+        #[ink(message)]
+        pub fn get_property_mitigations(&self, property_id: u64) -> Vec<u64> {
+            self.property_mitigations
+                .get(&property_id)
+                .unwrap_or_default()
+        }
+
+        // =====================================================================
+        // POOL DENOMINATIONS
+        // =====================================================================
+
+        /// Approve a PSP22 stablecoin pools may be denominated in (admin only)
+        #[ink(message)]
+        pub fn register_denomination_asset(
+            &mut self,
+            token: AccountId,
+            symbol: String,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if symbol.is_empty() {
+                return Err(InsuranceError::InvalidParameters);
             }
+            self.denomination_assets.insert(&token, &symbol);
+            Ok(())
+        }
 
-            self.env().emit_event(PayoutExecuted {
-                claim_id,
-                recipient,
-                amount,
-                timestamp: self.env().block_timestamp(),
+        /// Set the asset a pool operates in (admin only). Only possible before the
+        /// pool has taken capital or premiums, so balances never change asset.
+        #[ink(message)]
+        pub fn set_pool_denomination(
+            &mut self,
+            pool_id: u64,
+            denomination: Denomination,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            let pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if pool.total_capital > 0 || pool.total_premiums_collected > 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            if let Denomination::Asset(token) = denomination {
+                if !self.denomination_assets.contains(&token) {
+                    return Err(InsuranceError::AssetNotRegistered);
+                }
+            }
+            self.pool_denominations.insert(&pool_id, &denomination);
+            self.env().emit_event(PoolDenominationSet {
+                pool_id,
+                denomination,
             });
-
             Ok(())
         }
 
-        fn try_reinsurance_recovery(
-            &mut self,
-            claim_id: u64,
-            _policy_id: u64,
-            amount: u128,
-        ) -> Result<(), InsuranceError> {
-            // Look for an active reinsurance agreement
-            for i in 1..=self.reinsurance_count {
-                if let Some(mut agreement) = self.reinsurance_agreements.get(&i) {
-                    if !agreement.is_active {
-                        continue;
-                    }
-                    let now = self.env().block_timestamp();
-                    if now > agreement.end_time {
-                        continue;
-                    }
+        #[ink(message)]
+        pub fn get_pool_denomination(&self, pool_id: u64) -> Denomination {
+            self.pool_denomination(pool_id)
+        }
+
+        #[ink(message)]
+        pub fn get_denomination_asset(&self, token: AccountId) -> Option<String> {
+            self.denomination_assets.get(&token)
+        }
+
+        /// Platform fees held in an asset
+        #[ink(message)]
+        pub fn get_asset_treasury(&self, denomination: Denomination) -> u128 {
+            self.asset_treasuries.get(&denomination).unwrap_or(0)
+        }
+
+        /// Stablecoin owed to an account from SLA compensation, surplus sharing or levies
+        #[ink(message)]
+        pub fn get_asset_credit(&self, account: AccountId, token: AccountId) -> u128 {
+            self.asset_credits.get(&(account, token)).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn withdraw_asset_credit(&mut self, token: AccountId) -> Result<u128, InsuranceError> {
+            let caller = self.env().caller();
+            let credit = self.asset_credits.get(&(caller, token)).unwrap_or(0);
+            if credit == 0 {
+                return Ok(0);
+            }
+            self.asset_credits.insert(&(caller, token), &0u128);
+            self.transfer_out(Denomination::Asset(token), caller, credit)?;
+            Ok(credit)
+        }
+
+        /// Approve converting treasury funds of one asset into another (admin only).
+        /// `counterparty` settles by delivering `buy_amount` and receiving `sell_amount`.
+        #[ink(message)]
+        pub fn approve_asset_swap(
+            &mut self,
+            counterparty: AccountId,
+            sell: Denomination,
+            sell_amount: u128,
+            buy: Denomination,
+            buy_amount: u128,
+        ) -> Result<u64, InsuranceError> {
+            self.ensure_admin()?;
+            if sell == buy || sell_amount == 0 || buy_amount == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            for denomination in [sell, buy] {
+                if let Denomination::Asset(token) = denomination {
+                    if !self.denomination_assets.contains(&token) {
+                        return Err(InsuranceError::AssetNotRegistered);
+                    }
+                }
+            }
+            if self.asset_treasuries.get(&sell).unwrap_or(0) < sell_amount {
+                return Err(InsuranceError::InsufficientPoolFunds);
+            }
+            let swap_id = self.asset_swap_count + 1;
+            self.asset_swap_count = swap_id;
+            self.asset_swaps.insert(
+                &swap_id,
+                &AssetSwap {
+                    swap_id,
+                    counterparty,
+                    sell,
+                    sell_amount,
+                    buy,
+                    buy_amount,
+                    approved_at: self.env().block_timestamp(),
+                    executed: false,
+                },
+            );
+            Ok(swap_id)
+        }
+
+        /// Withdraw an approved swap that has not settled (admin only)
+        #[ink(message)]
+        pub fn cancel_asset_swap(&mut self, swap_id: u64) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            match self.asset_swaps.get(&swap_id) {
+                Some(swap) if !swap.executed => {
+                    self.asset_swaps.remove(&swap_id);
+                    Ok(())
+                }
+                _ => Err(InsuranceError::AssetSwapNotFound),
+            }
+        }
+
+        /// Counterparty settles an approved swap. A native `buy` is paid as the
+        /// transferred value; a stablecoin `buy` is pulled from the counterparty.
+        #[ink(message, payable)]
+        pub fn execute_asset_swap(&mut self, swap_id: u64) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let mut swap = self
+                .asset_swaps
+                .get(&swap_id)
+                .ok_or(InsuranceError::AssetSwapNotFound)?;
+            if swap.executed {
+                return Err(InsuranceError::AssetSwapNotFound);
+            }
+            if caller != swap.counterparty {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let sell_treasury = self.asset_treasuries.get(&swap.sell).unwrap_or(0);
+            if sell_treasury < swap.sell_amount {
+                return Err(InsuranceError::InsufficientPoolFunds);
+            }
+            match swap.buy {
+                Denomination::Native => {
+                    if self.env().transferred_value() != swap.buy_amount {
+                        return Err(InsuranceError::InvalidParameters);
+                    }
+                }
+                Denomination::Asset(token) => self.pull_asset(token, caller, swap.buy_amount)?,
+            }
+            self.asset_treasuries
+                .insert(&swap.sell, &(sell_treasury - swap.sell_amount));
+            let buy_treasury = self.asset_treasuries.get(&swap.buy).unwrap_or(0);
+            self.asset_treasuries
+                .insert(&swap.buy, &buy_treasury.saturating_add(swap.buy_amount));
+            swap.executed = true;
+            self.asset_swaps.insert(&swap_id, &swap);
+            self.transfer_out(swap.sell, caller, swap.sell_amount)?;
+
+            self.env().emit_event(AssetSwapExecuted {
+                swap_id,
+                counterparty: caller,
+                sell: swap.sell,
+                sell_amount: swap.sell_amount,
+                buy: swap.buy,
+                buy_amount: swap.buy_amount,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_asset_swap(&self, swap_id: u64) -> Option<AssetSwap> {
+            self.asset_swaps.get(&swap_id)
+        }
+
+        fn pool_denomination(&self, pool_id: u64) -> Denomination {
+            self.pool_denominations.get(&pool_id).unwrap_or_default()
+        }
+
+        /// Pay `amount` of a pool's funds out in the pool's asset
+        fn pay_from_pool(
+            &mut self,
+            pool_id: u64,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), InsuranceError> {
+            self.transfer_out(self.pool_denomination(pool_id), to, amount)
+        }
+
+        /// Credit a stablecoin pool's funds to an account's withdrawable asset
+        /// balance. Returns false for native pools, whose callers keep their own ledgers.
+        fn credit_in_pool_asset(&mut self, pool_id: u64, account: AccountId, amount: u128) -> bool {
+            let Denomination::Asset(token) = self.pool_denomination(pool_id) else {
+                return false;
+            };
+            let credit = self.asset_credits.get(&(account, token)).unwrap_or(0);
+            self.asset_credits
+                .insert(&(account, token), &credit.saturating_add(amount));
+            true
+        }
+
+        fn transfer_out(
+            &mut self,
+            denomination: Denomination,
+            to: AccountId,
+            amount: u128,
+        ) -> Result<(), InsuranceError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::Psp22;
+            let token = match denomination {
+                Denomination::Native => {
+                    return self
+                        .env()
+                        .transfer(to, amount)
+                        .map_err(|_| InsuranceError::TransferFailed)
+                }
+                Denomination::Asset(token) => token,
+            };
+            let mut asset: ink::contract_ref!(Psp22) = FromAccountId::from_account_id(token);
+            match asset
+                .call_mut()
+                .transfer(to, amount, Vec::new())
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(InsuranceError::TransferFailed),
+            }
+        }
+
+        /// Pull `amount` of a stablecoin from `from` into the contract
+        fn pull_asset(
+            &mut self,
+            token: AccountId,
+            from: AccountId,
+            amount: u128,
+        ) -> Result<(), InsuranceError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::Psp22;
+            let mut asset: ink::contract_ref!(Psp22) = FromAccountId::from_account_id(token);
+            match asset
+                .call_mut()
+                .transfer_from(from, self.env().account_id(), amount, Vec::new())
+                .try_invoke()
+            {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(InsuranceError::TransferFailed),
+            }
+        }
+
+        // =====================================================================
+        // ESG PROGRAM
+        // =====================================================================
+
+        /// Attest a property's energy rating (1 = A .. 7 = G) and materials
+        /// certification, replacing any earlier attestation (admin or oracle).
+        /// The derived tier applies once a notary verifies the supporting document.
+        #[ink(message)]
+        pub fn attach_esg_profile(
+            &mut self,
+            property_id: u64,
+            energy_rating: u8,
+            materials_certified: bool,
+            document_hash: Hash,
+            valid_until: u64,
+        ) -> Result<EsgTier, InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let now = self.env().block_timestamp();
+            if !(1..=7).contains(&energy_rating) || valid_until <= now {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let tier = match (energy_rating, materials_certified) {
+                (1..=2, true) => EsgTier::Gold,
+                (1..=3, _) => EsgTier::Silver,
+                (4..=5, _) => EsgTier::Bronze,
+                _ => EsgTier::Unrated,
+            };
+            self.esg_profiles.insert(
+                &property_id,
+                &EsgProfile {
+                    property_id,
+                    energy_rating,
+                    materials_certified,
+                    tier,
+                    document_hash,
+                    submitted_by: caller,
+                    submitted_at: now,
+                    valid_until,
+                    verified_by: None,
+                },
+            );
+
+            self.env().emit_event(EsgProfileAttached {
+                property_id,
+                tier,
+                valid_until,
+            });
+            Ok(tier)
+        }
+
+        /// Notary attests that a property's ESG document is genuine. The hash of
+        /// the document they reviewed must match the attached one, so a profile
+        /// replaced in the meantime is not verified by mistake.
+        #[ink(message)]
+        pub fn verify_esg_profile(
+            &mut self,
+            property_id: u64,
+            document_hash: Hash,
+        ) -> Result<(), InsuranceError> {
+            let notary = self.env().caller();
+            if !self.authorized_notaries.get(&notary).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let mut profile = self
+                .esg_profiles
+                .get(&property_id)
+                .ok_or(InsuranceError::DocumentNotFound)?;
+            if profile.verified_by.is_some() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            if profile.document_hash != document_hash {
+                return Err(InsuranceError::DocumentMismatch);
+            }
+            profile.verified_by = Some(notary);
+            self.esg_profiles.insert(&property_id, &profile);
+
+            self.env().emit_event(EsgProfileVerified {
+                property_id,
+                notary,
+                tier: profile.tier,
+            });
+            Ok(())
+        }
+
+        /// Set the premium discount a tier earns (admin)
+        #[ink(message)]
+        pub fn set_green_discount(
+            &mut self,
+            tier: EsgTier,
+            discount_bps: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if discount_bps > 10_000 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.green_discounts.insert(&tier, &discount_bps);
+            Ok(())
+        }
+
+        /// Restrict a pool to properties at or above `min_tier`, or lift the
+        /// restriction with `None` (admin). Applies to new policies only.
+        #[ink(message)]
+        pub fn set_pool_esg_filter(
+            &mut self,
+            pool_id: u64,
+            min_tier: Option<EsgTier>,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if !self.pools.contains(&pool_id) {
+                return Err(InsuranceError::PoolNotFound);
+            }
+            match min_tier {
+                Some(tier) => self.pool_esg_filters.insert(&pool_id, &tier),
+                None => {
+                    self.pool_esg_filters.remove(&pool_id);
+                    None
+                }
+            };
+            Ok(())
+        }
+
+        /// Tier of the property's verified, unexpired ESG attestation
+        #[ink(message)]
+        pub fn get_esg_tier(&self, property_id: u64) -> EsgTier {
+            match self.esg_profiles.get(&property_id) {
+                Some(profile)
+                    if profile.verified_by.is_some()
+                        && self.env().block_timestamp() <= profile.valid_until =>
+                {
+                    profile.tier
+                }
+                _ => EsgTier::Unrated,
+            }
+        }
+
+        /// Discount (basis points) the property currently earns under the green schedule
+        #[ink(message)]
+        pub fn get_green_discount(&self, property_id: u64) -> u32 {
+            self.green_discounts
+                .get(&self.get_esg_tier(property_id))
+                .unwrap_or(0)
+        }
+
+        #[ink(message)]
+        pub fn get_esg_profile(&self, property_id: u64) -> Option<EsgProfile> {
+            self.esg_profiles.get(&property_id)
+        }
+
+        #[ink(message)]
+        pub fn get_pool_esg_filter(&self, pool_id: u64) -> Option<EsgTier> {
+            self.pool_esg_filters.get(&pool_id)
+        }
+
+        // =====================================================================
+        // POLICY TEMPLATES
+        // =====================================================================
+
+        /// Register the policy terms for a jurisdiction (admin only). Supersedes
+        /// the jurisdiction's previous template; policies issued under it keep
+        /// their original terms.
+        #[ink(message)]
+        pub fn register_policy_template(
+            &mut self,
+            jurisdiction: String,
+            min_coverage: u128,
+            mandatory_perils: Vec<CoverageType>,
+            max_deductible: u128,
+            cancellation_notice: u64,
+        ) -> Result<u64, InsuranceError> {
+            self.ensure_admin()?;
+            if jurisdiction.is_empty() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let version = self
+                .jurisdiction_templates
+                .get(&jurisdiction)
+                .and_then(|id| self.policy_templates.get(&id))
+                .map_or(1, |previous| previous.version + 1);
+            let template_id = self.policy_template_count + 1;
+            self.policy_template_count = template_id;
+            self.policy_templates.insert(
+                &template_id,
+                &PolicyTemplate {
+                    template_id,
+                    jurisdiction: jurisdiction.clone(),
+                    version,
+                    min_coverage,
+                    mandatory_perils,
+                    max_deductible,
+                    cancellation_notice,
+                    created_at: self.env().block_timestamp(),
+                },
+            );
+            self.jurisdiction_templates
+                .insert(&jurisdiction, &template_id);
+
+            self.env().emit_event(PolicyTemplateRegistered {
+                template_id,
+                jurisdiction,
+                version,
+            });
+            Ok(template_id)
+        }
+
+        #[ink(message)]
+        pub fn get_policy_template(&self, template_id: u64) -> Option<PolicyTemplate> {
+            self.policy_templates.get(&template_id)
+        }
+
+        /// Current template version for a jurisdiction
+        #[ink(message)]
+        pub fn get_jurisdiction_template(&self, jurisdiction: String) -> Option<PolicyTemplate> {
+            self.jurisdiction_templates
+                .get(&jurisdiction)
+                .and_then(|id| self.policy_templates.get(&id))
+        }
+
+        /// Template a policy was issued under
+        #[ink(message)]
+        pub fn get_policy_template_id(&self, policy_id: u64) -> Option<u64> {
+            self.policy_template_ids.get(&policy_id)
+        }
+
+        /// Resolve the template cited by a new policy and check it is current
+        fn citable_template(&self, template_id: u64) -> Result<PolicyTemplate, InsuranceError> {
+            let template = self
+                .policy_templates
+                .get(&template_id)
+                .ok_or(InsuranceError::TemplateNotFound)?;
+            if self.jurisdiction_templates.get(&template.jurisdiction) != Some(template_id) {
+                return Err(InsuranceError::TemplateSuperseded);
+            }
+            Ok(template)
+        }
+
+        // =====================================================================
+        // PREMIUM LEVIES
+        // =====================================================================
+
+        /// Set the premium taxes and levies charged on policies for properties in a
+        /// jurisdiction (admin only). An empty schedule removes them.
+        #[ink(message)]
+        pub fn set_levy_schedule(
+            &mut self,
+            jurisdiction: String,
+            levies: Vec<LevyRate>,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            let total_rate_bp = levies
+                .iter()
+                .fold(0u32, |total, levy| total.saturating_add(levy.rate_bp));
+            if jurisdiction.is_empty()
+                || levies.iter().any(|levy| levy.rate_bp == 0)
+                || total_rate_bp.saturating_add(self.platform_fee_rate) > 10_000
+            {
+                return Err(InsuranceError::LevyScheduleInvalid);
+            }
+            if levies.is_empty() {
+                self.levy_schedules.remove(&jurisdiction);
+                self.levy_jurisdictions
+                    .retain(|known| *known != jurisdiction);
+            } else {
+                self.levy_schedules.insert(&jurisdiction, &levies);
+                if !self.levy_jurisdictions.contains(&jurisdiction) {
+                    self.levy_jurisdictions.push(jurisdiction.clone());
+                }
+            }
+            self.env().emit_event(LevyScheduleSet {
+                jurisdiction,
+                total_rate_bp,
+            });
+            Ok(())
+        }
+
+        /// Record the jurisdiction a property is taxed in (admin only). Policies on
+        /// the property must cite its template, and its levies apply to them.
+        #[ink(message)]
+        pub fn set_property_jurisdiction(
+            &mut self,
+            property_id: u64,
+            jurisdiction: String,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if jurisdiction.is_empty() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.property_jurisdictions
+                .insert(&property_id, &jurisdiction);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_property_jurisdiction(&self, property_id: u64) -> Option<String> {
+            self.property_jurisdictions.get(&property_id)
+        }
+
+        /// Record the age, appraised value and location underwriting checks a
+        /// property against (admin only)
+        #[ink(message)]
+        pub fn set_property_profile(
+            &mut self,
+            property_id: u64,
+            profile: PropertyProfile,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if profile.location.is_empty() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.property_profiles.insert(&property_id, &profile);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_property_profile(&self, property_id: u64) -> Option<PropertyProfile> {
+            self.property_profiles.get(&property_id)
+        }
+
+        #[ink(message)]
+        pub fn get_levy_schedule(&self, jurisdiction: String) -> Vec<LevyRate> {
+            self.levy_schedules.get(&jurisdiction).unwrap_or_default()
+        }
+
+        /// Levies collected in a jurisdiction during a statistics period, in one asset
+        #[ink(message)]
+        pub fn get_levy_report(
+            &self,
+            jurisdiction: String,
+            period: u64,
+            denomination: Denomination,
+        ) -> LevyReport {
+            self.levy_reports
+                .get(&(jurisdiction, period, denomination))
+                .unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn get_levy_balance(&self, account: AccountId) -> u128 {
+            self.levy_balances.get(&account).unwrap_or(0)
+        }
+
+        /// Remit the levies owed to the caller's levy account
+        #[ink(message)]
+        pub fn withdraw_levy_balance(&mut self) -> Result<u128, InsuranceError> {
+            let caller = self.env().caller();
+            let balance = self.levy_balances.get(&caller).unwrap_or(0);
+            if balance == 0 {
+                return Ok(0);
+            }
+            self.levy_balances.insert(&caller, &0u128);
+            self.env()
+                .transfer(caller, balance)
+                .map_err(|_| InsuranceError::TransferFailed)?;
+            Ok(balance)
+        }
+
+        /// Highest combined levy rate of any jurisdiction, in basis points
+        fn max_levy_rate_bp(&self) -> u32 {
+            self.levy_jurisdictions
+                .iter()
+                .map(|jurisdiction| {
+                    self.levy_schedules
+                        .get(jurisdiction)
+                        .unwrap_or_default()
+                        .iter()
+                        .fold(0u32, |total, levy| total.saturating_add(levy.rate_bp))
+                })
+                .max()
+                .unwrap_or(0)
+        }
+
+        /// Withhold the jurisdiction's levies from a premium collected by a pool,
+        /// crediting each levy account in the pool's asset and the period report.
+        /// Returns the per-levy charges.
+        fn collect_levies(
+            &mut self,
+            jurisdiction: &str,
+            pool_id: u64,
+            paid: u128,
+        ) -> Vec<(String, u128)> {
+            let schedule = self
+                .levy_schedules
+                .get(&String::from(jurisdiction))
+                .unwrap_or_default();
+            if schedule.is_empty() {
+                return Vec::new();
+            }
+            let denomination = self.pool_denomination(pool_id);
+            let period = self.env().block_timestamp() / self.statistics_period;
+            let key = (String::from(jurisdiction), period, denomination);
+            let mut report = self.levy_reports.get(&key).unwrap_or_default();
+            let mut charges = Vec::new();
+            for levy in schedule {
+                let amount = paid.saturating_mul(levy.rate_bp as u128) / 10_000;
+                if !self.credit_in_pool_asset(pool_id, levy.recipient, amount) {
+                    let balance = self.levy_balances.get(&levy.recipient).unwrap_or(0);
+                    self.levy_balances
+                        .insert(&levy.recipient, &balance.saturating_add(amount));
+                }
+                match report
+                    .levies
+                    .iter_mut()
+                    .find(|(name, _)| *name == levy.name)
+                {
+                    Some((_, total)) => *total = total.saturating_add(amount),
+                    None => report.levies.push((levy.name.clone(), amount)),
+                }
+                report.total_levied = report.total_levied.saturating_add(amount);
+                charges.push((levy.name, amount));
+            }
+            report.policies += 1;
+            report.premium_base = report.premium_base.saturating_add(paid);
+            self.levy_reports.insert(&key, &report);
+            charges
+        }
+
+        // =====================================================================
+        // POLICY MANAGEMENT
+        // =====================================================================
+
+        /// Create an insurance policy (policyholder pays premium). `terms.template_id`
+        /// cites the current template of the property's jurisdiction, whose terms the
+        /// policy must meet.
+        #[ink(message, payable)]
+        pub fn create_policy(
+            &mut self,
+            terms: PolicyTerms,
+            payout_mode: Option<PayoutMode>,
+        ) -> Result<u64, InsuranceError> {
+            let caller = self.env().caller();
+            let paid = self.env().transferred_value();
+            self.issue_policy(
+                caller,
+                paid,
+                Denomination::Native,
+                terms,
+                payout_mode.unwrap_or(PayoutMode::Policyholder),
+            )
+        }
+
+        /// Create a policy in a stablecoin pool; the premium is pulled from the
+        /// caller in the pool's token
+        #[ink(message)]
+        pub fn create_policy_in_asset(
+            &mut self,
+            terms: PolicyTerms,
+            premium: u128,
+        ) -> Result<u64, InsuranceError> {
+            let denomination = self.pool_denomination(terms.pool_id);
+            let Denomination::Asset(token) = denomination else {
+                return Err(InsuranceError::DenominationMismatch);
+            };
+            let caller = self.env().caller();
+            self.pull_asset(token, caller, premium)?;
+            self.issue_policy(
+                caller,
+                premium,
+                denomination,
+                terms,
+                PayoutMode::Policyholder,
+            )
+        }
+
+        fn issue_policy(
+            &mut self,
+            caller: AccountId,
+            paid: u128,
+            paid_in: Denomination,
+            terms: PolicyTerms,
+            payout_mode: PayoutMode,
+        ) -> Result<u64, InsuranceError> {
+            let PolicyTerms {
+                property_id,
+                coverage_type,
+                coverage_amount,
+                pool_id,
+                duration_seconds,
+                metadata_url,
+                template_id,
+                copay_bps,
+            } = terms;
+            let copay_bps = copay_bps.unwrap_or(0);
+            self.ensure_not_paused(PauseDomain::NewPolicies)?;
+            self.ensure_payout_target(property_id, pool_id, &payout_mode)?;
+            let now = self.env().block_timestamp();
+
+            // Enforce the cited template, which must be the property's jurisdiction's
+            let template = self.citable_template(template_id)?;
+            let jurisdiction = self
+                .property_jurisdictions
+                .get(&property_id)
+                .ok_or(InsuranceError::TemplateViolation)?;
+            if jurisdiction != template.jurisdiction
+                || coverage_amount < template.min_coverage
+                || template.mandatory_perils.iter().any(|peril| {
+                    coverage_type != *peril && coverage_type != CoverageType::Comprehensive
+                })
+            {
+                return Err(InsuranceError::TemplateViolation);
+            }
+
+            // Validate pool
+            let mut pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if self.pool_runoffs.contains(&pool_id) {
+                return Err(InsuranceError::PoolClosing);
+            }
+            if !pool.is_active {
+                return Err(InsuranceError::PoolNotFound);
+            }
+            if self.pool_denomination(pool_id) != paid_in {
+                return Err(InsuranceError::DenominationMismatch);
+            }
+            if let Some(min_tier) = self.pool_esg_filters.get(&pool_id) {
+                if self.get_esg_tier(property_id) < min_tier {
+                    return Err(InsuranceError::EsgTierTooLow);
+                }
+            }
+
+            // Check pool has enough capital for coverage
+            let max_exposure = pool
+                .available_capital
+                .saturating_mul(pool.max_coverage_ratio as u128)
+                / 10_000;
+            if coverage_amount > max_exposure {
+                return Err(InsuranceError::InsufficientPoolFunds);
+            }
+
+            // Get risk assessment
+            let assessment = self
+                .risk_assessments
+                .get(&property_id)
+                .ok_or(InsuranceError::PropertyNotInsurable)?;
+
+            // Check assessment is still valid
+            if now > assessment.valid_until {
+                return Err(InsuranceError::PropertyNotInsurable);
+            }
+
+            // Apply the pool's underwriting criteria, waiving approved overrides
+            let underwriting = self.underwrite(pool_id, property_id, &assessment)?;
+
+            // Calculate required premium
+            let calc = self.calculate_premium_with_copay(
+                property_id,
+                coverage_amount,
+                coverage_type.clone(),
+                copay_bps,
+            )?;
+            if paid < calc.annual_premium {
+                return Err(InsuranceError::InsufficientPremium);
+            }
+            let deductible = if template.max_deductible > 0 {
+                calc.deductible.min(template.max_deductible)
+            } else {
+                calc.deductible
+            };
+
+            // Platform fee and the jurisdiction's premium levies
+            let fee = paid.saturating_mul(self.platform_fee_rate as u128) / 10_000;
+            let treasury = self.asset_treasuries.get(&paid_in).unwrap_or(0);
+            self.asset_treasuries
+                .insert(&paid_in, &treasury.saturating_add(fee));
+            let levies = self.collect_levies(&jurisdiction, pool_id, paid);
+            let levied = levies
+                .iter()
+                .fold(0u128, |total, (_, amount)| total.saturating_add(*amount));
+            let pool_share = paid.saturating_sub(fee).saturating_sub(levied);
+
+            // Update pool
+            pool.total_premiums_collected += pool_share;
+            pool.available_capital += pool_share;
+            pool.active_policies += 1;
+            self.pools.insert(&pool_id, &pool);
+            self.record_nav(pool_id);
+
+            // Create policy
+            let policy_id = self.policy_count + 1;
+            self.policy_count = policy_id;
+
+            // A renewal keeps the waiting periods already served
+            let cover_since = self.continuous_cover_since(caller, property_id, &coverage_type, now);
+            let waiting_period_ends =
+                cover_since.saturating_add(self.waiting_periods.get(&coverage_type).unwrap_or(0));
+            let policy = InsurancePolicy {
+                policy_id,
+                property_id,
+                policyholder: caller,
+                coverage_type: coverage_type.clone(),
+                coverage_amount,
+                premium_amount: paid,
+                deductible,
+                start_time: now,
+                end_time: now.saturating_add(duration_seconds),
+                status: PolicyStatus::Active,
+                risk_level: assessment.risk_level.clone(),
+                pool_id,
+                claims_count: 0,
+                total_claimed: 0,
+                metadata_url,
+                payout_mode,
+                waiting_period_ends,
+                copay_bps,
+                cover_since,
+            };
+
+            self.store_new_policy(&policy);
+            self.policy_template_ids
+                .insert(&policy_id, &template.template_id);
+            if let Some((criteria, results)) = underwriting {
+                let overrides = self
+                    .underwriting_overrides
+                    .take(&(pool_id, property_id))
+                    .unwrap_or_default();
+                let decision = if results.iter().any(|result| result.overridden) {
+                    UnderwritingDecision::ApprovedWithOverrides
+                } else {
+                    UnderwritingDecision::Approved
+                };
+                self.underwriting_worksheets.insert(
+                    &policy_id,
+                    &UnderwritingWorksheet {
+                        policy_id,
+                        pool_id,
+                        property_id,
+                        criteria,
+                        assessment,
+                        results,
+                        overrides,
+                        decision,
+                        decided_at: now,
+                    },
+                );
+            }
+
+            // Mint insurance token
+            self.internal_mint_token(policy_id, caller, coverage_amount)?;
+
+            self.env().emit_event(PolicyCreated {
+                policy_id,
+                policyholder: caller,
+                property_id,
+                coverage_type,
+                coverage_amount,
+                premium_amount: paid,
+                levies,
+                start_time: now,
+                end_time: now.saturating_add(duration_seconds),
+                waiting_period_ends,
+            });
+            self.monitor_coverage(property_id);
+
+            Ok(policy_id)
+        }
+
+        /// Cancel an active policy (policyholder or admin)
+        #[ink(message)]
+        pub fn cancel_policy(&mut self, policy_id: u64) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let mut policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+
+            if caller != policy.policyholder && caller != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+
+            if policy.status != PolicyStatus::Active {
+                return Err(InsuranceError::PolicyInactive);
+            }
+
+            // A financed policy is the lender's collateral until the lien clears
+            if caller != self.admin && self.has_outstanding_lien(policy_id) {
+                return Err(InsuranceError::LienOutstanding);
+            }
+
+            // Insurer cancellations of templated policies take effect after the
+            // jurisdiction's notice period; cover runs until then
+            let now = self.env().block_timestamp();
+            let notice = self
+                .policy_template_ids
+                .get(&policy_id)
+                .and_then(|id| self.policy_templates.get(&id))
+                .map_or(0, |template| template.cancellation_notice);
+            if caller != policy.policyholder {
+                self.record_cancellation_notice(&policy, now.saturating_add(notice));
+            }
+            if caller != policy.policyholder && notice > 0 {
+                policy.end_time = policy.end_time.min(now.saturating_add(notice));
+                self.policies.insert(&policy_id, &policy);
+                self.env().emit_event(PolicyCancelled {
+                    policy_id,
+                    policyholder: policy.policyholder,
+                    cancelled_at: policy.end_time,
+                });
+                return Ok(());
+            }
+
+            policy.status = PolicyStatus::Cancelled;
+            self.policies.insert(&policy_id, &policy);
+            self.release_policy_assignments(policy_id);
+
+            // Reduce pool active count
+            if let Some(mut pool) = self.pools.get(&policy.pool_id) {
+                if pool.active_policies > 0 {
+                    pool.active_policies -= 1;
+                }
+                self.pools.insert(&policy.pool_id, &pool);
+            }
+
+            self.env().emit_event(PolicyCancelled {
+                policy_id,
+                policyholder: policy.policyholder,
+                cancelled_at: self.env().block_timestamp(),
+            });
+            self.monitor_coverage(policy.property_id);
+
+            Ok(())
+        }
+
+        /// Onboard policies from an existing book (admin only). Premiums are
+        /// recorded, not charged. Returns one result per item, in order.
+        #[ink(message)]
+        pub fn import_policies(
+            &mut self,
+            imports: Vec<PolicyImport>,
+        ) -> Result<Vec<Result<u64, InsuranceError>>, InsuranceError> {
+            self.ensure_admin()?;
+            self.ensure_not_paused(PauseDomain::NewPolicies)?;
+            if imports.is_empty() || imports.len() > self.max_import_batch as usize {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            Ok(imports
+                .into_iter()
+                .map(|item| self.import_policy(item))
+                .collect())
+        }
+
+        /// Cap the number of policies accepted per import call (admin only)
+        #[ink(message)]
+        pub fn set_max_import_batch(&mut self, max_batch: u32) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if max_batch == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.max_import_batch = max_batch;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_imported_policy(&self, policy_id: u64) -> bool {
+            self.imported_policies.get(&policy_id).unwrap_or(false)
+        }
+
+        #[ink(message)]
+        pub fn get_imported_policy_count(&self) -> u64 {
+            self.imported_policy_count
+        }
+
+        // =====================================================================
+        // COVERAGE CERTIFICATES
+        // =====================================================================
+
+        /// Issue a certificate of insurance for an active policy (policyholder or
+        /// admin). Returns the certificate id and its content hash.
+        #[ink(message)]
+        pub fn issue_certificate(&mut self, policy_id: u64) -> Result<(u64, Hash), InsuranceError> {
+            let policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            let caller = self.env().caller();
+            if caller != policy.policyholder && caller != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let now = self.env().block_timestamp();
+            if policy.status != PolicyStatus::Active {
+                return Err(InsuranceError::PolicyInactive);
+            }
+            if now > policy.end_time {
+                return Err(InsuranceError::PolicyExpired);
+            }
+
+            let certificate_id = self.certificate_count + 1;
+            self.certificate_count = certificate_id;
+            let certificate = CoverageCertificate {
+                certificate_id,
+                policy_id,
+                property_id: policy.property_id,
+                policyholder: policy.policyholder,
+                coverage_type: policy.coverage_type,
+                coverage_amount: policy.coverage_amount,
+                remaining_coverage: policy.coverage_amount.saturating_sub(policy.total_claimed),
+                deductible: policy.deductible,
+                start_time: policy.start_time,
+                end_time: policy.end_time,
+                status: policy.status,
+                issued_at: now,
+            };
+            let mut digest = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&certificate, &mut digest);
+            let content_hash = Hash::from(digest);
+            self.certificates.insert(&certificate_id, &certificate);
+            self.certificate_hashes
+                .insert(&certificate_id, &content_hash);
+
+            self.env().emit_event(CertificateIssued {
+                certificate_id,
+                policy_id,
+                content_hash,
+                valid_until: certificate.end_time,
+            });
+            Ok((certificate_id, content_hash))
+        }
+
+        /// True if `content_hash` matches the certificate and its policy is still
+        /// active and within term. Certificates lapse with their policy.
+        #[ink(message)]
+        pub fn verify_certificate(&self, certificate_id: u64, content_hash: Hash) -> bool {
+            if self.certificate_hashes.get(&certificate_id) != Some(content_hash) {
+                return false;
+            }
+            self.certificates
+                .get(&certificate_id)
+                .and_then(|certificate| self.policies.get(&certificate.policy_id))
+                .is_some_and(|policy| {
+                    policy.status == PolicyStatus::Active
+                        && self.env().block_timestamp() <= policy.end_time
+                })
+        }
+
+        #[ink(message)]
+        pub fn get_certificate(&self, certificate_id: u64) -> Option<CoverageCertificate> {
+            self.certificates.get(&certificate_id)
+        }
+
+        #[ink(message)]
+        pub fn get_certificate_hash(&self, certificate_id: u64) -> Option<Hash> {
+            self.certificate_hashes.get(&certificate_id)
+        }
+
+        // =====================================================================
+        // POLICYHOLDER NOTICES
+        // =====================================================================
+
+        /// Record a notice (renewal, lapse, ...) sent to the policyholder (admin only).
+        /// Claim decisions and insurer cancellations are recorded automatically.
+        #[ink(message)]
+        pub fn issue_notice(
+            &mut self,
+            policy_id: u64,
+            kind: NoticeKind,
+            content_hash: Hash,
+        ) -> Result<u64, InsuranceError> {
+            self.ensure_admin()?;
+            let policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            Ok(self.record_notice(policy_id, policy.policyholder, kind, content_hash))
+        }
+
+        /// Policyholder confirms receipt of a notice
+        #[ink(message)]
+        pub fn acknowledge_notice(&mut self, notice_id: u64) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let mut notice = self
+                .notices
+                .get(&notice_id)
+                .ok_or(InsuranceError::NoticeNotFound)?;
+            if caller != notice.policyholder {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if notice.acknowledged_at.is_some() {
+                return Err(InsuranceError::NoticeAlreadyAcknowledged);
+            }
+            let now = self.env().block_timestamp();
+            notice.acknowledged_at = Some(now);
+            self.notices.insert(&notice_id, &notice);
+            let mut pending = self.unacknowledged_notices.get(&caller).unwrap_or_default();
+            pending.retain(|id| *id != notice_id);
+            self.unacknowledged_notices.insert(&caller, &pending);
+            self.env().emit_event(NoticeAcknowledged {
+                notice_id,
+                policyholder: caller,
+                acknowledged_at: now,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_notice(&self, notice_id: u64) -> Option<PolicyNotice> {
+            self.notices.get(&notice_id)
+        }
+
+        /// Full communication trail of a policy, oldest first
+        #[ink(message)]
+        pub fn get_policy_notices(&self, policy_id: u64) -> Vec<PolicyNotice> {
+            self.policy_notices
+                .get(&policy_id)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|id| self.notices.get(&id))
+                .collect()
+        }
+
+        /// Notices the policyholder has not yet acknowledged
+        #[ink(message)]
+        pub fn get_outstanding_notices(&self, policyholder: AccountId) -> Vec<PolicyNotice> {
+            self.unacknowledged_notices
+                .get(&policyholder)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|id| self.notices.get(&id))
+                .collect()
+        }
+
+        fn record_notice(
+            &mut self,
+            policy_id: u64,
+            policyholder: AccountId,
+            kind: NoticeKind,
+            content_hash: Hash,
+        ) -> u64 {
+            self.notice_count += 1;
+            let notice_id = self.notice_count;
+            self.notices.insert(
+                &notice_id,
+                &PolicyNotice {
+                    notice_id,
+                    policy_id,
+                    policyholder,
+                    kind,
+                    content_hash,
+                    issued_at: self.env().block_timestamp(),
+                    acknowledged_at: None,
+                },
+            );
+            let mut trail = self.policy_notices.get(&policy_id).unwrap_or_default();
+            trail.push(notice_id);
+            self.policy_notices.insert(&policy_id, &trail);
+            let mut pending = self
+                .unacknowledged_notices
+                .get(&policyholder)
+                .unwrap_or_default();
+            pending.push(notice_id);
+            self.unacknowledged_notices.insert(&policyholder, &pending);
+            self.env().emit_event(NoticeIssued {
+                notice_id,
+                policy_id,
+                kind,
+                content_hash,
+            });
+            notice_id
+        }
+
+        /// Notice pinned to the decision itself: claim, outcome, payout and reason
+        fn record_claim_decision_notice(
+            &mut self,
+            claim: &InsuranceClaim,
+            policyholder: AccountId,
+        ) {
+            let mut digest = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(
+                    claim.claim_id,
+                    &claim.status,
+                    claim.payout_amount,
+                    &claim.rejection_reason,
+                ),
+                &mut digest,
+            );
+            self.record_notice(
+                claim.policy_id,
+                policyholder,
+                NoticeKind::ClaimDecision,
+                Hash::from(digest),
+            );
+        }
+
+        fn record_cancellation_notice(&mut self, policy: &InsurancePolicy, effective_at: u64) {
+            let mut digest = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(policy.policy_id, effective_at),
+                &mut digest,
+            );
+            self.record_notice(
+                policy.policy_id,
+                policy.policyholder,
+                NoticeKind::Cancellation,
+                Hash::from(digest),
+            );
+        }
+
+        // =====================================================================
+        // LOCALIZED POLICY DOCUMENTS
+        // =====================================================================
+
+        /// Publish a new version of a policy's terms in `locale` (admin only).
+        /// Versions of a locale must take effect in order; backdating is only
+        /// allowed until the policyholder has accepted the purchase terms.
+        #[ink(message)]
+        pub fn publish_policy_document(
+            &mut self,
+            policy_id: u64,
+            locale: String,
+            document_hash: Hash,
+            uri: String,
+            effective_from: u64,
+        ) -> Result<u32, InsuranceError> {
+            self.ensure_admin()?;
+            if !self.policies.contains(&policy_id) {
+                return Err(InsuranceError::PolicyNotFound);
+            }
+            if locale.is_empty() || locale.len() > MAX_LOCALE_LEN {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let now = self.env().block_timestamp();
+            if effective_from < now && self.accepted_terms.contains(&policy_id) {
+                return Err(InsuranceError::TermsAlreadyAcknowledged);
+            }
+            let key = (policy_id, locale.clone());
+            let latest = self.document_versions.get(&key).unwrap_or(0);
+            if latest > 0 {
+                let previous = self
+                    .policy_documents
+                    .get(&(policy_id, locale.clone(), latest))
+                    .ok_or(InsuranceError::TermsNotFound)?;
+                if effective_from <= previous.effective_from {
+                    return Err(InsuranceError::InvalidParameters);
+                }
+            } else {
+                let mut locales = self.policy_locales.get(&policy_id).unwrap_or_default();
+                locales.push(locale.clone());
+                self.policy_locales.insert(&policy_id, &locales);
+            }
+            let version = latest + 1;
+            self.policy_documents.insert(
+                &(policy_id, locale.clone(), version),
+                &LocalizedDocument {
+                    policy_id,
+                    locale: locale.clone(),
+                    version,
+                    document_hash,
+                    uri,
+                    effective_from,
+                    published_at: now,
+                },
+            );
+            self.document_versions.insert(&key, &version);
+            self.env().emit_event(PolicyDocumentPublished {
+                policy_id,
+                locale,
+                version,
+                document_hash,
+                effective_from,
+            });
+            Ok(version)
+        }
+
+        /// Policyholder accepts the terms in force at purchase in their locale. Only
+        /// versions already published when the policy started count, so terms
+        /// added afterwards cannot be passed off as the purchase terms. The hash
+        /// guards against a version being swapped in before the call lands.
+        #[ink(message)]
+        pub fn acknowledge_policy_terms(
+            &mut self,
+            policy_id: u64,
+            locale: String,
+            document_hash: Hash,
+        ) -> Result<u32, InsuranceError> {
+            let caller = self.env().caller();
+            let policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            if caller != policy.policyholder {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if self.accepted_terms.contains(&policy_id) {
+                return Err(InsuranceError::TermsAlreadyAcknowledged);
+            }
+            let document = self
+                .document_in_force(policy_id, &locale, policy.start_time)
+                .ok_or(InsuranceError::TermsNotFound)?;
+            if document.document_hash != document_hash {
+                return Err(InsuranceError::TermsMismatch);
+            }
+            self.accepted_terms.insert(
+                &policy_id,
+                &TermsAcceptance {
+                    locale: locale.clone(),
+                    version: document.version,
+                    document_hash,
+                    acknowledged_at: self.env().block_timestamp(),
+                },
+            );
+            self.env().emit_event(PolicyTermsAcknowledged {
+                policy_id,
+                policyholder: caller,
+                locale,
+                version: document.version,
+                document_hash,
+            });
+            Ok(document.version)
+        }
+
+        #[ink(message)]
+        pub fn get_policy_document(
+            &self,
+            policy_id: u64,
+            locale: String,
+            version: u32,
+        ) -> Option<LocalizedDocument> {
+            self.policy_documents.get(&(policy_id, locale, version))
+        }
+
+        /// Version of the terms currently in effect in `locale`
+        #[ink(message)]
+        pub fn get_current_policy_document(
+            &self,
+            policy_id: u64,
+            locale: String,
+        ) -> Option<LocalizedDocument> {
+            self.document_in_force(policy_id, &locale, self.env().block_timestamp())
+        }
+
+        /// Version of the terms that was in effect when the policy started
+        #[ink(message)]
+        pub fn get_purchase_terms(
+            &self,
+            policy_id: u64,
+            locale: String,
+        ) -> Option<LocalizedDocument> {
+            let policy = self.policies.get(&policy_id)?;
+            self.document_in_force(policy_id, &locale, policy.start_time)
+        }
+
+        #[ink(message)]
+        pub fn get_policy_locales(&self, policy_id: u64) -> Vec<String> {
+            self.policy_locales.get(&policy_id).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn get_accepted_terms(&self, policy_id: u64) -> Option<TermsAcceptance> {
+            self.accepted_terms.get(&policy_id)
+        }
+
+        /// Whether any locale had terms in force when the policy started
+        fn has_purchase_terms(&self, policy: &InsurancePolicy) -> bool {
+            self.policy_locales
+                .get(&policy.policy_id)
+                .unwrap_or_default()
+                .iter()
+                .any(|locale| {
+                    self.document_in_force(policy.policy_id, locale, policy.start_time)
+                        .is_some()
+                })
+        }
+
+        /// Latest version of `locale` that was published and took effect at or before `at`
+        fn document_in_force(
+            &self,
+            policy_id: u64,
+            locale: &str,
+            at: u64,
+        ) -> Option<LocalizedDocument> {
+            let latest = self
+                .document_versions
+                .get(&(policy_id, String::from(locale)))?;
+            (1..=latest)
+                .rev()
+                .filter_map(|version| {
+                    self.policy_documents
+                        .get(&(policy_id, String::from(locale), version))
+                })
+                .find(|document| document.effective_from <= at && document.published_at <= at)
+        }
+
+        // =====================================================================
+        // POLICY PORTABILITY
+        // =====================================================================
+
+        /// Set the PropertyToken contract allowed to report bridge movements
+        #[ink(message)]
+        pub fn set_bridge_hook_source(
+            &mut self,
+            source: Option<AccountId>,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.bridge_hook_source = source;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_bridge_hook_source(&self) -> Option<AccountId> {
+            self.bridge_hook_source
+        }
+
+        /// True if `attestation` matches the policy's portable coverage and the
+        /// policy is still ported and within term
+        #[ink(message)]
+        pub fn verify_portable_coverage(&self, policy_id: u64, attestation: Hash) -> bool {
+            if self.portability_attestations.get(&policy_id) != Some(attestation) {
+                return false;
+            }
+            self.policies.get(&policy_id).is_some_and(|policy| {
+                policy.status == PolicyStatus::Portable
+                    && self.env().block_timestamp() <= policy.end_time
+            })
+        }
+
+        #[ink(message)]
+        pub fn get_portable_coverage(&self, policy_id: u64) -> Option<PortableCoverage> {
+            self.portable_coverage.get(&policy_id)
+        }
+
+        #[ink(message)]
+        pub fn get_portability_attestation(&self, policy_id: u64) -> Option<Hash> {
+            self.portability_attestations.get(&policy_id)
+        }
+
+        fn ensure_bridge_hook_source(&self) -> Result<(), BridgeHookError> {
+            let caller = self.env().caller();
+            if caller != self.admin && Some(caller) != self.bridge_hook_source {
+                return Err(BridgeHookError::Unauthorized);
+            }
+            Ok(())
+        }
+
+        /// Mark the property's active policies Portable and attest their coverage
+        fn port_policies(
+            &mut self,
+            property_id: u64,
+            destination_chain: u64,
+            bridge_transaction: Hash,
+        ) -> u32 {
+            let now = self.env().block_timestamp();
+            let mut ported = 0u32;
+            for policy_id in self.property_policies.get(&property_id).unwrap_or_default() {
+                let Some(mut policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                if policy.status != PolicyStatus::Active || now > policy.end_time {
+                    continue;
+                }
+                let coverage = PortableCoverage {
+                    policy_id,
+                    property_id,
+                    policyholder: policy.policyholder,
+                    coverage_type: policy.coverage_type.clone(),
+                    remaining_coverage: policy.coverage_amount.saturating_sub(policy.total_claimed),
+                    deductible: policy.deductible,
+                    end_time: policy.end_time,
+                    destination_chain,
+                    bridge_transaction,
+                    ported_at: now,
+                };
+                let mut digest = [0u8; 32];
+                ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&coverage, &mut digest);
+                let attestation = Hash::from(digest);
+                policy.status = PolicyStatus::Portable;
+                self.policies.insert(&policy_id, &policy);
+                self.portable_coverage.insert(&policy_id, &coverage);
+                self.portability_attestations
+                    .insert(&policy_id, &attestation);
+                ported += 1;
+                self.env().emit_event(PolicyPorted {
+                    policy_id,
+                    destination_chain,
+                    attestation,
+                });
+            }
+            ported
+        }
+
+        /// Reactivate the property's ported policies and revoke their attestations
+        fn restore_policies(&mut self, property_id: u64) -> u32 {
+            let now = self.env().block_timestamp();
+            let mut restored = 0u32;
+            for policy_id in self.property_policies.get(&property_id).unwrap_or_default() {
+                let Some(mut policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                if policy.status != PolicyStatus::Portable {
+                    continue;
+                }
+                policy.status = PolicyStatus::Active;
+                self.policies.insert(&policy_id, &policy);
+                self.portability_attestations.remove(&policy_id);
+                restored += 1;
+                self.env().emit_event(PolicyReturned {
+                    policy_id,
+                    property_id,
+                    returned_at: now,
+                });
+            }
+            restored
+        }
+
+        // =====================================================================
+        // COMPLIANCE SUSPENSION
+        // =====================================================================
+
+        /// Set the PropertyToken contract consulted for property compliance
+        #[ink(message)]
+        pub fn set_compliance_source(
+            &mut self,
+            source: Option<AccountId>,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.compliance_source = source;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_compliance_grace_period(
+            &mut self,
+            grace_seconds: u64,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.compliance_grace_period = grace_seconds;
+            Ok(())
+        }
+
+        /// Re-check a property's compliance with the token contract (callable by anyone).
+        /// Policies are suspended once a lapse outlasts the grace period and reinstated,
+        /// with their term extended by the time spent suspended, when compliance returns.
+        /// Returns the number of policies whose status changed.
+        #[ink(message)]
+        pub fn check_property_compliance(
+            &mut self,
+            property_id: u64,
+        ) -> Result<u32, InsuranceError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::PropertyCompliance;
+            let source = self
+                .compliance_source
+                .ok_or(InsuranceError::ComplianceSourceUnavailable)?;
+            let token: ink::contract_ref!(PropertyCompliance) =
+                FromAccountId::from_account_id(source);
+            let compliant = match token.call().is_property_compliant(property_id).try_invoke() {
+                Ok(Ok(compliant)) => compliant,
+                _ => return Err(InsuranceError::ComplianceSourceUnavailable),
+            };
+            Ok(self.apply_property_compliance(property_id, compliant))
+        }
+
+        /// Push a property's compliance status (admin or the compliance source)
+        #[ink(message)]
+        pub fn report_property_compliance(
+            &mut self,
+            property_id: u64,
+            compliant: bool,
+        ) -> Result<u32, InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && Some(caller) != self.compliance_source {
+                return Err(InsuranceError::Unauthorized);
+            }
+            Ok(self.apply_property_compliance(property_id, compliant))
+        }
+
+        /// When the property first failed compliance, if it is currently lapsed
+        #[ink(message)]
+        pub fn get_compliance_lapse(&self, property_id: u64) -> Option<u64> {
+            self.compliance_lapses.get(&property_id)
+        }
+
+        /// When the policy was suspended for non-compliance, if it is currently suspended
+        #[ink(message)]
+        pub fn get_policy_suspension(&self, policy_id: u64) -> Option<u64> {
+            self.compliance_suspensions.get(&policy_id)
+        }
+
+        // =====================================================================
+        // KEEPER MAINTENANCE
+        // =====================================================================
+
+        /// Permissionless keeper entry point. Examines up to `limit` claims and
+        /// `limit` policies from rotating cursors: escalates claims past their SLA
+        /// deadline, expires ended policies and suspends policies whose property
+        /// lapse outlived the grace period. Pays the keeper the configured reward
+        /// per action, up to the maintenance fund.
+        #[ink(message)]
+        pub fn run_maintenance(&mut self, limit: u32) -> Result<MaintenanceReport, InsuranceError> {
+            let keeper = self.env().caller();
+            let now = self.env().block_timestamp();
+            let mut report = MaintenanceReport::default();
+
+            for _ in 0..(limit as u64).min(self.claim_count) {
+                let claim_id = self.maintenance_claim_cursor.max(1);
+                self.maintenance_claim_cursor = if claim_id >= self.claim_count {
+                    1
+                } else {
+                    claim_id + 1
+                };
+                report.claims_examined += 1;
+                let Some(claim) = self.claims.get(&claim_id) else {
+                    continue;
+                };
+                let overdue = claim.review_deadline.is_some_and(|deadline| now > deadline)
+                    && !claim.escalated
+                    && matches!(
+                        claim.status,
+                        ClaimStatus::Pending | ClaimStatus::UnderReview
+                    );
+                if overdue && self.escalate_overdue_claim(claim_id).is_ok() {
+                    report.claims_escalated += 1;
+                }
+            }
+
+            for _ in 0..(limit as u64).min(self.policy_count) {
+                let policy_id = self.maintenance_policy_cursor.max(1);
+                self.maintenance_policy_cursor = if policy_id >= self.policy_count {
+                    1
+                } else {
+                    policy_id + 1
+                };
+                report.policies_examined += 1;
+                let Some(mut policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                if policy.status != PolicyStatus::Active {
+                    continue;
+                }
+                if now > policy.end_time {
+                    policy.status = PolicyStatus::Expired;
+                    self.policies.insert(&policy_id, &policy);
+                    if let Some(mut pool) = self.pools.get(&policy.pool_id) {
+                        pool.active_policies = pool.active_policies.saturating_sub(1);
+                        self.pools.insert(&policy.pool_id, &pool);
+                    }
+                    report.policies_expired += 1;
+                    self.monitor_coverage(policy.property_id);
+                    continue;
+                }
+                let grace_over =
+                    self.compliance_lapses
+                        .get(&policy.property_id)
+                        .is_some_and(|lapsed_at| {
+                            now >= lapsed_at.saturating_add(self.compliance_grace_period)
+                        });
+                if grace_over {
+                    report.policies_suspended +=
+                        self.apply_property_compliance(policy.property_id, false);
+                }
+            }
+
+            let units =
+                report.claims_escalated + report.policies_expired + report.policies_suspended;
+            let reward = self
+                .maintenance_reward
+                .saturating_mul(units as u128)
+                .min(self.maintenance_fund);
+            if reward > 0 {
+                self.maintenance_fund -= reward;
+                self.env()
+                    .transfer(keeper, reward)
+                    .map_err(|_| InsuranceError::TransferFailed)?;
+            }
+            report.reward_paid = reward;
+
+            self.env().emit_event(MaintenanceRun {
+                keeper,
+                claims_escalated: report.claims_escalated,
+                policies_expired: report.policies_expired,
+                policies_suspended: report.policies_suspended,
+                reward_paid: reward,
+            });
+            Ok(report)
+        }
+
+        /// Add funds for keeper rewards; anyone may top up
+        #[ink(message, payable)]
+        pub fn fund_maintenance(&mut self) -> Result<u128, InsuranceError> {
+            self.maintenance_fund = self
+                .maintenance_fund
+                .saturating_add(self.env().transferred_value());
+            Ok(self.maintenance_fund)
+        }
+
+        /// Set the reward paid per unit of maintenance work (admin)
+        #[ink(message)]
+        pub fn set_maintenance_reward(&mut self, reward: u128) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.maintenance_reward = reward;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_maintenance_fund(&self) -> u128 {
+            self.maintenance_fund
+        }
+
+        // =====================================================================
+        // PREMIUM FINANCING
+        // =====================================================================
+
+        /// Issue a policy to `policyholder` with the premium paid by an authorized
+        /// lender; the lender holds a lien on the policy until released
+        #[ink(message, payable)]
+        pub fn create_financed_policy(
+            &mut self,
+            policyholder: AccountId,
+            terms: PolicyTerms,
+        ) -> Result<u64, InsuranceError> {
+            let lender = self.env().caller();
+            if !self.authorized_lenders.get(&lender).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let paid = self.env().transferred_value();
+            let policy_id = self.issue_policy(
+                policyholder,
+                paid,
+                Denomination::Native,
+                terms,
+                PayoutMode::Policyholder,
+            )?;
+
+            let lien = PremiumLien {
+                policy_id,
+                lender,
+                borrower: policyholder,
+                financed_amount: paid,
+                financed_at: self.env().block_timestamp(),
+                status: LienStatus::Active,
+                refund_paid: 0,
+            };
+            self.premium_liens.insert(&policy_id, &lien);
+
+            self.env().emit_event(PremiumFinanced {
+                policy_id,
+                lender,
+                borrower: policyholder,
+                amount: paid,
+            });
+
+            Ok(policy_id)
+        }
+
+        /// Lender reports that the borrower has defaulted on the premium loan
+        #[ink(message)]
+        pub fn report_financing_default(&mut self, policy_id: u64) -> Result<(), InsuranceError> {
+            let mut lien = self.lender_lien(policy_id)?;
+            if lien.status != LienStatus::Active {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            lien.status = LienStatus::Defaulted;
+            self.premium_liens.insert(&policy_id, &lien);
+
+            self.env().emit_event(FinancingDefaultReported {
+                policy_id,
+                lender: lien.lender,
+                timestamp: self.env().block_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Lender releases the lien once the premium loan is repaid
+        #[ink(message)]
+        pub fn release_premium_lien(&mut self, policy_id: u64) -> Result<(), InsuranceError> {
+            let mut lien = self.lender_lien(policy_id)?;
+            if lien.status != LienStatus::Active {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            lien.status = LienStatus::Released;
+            self.premium_liens.insert(&policy_id, &lien);
+
+            self.env().emit_event(PremiumLienReleased {
+                policy_id,
+                lender: lien.lender,
+            });
+            Ok(())
+        }
+
+        /// Lender cancels a defaulted policy and receives the unearned premium,
+        /// pro-rata to the time left in the term
+        #[ink(message)]
+        pub fn cancel_defaulted_policy(&mut self, policy_id: u64) -> Result<u128, InsuranceError> {
+            let mut lien = self.lender_lien(policy_id)?;
+            if lien.status != LienStatus::Defaulted {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let mut policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            if policy.status != PolicyStatus::Active {
+                return Err(InsuranceError::PolicyInactive);
+            }
+
+            let now = self.env().block_timestamp();
+            let term = policy.end_time.saturating_sub(policy.start_time);
+            let remaining = policy.end_time.saturating_sub(now);
+            let unearned = policy
+                .premium_amount
+                .saturating_mul(remaining as u128)
+                .checked_div(term as u128)
+                .unwrap_or(0);
+
+            let mut refund = 0;
+            if let Some(mut pool) = self.pools.get(&policy.pool_id) {
+                refund = unearned.min(pool.available_capital);
+                pool.available_capital -= refund;
+                pool.total_premiums_collected =
+                    pool.total_premiums_collected.saturating_sub(refund);
+                if pool.active_policies > 0 {
+                    pool.active_policies -= 1;
+                }
+                self.pools.insert(&policy.pool_id, &pool);
+                self.record_nav(policy.pool_id);
+            }
+
+            policy.status = PolicyStatus::Cancelled;
+            self.policies.insert(&policy_id, &policy);
+            self.release_policy_assignments(policy_id);
+            lien.status = LienStatus::Settled;
+            lien.refund_paid = refund;
+            self.premium_liens.insert(&policy_id, &lien);
+
+            if refund > 0 {
+                self.pay_from_pool(policy.pool_id, lien.lender, refund)?;
+            }
+
+            self.env().emit_event(PolicyCancelled {
+                policy_id,
+                policyholder: policy.policyholder,
+                cancelled_at: now,
+            });
+            self.env().emit_event(FinancedPolicyCancelled {
+                policy_id,
+                lender: lien.lender,
+                unearned_refund: refund,
+                timestamp: now,
+            });
+            self.monitor_coverage(policy.property_id);
+
+            Ok(refund)
+        }
+
+        #[ink(message)]
+        pub fn get_premium_lien(&self, policy_id: u64) -> Option<PremiumLien> {
+            self.premium_liens.get(&policy_id)
+        }
+
+        // =====================================================================
+        // LENDER COVERAGE COVENANTS
+        // =====================================================================
+
+        /// Require `property_id` to stay insured for at least `min_coverage`
+        /// (authorized lender). Only the lender of record may change it.
+        #[ink(message)]
+        pub fn set_coverage_requirement(
+            &mut self,
+            property_id: u64,
+            min_coverage: u128,
+        ) -> Result<CoverageCheck, InsuranceError> {
+            let lender = self.env().caller();
+            if !self.authorized_lenders.get(&lender).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if min_coverage == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let shortfall_since = match self.coverage_requirements.get(&property_id) {
+                Some(existing) if existing.lender != lender => {
+                    return Err(InsuranceError::Unauthorized)
+                }
+                Some(existing) => existing.shortfall_since,
+                None => None,
+            };
+            self.coverage_requirements.insert(
+                &property_id,
+                &CoverageRequirement {
+                    lender,
+                    min_coverage,
+                    set_at: self.env().block_timestamp(),
+                    shortfall_since,
+                },
+            );
+            self.env().emit_event(CoverageRequirementSet {
+                property_id,
+                lender,
+                min_coverage,
+            });
+            self.monitor_coverage(property_id)
+                .ok_or(InsuranceError::CoverageRequirementNotFound)
+        }
+
+        /// Drop the coverage requirement (its lender or admin)
+        #[ink(message)]
+        pub fn remove_coverage_requirement(
+            &mut self,
+            property_id: u64,
+        ) -> Result<(), InsuranceError> {
+            let requirement = self
+                .coverage_requirements
+                .get(&property_id)
+                .ok_or(InsuranceError::CoverageRequirementNotFound)?;
+            let caller = self.env().caller();
+            if caller != requirement.lender && caller != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+            self.coverage_requirements.remove(&property_id);
+            self.env().emit_event(CoverageRequirementRemoved {
+                property_id,
+                lender: requirement.lender,
+            });
+            Ok(())
+        }
+
+        /// Measure the property's in-force cover against its requirement. Callable
+        /// by anyone, so keepers can catch policies that lapsed by running out their
+        /// term; emits CoverageShortfall / CoverageRestored on changes.
+        #[ink(message)]
+        pub fn check_coverage_requirement(
+            &mut self,
+            property_id: u64,
+        ) -> Result<CoverageCheck, InsuranceError> {
+            self.monitor_coverage(property_id)
+                .ok_or(InsuranceError::CoverageRequirementNotFound)
+        }
+
+        #[ink(message)]
+        pub fn get_coverage_requirement(&self, property_id: u64) -> Option<CoverageRequirement> {
+            self.coverage_requirements.get(&property_id)
+        }
+
+        /// Unclaimed cover of the property's active, unexpired policies
+        fn in_force_coverage(&self, property_id: u64) -> u128 {
+            let now = self.env().block_timestamp();
+            self.property_policies
+                .get(&property_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|policy_id| self.policies.get(policy_id))
+                .filter(|policy| policy.status == PolicyStatus::Active && now <= policy.end_time)
+                .map(|policy| policy.coverage_amount.saturating_sub(policy.total_claimed))
+                .fold(0u128, |total, cover| total.saturating_add(cover))
+        }
+
+        /// Re-evaluate the property's coverage requirement, if any, and emit on a
+        /// move into or out of shortfall
+        fn monitor_coverage(&mut self, property_id: u64) -> Option<CoverageCheck> {
+            let mut requirement = self.coverage_requirements.get(&property_id)?;
+            let now = self.env().block_timestamp();
+            let in_force = self.in_force_coverage(property_id);
+            let satisfied = in_force >= requirement.min_coverage;
+            match (satisfied, requirement.shortfall_since) {
+                (false, None) => {
+                    requirement.shortfall_since = Some(now);
+                    self.coverage_requirements
+                        .insert(&property_id, &requirement);
+                    self.env().emit_event(CoverageShortfall {
+                        property_id,
+                        lender: requirement.lender,
+                        required: requirement.min_coverage,
+                        in_force,
+                        timestamp: now,
+                    });
+                }
+                (true, Some(_)) => {
+                    requirement.shortfall_since = None;
+                    self.coverage_requirements
+                        .insert(&property_id, &requirement);
+                    self.env().emit_event(CoverageRestored {
+                        property_id,
+                        lender: requirement.lender,
+                        in_force,
+                        timestamp: now,
+                    });
+                }
+                _ => {}
+            }
+            Some(CoverageCheck {
+                property_id,
+                lender: requirement.lender,
+                required: requirement.min_coverage,
+                in_force,
+                satisfied,
+                shortfall_since: requirement.shortfall_since,
+            })
+        }
+
+        // =====================================================================
+        // INCIDENT REGISTRY
+        // =====================================================================
+
+        /// Register an incident, or return the existing one for the same property,
+        /// peril and date window. Open to admin, oracles and holders of an active
+        /// policy on the property.
+        #[ink(message)]
+        pub fn report_incident(
+            &mut self,
+            property_id: u64,
+            peril: CoverageType,
+            occurred_at: u64,
+        ) -> Result<u64, InsuranceError> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+            if occurred_at > now {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            if caller != self.admin
+                && !self.authorized_oracles.get(&caller).unwrap_or(false)
+                && !self.holds_active_policy(caller, property_id)
+            {
+                return Err(InsuranceError::Unauthorized);
+            }
+
+            let key = (
+                property_id,
+                peril.clone(),
+                occurred_at / self.incident_window.max(1),
+            );
+            if let Some(incident_id) = self.incident_index.get(&key) {
+                return Ok(incident_id);
+            }
+
+            let incident_id = self.incident_count + 1;
+            self.incident_count = incident_id;
+            self.incidents.insert(
+                &incident_id,
+                &Incident {
+                    incident_id,
+                    property_id,
+                    peril: peril.clone(),
+                    occurred_at,
+                    reported_by: caller,
+                    reported_at: now,
+                    claim_ids: Vec::new(),
+                    flagged: false,
+                },
+            );
+            self.incident_index.insert(&key, &incident_id);
+
+            self.env().emit_event(IncidentReported {
+                incident_id,
+                property_id,
+                peril,
+                occurred_at,
+            });
+
+            Ok(incident_id)
+        }
+
+        /// Incident already registered for the property, peril and date window
+        #[ink(message)]
+        pub fn find_incident(
+            &self,
+            property_id: u64,
+            peril: CoverageType,
+            occurred_at: u64,
+        ) -> Option<u64> {
+            self.incident_index.get(&(
+                property_id,
+                peril,
+                occurred_at / self.incident_window.max(1),
+            ))
+        }
+
+        #[ink(message)]
+        pub fn get_incident(&self, incident_id: u64) -> Option<Incident> {
+            self.incidents.get(&incident_id)
+        }
+
+        /// Total paid out on a policy for one incident
+        #[ink(message)]
+        pub fn get_incident_payout(&self, incident_id: u64, policy_id: u64) -> u128 {
+            self.incident_payouts
+                .get(&(incident_id, policy_id))
+                .unwrap_or(0)
+        }
+
+        // =====================================================================
+        // CLAIMS PROCESSING
+        // =====================================================================
+
+        /// Submit an insurance claim
+        #[ink(message)]
+        pub fn submit_claim(
+            &mut self,
+            policy_id: u64,
+            incident_id: u64,
+            claim_amount: u128,
+            description: String,
+            evidence_url: String,
+        ) -> Result<u64, InsuranceError> {
+            self.ensure_not_paused(PauseDomain::ClaimsIntake)?;
+            self.open_claim(
+                policy_id,
+                incident_id,
+                claim_amount,
+                description,
+                evidence_url,
+                None,
+            )
+        }
+
+        /// Open a claim for the caller. `linked` is set for coordinated filings and
+        /// lists the claims already opened in the same call: a coordinated claim may
+        /// cover another peril of the incident, and its siblings do not count as
+        /// suspicious repeat filings.
+        fn open_claim(
+            &mut self,
+            policy_id: u64,
+            incident_id: u64,
+            claim_amount: u128,
+            description: String,
+            evidence_url: String,
+            linked: Option<&[u64]>,
+        ) -> Result<u64, InsuranceError> {
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+
+            let mut policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+
+            if policy.policyholder != caller {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if policy.status != PolicyStatus::Active {
+                return Err(InsuranceError::PolicyInactive);
+            }
+            if now > policy.end_time {
+                return Err(InsuranceError::PolicyExpired);
+            }
+
+            // Check claim amount doesn't exceed remaining coverage
+            let remaining = policy.coverage_amount.saturating_sub(policy.total_claimed);
+            if claim_amount > remaining {
+                return Err(InsuranceError::ClaimExceedsCoverage);
+            }
+
+            // Cooldown check
+            let last_claim = self.claim_cooldowns.get(&policy.property_id).unwrap_or(0);
+            if now.saturating_sub(last_claim) < self.claim_cooldown_period {
+                return Err(InsuranceError::CooldownPeriodActive);
+            }
+
+            // The incident must match the insured property, peril and policy term
+            let mut incident = self
+                .incidents
+                .get(&incident_id)
+                .ok_or(InsuranceError::IncidentNotFound)?;
+            if incident.property_id != policy.property_id
+                || (incident.peril != policy.coverage_type
+                    && policy.coverage_type != CoverageType::Comprehensive
+                    && !(linked.is_some()
+                        && Self::covers_linked_peril(&policy.coverage_type, &incident.peril)))
+                || incident.occurred_at < policy.start_time
+                || incident.occurred_at > policy.end_time
+            {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            if incident.occurred_at < self.waiting_period_end(&policy, &incident.peril) {
+                return Err(InsuranceError::WaitingPeriodActive);
+            }
+            // Terms in force at purchase must be accepted before claiming
+            if !self.accepted_terms.contains(&policy_id) && self.has_purchase_terms(&policy) {
+                return Err(InsuranceError::TermsNotAcknowledged);
+            }
+            // Once coordinated, further recoveries on the incident need the same filing
+            if linked.is_none() && self.incident_coordinations.contains(&(incident_id, caller)) {
+                return Err(InsuranceError::DuplicateClaim);
+            }
+
+            // One live claim per incident per policy; re-filings and filings on
+            // several policies for the same incident are flagged
+            let mut suspicious = false;
+            for prior_id in incident.claim_ids.iter() {
+                if linked.is_some_and(|linked| linked.contains(prior_id)) {
+                    continue;
+                }
+                let Some(prior) = self.claims.get(prior_id) else {
+                    continue;
+                };
+                if prior.policy_id == policy_id {
+                    if prior.status != ClaimStatus::Rejected {
+                        return Err(InsuranceError::DuplicateClaim);
+                    }
+                    suspicious = true;
+                } else if prior.claimant == caller {
+                    suspicious = true;
+                }
+            }
+
+            let claim_id = self.claim_count + 1;
+            self.claim_count = claim_id;
+
+            incident.claim_ids.push(claim_id);
+            if suspicious {
+                incident.flagged = true;
+                self.env().emit_event(SuspiciousFilingFlagged {
+                    incident_id,
+                    claim_id,
+                    claimant: caller,
+                });
+            }
+            self.incidents.insert(&incident_id, &incident);
+
+            let review_deadline = self
+                .claim_sla_configs
+                .get(&policy.pool_id)
+                .map(|sla| now.saturating_add(sla.review_period));
+            if review_deadline.is_some() {
+                let mut stats = self
+                    .claim_sla_stats
+                    .get(&policy.pool_id)
+                    .unwrap_or_default();
+                stats.claims_tracked += 1;
+                self.claim_sla_stats.insert(&policy.pool_id, &stats);
+            }
+
+            let claim = InsuranceClaim {
+                claim_id,
+                policy_id,
+                claimant: caller,
+                claim_amount,
+                description,
+                evidence_url,
+                oracle_report_url: String::new(),
+                status: ClaimStatus::Pending,
+                submitted_at: now,
+                processed_at: None,
+                payout_amount: 0,
+                assessor: None,
+                rejection_reason: String::new(),
+                review_deadline,
+                escalated: false,
+                incident_id,
+            };
+
+            self.claims.insert(&claim_id, &claim);
+            self.adjust_claim_reserve(policy.pool_id, claim_amount, true);
+            self.assign_assessor(claim_id, caller, None);
+
+            let mut policy_claims = self.policy_claims.get(&policy_id).unwrap_or_default();
+            policy_claims.push(claim_id);
+            self.policy_claims.insert(&policy_id, &policy_claims);
+
+            policy.claims_count += 1;
+            self.policies.insert(&policy_id, &policy);
+            self.record_nav(policy.pool_id);
+
+            self.env().emit_event(ClaimSubmitted {
+                claim_id,
+                policy_id,
+                claimant: caller,
+                claim_amount,
+                submitted_at: now,
+            });
+
+            Ok(claim_id)
+        }
+
+        /// Dry run of a claim against current policy terms, incident caps,
+        /// reinsurance layers and pool liquidity. Writes nothing and commits to
+        /// nothing; the assessor's decision governs the actual payout.
+        #[ink(message)]
+        pub fn simulate_claim(
+            &self,
+            policy_id: u64,
+            claim_amount: u128,
+        ) -> Result<ClaimSimulation, InsuranceError> {
+            let policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            let pool = self
+                .pools
+                .get(&policy.pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            let now = self.env().block_timestamp();
+
+            let coverage_remaining = policy.coverage_amount.saturating_sub(policy.total_claimed);
+            let incident_cap = policy
+                .coverage_amount
+                .saturating_mul(self.incident_payout_limit as u128)
+                / 10_000;
+            let (_, copay_amount, payable) =
+                Self::split_loss(&policy, claim_amount.min(coverage_remaining));
+            let expected_payout = payable.min(incident_cap);
+
+            let mut reinsurance_layers = Vec::new();
+            let mut reinsurance_recovery: u128 = 0;
+            if expected_payout > pool.reinsurance_threshold {
+                for mut agreement in self.covering_layers(&policy.coverage_type, now) {
+                    let recovery = Self::charge_layer(&mut agreement, expected_payout);
+                    if recovery == 0 {
+                        continue;
+                    }
+                    reinsurance_recovery = reinsurance_recovery.saturating_add(recovery);
+                    reinsurance_layers.push(LayerAllocation {
+                        agreement_id: agreement.agreement_id,
+                        recovery,
+                    });
+                }
+            }
+
+            let last_claim = self.claim_cooldowns.get(&policy.property_id).unwrap_or(0);
+            let eligible = policy.status == PolicyStatus::Active
+                && now <= policy.end_time
+                && claim_amount <= coverage_remaining
+                && now.saturating_sub(last_claim) >= self.claim_cooldown_period
+                && !self.is_domain_paused(PauseDomain::ClaimsIntake);
+
+            Ok(ClaimSimulation {
+                policy_id,
+                claim_amount,
+                deductible: policy.deductible,
+                copay_amount,
+                coverage_remaining,
+                incident_cap,
+                expected_payout,
+                reinsurance_recovery,
+                reinsurance_layers,
+                pool_available: pool.available_capital,
+                liquidity_shortfall: expected_payout.saturating_sub(pool.available_capital),
+                eligible,
+                binding: false,
+                simulated_at: now,
+            })
+        }
+
+        /// Assessor reviews a claim and either approves or rejects it
+        #[ink(message)]
+        pub fn process_claim(
+            &mut self,
+            claim_id: u64,
+            approved: bool,
+            oracle_report_url: String,
+            rejection_reason: String,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+
+            if caller != self.admin && !self.authorized_assessors.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+
+            let mut claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            if claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview {
+                return Err(InsuranceError::ClaimAlreadyProcessed);
+            }
+            // Escalated claims are decided from the admin queue
+            if claim.escalated && caller != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+            // Linked claims pay out against the assessed loss only
+            if approved {
+                if let Some(coordination_id) = self.claim_coordination.get(&claim_id) {
+                    if self
+                        .claim_coordinations
+                        .get(&coordination_id)
+                        .is_some_and(|coordination| coordination.assessed_loss.is_none())
+                    {
+                        return Err(InsuranceError::LossNotAssessed);
+                    }
+                }
+            }
+            // A claim assigned from the staked pool is decided by its assessor
+            if let Some(assigned) = self.claim_assignments.get(&claim_id) {
+                if caller != assigned && caller != self.admin {
+                    return Err(InsuranceError::Unauthorized);
+                }
+                self.release_assessor_slot(assigned);
+            }
+            if let Some(mut stats) = self.assessor_stats.get(&caller) {
+                stats.decisions += 1;
+                self.assessor_stats.insert(&caller, &stats);
+            }
+
+            let now = self.env().block_timestamp();
+            self.record_sla_outcome(&claim, now);
+            claim.assessor = Some(caller);
+            claim.oracle_report_url = oracle_report_url;
+            claim.processed_at = Some(now);
+
+            if approved {
+                let policy = self
+                    .policies
+                    .get(&claim.policy_id)
+                    .ok_or(InsuranceError::PolicyNotFound)?;
+
+                // Apply deductible, then the policyholder's co-pay
+                let (deductible_applied, copay_amount, payout) =
+                    Self::split_loss(&policy, claim.claim_amount);
+
+                // Cap aggregate payouts for this incident on this policy
+                let incident_key = (claim.incident_id, claim.policy_id);
+                let incident_paid = self.incident_payouts.get(&incident_key).unwrap_or(0);
+                let incident_cap = policy
+                    .coverage_amount
+                    .saturating_mul(self.incident_payout_limit as u128)
+                    / 10_000;
+                let payout = payout.min(incident_cap.saturating_sub(incident_paid));
+                let payout = self.cap_coordinated_payout(claim_id, payout);
+                self.incident_payouts
+                    .insert(&incident_key, &incident_paid.saturating_add(payout));
+
+                claim.payout_amount = payout;
+                self.set_claim_status(&mut claim, ClaimStatus::Approved);
+                self.claims.insert(&claim_id, &claim);
+
+                // Execute payout
+                self.execute_payout(
+                    claim_id,
+                    claim.policy_id,
+                    claim.claimant,
+                    payout,
+                    deductible_applied,
+                    copay_amount,
+                )?;
+
+                self.env().emit_event(ClaimApproved {
+                    claim_id,
+                    policy_id: claim.policy_id,
+                    payout_amount: payout,
+                    approved_by: caller,
+                    timestamp: now,
+                });
+                self.record_claim_decision_notice(&claim, policy.policyholder);
+            } else {
+                self.set_claim_status(&mut claim, ClaimStatus::Rejected);
+                claim.rejection_reason = rejection_reason.clone();
+                self.claims.insert(&claim_id, &claim);
+
+                self.env().emit_event(ClaimRejected {
+                    claim_id,
+                    policy_id: claim.policy_id,
+                    reason: rejection_reason,
+                    rejected_by: caller,
+                    timestamp: now,
+                });
+                if let Some(policy) = self.policies.get(&claim.policy_id) {
+                    self.record_claim_decision_notice(&claim, policy.policyholder);
+                }
+            }
+            self.record_claim_statistics(&claim, now);
+            self.settle_coordination(claim_id);
+
+            Ok(())
+        }
+
+        /// Escalate a claim whose SLA deadline has passed without a decision.
+        /// Callable by anyone; credits the claimant with the pool's compensation rate.
+        #[ink(message)]
+        pub fn escalate_overdue_claim(&mut self, claim_id: u64) -> Result<u128, InsuranceError> {
+            let mut claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            if claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview {
+                return Err(InsuranceError::ClaimAlreadyProcessed);
+            }
+            let now = self.env().block_timestamp();
+            let deadline = match claim.review_deadline {
+                Some(deadline) if !claim.escalated && now > deadline => deadline,
+                _ => return Err(InsuranceError::ClaimNotOverdue),
+            };
+            let policy = self
+                .policies
+                .get(&claim.policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            let sla = self
+                .claim_sla_configs
+                .get(&policy.pool_id)
+                .ok_or(InsuranceError::ClaimNotOverdue)?;
+            let mut pool = self
+                .pools
+                .get(&policy.pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+
+            let compensation = (claim
+                .claim_amount
+                .saturating_mul(sla.compensation_rate as u128)
+                / 10_000)
+                .min(pool.available_capital);
+            pool.available_capital -= compensation;
+            self.pools.insert(&policy.pool_id, &pool);
+            self.record_nav(policy.pool_id);
+
+            if !self.credit_in_pool_asset(policy.pool_id, claim.claimant, compensation) {
+                let credit = self.sla_credits.get(&claim.claimant).unwrap_or(0);
+                self.sla_credits
+                    .insert(&claim.claimant, &credit.saturating_add(compensation));
+            }
+
+            let mut stats = self
+                .claim_sla_stats
+                .get(&policy.pool_id)
+                .unwrap_or_default();
+            stats.breaches += 1;
+            stats.total_compensation = stats.total_compensation.saturating_add(compensation);
+            self.claim_sla_stats.insert(&policy.pool_id, &stats);
+
+            claim.escalated = true;
+            self.claims.insert(&claim_id, &claim);
+            self.enqueue_escalated_claim(claim_id);
+
+            self.env().emit_event(ClaimEscalated {
+                claim_id,
+                pool_id: policy.pool_id,
+                deadline,
+                compensation,
+                timestamp: now,
+            });
+
+            Ok(compensation)
+        }
+
+        /// Withdraw SLA compensation credited to the caller
+        #[ink(message)]
+        pub fn withdraw_sla_credit(&mut self) -> Result<u128, InsuranceError> {
+            let caller = self.env().caller();
+            let credit = self.sla_credits.get(&caller).unwrap_or(0);
+            if credit == 0 {
+                return Ok(0);
+            }
+            self.sla_credits.insert(&caller, &0u128);
+            self.env()
+                .transfer(caller, credit)
+                .map_err(|_| InsuranceError::TransferFailed)?;
+            Ok(credit)
+        }
+
+        /// Commit private evidence for a claim (claimant only). Only the content hash is
+        /// meant for public use; the pointer should be encrypted for assessors.
+        #[ink(message)]
+        pub fn commit_claim_evidence(
+            &mut self,
+            claim_id: u64,
+            content_hash: Hash,
+            encrypted_pointer: Vec<u8>,
+        ) -> Result<u32, InsuranceError> {
+            let claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            if self.env().caller() != claim.claimant {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if claim.status != ClaimStatus::Pending && claim.status != ClaimStatus::UnderReview {
+                return Err(InsuranceError::ClaimAlreadyProcessed);
+            }
+            let mut evidence = self.claim_evidence.get(&claim_id).unwrap_or_default();
+            let index = evidence.len() as u32;
+            evidence.push(EvidenceCommitment {
+                content_hash,
+                encrypted_pointer,
+                committed_at: self.env().block_timestamp(),
+            });
+            self.claim_evidence.insert(&claim_id, &evidence);
+            self.env().emit_event(EvidenceCommitted {
+                claim_id,
+                content_hash,
+                index,
+            });
+            Ok(index)
+        }
+
+        /// Grant an authorized assessor access to a claim's evidence (claimant or admin)
+        #[ink(message)]
+        pub fn grant_evidence_access(
+            &mut self,
+            claim_id: u64,
+            assessor: AccountId,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            if caller != claim.claimant && caller != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if assessor != self.admin && !self.authorized_assessors.get(&assessor).unwrap_or(false)
+            {
+                return Err(InsuranceError::Unauthorized);
+            }
+            self.evidence_grants
+                .insert(&(claim_id, assessor), &self.env().block_timestamp());
+            self.env().emit_event(EvidenceAccessGranted {
+                claim_id,
+                assessor,
+                granted_by: caller,
+            });
+            Ok(())
+        }
+
+        /// Retrieve a claim's evidence commitments as a granted assessor; every
+        /// access is recorded in the claim's access log
+        #[ink(message)]
+        pub fn access_claim_evidence(
+            &mut self,
+            claim_id: u64,
+        ) -> Result<Vec<EvidenceCommitment>, InsuranceError> {
+            let caller = self.env().caller();
+            if !self.evidence_grants.contains(&(claim_id, caller)) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let evidence = self
+                .claim_evidence
+                .get(&claim_id)
+                .ok_or(InsuranceError::EvidenceNotFound)?;
+            let now = self.env().block_timestamp();
+            let mut log = self.evidence_access_log.get(&claim_id).unwrap_or_default();
+            log.push(EvidenceAccess {
+                accessor: caller,
+                accessed_at: now,
+            });
+            self.evidence_access_log.insert(&claim_id, &log);
+            self.env().emit_event(EvidenceAccessed {
+                claim_id,
+                accessor: caller,
+                timestamp: now,
+            });
+            Ok(evidence)
+        }
+
+        /// Check revealed evidence content against its committed hash
+        #[ink(message)]
+        pub fn verify_claim_evidence(&self, claim_id: u64, index: u32, content: Vec<u8>) -> bool {
+            let Some(commitment) = self
+                .claim_evidence
+                .get(&claim_id)
+                .and_then(|evidence| evidence.get(index as usize).cloned())
+            else {
+                return false;
+            };
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&content, &mut output);
+            Hash::from(output) == commitment.content_hash
+        }
+
+        /// Route this policy's claim payouts to the insured token's shareholders
+        /// (policyholder or admin). Fixed once a claim has been filed.
+        #[ink(message)]
+        pub fn set_payout_mode(
+            &mut self,
+            policy_id: u64,
+            payout_mode: PayoutMode,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let mut policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            if caller != policy.policyholder && caller != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if policy.claims_count > 0 {
+                return Err(InsuranceError::PayoutModeLocked);
+            }
+            self.ensure_payout_target(policy.property_id, policy.pool_id, &payout_mode)?;
+            policy.payout_mode = payout_mode;
+            self.policies.insert(&policy_id, &policy);
+            Ok(())
+        }
+
+        // =====================================================================
+        // COORDINATED INCIDENT CLAIMS
+        // =====================================================================
+
+        /// Open linked claims on several of the caller's policies for one incident
+        /// (e.g. fire, liability and natural-disaster cover on the same property).
+        /// Claimed amounts may not add up to more than the declared loss, and
+        /// payouts across the linked claims are capped at it. A holder gets one
+        /// coordinated filing per incident and no standalone claims beside it.
+        #[ink(message)]
+        pub fn submit_coordinated_claim(
+            &mut self,
+            incident_id: u64,
+            total_loss: u128,
+            parts: Vec<(u64, u128)>, // (policy_id, claim_amount)
+            description: String,
+            evidence_url: String,
+        ) -> Result<u64, InsuranceError> {
+            self.ensure_not_paused(PauseDomain::ClaimsIntake)?;
+            // A single policy claims through submit_claim
+            if parts.len() < 2 || parts.len() > MAX_COORDINATED_CLAIMS {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let mut claimed: u128 = 0;
+            for (index, (policy_id, amount)) in parts.iter().enumerate() {
+                if parts[..index].iter().any(|(prior, _)| prior == policy_id) {
+                    return Err(InsuranceError::DuplicateClaim);
+                }
+                claimed = claimed.saturating_add(*amount);
+            }
+            if claimed > total_loss {
+                return Err(InsuranceError::RecoveryExceedsLoss);
+            }
+            // All of a holder's recoveries on an incident go through one filing
+            let claimant = self.env().caller();
+            if self
+                .incident_coordinations
+                .contains(&(incident_id, claimant))
+            {
+                return Err(InsuranceError::DuplicateClaim);
+            }
+            let incident = self
+                .incidents
+                .get(&incident_id)
+                .ok_or(InsuranceError::IncidentNotFound)?;
+            if incident.claim_ids.iter().any(|id| {
+                self.claims.get(id).is_some_and(|claim| {
+                    claim.claimant == claimant && claim.status != ClaimStatus::Rejected
+                })
+            }) {
+                return Err(InsuranceError::DuplicateClaim);
+            }
+
+            let mut claim_ids = Vec::new();
+            for (policy_id, amount) in parts {
+                let claim_id = self.open_claim(
+                    policy_id,
+                    incident_id,
+                    amount,
+                    description.clone(),
+                    evidence_url.clone(),
+                    Some(&claim_ids),
+                )?;
+                claim_ids.push(claim_id);
+            }
+
+            let coordination_id = self.coordination_count + 1;
+            self.coordination_count = coordination_id;
+            for claim_id in claim_ids.iter() {
+                self.claim_coordination.insert(claim_id, &coordination_id);
+            }
+            self.incident_coordinations
+                .insert(&(incident_id, claimant), &coordination_id);
+            self.claim_coordinations.insert(
+                &coordination_id,
+                &ClaimCoordination {
+                    coordination_id,
+                    incident_id,
+                    claimant,
+                    total_loss,
+                    assessed_loss: None,
+                    claim_ids: claim_ids.clone(),
+                    total_paid: 0,
+                    opened_at: self.env().block_timestamp(),
+                    settled_at: None,
+                },
+            );
+            self.env().emit_event(CoordinatedClaimOpened {
+                coordination_id,
+                incident_id,
+                claimant,
+                total_loss,
+                claim_ids,
+            });
+            Ok(coordination_id)
+        }
+
+        /// Record the assessed loss of a coordinated filing (admin or assessor). The
+        /// declared loss is lowered to it, and linked claims can be approved only
+        /// once it is set.
+        #[ink(message)]
+        pub fn assess_coordinated_loss(
+            &mut self,
+            coordination_id: u64,
+            assessed_loss: u128,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_assessors.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let mut coordination = self
+                .claim_coordinations
+                .get(&coordination_id)
+                .ok_or(InsuranceError::CoordinationNotFound)?;
+            if coordination.assessed_loss.is_some() {
+                return Err(InsuranceError::ClaimAlreadyProcessed);
+            }
+            coordination.assessed_loss = Some(assessed_loss);
+            coordination.total_loss = coordination.total_loss.min(assessed_loss);
+            self.claim_coordinations
+                .insert(&coordination_id, &coordination);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_claim_coordination(&self, coordination_id: u64) -> Option<ClaimCoordination> {
+            self.claim_coordinations.get(&coordination_id)
+        }
+
+        /// Coordinated filing a claim belongs to, if any
+        #[ink(message)]
+        pub fn get_claim_coordination_id(&self, claim_id: u64) -> Option<u64> {
+            self.claim_coordination.get(&claim_id)
+        }
+
+        /// Consolidated statement across a coordinated filing's linked claims
+        #[ink(message)]
+        pub fn get_coordinated_claim_statement(
+            &self,
+            coordination_id: u64,
+        ) -> Result<CoordinatedClaimStatement, InsuranceError> {
+            let coordination = self
+                .claim_coordinations
+                .get(&coordination_id)
+                .ok_or(InsuranceError::CoordinationNotFound)?;
+            Ok(self.coordinated_statement(&coordination))
+        }
+
+        fn coordinated_statement(
+            &self,
+            coordination: &ClaimCoordination,
+        ) -> CoordinatedClaimStatement {
+            let mut lines = Vec::new();
+            let mut total_claimed: u128 = 0;
+            for claim_id in coordination.claim_ids.iter() {
+                let Some(claim) = self.claims.get(claim_id) else {
+                    continue;
+                };
+                let coverage_type = self
+                    .policies
+                    .get(&claim.policy_id)
+                    .map(|policy| policy.coverage_type)
+                    .unwrap_or(CoverageType::Comprehensive);
+                total_claimed = total_claimed.saturating_add(claim.claim_amount);
+                lines.push(CoordinatedClaimLine {
+                    claim_id: *claim_id,
+                    policy_id: claim.policy_id,
+                    coverage_type,
+                    claimed: claim.claim_amount,
+                    paid: claim.payout_amount,
+                    status: claim.status,
+                });
+            }
+            CoordinatedClaimStatement {
+                coordination_id: coordination.coordination_id,
+                incident_id: coordination.incident_id,
+                claimant: coordination.claimant,
+                total_loss: coordination.total_loss,
+                lines,
+                total_claimed,
+                total_paid: coordination.total_paid,
+                settled_at: coordination.settled_at,
+            }
+        }
+
+        /// Whether a policy of one coverage type can respond to an incident of
+        /// another peril as part of a coordinated filing: liability cover follows
+        /// any peril, natural-disaster cover its constituent perils
+        fn covers_linked_peril(coverage: &CoverageType, peril: &CoverageType) -> bool {
+            match coverage {
+                CoverageType::LiabilityDamage => true,
+                CoverageType::NaturalDisaster => matches!(
+                    peril,
+                    CoverageType::Fire | CoverageType::Flood | CoverageType::Earthquake
+                ),
+                _ => false,
+            }
+        }
+
+        /// Cap an approved payout so the linked claims never recover more than
+        /// the declared loss between them
+        fn cap_coordinated_payout(&mut self, claim_id: u64, payout: u128) -> u128 {
+            let Some(coordination_id) = self.claim_coordination.get(&claim_id) else {
+                return payout;
+            };
+            let Some(mut coordination) = self.claim_coordinations.get(&coordination_id) else {
+                return payout;
+            };
+            let payout = payout.min(
+                coordination
+                    .total_loss
+                    .saturating_sub(coordination.total_paid),
+            );
+            coordination.total_paid = coordination.total_paid.saturating_add(payout);
+            self.claim_coordinations
+                .insert(&coordination_id, &coordination);
+            payout
+        }
+
+        /// Settle the coordinated filing once every linked claim has been decided;
+        /// the event pins the consolidated statement by hash
+        fn settle_coordination(&mut self, claim_id: u64) {
+            let Some(coordination_id) = self.claim_coordination.get(&claim_id) else {
+                return;
+            };
+            let Some(mut coordination) = self.claim_coordinations.get(&coordination_id) else {
+                return;
+            };
+            let decided = coordination.claim_ids.iter().all(|id| {
+                self.claims.get(id).is_some_and(|claim| {
+                    matches!(
+                        claim.status,
+                        ClaimStatus::Approved | ClaimStatus::Rejected | ClaimStatus::Paid
+                    )
+                })
+            });
+            if !decided {
+                // A dispute reopened a linked claim
+                coordination.settled_at = None;
+                self.claim_coordinations
+                    .insert(&coordination_id, &coordination);
+                return;
+            }
+            if coordination.settled_at.is_some() {
+                return;
+            }
+            coordination.settled_at = Some(self.env().block_timestamp());
+            self.claim_coordinations
+                .insert(&coordination_id, &coordination);
+            let statement = self.coordinated_statement(&coordination);
+            let mut digest = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&statement, &mut digest);
+            self.env().emit_event(CoordinatedClaimSettled {
+                coordination_id,
+                incident_id: coordination.incident_id,
+                total_paid: coordination.total_paid,
+                statement_hash: Hash::from(digest),
+            });
+        }
+
+        // =====================================================================
+        // THIRD-PARTY LIABILITY CLAIMS
+        // =====================================================================
+
+        /// File a claim against a policy's liability cover as an injured third party.
+        /// The policyholder is sent a notice and may contest before a decision.
+        #[ink(message)]
+        pub fn file_liability_claim(
+            &mut self,
+            policy_id: u64,
+            incident_id: u64,
+            claim_amount: u128,
+            description: String,
+            evidence_url: String,
+        ) -> Result<u64, InsuranceError> {
+            self.ensure_not_paused(PauseDomain::ClaimsIntake)?;
+            let caller = self.env().caller();
+            let now = self.env().block_timestamp();
+
+            let policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            if policy.policyholder == caller {
+                return Err(InsuranceError::ThirdPartyOnly);
+            }
+            if policy.status != PolicyStatus::Active {
+                return Err(InsuranceError::PolicyInactive);
+            }
+            if now > policy.end_time {
+                return Err(InsuranceError::PolicyExpired);
+            }
+            if policy.coverage_type != CoverageType::LiabilityDamage
+                && policy.coverage_type != CoverageType::Comprehensive
+            {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let remaining = policy.coverage_amount.saturating_sub(policy.total_claimed);
+            if claim_amount == 0 || claim_amount > remaining {
+                return Err(InsuranceError::ClaimExceedsCoverage);
+            }
+
+            let mut incident = self
+                .incidents
+                .get(&incident_id)
+                .ok_or(InsuranceError::IncidentNotFound)?;
+            if incident.property_id != policy.property_id
+                || incident.peril != CoverageType::LiabilityDamage
+                || incident.occurred_at < policy.start_time
+                || incident.occurred_at > policy.end_time
+            {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            if incident.occurred_at < self.waiting_period_end(&policy, &incident.peril) {
+                return Err(InsuranceError::WaitingPeriodActive);
+            }
+
+            let prior_filings = self.liability_filings.get(&caller).unwrap_or(0);
+            let fraud_score = Self::liability_fraud_score(
+                &policy,
+                &incident,
+                claim_amount,
+                remaining,
+                prior_filings,
+            );
+
+            let claim_id = self.claim_count + 1;
+            self.claim_count = claim_id;
+            incident.claim_ids.push(claim_id);
+            self.incidents.insert(&incident_id, &incident);
+            self.liability_filings
+                .insert(&caller, &prior_filings.saturating_add(1));
+
+            let claim = ThirdPartyClaim {
+                claim_id,
+                policy_id,
+                incident_id,
+                claimant: caller,
+                claim_amount,
+                description,
+                evidence_url,
+                status: ClaimStatus::Pending,
+                fraud_score,
+                contested: false,
+                contest_reason: String::new(),
+                submitted_at: now,
+                processed_at: None,
+                payout_amount: 0,
+                assessor: None,
+                rejection_reason: String::new(),
+            };
+            self.liability_claims.insert(&claim_id, &claim);
+            let mut policy_claims = self
+                .policy_liability_claims
+                .get(&policy_id)
+                .unwrap_or_default();
+            policy_claims.push(claim_id);
+            self.policy_liability_claims
+                .insert(&policy_id, &policy_claims);
+
+            self.liability_stats.filed += 1;
+            if fraud_score >= self.liability_fraud_threshold {
+                self.liability_stats.flagged += 1;
+            }
+
+            let mut digest = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(
+                &(claim_id, caller, claim_amount, &claim.evidence_url),
+                &mut digest,
+            );
+            self.record_notice(
+                policy_id,
+                policy.policyholder,
+                NoticeKind::ThirdPartyClaim,
+                Hash::from(digest),
+            );
+            self.env().emit_event(LiabilityClaimFiled {
+                claim_id,
+                policy_id,
+                policyholder: policy.policyholder,
+                claimant: caller,
+                claim_amount,
+                fraud_score,
+            });
+            Ok(claim_id)
+        }
+
+        /// Policyholder contests a pending third-party claim; the reason goes to the assessor
+        #[ink(message)]
+        pub fn contest_liability_claim(
+            &mut self,
+            claim_id: u64,
+            reason: String,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let mut claim = self
+                .liability_claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::LiabilityClaimNotFound)?;
+            let policy = self
+                .policies
+                .get(&claim.policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            if caller != policy.policyholder {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if claim.status != ClaimStatus::Pending {
+                return Err(InsuranceError::ClaimAlreadyProcessed);
+            }
+            if !claim.contested {
+                self.liability_stats.contested += 1;
+            }
+            claim.contested = true;
+            claim.contest_reason = reason.clone();
+            self.liability_claims.insert(&claim_id, &claim);
+            self.env().emit_event(LiabilityClaimContested {
+                claim_id,
+                policyholder: caller,
+                reason,
+            });
+            Ok(())
+        }
+
+        /// Assessor decides a third-party claim; an approved payout goes to the third
+        /// party. Claims scored at or above the fraud threshold need the admin.
+        #[ink(message)]
+        pub fn adjudicate_liability_claim(
+            &mut self,
+            claim_id: u64,
+            approved: bool,
+            rejection_reason: String,
+        ) -> Result<u128, InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_assessors.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let mut claim = self
+                .liability_claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::LiabilityClaimNotFound)?;
+            if claim.status != ClaimStatus::Pending {
+                return Err(InsuranceError::ClaimAlreadyProcessed);
+            }
+            if claim.fraud_score >= self.liability_fraud_threshold && caller != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+
+            let now = self.env().block_timestamp();
+            claim.assessor = Some(caller);
+            claim.processed_at = Some(now);
+            if approved {
+                let policy = self
+                    .policies
+                    .get(&claim.policy_id)
+                    .ok_or(InsuranceError::PolicyNotFound)?;
+                let incident_key = (claim.incident_id, claim.policy_id);
+                let incident_paid = self.incident_payouts.get(&incident_key).unwrap_or(0);
+                let incident_cap = policy
+                    .coverage_amount
+                    .saturating_mul(self.incident_payout_limit as u128)
+                    / 10_000;
+                let (deductible_applied, copay_amount, payout) =
+                    Self::split_loss(&policy, claim.claim_amount);
+                let payout = payout
+                    .min(policy.coverage_amount.saturating_sub(policy.total_claimed))
+                    .min(incident_cap.saturating_sub(incident_paid));
+                self.incident_payouts
+                    .insert(&incident_key, &incident_paid.saturating_add(payout));
+
+                claim.payout_amount = payout;
+                claim.status = ClaimStatus::Approved;
+                self.liability_claims.insert(&claim_id, &claim);
+                self.execute_payout(
+                    claim_id,
+                    claim.policy_id,
+                    claim.claimant,
+                    payout,
+                    deductible_applied,
+                    copay_amount,
+                )?;
+                if payout > 0 {
+                    claim.status = ClaimStatus::Paid;
+                }
+                self.liability_stats.approved += 1;
+                self.liability_stats.total_paid =
+                    self.liability_stats.total_paid.saturating_add(payout);
+            } else {
+                claim.status = ClaimStatus::Rejected;
+                claim.rejection_reason = rejection_reason;
+                self.liability_stats.rejected += 1;
+            }
+            self.liability_claims.insert(&claim_id, &claim);
+
+            self.env().emit_event(LiabilityClaimDecided {
+                claim_id,
+                claimant: claim.claimant,
+                approved,
+                payout_amount: claim.payout_amount,
+                decided_by: caller,
+            });
+            Ok(claim.payout_amount)
+        }
+
+        /// Set the fraud score at which third-party claims need an admin decision (admin only)
+        #[ink(message)]
+        pub fn set_liability_fraud_threshold(
+            &mut self,
+            threshold: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if threshold > 100 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.liability_fraud_threshold = threshold;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_liability_claim(&self, claim_id: u64) -> Option<ThirdPartyClaim> {
+            self.liability_claims.get(&claim_id)
+        }
+
+        #[ink(message)]
+        pub fn get_policy_liability_claims(&self, policy_id: u64) -> Vec<u64> {
+            self.policy_liability_claims
+                .get(&policy_id)
+                .unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn get_liability_claim_stats(&self) -> LiabilityClaimStats {
+            self.liability_stats.clone()
+        }
+
+        /// Scores a third-party filing 0-100 from signals kept apart from
+        /// policyholder claims: a flagged incident, repeat filers, losses soon after
+        /// cover starts and claims for nearly all remaining cover
+        fn liability_fraud_score(
+            policy: &InsurancePolicy,
+            incident: &Incident,
+            claim_amount: u128,
+            remaining: u128,
+            prior_filings: u32,
+        ) -> u32 {
+            let mut score = 0u32;
+            if incident.flagged {
+                score += 30;
+            }
+            score += prior_filings.min(2) * 15;
+            if incident.occurred_at.saturating_sub(policy.start_time) < 604_800 {
+                score += 20; // Within a week of cover starting
+            }
+            if claim_amount.saturating_mul(10) >= remaining.saturating_mul(8) {
+                score += 20;
+            }
+            score
+        }
+
+        // =====================================================================
+        // ADJUSTER EXPENSES
+        // =====================================================================
+
+        /// Set the most an assigned adjuster may charge to one claim without the
+        /// admin recording the expense (admin only)
+        #[ink(message)]
+        pub fn set_adjuster_expense_cap(
+            &mut self,
+            pool_id: u64,
+            cap: u128,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            self.adjuster_expense_caps.insert(&pool_id, &cap);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_adjuster_expense_cap(&self, pool_id: u64) -> u128 {
+            self.adjuster_expense_caps.get(&pool_id).unwrap_or(0)
+        }
+
+        /// Record a loss-adjustment expense against a claim and pay it from the
+        /// claim's pool. The assessor assigned to an open claim may record expenses
+        /// up to the pool's per-claim cap; anything else needs the admin.
+        #[ink(message)]
+        pub fn record_adjuster_expense(
+            &mut self,
+            claim_id: u64,
+            payee: AccountId,
+            amount: u128,
+            reason_hash: Hash,
+        ) -> Result<u64, InsuranceError> {
+            let caller = self.env().caller();
+            if amount == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.ensure_not_paused(PauseDomain::Payouts)?;
+            let claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            let pool_id = self
+                .policies
+                .get(&claim.policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?
+                .pool_id;
+            let claim_total = self
+                .claim_expense_totals
+                .get(&claim_id)
+                .unwrap_or(0)
+                .saturating_add(amount);
+            if caller != self.admin {
+                if self.claim_assignments.get(&claim_id) != Some(caller) {
+                    return Err(InsuranceError::Unauthorized);
+                }
+                if !matches!(
+                    claim.status,
+                    ClaimStatus::Pending | ClaimStatus::UnderReview | ClaimStatus::OracleVerifying
+                ) {
+                    return Err(InsuranceError::ClaimAlreadyProcessed);
+                }
+                if claim_total > self.adjuster_expense_caps.get(&pool_id).unwrap_or(0) {
+                    return Err(InsuranceError::ExpenseCapExceeded);
+                }
+            }
+            let mut pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if pool.available_capital < amount {
+                return Err(InsuranceError::InsufficientPoolFunds);
+            }
+            pool.available_capital -= amount;
+            self.pools.insert(&pool_id, &pool);
+            self.record_nav(pool_id);
+
+            let now = self.env().block_timestamp();
+            let expense_id = self.adjuster_expense_count + 1;
+            self.adjuster_expense_count = expense_id;
+            self.adjuster_expenses.insert(
+                &expense_id,
+                &AdjusterExpense {
+                    expense_id,
+                    claim_id,
+                    pool_id,
+                    payee,
+                    amount,
+                    reason_hash,
+                    recorded_by: caller,
+                    recorded_at: now,
+                },
+            );
+            let mut ids = self.claim_expenses.get(&claim_id).unwrap_or_default();
+            ids.push(expense_id);
+            self.claim_expenses.insert(&claim_id, &ids);
+            self.claim_expense_totals.insert(&claim_id, &claim_total);
+            let total = self.pool_expenses.get(&pool_id).unwrap_or(0);
+            self.pool_expenses
+                .insert(&pool_id, &total.saturating_add(amount));
+            let period_key = (pool_id, now / self.statistics_period);
+            let period_total = self.period_expenses.get(&period_key).unwrap_or(0);
+            self.period_expenses
+                .insert(&period_key, &period_total.saturating_add(amount));
+
+            self.pay_from_pool(pool_id, payee, amount)?;
+
+            self.env().emit_event(AdjusterExpenseRecorded {
+                expense_id,
+                claim_id,
+                payee,
+                amount,
+                reason_hash,
+            });
+            Ok(expense_id)
+        }
+
+        #[ink(message)]
+        pub fn get_adjuster_expense(&self, expense_id: u64) -> Option<AdjusterExpense> {
+            self.adjuster_expenses.get(&expense_id)
+        }
+
+        /// Expenses recorded against a claim, oldest first
+        #[ink(message)]
+        pub fn get_claim_expenses(&self, claim_id: u64) -> Vec<AdjusterExpense> {
+            self.claim_expenses
+                .get(&claim_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| self.adjuster_expenses.get(id))
+                .collect()
+        }
+
+        /// Expenses a pool paid during a statistics period
+        #[ink(message)]
+        pub fn get_period_expenses(&self, pool_id: u64, period: u64) -> u128 {
+            self.period_expenses.get(&(pool_id, period)).unwrap_or(0)
+        }
+
+        /// Loss, expense and combined ratios of a pool since inception
+        #[ink(message)]
+        pub fn get_pool_kpis(&self, pool_id: u64) -> Result<PoolKpis, InsuranceError> {
+            let pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            let expenses = self.pool_expenses.get(&pool_id).unwrap_or(0);
+            let premiums = pool.total_premiums_collected;
+            let ratio = |amount: u128| -> u32 {
+                if amount == 0 {
+                    return 0;
+                }
+                amount
+                    .saturating_mul(10_000)
+                    .checked_div(premiums)
+                    .map_or(u32::MAX, |r| r.min(u32::MAX as u128) as u32)
+            };
+            let loss_ratio = ratio(pool.total_claims_paid);
+            let expense_ratio = ratio(expenses);
+            Ok(PoolKpis {
+                pool_id,
+                premiums_collected: premiums,
+                claims_paid: pool.total_claims_paid,
+                adjuster_expenses: expenses,
+                loss_ratio,
+                expense_ratio,
+                combined_ratio: loss_ratio.saturating_add(expense_ratio),
+            })
+        }
+
+        // =====================================================================
+        // ASSESSOR STAKING
+        // =====================================================================
+
+        /// Bond stake as an authorized assessor; once the bond reaches the minimum
+        /// the assessor joins the pool that new claims are assigned from
+        #[ink(message, payable)]
+        pub fn bond_assessor(&mut self) -> Result<u128, InsuranceError> {
+            let caller = self.env().caller();
+            if !self.authorized_assessors.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(InsuranceError::InsufficientStake);
+            }
+            let mut stats = self.assessor_stats.get(&caller).unwrap_or(AssessorStats {
+                reputation: INITIAL_ASSESSOR_REPUTATION,
+                ..Default::default()
+            });
+            stats.stake = stats.stake.saturating_add(amount);
+            self.assessor_stats.insert(&caller, &stats);
+            if stats.stake >= self.min_assessor_stake && !self.assessor_pool.contains(&caller) {
+                self.assessor_pool.push(caller);
+            }
+
+            self.env().emit_event(AssessorBonded {
+                assessor: caller,
+                amount,
+                total_stake: stats.stake,
+            });
+            Ok(stats.stake)
+        }
+
+        /// Withdraw bonded stake; not while assigned claims are undecided
+        #[ink(message)]
+        pub fn unbond_assessor(&mut self, amount: u128) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let mut stats = self
+                .assessor_stats
+                .get(&caller)
+                .ok_or(InsuranceError::Unauthorized)?;
+            if stats.open_assignments > 0 {
+                return Err(InsuranceError::AssessmentsPending);
+            }
+            if amount > stats.stake {
+                return Err(InsuranceError::InsufficientStake);
+            }
+            stats.stake -= amount;
+            self.assessor_stats.insert(&caller, &stats);
+            if stats.stake < self.min_assessor_stake {
+                self.assessor_pool.retain(|a| *a != caller);
+            }
+            self.env()
+                .transfer(caller, amount)
+                .map_err(|_| InsuranceError::TransferFailed)
+        }
+
+        /// Assessor declares (or withdraws) a conflict of interest with a party.
+        /// Claims that party files are never assigned to the assessor.
+        #[ink(message)]
+        pub fn declare_assessor_conflict(
+            &mut self,
+            party: AccountId,
+            conflicted: bool,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            if !self.authorized_assessors.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if conflicted {
+                self.assessor_conflicts.insert(&(caller, party), &true);
+            } else {
+                self.assessor_conflicts.remove(&(caller, party));
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn has_assessor_conflict(&self, assessor: AccountId, party: AccountId) -> bool {
+            self.assessor_conflicts
+                .get(&(assessor, party))
+                .unwrap_or(false)
+        }
+
+        /// Assigned assessor steps down from an undecided claim. The conflict with the
+        /// claimant is recorded and the claim is drawn again among the other assessors.
+        #[ink(message)]
+        pub fn recuse_from_claim(&mut self, claim_id: u64) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            if self.claim_assignments.get(&claim_id) != Some(caller) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if !matches!(
+                claim.status,
+                ClaimStatus::Pending | ClaimStatus::UnderReview | ClaimStatus::OracleVerifying
+            ) {
+                return Err(InsuranceError::ClaimAlreadyProcessed);
+            }
+            self.assessor_conflicts
+                .insert(&(caller, claim.claimant), &true);
+            self.claim_assignments.remove(&claim_id);
+            self.release_assessor_slot(caller);
+            self.env().emit_event(AssessorRecused {
+                claim_id,
+                assessor: caller,
+            });
+            self.assign_assessor(claim_id, claim.claimant, Some(caller));
+            Ok(())
+        }
+
+        /// Claimant disputes a rejection made by a pool assessor
+        #[ink(message)]
+        pub fn dispute_claim(&mut self, claim_id: u64) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            let mut claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            if caller != claim.claimant {
+                return Err(InsuranceError::Unauthorized);
+            }
+            let assessor = match claim.assessor {
+                Some(assessor)
+                    if claim.status == ClaimStatus::Rejected && assessor != self.admin =>
+                {
+                    assessor
+                }
+                _ => return Err(InsuranceError::ClaimNotDisputable),
+            };
+            self.set_claim_status(&mut claim, ClaimStatus::Disputed);
+            self.claims.insert(&claim_id, &claim);
+
+            self.env().emit_event(ClaimDisputed {
+                claim_id,
+                assessor,
+                disputed_by: caller,
+            });
+            Ok(())
+        }
+
+        /// Admin settles a dispute. An overturned rejection costs the assessor
+        /// reputation and part of their stake (paid into the claim's pool), and the
+        /// claim goes back for review by a different assessor.
+        #[ink(message)]
+        pub fn resolve_dispute(
+            &mut self,
+            claim_id: u64,
+            overturned: bool,
+        ) -> Result<u128, InsuranceError> {
+            self.ensure_admin()?;
+            let mut claim = self
+                .claims
+                .get(&claim_id)
+                .ok_or(InsuranceError::ClaimNotFound)?;
+            if claim.status != ClaimStatus::Disputed {
+                return Err(InsuranceError::ClaimNotDisputable);
+            }
+            let assessor = claim.assessor.ok_or(InsuranceError::ClaimNotDisputable)?;
+            let mut stats = self.assessor_stats.get(&assessor).unwrap_or_default();
+            let mut slashed = 0u128;
+
+            if overturned {
+                slashed = stats.stake.saturating_mul(self.assessor_slash_rate as u128) / 10_000;
+                stats.stake -= slashed;
+                stats.slashed_total = stats.slashed_total.saturating_add(slashed);
+                stats.overturned += 1;
+                stats.reputation = stats
+                    .reputation
+                    .saturating_sub(self.overturn_reputation_penalty);
+                if stats.stake < self.min_assessor_stake {
+                    self.assessor_pool.retain(|a| *a != assessor);
+                }
+                if slashed > 0 {
+                    let policy = self
+                        .policies
+                        .get(&claim.policy_id)
+                        .ok_or(InsuranceError::PolicyNotFound)?;
+                    if let Some(mut pool) = self.pools.get(&policy.pool_id) {
+                        pool.available_capital = pool.available_capital.saturating_add(slashed);
+                        self.pools.insert(&policy.pool_id, &pool);
+                        self.record_nav(policy.pool_id);
+                    }
+                }
+                self.set_claim_status(&mut claim, ClaimStatus::Pending);
+                claim.assessor = None;
+                claim.processed_at = None;
+                claim.rejection_reason = String::new();
+            } else {
+                stats.disputes_upheld += 1;
+                stats.reputation = stats
+                    .reputation
+                    .saturating_add(UPHELD_REPUTATION_REWARD)
+                    .min(MAX_ASSESSOR_REPUTATION);
+                self.set_claim_status(&mut claim, ClaimStatus::Rejected);
+            }
+            self.assessor_stats.insert(&assessor, &stats);
+            self.claims.insert(&claim_id, &claim);
+            if overturned {
+                self.claim_assignments.remove(&claim_id);
+                self.assign_assessor(claim_id, claim.claimant, Some(assessor));
+            }
+            self.settle_coordination(claim_id);
+
+            self.env().emit_event(DisputeResolved {
+                claim_id,
+                assessor,
+                overturned,
+                slashed,
+                reputation: stats.reputation,
+            });
+            Ok(slashed)
+        }
+
+        /// Configure the minimum bond, slash rate and reputation penalty (admin only)
+        #[ink(message)]
+        pub fn set_assessor_params(
+            &mut self,
+            min_stake: u128,
+            slash_rate: u32,
+            overturn_penalty: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if slash_rate > 10_000 || overturn_penalty > MAX_ASSESSOR_REPUTATION {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.min_assessor_stake = min_stake;
+            self.assessor_slash_rate = slash_rate;
+            self.overturn_reputation_penalty = overturn_penalty;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_assessor_stats(&self, account: AccountId) -> Option<AssessorStats> {
+            self.assessor_stats.get(&account)
+        }
+
+        #[ink(message)]
+        pub fn get_claim_assignment(&self, claim_id: u64) -> Option<AccountId> {
+            self.claim_assignments.get(&claim_id)
+        }
+
+        #[ink(message)]
+        pub fn get_assessor_pool(&self) -> Vec<AccountId> {
+            self.assessor_pool.clone()
+        }
+
+        // =====================================================================
+        // SURPLUS SHARING
+        // =====================================================================
+
+        /// Close the pool's surplus period (admin only). When the period loss ratio is
+        /// within the configured threshold, the configured share of surplus is credited
+        /// to claim-free policyholders pro-rata to premiums paid. Policies still in
+        /// force, or that expired during the period, take part.
+        ///
+        /// Each call examines up to `limit` policies, first to tally eligibility and
+        /// then to credit shares; returns the report once the period is closed.
+        #[ink(message)]
+        pub fn close_surplus_period(
+            &mut self,
+            pool_id: u64,
+            limit: u32,
+        ) -> Result<Option<SurplusPeriodReport>, InsuranceError> {
+            self.ensure_admin()?;
+            let config = self
+                .surplus_configs
+                .get(&pool_id)
+                .ok_or(InsuranceError::SurplusSharingNotConfigured)?;
+            let period = self
+                .surplus_periods
+                .get(&pool_id)
+                .ok_or(InsuranceError::SurplusSharingNotConfigured)?;
+            let mut pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if limit == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let policies = self.pool_policies.get(&pool_id).unwrap_or_default();
+            let mut close = match self.surplus_closes.get(&pool_id) {
+                Some(close) => close,
+                None => {
+                    let premiums = pool
+                        .total_premiums_collected
+                        .saturating_sub(period.premiums_at_start);
+                    let claims_paid = pool
+                        .total_claims_paid
+                        .saturating_sub(period.claims_at_start);
+                    SurplusClose {
+                        closed_at: self.env().block_timestamp(),
+                        premiums,
+                        claims_paid,
+                        // No premiums in the period counts as a 100% loss ratio
+                        loss_ratio: claims_paid
+                            .saturating_mul(10_000)
+                            .checked_div(premiums)
+                            .map_or(10_000, |ratio| ratio.min(u32::MAX as u128) as u32),
+                        policy_count: policies.len() as u32,
+                        scanned: 0,
+                        eligible_policies: 0,
+                        eligible_premiums: 0,
+                        pot: 0,
+                        credited: 0,
+                        distributed: 0,
+                    }
+                }
+            };
+            let mut budget = limit;
+            let scanning = close.scanned < close.policy_count;
+
+            // Claim-free policies weighted by premium paid
+            while budget > 0 && close.scanned < close.policy_count {
+                let policy_id = policies[close.scanned as usize];
+                close.scanned += 1;
+                budget -= 1;
+                let Some(policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                let in_period = policy.status == PolicyStatus::Active
+                    || (policy.status == PolicyStatus::Expired
+                        && policy.end_time >= period.started_at);
+                if !in_period {
+                    continue;
+                }
+                let claimed_in_period = self
+                    .policy_claims
+                    .get(&policy_id)
+                    .unwrap_or_default()
+                    .iter()
+                    .filter_map(|id| self.claims.get(id))
+                    .any(|claim| claim.submitted_at >= period.started_at);
+                if claimed_in_period {
+                    continue;
+                }
+                self.surplus_eligible.insert(
+                    &(pool_id, close.eligible_policies),
+                    &(policy.policyholder, policy.premium_amount),
+                );
+                close.eligible_policies += 1;
+                close.eligible_premiums = close
+                    .eligible_premiums
+                    .saturating_add(policy.premium_amount);
+            }
+            if scanning
+                && close.scanned == close.policy_count
+                && close.premiums > 0
+                && close.loss_ratio <= config.max_loss_ratio
+                && close.eligible_premiums > 0
+            {
+                close.pot = (close
+                    .premiums
+                    .saturating_sub(close.claims_paid)
+                    .saturating_mul(config.share_rate as u128)
+                    / 10_000)
+                    .min(pool.available_capital);
+                pool.available_capital -= close.pot;
+                self.pools.insert(&pool_id, &pool);
+            }
+
+            while budget > 0
+                && close.scanned == close.policy_count
+                && close.credited < close.eligible_policies
+            {
+                let index = close.credited;
+                close.credited += 1;
+                budget -= 1;
+                let Some((holder, premium)) = self.surplus_eligible.take(&(pool_id, index)) else {
+                    continue;
+                };
+                let share = close.pot.saturating_mul(premium) / close.eligible_premiums;
+                if share == 0 {
+                    continue;
+                }
+                if !self.credit_in_pool_asset(pool_id, holder, share) {
+                    let credit = self.surplus_credits.get(&holder).unwrap_or(0);
+                    self.surplus_credits
+                        .insert(&holder, &credit.saturating_add(share));
+                }
+                close.distributed += share;
+            }
+
+            if close.scanned < close.policy_count || close.credited < close.eligible_policies {
+                self.surplus_closes.insert(&pool_id, &close);
+                return Ok(None);
+            }
+            self.surplus_closes.remove(&pool_id);
+            let mut pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            // Rounding dust stays with the pool
+            pool.available_capital += close.pot - close.distributed;
+            self.pools.insert(&pool_id, &pool);
+            if close.pot > 0 {
+                self.record_nav(pool_id);
+            }
+
+            let period_index = self.surplus_period_count.get(&pool_id).unwrap_or(0) + 1;
+            let report = SurplusPeriodReport {
+                pool_id,
+                period: period_index,
+                started_at: period.started_at,
+                closed_at: close.closed_at,
+                premiums: close.premiums,
+                claims_paid: close.claims_paid,
+                loss_ratio: close.loss_ratio,
+                distributed: close.distributed,
+                eligible_policies: close.eligible_policies,
+            };
+            self.surplus_reports
+                .insert(&(pool_id, period_index), &report);
+            self.surplus_period_count.insert(&pool_id, &period_index);
+            self.surplus_periods.insert(
+                &pool_id,
+                &SurplusPeriod {
+                    started_at: close.closed_at,
+                    premiums_at_start: period.premiums_at_start.saturating_add(close.premiums),
+                    claims_at_start: period.claims_at_start.saturating_add(close.claims_paid),
+                },
+            );
+
+            self.env().emit_event(SurplusDistributed {
+                pool_id,
+                period: period_index,
+                loss_ratio: close.loss_ratio,
+                distributed: close.distributed,
+                eligible_policies: report.eligible_policies,
+                timestamp: close.closed_at,
+            });
+
+            Ok(Some(report))
+        }
+
+        #[ink(message)]
+        pub fn get_surplus_close(&self, pool_id: u64) -> Option<SurplusClose> {
+            self.surplus_closes.get(&pool_id)
+        }
+
+        /// Withdraw surplus distributions credited to the caller
+        #[ink(message)]
+        pub fn withdraw_surplus_credit(&mut self) -> Result<u128, InsuranceError> {
+            let caller = self.env().caller();
+            let credit = self.surplus_credits.get(&caller).unwrap_or(0);
+            if credit == 0 {
+                return Ok(0);
+            }
+            self.surplus_credits.insert(&caller, &0u128);
+            self.env()
+                .transfer(caller, credit)
+                .map_err(|_| InsuranceError::TransferFailed)?;
+            Ok(credit)
+        }
+
+        // =====================================================================
+        // REINSURANCE
+        // =====================================================================
+
+        /// Register a reinsurance agreement (admin only)
+        #[ink(message)]
+        pub fn register_reinsurance(
+            &mut self,
+            reinsurer: AccountId,
+            coverage_limit: u128,
+            retention_limit: u128,
+            premium_ceded_rate: u32,
+            coverage_types: Vec<CoverageType>,
+            duration_seconds: u64,
+        ) -> Result<u64, InsuranceError> {
+            self.ensure_admin()?;
+
+            let now = self.env().block_timestamp();
+            let agreement_id = self.reinsurance_count + 1;
+            self.reinsurance_count = agreement_id;
+
+            let agreement = ReinsuranceAgreement {
+                agreement_id,
+                reinsurer,
+                coverage_limit,
+                retention_limit,
+                premium_ceded_rate,
+                coverage_types,
+                start_time: now,
+                end_time: now.saturating_add(duration_seconds),
+                is_active: true,
+                total_ceded_premiums: 0,
+                total_recoveries: 0,
+                limit_utilized: 0,
+                reinstatements: 0,
+                reinstatements_used: 0,
+            };
+
+            self.reinsurance_agreements
+                .insert(&agreement_id, &agreement);
+            Ok(agreement_id)
+        }
+
+        /// Set how many times an agreement's layer limit can be reinstated (admin only)
+        #[ink(message)]
+        pub fn set_reinsurance_reinstatements(
+            &mut self,
+            agreement_id: u64,
+            reinstatements: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            let mut agreement = self
+                .reinsurance_agreements
+                .get(&agreement_id)
+                .ok_or(InsuranceError::InvalidParameters)?;
+            agreement.reinstatements = reinstatements;
+            self.reinsurance_agreements
+                .insert(&agreement_id, &agreement);
+            Ok(())
+        }
+
+        /// Propose terms for the period after an agreement ends (reinsurer only).
+        /// Allowed during the renewal window before `end_time`; a new proposal
+        /// replaces any pending one.
+        #[ink(message)]
+        pub fn propose_treaty_renewal(
+            &mut self,
+            agreement_id: u64,
+            coverage_limit: u128,
+            retention_limit: u128,
+            premium_ceded_rate: u32,
+            coverage_types: Vec<CoverageType>,
+            duration_seconds: u64,
+        ) -> Result<(), InsuranceError> {
+            let agreement = self
+                .reinsurance_agreements
+                .get(&agreement_id)
+                .ok_or(InsuranceError::InvalidParameters)?;
+            if self.env().caller() != agreement.reinsurer {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if coverage_limit == 0 || premium_ceded_rate > 10_000 || duration_seconds == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.ensure_renewal_window(&agreement_id, &agreement)?;
+
+            let proposal = TreatyRenewalProposal {
+                agreement_id,
+                coverage_limit,
+                retention_limit,
+                premium_ceded_rate,
+                coverage_types,
+                duration_seconds,
+                proposed_at: self.env().block_timestamp(),
+            };
+            self.treaty_renewals.insert(&agreement_id, &proposal);
+
+            self.env().emit_event(TreatyRenewalProposed {
+                agreement_id,
+                reinsurer: agreement.reinsurer,
+                coverage_limit,
+                retention_limit,
+                premium_ceded_rate,
+            });
+            Ok(())
+        }
+
+        /// Accept the pending renewal terms (admin only). The renewed treaty is a new
+        /// agreement starting when the current one ends, with a fresh layer limit.
+        #[ink(message)]
+        pub fn accept_treaty_renewal(&mut self, agreement_id: u64) -> Result<u64, InsuranceError> {
+            self.ensure_admin()?;
+            let agreement = self
+                .reinsurance_agreements
+                .get(&agreement_id)
+                .ok_or(InsuranceError::InvalidParameters)?;
+            let proposal = self
+                .treaty_renewals
+                .get(&agreement_id)
+                .ok_or(InsuranceError::RenewalNotFound)?;
+            self.ensure_renewal_window(&agreement_id, &agreement)?;
+
+            let renewed_id = self.reinsurance_count + 1;
+            self.reinsurance_count = renewed_id;
+            let renewed = ReinsuranceAgreement {
+                agreement_id: renewed_id,
+                reinsurer: agreement.reinsurer,
+                coverage_limit: proposal.coverage_limit,
+                retention_limit: proposal.retention_limit,
+                premium_ceded_rate: proposal.premium_ceded_rate,
+                coverage_types: proposal.coverage_types,
+                // `end_time` is inclusive; the renewed period picks up right after it
+                start_time: agreement.end_time.saturating_add(1),
+                end_time: agreement.end_time.saturating_add(proposal.duration_seconds),
+                is_active: true,
+                total_ceded_premiums: 0,
+                total_recoveries: 0,
+                limit_utilized: 0,
+                reinstatements: agreement.reinstatements,
+                reinstatements_used: 0,
+            };
+            self.reinsurance_agreements.insert(&renewed_id, &renewed);
+            self.treaty_successors.insert(&agreement_id, &renewed_id);
+            self.treaty_renewals.remove(&agreement_id);
+
+            self.env().emit_event(TreatyRenewalResolved {
+                agreement_id,
+                renewed_agreement_id: Some(renewed_id),
+            });
+            Ok(renewed_id)
+        }
+
+        /// Reject the pending renewal terms (admin only); the reinsurer may propose again
+        #[ink(message)]
+        pub fn reject_treaty_renewal(&mut self, agreement_id: u64) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if !self.treaty_renewals.contains(&agreement_id) {
+                return Err(InsuranceError::RenewalNotFound);
+            }
+            self.treaty_renewals.remove(&agreement_id);
+
+            self.env().emit_event(TreatyRenewalResolved {
+                agreement_id,
+                renewed_agreement_id: None,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_treaty_renewal(&self, agreement_id: u64) -> Option<TreatyRenewalProposal> {
+            self.treaty_renewals.get(&agreement_id)
+        }
+
+        /// Agreement that continues `agreement_id` after its end, if renewed
+        #[ink(message)]
+        pub fn get_treaty_successor(&self, agreement_id: u64) -> Option<u64> {
+            self.treaty_successors.get(&agreement_id)
+        }
+
+        /// Renewal is negotiated in the window before `end_time`, once per agreement
+        fn ensure_renewal_window(
+            &self,
+            agreement_id: &u64,
+            agreement: &ReinsuranceAgreement,
+        ) -> Result<(), InsuranceError> {
+            let now = self.env().block_timestamp();
+            if !agreement.is_active
+                || self.treaty_successors.contains(agreement_id)
+                || now > agreement.end_time
+                || now < agreement.end_time.saturating_sub(TREATY_RENEWAL_WINDOW)
+            {
+                return Err(InsuranceError::RenewalWindowClosed);
+            }
+            Ok(())
+        }
+
+        // =====================================================================
+        // INSURANCE TOKENIZATION & SECONDARY MARKET
+        // =====================================================================
+
+        /// List an insurance token for sale on the secondary market
+        #[ink(message)]
+        pub fn list_token_for_sale(
+            &mut self,
+            token_id: u64,
+            price: u128,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_not_paused(PauseDomain::SecondaryMarket)?;
+            let caller = self.env().caller();
+            let mut token = self
+                .insurance_tokens
+                .get(&token_id)
+                .ok_or(InsuranceError::TokenNotFound)?;
+
+            if token.owner != caller {
+                return Err(InsuranceError::Unauthorized);
+            }
+            if !token.is_tradeable {
+                return Err(InsuranceError::InvalidParameters);
+            }
+
+            token.listed_price = Some(price);
+            self.insurance_tokens.insert(&token_id, &token);
+
+            if !self.token_listings.contains(&token_id) {
+                self.token_listings.push(token_id);
+            }
+
+            Ok(())
+        }
+
+        /// Purchase an insurance token from the secondary market
+        #[ink(message, payable)]
+        pub fn purchase_token(&mut self, token_id: u64) -> Result<(), InsuranceError> {
+            self.ensure_not_paused(PauseDomain::SecondaryMarket)?;
+            let caller = self.env().caller();
+            let paid = self.env().transferred_value();
+
+            let mut token = self
+                .insurance_tokens
+                .get(&token_id)
+                .ok_or(InsuranceError::TokenNotFound)?;
+            let price = token
+                .listed_price
+                .ok_or(InsuranceError::InvalidParameters)?;
+
+            if paid < price {
+                return Err(InsuranceError::InsufficientPremium);
+            }
+
+            let seller = token.owner;
+            let old_owner = seller;
+
+            // Transfer the policy to the buyer
+            let policy = self
+                .policies
+                .get(&token.policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            if policy.status != PolicyStatus::Active {
+                return Err(InsuranceError::PolicyInactive);
+            }
+
+            // Update policy policyholder
+            let mut updated_policy = policy;
+            updated_policy.policyholder = caller;
+            self.policies.insert(&token.policy_id, &updated_policy);
+
+            // Update ownership tracking
+            let mut seller_policies = self.policyholder_policies.get(&seller).unwrap_or_default();
+            seller_policies.retain(|&p| p != token.policy_id);
+            self.policyholder_policies.insert(&seller, &seller_policies);
+
+            let mut buyer_policies = self.policyholder_policies.get(&caller).unwrap_or_default();
+            buyer_policies.push(token.policy_id);
+            self.policyholder_policies.insert(&caller, &buyer_policies);
+
+            // Update token
+            token.owner = caller;
+            token.listed_price = None;
+            self.insurance_tokens.insert(&token_id, &token);
+
+            // Remove from listings
+            self.token_listings.retain(|&t| t != token_id);
+
+            self.env().emit_event(InsuranceTokenTransferred {
+                token_id,
+                from: old_owner,
+                to: caller,
+                price: paid,
+            });
+
+            Ok(())
+        }
+
+        // =====================================================================
+        // ACTUARIAL MODELING
+        // =====================================================================
+
+        /// Update actuarial model (admin/authorized oracle)
+        #[ink(message)]
+        pub fn update_actuarial_model(
+            &mut self,
+            coverage_type: CoverageType,
+            loss_frequency: u32,
+            average_loss_severity: u128,
+            expected_loss_ratio: u32,
+            confidence_level: u32,
+            data_points: u32,
+        ) -> Result<u64, InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+
+            let model_id = self.model_count + 1;
+            self.model_count = model_id;
+
+            let model = ActuarialModel {
+                model_id,
+                coverage_type,
+                loss_frequency,
+                average_loss_severity,
+                expected_loss_ratio,
+                confidence_level,
+                last_updated: self.env().block_timestamp(),
+                data_points,
+            };
+
+            self.actuarial_models.insert(&model_id, &model);
+            Ok(model_id)
+        }
+
+        /// Permissionless keeper entry point. Produces a new model version for every
+        /// coverage type with claims settled since its last recalibration. Versions
+        /// within the shift limit of the adopted model are adopted for pricing; larger
+        /// shifts (or a first model) wait for admin review.
+        #[ink(message)]
+        pub fn recalibrate_models(&mut self) -> Result<Vec<u64>, InsuranceError> {
+            let now = self.env().block_timestamp();
+            let mut produced = Vec::new();
+            for coverage_type in [
+                CoverageType::Fire,
+                CoverageType::Flood,
+                CoverageType::Earthquake,
+                CoverageType::Theft,
+                CoverageType::LiabilityDamage,
+                CoverageType::NaturalDisaster,
+                CoverageType::Comprehensive,
+            ] {
+                let Some(mut experience) = self.loss_experience.get(&coverage_type) else {
+                    continue;
+                };
+                if experience.claims_settled == experience.calibrated_claims {
+                    continue;
+                }
+                experience.calibrated_claims = experience.claims_settled;
+                self.loss_experience.insert(&coverage_type, &experience);
+
+                let model_id = self.model_count + 1;
+                self.model_count = model_id;
+                let model = ActuarialModel {
+                    model_id,
+                    coverage_type: coverage_type.clone(),
+                    loss_frequency: Self::ratio_bps(
+                        experience.claims_approved as u128,
+                        experience.policies_written as u128,
+                    ),
+                    average_loss_severity: experience
+                        .total_payout
+                        .checked_div(experience.claims_approved as u128)
+                        .unwrap_or(0),
+                    expected_loss_ratio: Self::ratio_bps(
+                        experience.total_payout,
+                        experience.premiums_written,
+                    ),
+                    confidence_level: (experience.claims_settled * 100 / FULL_CREDIBILITY_CLAIMS)
+                        .min(100) as u32,
+                    last_updated: now,
+                    data_points: experience.claims_settled.min(u32::MAX as u64) as u32,
+                };
+                self.actuarial_models.insert(&model_id, &model);
+
+                let parameter_shift = self
+                    .adopted_models
+                    .get(&coverage_type)
+                    .and_then(|adopted| self.actuarial_models.get(&adopted))
+                    .map_or(u32::MAX, |adopted| Self::parameter_shift(&adopted, &model));
+                let pending_review = parameter_shift > self.recalibration_shift_limit;
+                if pending_review {
+                    self.models_pending_review.insert(&model_id, &true);
+                } else {
+                    self.adopt_model(&model);
+                }
+                self.env().emit_event(ActuarialModelRecalibrated {
+                    model_id,
+                    coverage_type,
+                    parameter_shift,
+                    pending_review,
+                });
+                produced.push(model_id);
+            }
+            Ok(produced)
+        }
+
+        /// Approve a flagged model for pricing, or discard it (admin only)
+        #[ink(message)]
+        pub fn review_actuarial_model(
+            &mut self,
+            model_id: u64,
+            approve: bool,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if self.models_pending_review.take(&model_id).is_none() {
+                return Err(InsuranceError::ModelNotPendingReview);
+            }
+            if approve {
+                let model = self
+                    .actuarial_models
+                    .get(&model_id)
+                    .ok_or(InsuranceError::ModelNotPendingReview)?;
+                self.adopt_model(&model);
+            }
+            Ok(())
+        }
+
+        /// Set the parameter shift needing review and the target loss ratio, both in
+        /// basis points (admin only)
+        #[ink(message)]
+        pub fn set_recalibration_params(
+            &mut self,
+            shift_limit: u32,
+            target_loss_ratio: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if target_loss_ratio == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.recalibration_shift_limit = shift_limit;
+            self.target_loss_ratio = target_loss_ratio;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_adopted_actuarial_model(
+            &self,
+            coverage_type: CoverageType,
+        ) -> Option<ActuarialModel> {
+            self.adopted_models
+                .get(&coverage_type)
+                .and_then(|model_id| self.actuarial_models.get(&model_id))
+        }
+
+        #[ink(message)]
+        pub fn is_model_pending_review(&self, model_id: u64) -> bool {
+            self.models_pending_review.get(&model_id).unwrap_or(false)
+        }
+
+        #[ink(message)]
+        pub fn get_loss_experience(&self, coverage_type: CoverageType) -> LossExperience {
+            self.loss_experience.get(&coverage_type).unwrap_or_default()
+        }
+
+        // =====================================================================
+        // UNDERWRITING
+        // =====================================================================
+
+        /// Set underwriting criteria for a pool (admin only)
+        #[ink(message)]
+        pub fn set_underwriting_criteria(
+            &mut self,
+            pool_id: u64,
+            max_property_age_years: u32,
+            min_property_value: u128,
+            max_property_value: u128,
+            required_safety_features: bool,
+            max_previous_claims: u32,
+            min_risk_score: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+
+            let criteria = UnderwritingCriteria {
+                max_property_age_years,
+                min_property_value,
+                max_property_value,
+                excluded_locations: Vec::new(),
+                required_safety_features,
+                max_previous_claims,
+                min_risk_score,
+            };
+
+            self.underwriting_criteria.insert(&pool_id, &criteria);
+            Ok(())
+        }
+
+        /// Replace the locations a pool declines to underwrite (admin only)
+        #[ink(message)]
+        pub fn set_excluded_locations(
+            &mut self,
+            pool_id: u64,
+            locations: Vec<String>,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            let mut criteria = self
+                .underwriting_criteria
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            criteria.excluded_locations = locations;
+            self.underwriting_criteria.insert(&pool_id, &criteria);
+            Ok(())
+        }
+
+        /// Waive a criterion for a property's next application to a pool (admin
+        /// only). The approver and reason are recorded on the policy's worksheet.
+        #[ink(message)]
+        pub fn approve_underwriting_override(
+            &mut self,
+            pool_id: u64,
+            property_id: u64,
+            criterion: UnderwritingCriterion,
+            reason: String,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if !self.underwriting_criteria.contains(&pool_id) {
+                return Err(InsuranceError::PoolNotFound);
+            }
+            if reason.is_empty() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            let approver = self.env().caller();
+            let mut overrides = self
+                .underwriting_overrides
+                .get(&(pool_id, property_id))
+                .unwrap_or_default();
+            overrides.retain(|o| o.criterion != criterion);
+            overrides.push(UnderwritingOverride {
+                criterion,
+                approver,
+                reason,
+                approved_at: self.env().block_timestamp(),
+            });
+            self.underwriting_overrides
+                .insert(&(pool_id, property_id), &overrides);
+
+            self.env().emit_event(UnderwritingOverrideApproved {
+                pool_id,
+                property_id,
+                criterion,
+                approver,
+            });
+            Ok(())
+        }
+
+        /// Overrides awaiting a property's next application to a pool
+        #[ink(message)]
+        pub fn get_underwriting_overrides(
+            &self,
+            pool_id: u64,
+            property_id: u64,
+        ) -> Vec<UnderwritingOverride> {
+            self.underwriting_overrides
+                .get(&(pool_id, property_id))
+                .unwrap_or_default()
+        }
+
+        /// Underwriting worksheet justifying a policy's issuance, for regulators.
+        /// None when the pool had no criteria at the time.
+        #[ink(message)]
+        pub fn get_underwriting_worksheet(&self, policy_id: u64) -> Option<UnderwritingWorksheet> {
+            self.underwriting_worksheets.get(&policy_id)
+        }
+
+        /// Evaluate a pool's criteria for an application. Failures without an
+        /// approved override decline it; returns the criteria and results to
+        /// record, or None when the pool has no criteria. Age, value and location
+        /// come from the property's recorded profile and fail when it is missing.
+        fn underwrite(
+            &self,
+            pool_id: u64,
+            property_id: u64,
+            assessment: &RiskAssessment,
+        ) -> Result<Option<(UnderwritingCriteria, Vec<CriterionResult>)>, InsuranceError> {
+            let Some(criteria) = self.underwriting_criteria.get(&pool_id) else {
+                return Ok(None);
+            };
+            let now = self.env().block_timestamp();
+            let previous_claims: u128 = self
+                .get_property_policies(property_id)
+                .iter()
+                .filter_map(|id| self.policies.get(id))
+                .map(|policy| policy.claims_count as u128)
+                .sum();
+            let safety_features = self
+                .property_mitigations
+                .get(&property_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| self.mitigation_documents.get(id))
+                .filter(|doc| doc.verified_by.is_some() && now <= doc.valid_until)
+                .count() as u128;
+            let overrides = self
+                .underwriting_overrides
+                .get(&(pool_id, property_id))
+                .unwrap_or_default();
+            let profile = self.property_profiles.get(&property_id);
+            let value = profile.as_ref().map(|p| p.appraised_value);
+            let age_years = profile
+                .as_ref()
+                .map(|p| now.saturating_sub(p.built_at) / (86_400 * 365));
+            let excluded = profile
+                .as_ref()
+                .map(|p| criteria.excluded_locations.contains(&p.location));
+
+            let checks = [
+                (
+                    UnderwritingCriterion::MinRiskScore,
+                    criteria.min_risk_score as u128,
+                    assessment.overall_risk_score as u128,
+                    assessment.overall_risk_score >= criteria.min_risk_score,
+                ),
+                (
+                    UnderwritingCriterion::MinPropertyValue,
+                    criteria.min_property_value,
+                    value.unwrap_or(0),
+                    value.is_some_and(|v| v >= criteria.min_property_value),
+                ),
+                (
+                    UnderwritingCriterion::MaxPropertyValue,
+                    criteria.max_property_value,
+                    value.unwrap_or(0),
+                    value.is_some_and(|v| v <= criteria.max_property_value),
+                ),
+                (
+                    UnderwritingCriterion::MaxPreviousClaims,
+                    criteria.max_previous_claims as u128,
+                    previous_claims,
+                    previous_claims <= criteria.max_previous_claims as u128,
+                ),
+                (
+                    UnderwritingCriterion::SafetyFeatures,
+                    criteria.required_safety_features as u128,
+                    safety_features,
+                    !criteria.required_safety_features || safety_features > 0,
+                ),
+                (
+                    UnderwritingCriterion::MaxPropertyAge,
+                    criteria.max_property_age_years as u128,
+                    age_years.unwrap_or(0) as u128,
+                    age_years.is_some_and(|age| age <= criteria.max_property_age_years as u64),
+                ),
+                (
+                    UnderwritingCriterion::ExcludedLocation,
+                    criteria.excluded_locations.len() as u128,
+                    excluded.unwrap_or(false) as u128,
+                    excluded == Some(false),
+                ),
+            ];
+            let mut results = Vec::new();
+            for (criterion, threshold, observed, passed) in checks {
+                let overridden = !passed && overrides.iter().any(|o| o.criterion == criterion);
+                if !passed && !overridden {
+                    return Err(InsuranceError::UnderwritingDeclined);
+                }
+                results.push(CriterionResult {
+                    criterion,
+                    threshold,
+                    observed,
+                    passed,
+                    overridden,
+                });
+            }
+            Ok(Some((criteria, results)))
+        }
+
+        // =====================================================================
+        // EMERGENCY PAUSE
+        // =====================================================================
+
+        /// Replace the guardian set and the confirmations needed to pause payouts (admin only)
+        #[ink(message)]
+        pub fn set_guardians(
+            &mut self,
+            guardians: Vec<AccountId>,
+            threshold: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if threshold == 0 || threshold as usize > guardians.len() {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.guardians = guardians;
+            self.guardian_threshold = threshold;
+            self.payout_pause_confirmations.clear();
+            Ok(())
+        }
+
+        /// Pause a domain. Payouts pause only once `guardian_threshold` guardians
+        /// have confirmed; other domains pause immediately for the admin or any
+        /// guardian. Returns whether the domain is now paused.
+        #[ink(message)]
+        pub fn pause_domain(&mut self, domain: PauseDomain) -> Result<bool, InsuranceError> {
+            let caller = self.env().caller();
+            let is_guardian = self.guardians.contains(&caller);
+
+            if domain == PauseDomain::Payouts {
+                if !is_guardian {
+                    return Err(InsuranceError::Unauthorized);
+                }
+                // Stale confirmations lapse; a repeat confirmation restarts the guardian's clock
+                let now = self.env().block_timestamp();
+                self.payout_pause_confirmations
+                    .retain(|(guardian, confirmed_at)| {
+                        *guardian != caller
+                            && now.saturating_sub(*confirmed_at) <= PAUSE_CONFIRMATION_TTL
+                    });
+                self.payout_pause_confirmations.push((caller, now));
+                let confirmations = self.payout_pause_confirmations.len() as u32;
+                self.env().emit_event(PayoutPauseConfirmed {
+                    guardian: caller,
+                    confirmations,
+                    required: self.guardian_threshold,
+                });
+                if confirmations < self.guardian_threshold {
+                    return Ok(false);
+                }
+                self.payout_pause_confirmations.clear();
+            } else if caller != self.admin && !is_guardian {
+                return Err(InsuranceError::Unauthorized);
+            }
+
+            self.set_domain_paused(domain, true, caller);
+            Ok(true)
+        }
+
+        /// Lift a pause (admin only). Unpausing payouts also restarts the velocity window.
+        #[ink(message)]
+        pub fn unpause_domain(&mut self, domain: PauseDomain) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if domain == PauseDomain::Payouts {
+                self.payout_pause_confirmations.clear();
+                self.payout_window_start = self.env().block_timestamp();
+                self.payout_window_total = 0;
+            }
+            self.set_domain_paused(domain, false, self.env().caller());
+            Ok(())
+        }
+
+        /// Configure the payout velocity breaker; a zero limit disables it (admin only)
+        #[ink(message)]
+        pub fn set_circuit_breaker(
+            &mut self,
+            max_payout_per_window: u128,
+            window_seconds: u64,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if window_seconds == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.payout_velocity_limit = max_payout_per_window;
+            self.payout_velocity_window = window_seconds;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn is_domain_paused(&self, domain: PauseDomain) -> bool {
+            self.paused_domains.get(&domain).unwrap_or(false)
+        }
+
+        #[ink(message)]
+        pub fn get_guardians(&self) -> (Vec<AccountId>, u32) {
+            (self.guardians.clone(), self.guardian_threshold)
+        }
+
+        /// Guardians whose payout pause confirmations have not yet lapsed
+        #[ink(message)]
+        pub fn get_payout_pause_confirmations(&self) -> Vec<AccountId> {
+            let now = self.env().block_timestamp();
+            self.payout_pause_confirmations
+                .iter()
+                .filter(|(_, confirmed_at)| {
+                    now.saturating_sub(*confirmed_at) <= PAUSE_CONFIRMATION_TTL
+                })
+                .map(|(guardian, _)| *guardian)
+                .collect()
+        }
+
+        // =====================================================================
+        // ADMIN / AUTHORITY MANAGEMENT
+        // =====================================================================
+
+        /// Authorize an oracle address
+        #[ink(message)]
+        pub fn authorize_oracle(&mut self, oracle: AccountId) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.authorized_oracles.insert(&oracle, &true);
+            Ok(())
+        }
+
+        /// Authorize a notary for mitigation documents
+        #[ink(message)]
+        pub fn authorize_notary(&mut self, notary: AccountId) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.authorized_notaries.insert(&notary, &true);
+            Ok(())
+        }
+
+        /// Authorize a premium finance lender
+        #[ink(message)]
+        pub fn authorize_lender(&mut self, lender: AccountId) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.authorized_lenders.insert(&lender, &true);
+            Ok(())
+        }
+
+        /// Authorize a claims assessor
+        #[ink(message)]
+        pub fn authorize_assessor(&mut self, assessor: AccountId) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.authorized_assessors.insert(&assessor, &true);
+            Ok(())
+        }
+
+        /// Update platform fee rate (admin only)
+        #[ink(message)]
+        pub fn set_platform_fee_rate(&mut self, rate: u32) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if rate > 1000 {
+                return Err(InsuranceError::InvalidParameters); // Max 10%
+            }
+            // The fee and any jurisdiction's levies are withheld from the same premium
+            if rate.saturating_add(self.max_levy_rate_bp()) > 10_000 {
+                return Err(InsuranceError::LevyScheduleInvalid);
+            }
+            self.platform_fee_rate = rate;
+            Ok(())
+        }
+
+        /// Update claim cooldown period (admin only)
+        #[ink(message)]
+        pub fn set_claim_cooldown(&mut self, period_seconds: u64) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.claim_cooldown_period = period_seconds;
+            Ok(())
+        }
+
+        /// Set how long after purchase new policies of a coverage type exclude
+        /// incidents, e.g. 14 days for flood (admin only). Issued policies keep theirs.
+        #[ink(message)]
+        pub fn set_waiting_period(
+            &mut self,
+            coverage_type: CoverageType,
+            period_seconds: u64,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.waiting_periods.insert(&coverage_type, &period_seconds);
+            self.env().emit_event(WaitingPeriodSet {
+                coverage_type,
+                period_seconds,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_waiting_period(&self, coverage_type: CoverageType) -> u64 {
+            self.waiting_periods.get(&coverage_type).unwrap_or(0)
+        }
+
+        /// Configure the incident date window and per-incident payout cap (admin only)
+        #[ink(message)]
+        pub fn set_incident_limits(
+            &mut self,
+            window_seconds: u64,
+            payout_limit: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if window_seconds == 0 || payout_limit == 0 || payout_limit > 10_000 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.incident_window = window_seconds;
+            self.incident_payout_limit = payout_limit;
+            Ok(())
+        }
+
+        /// Set the claim decision SLA for a pool (admin only)
+        #[ink(message)]
+        pub fn set_claim_sla(
+            &mut self,
+            pool_id: u64,
+            review_period: u64,
+            compensation_rate: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if review_period == 0 || compensation_rate > 10_000 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.claim_sla_configs.insert(
+                &pool_id,
+                &ClaimSlaConfig {
+                    review_period,
+                    compensation_rate,
+                },
+            );
+            Ok(())
+        }
+
+        /// Configure surplus sharing for a pool (admin only). Opens a new period if none is open.
+        #[ink(message)]
+        pub fn set_surplus_sharing(
+            &mut self,
+            pool_id: u64,
+            max_loss_ratio: u32,
+            share_rate: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            let pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if max_loss_ratio > 10_000 || share_rate > 10_000 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.surplus_configs.insert(
+                &pool_id,
+                &SurplusSharingConfig {
+                    max_loss_ratio,
+                    share_rate,
+                },
+            );
+            if self.surplus_periods.get(&pool_id).is_none() {
+                self.surplus_periods.insert(
+                    &pool_id,
+                    &SurplusPeriod {
+                        started_at: self.env().block_timestamp(),
+                        premiums_at_start: pool.total_premiums_collected,
+                        claims_at_start: pool.total_claims_paid,
+                    },
+                );
+            }
+            Ok(())
+        }
+
+        // =====================================================================
+        // REGULATORY REPORTING
+        // =====================================================================
+
+        /// Tag a property with the region used in exposure reports (oracle/admin)
+        #[ink(message)]
+        pub fn set_property_region(
+            &mut self,
+            property_id: u64,
+            region: String,
+        ) -> Result<(), InsuranceError> {
+            let caller = self.env().caller();
+            if caller != self.admin && !self.authorized_oracles.get(&caller).unwrap_or(false) {
+                return Err(InsuranceError::Unauthorized);
+            }
+            self.property_regions.insert(&property_id, &region);
+            Ok(())
+        }
+
+        /// Summarize a pool's in-force exposure, reserves and reinsurance for regulators
+        #[ink(message)]
+        pub fn get_exposure_report(&self, pool_id: u64) -> Result<ExposureReport, InsuranceError> {
+            let pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            let now = self.env().block_timestamp();
+            let policy_ids = self.pool_policies.get(&pool_id).unwrap_or_default();
+
+            let mut policies_in_force: u64 = 0;
+            let mut total_insured_value: u128 = 0;
+            let mut insured_by_coverage: Vec<(CoverageType, u128)> = Vec::new();
+            let mut insured_by_region: Vec<(String, u128)> = Vec::new();
+            let mut insured_by_esg_tier: Vec<(EsgTier, u128)> = Vec::new();
+            for policy_id in policy_ids.iter() {
+                let Some(policy) = self.policies.get(policy_id) else {
+                    continue;
+                };
+                if policy.status != PolicyStatus::Active || now > policy.end_time {
+                    continue;
+                }
+                policies_in_force += 1;
+                total_insured_value = total_insured_value.saturating_add(policy.coverage_amount);
+                match insured_by_coverage
+                    .iter_mut()
+                    .find(|(coverage, _)| *coverage == policy.coverage_type)
+                {
+                    Some((_, value)) => *value = value.saturating_add(policy.coverage_amount),
+                    None => {
+                        insured_by_coverage.push((policy.coverage_type, policy.coverage_amount))
+                    }
+                }
+                let region = self
+                    .property_regions
+                    .get(&policy.property_id)
+                    .unwrap_or_default();
+                match insured_by_region.iter_mut().find(|(r, _)| *r == region) {
+                    Some((_, value)) => *value = value.saturating_add(policy.coverage_amount),
+                    None => insured_by_region.push((region, policy.coverage_amount)),
+                }
+                let tier = self.get_esg_tier(policy.property_id);
+                match insured_by_esg_tier.iter_mut().find(|(t, _)| *t == tier) {
+                    Some((_, value)) => *value = value.saturating_add(policy.coverage_amount),
+                    None => insured_by_esg_tier.push((tier, policy.coverage_amount)),
+                }
+            }
+
+            let open_claims_reserve = self.pool_claim_reserves.get(&pool_id).unwrap_or(0);
+
+            let mut reinsurance_attachment: Option<u128> = None;
+            let mut reinsurance_capacity: u128 = 0;
+            let mut uncovered_layers = Vec::new();
+            for agreement_id in 1..=self.reinsurance_count {
+                let Some(agreement) = self.reinsurance_agreements.get(&agreement_id) else {
+                    continue;
+                };
+                if agreement.is_active
+                    && now > agreement.end_time
+                    && agreement.coverage_types.contains(&pool.coverage_type)
+                    && !self.treaty_successors.contains(&agreement_id)
+                {
+                    uncovered_layers.push(agreement_id);
+                    continue;
+                }
+                if !agreement.is_active
+                    || now < agreement.start_time
+                    || now > agreement.end_time
+                    || !agreement.coverage_types.contains(&pool.coverage_type)
+                {
+                    continue;
+                }
+                reinsurance_capacity = reinsurance_capacity.saturating_add(
+                    agreement
+                        .coverage_limit
+                        .saturating_sub(agreement.limit_utilized),
+                );
+                reinsurance_attachment = Some(
+                    reinsurance_attachment.map_or(agreement.retention_limit, |a| {
+                        a.min(agreement.retention_limit)
+                    }),
+                );
+            }
+
+            let solvency_ratio = pool
+                .available_capital
+                .saturating_add(reinsurance_capacity)
+                .saturating_mul(10_000)
+                .checked_div(total_insured_value)
+                .map_or(u32::MAX, |ratio| ratio.min(u32::MAX as u128) as u32);
+
+            Ok(ExposureReport {
+                pool_id,
+                generated_at: now,
+                total_policies: policy_ids.len() as u64,
+                policies_in_force,
+                total_insured_value,
+                insured_by_coverage,
+                insured_by_region,
+                insured_by_esg_tier,
+                total_capital: pool.total_capital,
+                available_capital: pool.available_capital,
+                open_claims_reserve,
+                reinsurance_attachment,
+                reinsurance_capacity,
+                solvency_ratio,
+                uncovered_layers,
+            })
+        }
+
+        /// Record the hash of the current exposure report so filings can reference it.
+        /// Anyone may snapshot once per interval; the admin at any time.
+        #[ink(message)]
+        pub fn snapshot_exposure_report(&mut self, pool_id: u64) -> Result<Hash, InsuranceError> {
+            let report = self.get_exposure_report(pool_id)?;
+            let count = self.exposure_snapshot_count.get(&pool_id).unwrap_or(0);
+            if count > 0 && self.env().caller() != self.admin {
+                let last = self
+                    .exposure_snapshots
+                    .get(&(pool_id, count - 1))
+                    .map_or(0, |s| s.taken_at);
+                if report.generated_at < last.saturating_add(self.exposure_snapshot_interval) {
+                    return Err(InsuranceError::SnapshotTooSoon);
+                }
+            }
+
+            let mut digest = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&report, &mut digest);
+            let report_hash = Hash::from(digest);
+            self.exposure_snapshots.insert(
+                &(pool_id, count),
+                &ExposureSnapshot {
+                    report_hash,
+                    taken_at: report.generated_at,
+                    policies_in_force: report.policies_in_force,
+                    total_insured_value: report.total_insured_value,
+                    solvency_ratio: report.solvency_ratio,
+                },
+            );
+            self.exposure_snapshot_count
+                .insert(&pool_id, &count.saturating_add(1));
+
+            self.env().emit_event(ExposureSnapshotTaken {
+                pool_id,
+                report_hash,
+                index: count,
+                solvency_ratio: report.solvency_ratio,
+                timestamp: report.generated_at,
+            });
+            Ok(report_hash)
+        }
+
+        #[ink(message)]
+        pub fn set_exposure_snapshot_interval(
+            &mut self,
+            interval_seconds: u64,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            self.exposure_snapshot_interval = interval_seconds;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_exposure_snapshot(&self, pool_id: u64, index: u32) -> Option<ExposureSnapshot> {
+            self.exposure_snapshots.get(&(pool_id, index))
+        }
+
+        #[ink(message)]
+        pub fn get_exposure_snapshot_count(&self, pool_id: u64) -> u32 {
+            self.exposure_snapshot_count.get(&pool_id).unwrap_or(0)
+        }
+
+        /// Project a catastrophe scenario onto the pool's in-force policies and record
+        /// the result (admin only). Each policy loses the shocked severity, capped at its
+        /// remaining cover, with the shocked frequency; the aggregate is treated as one
+        /// event against the covering reinsurance layers. Returns the result's index.
+        #[ink(message)]
+        pub fn run_stress_test(
+            &mut self,
+            pool_id: u64,
+            scenario: StressScenario,
+        ) -> Result<u32, InsuranceError> {
+            self.ensure_admin()?;
+            let pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            let model = self
+                .get_adopted_actuarial_model(pool.coverage_type.clone())
+                .ok_or(InsuranceError::NoAdoptedModel)?;
+            let now = self.env().block_timestamp();
+
+            let shocked_frequency =
+                (model.loss_frequency as u64 * scenario.frequency_multiplier as u64 / 10_000)
+                    .min(10_000) as u32;
+            let shocked_severity = model
+                .average_loss_severity
+                .saturating_mul(scenario.severity_multiplier as u128)
+                / 10_000;
+            let mut projected_losses: u128 = 0;
+            for policy_id in self.pool_policies.get(&pool_id).unwrap_or_default() {
+                let Some(policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                if policy.status == PolicyStatus::Active && now <= policy.end_time {
+                    let remaining = policy.coverage_amount.saturating_sub(policy.total_claimed);
+                    projected_losses = projected_losses.saturating_add(
+                        shocked_severity
+                            .min(remaining)
+                            .saturating_mul(shocked_frequency as u128)
+                            / 10_000,
+                    );
+                }
+            }
+            let (policies_in_force, exposure) = self.pool_exposure(pool_id, now);
+
+            // Layers are charged on copies; nothing is persisted
+            let mut reinsurance_recoveries: u128 = 0;
+            let mut reinsurance_left: u128 = 0;
+            for mut agreement in self.covering_layers(&pool.coverage_type, now) {
+                reinsurance_recoveries = reinsurance_recoveries
+                    .saturating_add(Self::charge_layer(&mut agreement, projected_losses));
+                reinsurance_left = reinsurance_left.saturating_add(
+                    agreement
+                        .coverage_limit
+                        .saturating_sub(agreement.limit_utilized),
+                );
+            }
+            let net_losses = projected_losses.saturating_sub(reinsurance_recoveries);
+            let post_shock_capital = pool.available_capital.saturating_sub(net_losses);
+            let post_shock_solvency_ratio = post_shock_capital
+                .saturating_add(reinsurance_left)
+                .saturating_mul(10_000)
+                .checked_div(exposure)
+                .map_or(u32::MAX, |ratio| ratio.min(u32::MAX as u128) as u32);
+            let solvent = net_losses <= pool.available_capital;
+
+            let index = self.stress_test_count.get(&pool_id).unwrap_or(0);
+            self.stress_tests.insert(
+                &(pool_id, index),
+                &StressTestResult {
+                    pool_id,
+                    scenario,
+                    model_id: model.model_id,
+                    run_at: now,
+                    policies_in_force,
+                    exposure,
+                    shocked_frequency,
+                    shocked_severity,
+                    projected_losses,
+                    reinsurance_recoveries,
+                    net_losses,
+                    post_shock_capital,
+                    post_shock_solvency_ratio,
+                    solvent,
+                },
+            );
+            self.stress_test_count
+                .insert(&pool_id, &index.saturating_add(1));
+
+            self.env().emit_event(StressTestRun {
+                pool_id,
+                index,
+                projected_losses,
+                net_losses,
+                solvent,
+            });
+            Ok(index)
+        }
+
+        #[ink(message)]
+        pub fn get_stress_test(&self, pool_id: u64, index: u32) -> Option<StressTestResult> {
+            self.stress_tests.get(&(pool_id, index))
+        }
+
+        #[ink(message)]
+        pub fn get_stress_test_count(&self, pool_id: u64) -> u32 {
+            self.stress_test_count.get(&pool_id).unwrap_or(0)
+        }
+
+        /// Current NAV of a pool and its value per LP unit
+        #[ink(message)]
+        pub fn get_nav(&self, pool_id: u64) -> Result<PoolNav, InsuranceError> {
+            let pool = self
+                .pools
+                .get(&pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            let receivables = self.pool_receivables.get(&pool_id).unwrap_or(0);
+            let reserves = self.pool_claim_reserves.get(&pool_id).unwrap_or(0);
+            let nav = pool
+                .available_capital
+                .saturating_add(receivables)
+                .saturating_sub(reserves);
+            let lp_units = self.pool_lp_units.get(&pool_id).unwrap_or(0);
+            let nav_per_unit = nav
+                .saturating_mul(NAV_PRECISION)
+                .checked_div(lp_units)
+                .unwrap_or(NAV_PRECISION);
+            Ok(PoolNav {
+                pool_id,
+                computed_at: self.env().block_timestamp(),
+                capital: pool.available_capital,
+                receivables,
+                reserves,
+                nav,
+                lp_units,
+                nav_per_unit,
+            })
+        }
+
+        /// Recorded NAV points of a pool, oldest first (at most MAX_NAV_HISTORY)
+        #[ink(message)]
+        pub fn get_nav_history(&self, pool_id: u64) -> Vec<NavPoint> {
+            let count = self.nav_history_count.get(&pool_id).unwrap_or(0);
+            (count.saturating_sub(MAX_NAV_HISTORY)..count)
+                .filter_map(|i| self.nav_history.get(&(pool_id, i % MAX_NAV_HISTORY)))
+                .collect()
+        }
+
+        /// Claims frequency and severity for a coverage type, region ("" for properties
+        /// without one) and period (`timestamp / statistics period`). Cells with fewer
+        /// settled claims than the minimum cell count are withheld.
+        #[ink(message)]
+        pub fn get_claims_statistics(
+            &self,
+            coverage_type: CoverageType,
+            region: String,
+            period: u64,
+        ) -> Option<ClaimsStatistics> {
+            let cell = self
+                .claims_statistics
+                .get(&(coverage_type, region, period))?;
+            if cell.claims_settled < self.min_cell_count as u64 {
+                return None;
+            }
+            let frequency_bps = (cell.claims_settled as u128)
+                .saturating_mul(10_000)
+                .checked_div(cell.policies_written as u128)
+                .map_or(u32::MAX, |f| f.min(u32::MAX as u128) as u32);
+            Some(ClaimsStatistics {
+                policies_written: cell.policies_written,
+                claims_settled: cell.claims_settled,
+                claims_approved: cell.claims_approved,
+                frequency_bps,
+                average_severity: cell
+                    .total_payout
+                    .checked_div(cell.claims_approved as u128)
+                    .unwrap_or(0),
+            })
+        }
+
+        /// Set the statistics period length and minimum published cell size (admin only)
+        #[ink(message)]
+        pub fn set_statistics_params(
+            &mut self,
+            period_seconds: u64,
+            min_cell_count: u32,
+        ) -> Result<(), InsuranceError> {
+            self.ensure_admin()?;
+            if period_seconds == 0 || min_cell_count == 0 {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            self.statistics_period = period_seconds;
+            self.min_cell_count = min_cell_count;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn get_statistics_period(&self) -> u64 {
+            self.statistics_period
+        }
+
+        // =====================================================================
+        // QUERIES
+        // =====================================================================
+
+        /// Get policy details
+        #[ink(message)]
+        pub fn get_policy(&self, policy_id: u64) -> Option<InsurancePolicy> {
+            self.policies.get(&policy_id)
+        }
+
+        /// Get claim details
+        #[ink(message)]
+        pub fn get_claim(&self, claim_id: u64) -> Option<InsuranceClaim> {
+            self.claims.get(&claim_id)
+        }
+
+        /// Get pool details
+        #[ink(message)]
+        pub fn get_pool(&self, pool_id: u64) -> Option<RiskPool> {
+            self.pools.get(&pool_id)
+        }
+
+        /// Get risk assessment for a property
+        #[ink(message)]
+        pub fn get_risk_assessment(&self, property_id: u64) -> Option<RiskAssessment> {
+            self.risk_assessments.get(&property_id)
+        }
+
+        /// Get all policies for a policyholder
+        #[ink(message)]
+        pub fn get_policyholder_policies(&self, holder: AccountId) -> Vec<u64> {
+            self.policyholder_policies.get(&holder).unwrap_or_default()
+        }
+
+        /// Get all policy IDs for a property
+        #[ink(message)]
+        pub fn get_property_policies(&self, property_id: u64) -> Vec<u64> {
+            self.property_policies.get(&property_id).unwrap_or_default()
+        }
+
+        /// Get all claims for a policy
+        #[ink(message)]
+        pub fn get_policy_claims(&self, policy_id: u64) -> Vec<u64> {
+            self.policy_claims.get(&policy_id).unwrap_or_default()
+        }
+
+        /// Get insurance token details
+        #[ink(message)]
+        pub fn get_token(&self, token_id: u64) -> Option<InsuranceToken> {
+            self.insurance_tokens.get(&token_id)
+        }
+
+        /// Get all token listings on the secondary market
+        #[ink(message)]
+        pub fn get_token_listings(&self) -> Vec<u64> {
+            self.token_listings.clone()
+        }
+
+        /// Get actuarial model
+        #[ink(message)]
+        pub fn get_actuarial_model(&self, model_id: u64) -> Option<ActuarialModel> {
+            self.actuarial_models.get(&model_id)
+        }
+
+        /// Get reinsurance agreement
+        #[ink(message)]
+        pub fn get_reinsurance_agreement(&self, agreement_id: u64) -> Option<ReinsuranceAgreement> {
+            self.reinsurance_agreements.get(&agreement_id)
+        }
+
+        /// Get how a claim's loss was allocated across reinsurance layers
+        #[ink(message)]
+        pub fn get_reinsurance_allocation(&self, claim_id: u64) -> Vec<LayerAllocation> {
+            self.claim_reinsurance_allocations
+                .get(&claim_id)
+                .unwrap_or_default()
+        }
+
+        /// Get underwriting criteria for a pool
+        #[ink(message)]
+        pub fn get_underwriting_criteria(&self, pool_id: u64) -> Option<UnderwritingCriteria> {
+            self.underwriting_criteria.get(&pool_id)
+        }
+
+        /// Get liquidity provider info
+        #[ink(message)]
+        pub fn get_liquidity_provider(
+            &self,
+            pool_id: u64,
+            provider: AccountId,
+        ) -> Option<PoolLiquidityProvider> {
+            self.liquidity_providers.get(&(pool_id, provider))
+        }
+
+        /// Get the claim SLA configured for a pool
+        #[ink(message)]
+        pub fn get_claim_sla(&self, pool_id: u64) -> Option<ClaimSlaConfig> {
+            self.claim_sla_configs.get(&pool_id)
+        }
+
+        /// Get SLA breach statistics for a pool
+        #[ink(message)]
+        pub fn get_claim_sla_stats(&self, pool_id: u64) -> ClaimSlaStats {
+            self.claim_sla_stats.get(&pool_id).unwrap_or_default()
+        }
+
+        /// Page of the admin queue of escalated claims awaiting a decision. Queue
+        /// slots are reused as claims are decided, so order is not preserved.
+        #[ink(message)]
+        pub fn get_escalated_claims(&self, start: u64, limit: u32) -> Vec<u64> {
+            let end = start
+                .saturating_add(limit as u64)
+                .min(self.escalated_claim_count);
+            (start..end)
+                .filter_map(|slot| self.escalated_claims.get(&slot))
+                .collect()
+        }
+
+        #[ink(message)]
+        pub fn get_escalated_claim_count(&self) -> u64 {
+            self.escalated_claim_count
+        }
+
+        /// Get SLA compensation credit owed to an account
+        #[ink(message)]
+        pub fn get_sla_credit(&self, account: AccountId) -> u128 {
+            self.sla_credits.get(&account).unwrap_or(0)
+        }
+
+        /// Get the surplus sharing configuration for a pool
+        #[ink(message)]
+        pub fn get_surplus_sharing(&self, pool_id: u64) -> Option<SurplusSharingConfig> {
+            self.surplus_configs.get(&pool_id)
+        }
+
+        /// Get the currently open surplus period for a pool
+        #[ink(message)]
+        pub fn get_surplus_period(&self, pool_id: u64) -> Option<SurplusPeriod> {
+            self.surplus_periods.get(&pool_id)
+        }
+
+        /// Get the report for a closed surplus period (periods start at 1)
+        #[ink(message)]
+        pub fn get_surplus_report(&self, pool_id: u64, period: u32) -> Option<SurplusPeriodReport> {
+            self.surplus_reports.get(&(pool_id, period))
+        }
+
+        /// Get surplus distributions credited to an account
+        #[ink(message)]
+        pub fn get_surplus_credit(&self, account: AccountId) -> u128 {
+            self.surplus_credits.get(&account).unwrap_or(0)
+        }
+
+        /// Get the public content hashes of a claim's committed evidence
+        #[ink(message)]
+        pub fn get_claim_evidence_hashes(&self, claim_id: u64) -> Vec<Hash> {
+            self.claim_evidence
+                .get(&claim_id)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|e| e.content_hash)
+                .collect()
+        }
+
+        /// Get who accessed a claim's evidence and when
+        #[ink(message)]
+        pub fn get_evidence_access_log(&self, claim_id: u64) -> Vec<EvidenceAccess> {
+            self.evidence_access_log.get(&claim_id).unwrap_or_default()
+        }
+
+        /// Get total policies count
+        #[ink(message)]
+        pub fn get_policy_count(&self) -> u64 {
+            self.policy_count
+        }
+
+        /// Get total claims count
+        #[ink(message)]
+        pub fn get_claim_count(&self) -> u64 {
+            self.claim_count
+        }
+
+        /// Get admin address
+        #[ink(message)]
+        pub fn get_admin(&self) -> AccountId {
+            self.admin
+        }
+
+        // =====================================================================
+        // INTERNAL HELPERS
+        // =====================================================================
+
+        /// The lien on `policy_id`, provided the caller is its lender
+        fn lender_lien(&self, policy_id: u64) -> Result<PremiumLien, InsuranceError> {
+            let lien = self
+                .premium_liens
+                .get(&policy_id)
+                .ok_or(InsuranceError::LienNotFound)?;
+            if self.env().caller() != lien.lender {
+                return Err(InsuranceError::Unauthorized);
+            }
+            Ok(lien)
+        }
+
+        fn has_outstanding_lien(&self, policy_id: u64) -> bool {
+            self.premium_liens.get(&policy_id).is_some_and(|lien| {
+                matches!(lien.status, LienStatus::Active | LienStatus::Defaulted)
+            })
+        }
+
+        /// (policies in force, unclaimed coverage) for a pool at `now`
+        fn pool_exposure(&self, pool_id: u64, now: u64) -> (u64, u128) {
+            let mut count: u64 = 0;
+            let mut exposure: u128 = 0;
+            for policy_id in self.pool_policies.get(&pool_id).unwrap_or_default() {
+                let Some(policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                if policy.status == PolicyStatus::Active && now <= policy.end_time {
+                    count += 1;
+                    exposure = exposure.saturating_add(
+                        policy.coverage_amount.saturating_sub(policy.total_claimed),
+                    );
+                }
+            }
+            (count, exposure)
+        }
+
+        /// Appends the pool's current NAV to its series; a later point in the same
+        /// block replaces the earlier one
+        fn record_nav(&mut self, pool_id: u64) {
+            let Ok(nav) = self.get_nav(pool_id) else {
+                return;
+            };
+            let point = NavPoint {
+                timestamp: nav.computed_at,
+                nav: nav.nav,
+                lp_units: nav.lp_units,
+                nav_per_unit: nav.nav_per_unit,
+            };
+            let mut count = self.nav_history_count.get(&pool_id).unwrap_or(0);
+            let same_block = count > 0
+                && self
+                    .nav_history
+                    .get(&(pool_id, (count - 1) % MAX_NAV_HISTORY))
+                    .is_some_and(|last| last.timestamp == point.timestamp);
+            if !same_block {
+                count += 1;
+                self.nav_history_count.insert(&pool_id, &count);
+            }
+            self.nav_history
+                .insert(&(pool_id, (count - 1) % MAX_NAV_HISTORY), &point);
+        }
+
+        /// Moves a claim to `status`, keeping its pool's open-claim reserve in step
+        fn set_claim_status(&mut self, claim: &mut InsuranceClaim, status: ClaimStatus) {
+            let was_open = Self::is_open_claim(&claim.status);
+            let now_open = Self::is_open_claim(&status);
+            claim.status = status;
+            if was_open != now_open {
+                if let Some(policy) = self.policies.get(&claim.policy_id) {
+                    self.adjust_claim_reserve(policy.pool_id, claim.claim_amount, now_open);
+                }
+            }
+        }
+
+        fn adjust_claim_reserve(&mut self, pool_id: u64, amount: u128, open: bool) {
+            let reserve = self.pool_claim_reserves.get(&pool_id).unwrap_or(0);
+            let reserve = if open {
+                reserve.saturating_add(amount)
+            } else {
+                reserve.saturating_sub(amount)
+            };
+            self.pool_claim_reserves.insert(&pool_id, &reserve);
+        }
+
+        fn is_open_claim(status: &ClaimStatus) -> bool {
+            matches!(
+                status,
+                ClaimStatus::Pending
+                    | ClaimStatus::UnderReview
+                    | ClaimStatus::OracleVerifying
+                    | ClaimStatus::Approved
+                    | ClaimStatus::Disputed
+            )
+        }
+
+        fn holds_active_policy(&self, account: AccountId, property_id: u64) -> bool {
+            self.policyholder_policies
+                .get(&account)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|policy_id| self.policies.get(policy_id))
+                .any(|policy| {
+                    policy.property_id == property_id && policy.status == PolicyStatus::Active
+                })
+        }
+
+        fn apply_property_compliance(&mut self, property_id: u64, compliant: bool) -> u32 {
+            let now = self.env().block_timestamp();
+            let mut changed = 0u32;
+
+            if compliant {
+                self.compliance_lapses.remove(&property_id);
+                for policy_id in self.property_policies.get(&property_id).unwrap_or_default() {
+                    let Some(suspended_at) = self.compliance_suspensions.get(&policy_id) else {
+                        continue;
+                    };
+                    let Some(mut policy) = self.policies.get(&policy_id) else {
+                        continue;
+                    };
+                    // Coverage time lost while suspended is added back to the term
+                    let extended_by = now.saturating_sub(suspended_at);
+                    policy.end_time = policy.end_time.saturating_add(extended_by);
+                    policy.status = PolicyStatus::Active;
+                    self.policies.insert(&policy_id, &policy);
+                    self.compliance_suspensions.remove(&policy_id);
+                    changed += 1;
+                    self.env().emit_event(PolicyReinstated {
+                        policy_id,
+                        property_id,
+                        extended_by,
+                    });
+                }
+                self.monitor_coverage(property_id);
+                return changed;
+            }
+
+            let lapsed_at = match self.compliance_lapses.get(&property_id) {
+                Some(lapsed_at) => lapsed_at,
+                None => {
+                    self.compliance_lapses.insert(&property_id, &now);
+                    self.env().emit_event(PropertyComplianceLapsed {
+                        property_id,
+                        grace_ends: now.saturating_add(self.compliance_grace_period),
+                    });
+                    now
+                }
+            };
+            if now < lapsed_at.saturating_add(self.compliance_grace_period) {
+                return 0;
+            }
+
+            for policy_id in self.property_policies.get(&property_id).unwrap_or_default() {
+                let Some(mut policy) = self.policies.get(&policy_id) else {
+                    continue;
+                };
+                if policy.status != PolicyStatus::Active || now > policy.end_time {
+                    continue;
+                }
+                policy.status = PolicyStatus::Suspended;
+                self.policies.insert(&policy_id, &policy);
+                self.compliance_suspensions.insert(&policy_id, &now);
+                changed += 1;
+                self.env().emit_event(PolicySuspended {
+                    policy_id,
+                    property_id,
+                    suspended_at: now,
+                });
+            }
+            self.monitor_coverage(property_id);
+            changed
+        }
+
+        fn ensure_admin(&self) -> Result<(), InsuranceError> {
+            if self.env().caller() != self.admin {
+                return Err(InsuranceError::Unauthorized);
+            }
+            Ok(())
+        }
+
+        fn ensure_not_paused(&self, domain: PauseDomain) -> Result<(), InsuranceError> {
+            if self.is_domain_paused(domain) {
+                return Err(InsuranceError::DomainPaused);
+            }
+            Ok(())
+        }
+
+        fn set_domain_paused(&mut self, domain: PauseDomain, paused: bool, changed_by: AccountId) {
+            self.paused_domains.insert(&domain, &paused);
+            self.env().emit_event(DomainPauseChanged {
+                domain,
+                changed_by,
+                paused,
+            });
+        }
+
+        /// Adds a payout to the current velocity window and pauses payouts once
+        /// the configured limit is exceeded. The payout that trips it still settles.
+        fn record_payout_velocity(&mut self, amount: u128) {
+            if self.payout_velocity_limit == 0 {
+                return;
+            }
+            let now = self.env().block_timestamp();
+            if now
+                >= self
+                    .payout_window_start
+                    .saturating_add(self.payout_velocity_window)
+            {
+                self.payout_window_start = now;
+                self.payout_window_total = 0;
+            }
+            self.payout_window_total = self.payout_window_total.saturating_add(amount);
+            if self.payout_window_total > self.payout_velocity_limit {
+                self.set_domain_paused(PauseDomain::Payouts, true, self.env().account_id());
+                self.env().emit_event(CircuitBreakerTripped {
+                    domain: PauseDomain::Payouts,
+                    observed: self.payout_window_total,
+                    limit: self.payout_velocity_limit,
+                    timestamp: now,
+                });
+            }
+        }
+
+        fn release_assessor_slot(&mut self, assessor: AccountId) {
+            let mut stats = self.assessor_stats.get(&assessor).unwrap_or_default();
+            stats.open_assignments = stats.open_assignments.saturating_sub(1);
+            self.assessor_stats.insert(&assessor, &stats);
+        }
+
+        /// A cancelled policy's undecided claims go back to the general queue,
+        /// freeing the slots they held
+        fn release_policy_assignments(&mut self, policy_id: u64) {
+            for claim_id in self.policy_claims.get(&policy_id).unwrap_or_default() {
+                let undecided = self.claims.get(&claim_id).is_some_and(|claim| {
+                    matches!(
+                        claim.status,
+                        ClaimStatus::Pending
+                            | ClaimStatus::UnderReview
+                            | ClaimStatus::OracleVerifying
+                    )
+                });
+                if let Some(assessor) = self.claim_assignments.get(&claim_id) {
+                    if undecided {
+                        self.claim_assignments.remove(&claim_id);
+                        self.release_assessor_slot(assessor);
+                    }
+                }
+            }
+        }
+
+        /// Picks a bonded assessor for a claim, weighted by reputation, skipping
+        /// assessors with a declared conflict with the claimant. The draw is seeded
+        /// from the claim id, block time and claimant, all of which the claimant knows
+        /// when submitting, so the outcome is predictable and can be steered by
+        /// timing; conflict declarations and recusal are the safeguard.
+        fn assign_assessor(
+            &mut self,
+            claim_id: u64,
+            claimant: AccountId,
+            exclude: Option<AccountId>,
+        ) {
+            let candidates: Vec<(AccountId, AssessorStats)> = self
+                .assessor_pool
+                .iter()
+                .filter(|a| **a != claimant && Some(**a) != exclude)
+                .filter(|a| self.authorized_assessors.get(*a).unwrap_or(false))
+                .filter(|a| !self.has_assessor_conflict(**a, claimant))
+                .filter_map(|a| self.assessor_stats.get(a).map(|stats| (*a, stats)))
+                .filter(|(_, stats)| stats.stake >= self.min_assessor_stake && stats.reputation > 0)
+                .collect();
+            let total_weight: u64 = candidates.iter().map(|(_, s)| s.reputation as u64).sum();
+            if total_weight == 0 {
+                return;
+            }
+
+            let seed = (claim_id, self.env().block_timestamp(), claimant);
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&seed, &mut output);
+            let mut draw = u64::from_le_bytes([
+                output[0], output[1], output[2], output[3], output[4], output[5], output[6],
+                output[7],
+            ]) % total_weight;
+
+            for (assessor, mut stats) in candidates {
+                if draw < stats.reputation as u64 {
+                    stats.assignments += 1;
+                    stats.open_assignments += 1;
+                    self.assessor_stats.insert(&assessor, &stats);
+                    self.claim_assignments.insert(&claim_id, &assessor);
+                    self.env().emit_event(ClaimAssigned { claim_id, assessor });
+                    return;
+                }
+                draw -= stats.reputation as u64;
+            }
+        }
+
+        fn statistics_key(
+            &self,
+            property_id: u64,
+            coverage_type: CoverageType,
+            timestamp: u64,
+        ) -> StatisticsKey {
+            let region = self.property_regions.get(&property_id).unwrap_or_default();
+            (coverage_type, region, timestamp / self.statistics_period)
+        }
+
+        fn record_claim_statistics(&mut self, claim: &InsuranceClaim, now: u64) {
+            let Some(policy) = self.policies.get(&claim.policy_id) else {
+                return;
+            };
+            let key = self.statistics_key(policy.property_id, policy.coverage_type.clone(), now);
+            let mut cell = self.claims_statistics.get(&key).unwrap_or_default();
+            cell.claims_settled += 1;
+            if claim.status == ClaimStatus::Approved {
+                cell.claims_approved += 1;
+                cell.total_payout = cell.total_payout.saturating_add(claim.payout_amount);
+            }
+            self.claims_statistics.insert(&key, &cell);
+
+            let mut experience = self
+                .loss_experience
+                .get(&policy.coverage_type)
+                .unwrap_or_default();
+            experience.claims_settled += 1;
+            if claim.status == ClaimStatus::Approved {
+                experience.claims_approved += 1;
+                experience.total_payout =
+                    experience.total_payout.saturating_add(claim.payout_amount);
+            }
+            self.loss_experience
+                .insert(&policy.coverage_type, &experience);
+        }
+
+        fn adopt_model(&mut self, model: &ActuarialModel) {
+            self.adopted_models
+                .insert(&model.coverage_type, &model.model_id);
+            self.env().emit_event(ActuarialModelAdopted {
+                model_id: model.model_id,
+                coverage_type: model.coverage_type.clone(),
+            });
+        }
+
+        /// Largest relative move, in basis points, of frequency, severity or loss ratio
+        fn parameter_shift(from: &ActuarialModel, to: &ActuarialModel) -> u32 {
+            let shift = |old: u128, new: u128| -> u32 {
+                if old == new {
+                    return 0;
+                }
+                Self::ratio_bps(old.abs_diff(new), old)
+            };
+            shift(from.loss_frequency as u128, to.loss_frequency as u128)
+                .max(shift(from.average_loss_severity, to.average_loss_severity))
+                .max(shift(
+                    from.expected_loss_ratio as u128,
+                    to.expected_loss_ratio as u128,
+                ))
+        }
+
+        /// `numerator / denominator` in basis points, saturating (and for a zero denominator)
+        fn ratio_bps(numerator: u128, denominator: u128) -> u32 {
+            numerator
+                .saturating_mul(10_000)
+                .checked_div(denominator)
+                .map_or(u32::MAX, |r| r.min(u32::MAX as u128) as u32)
+        }
+
+        fn enqueue_escalated_claim(&mut self, claim_id: u64) {
+            let slot = self.escalated_claim_count;
+            self.escalated_claims.insert(&slot, &claim_id);
+            self.escalation_slots.insert(&claim_id, &slot);
+            self.escalated_claim_count = slot + 1;
+        }
+
+        /// Removes a claim from the admin queue, moving the last entry into its slot
+        fn dequeue_escalated_claim(&mut self, claim_id: u64) {
+            let Some(slot) = self.escalation_slots.take(&claim_id) else {
+                return;
+            };
+            let last = self.escalated_claim_count.saturating_sub(1);
+            if slot != last {
+                if let Some(moved) = self.escalated_claims.get(&last) {
+                    self.escalated_claims.insert(&slot, &moved);
+                    self.escalation_slots.insert(&moved, &slot);
+                }
+            }
+            self.escalated_claims.remove(&last);
+            self.escalated_claim_count = last;
+        }
+
+        fn record_sla_outcome(&mut self, claim: &InsuranceClaim, now: u64) {
+            if claim.escalated {
+                self.dequeue_escalated_claim(claim.claim_id);
+                return;
+            }
+            if let Some(deadline) = claim.review_deadline {
+                if let Some(policy) = self.policies.get(&claim.policy_id) {
+                    let mut stats = self
+                        .claim_sla_stats
+                        .get(&policy.pool_id)
+                        .unwrap_or_default();
+                    if now <= deadline {
+                        stats.decided_within_sla += 1;
+                    } else {
+                        stats.breaches += 1;
+                    }
+                    self.claim_sla_stats.insert(&policy.pool_id, &stats);
+                }
+            }
+        }
+
+        /// Shareholder payouts must go to the token the insured property is issued
+        /// as, in the native currency
+        fn ensure_payout_target(
+            &self,
+            property_id: u64,
+            pool_id: u64,
+            payout_mode: &PayoutMode,
+        ) -> Result<(), InsuranceError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::DividendDistributor;
+            let PayoutMode::TokenDividends {
+                token_contract,
+                token_id,
+            } = payout_mode
+            else {
+                return Ok(());
+            };
+            if self.pool_denomination(pool_id) != Denomination::Native {
+                return Err(InsuranceError::DenominationMismatch);
+            }
+            let distributor: ink::contract_ref!(propchain_traits::DividendDistributor) =
+                FromAccountId::from_account_id(*token_contract);
+            match distributor
+                .call()
+                .property_token_id(property_id)
+                .try_invoke()
+            {
+                Ok(Ok(Some(linked))) if linked == *token_id => Ok(()),
+                _ => Err(InsuranceError::PayoutTargetMismatch),
+            }
+        }
+
+        /// When the holder's cover of `coverage_type` on the property began, if a
+        /// policy of theirs covering it is still in force or ends at `now`
+        fn continuous_cover_since(
+            &self,
+            holder: AccountId,
+            property_id: u64,
+            coverage_type: &CoverageType,
+            now: u64,
+        ) -> u64 {
+            self.property_policies
+                .get(&property_id)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| self.policies.get(id))
+                .filter(|prior| {
+                    prior.policyholder == holder
+                        && (prior.status == PolicyStatus::Active
+                            || prior.status == PolicyStatus::Expired)
+                        && prior.end_time >= now
+                        && (prior.coverage_type == *coverage_type
+                            || prior.coverage_type == CoverageType::Comprehensive)
+                })
+                .fold(now, |since, prior| since.min(prior.cover_since))
+        }
+
+        /// Incidents of `peril` before this are inside the policy's waiting period
+        fn waiting_period_end(&self, policy: &InsurancePolicy, peril: &CoverageType) -> u64 {
+            policy
+                .cover_since
+                .saturating_add(self.waiting_periods.get(peril).unwrap_or(0))
+        }
+
+        fn deposit_to_shareholders(
+            &self,
+            token_contract: AccountId,
+            token_id: u64,
+            amount: u128,
+        ) -> Result<(), InsuranceError> {
+            use ink::codegen::TraitCallBuilder;
+            use ink::env::call::FromAccountId;
+            use propchain_traits::DividendDistributor;
+            let mut distributor: ink::contract_ref!(propchain_traits::DividendDistributor) =
+                FromAccountId::from_account_id(token_contract);
+            let result = distributor
+                .call_mut()
+                .deposit_token_dividends(token_id)
+                .transferred_value(amount)
+                .try_invoke();
+            match result {
+                Ok(Ok(Ok(()))) => Ok(()),
+                _ => Err(InsuranceError::TransferFailed),
+            }
+        }
+
+        fn score_to_risk_level(score: u32) -> RiskLevel {
+            match score {
+                0..=20 => RiskLevel::VeryHigh,
+                21..=40 => RiskLevel::High,
+                41..=60 => RiskLevel::Medium,
+                61..=80 => RiskLevel::Low,
+                _ => RiskLevel::VeryLow,
+            }
+        }
+
+        fn risk_score_to_multiplier(&self, score: u32) -> u32 {
+            // score 0-100: higher score = lower risk = lower multiplier
+            // Range: 400 (very high risk) to 80 (very low risk)
+            match score {
+                0..=20 => 400,
+                21..=40 => 250,
+                41..=60 => 150,
+                61..=80 => 110,
+                _ => 80,
+            }
+        }
+
+        fn coverage_type_multiplier(coverage_type: &CoverageType) -> u32 {
+            match coverage_type {
+                CoverageType::Fire => 100,
+                CoverageType::Theft => 80,
+                CoverageType::Flood => 150,
+                CoverageType::Earthquake => 200,
+                CoverageType::LiabilityDamage => 120,
+                CoverageType::NaturalDisaster => 180,
+                CoverageType::Comprehensive => 250,
+            }
+        }
+
+        /// Index a freshly created policy by holder, property and pool, and
+        /// count it in the claims statistics for its start period
+        fn store_new_policy(&mut self, policy: &InsurancePolicy) {
+            self.policies.insert(&policy.policy_id, policy);
+
+            let mut ph_policies = self
+                .policyholder_policies
+                .get(&policy.policyholder)
+                .unwrap_or_default();
+            ph_policies.push(policy.policy_id);
+            self.policyholder_policies
+                .insert(&policy.policyholder, &ph_policies);
+
+            let mut prop_policies = self
+                .property_policies
+                .get(&policy.property_id)
+                .unwrap_or_default();
+            prop_policies.push(policy.policy_id);
+            self.property_policies
+                .insert(&policy.property_id, &prop_policies);
+
+            let mut pool_policies = self.pool_policies.get(&policy.pool_id).unwrap_or_default();
+            pool_policies.push(policy.policy_id);
+            self.pool_policies.insert(&policy.pool_id, &pool_policies);
+
+            let key = self.statistics_key(
+                policy.property_id,
+                policy.coverage_type.clone(),
+                policy.start_time,
+            );
+            let mut cell = self.claims_statistics.get(&key).unwrap_or_default();
+            cell.policies_written += 1;
+            self.claims_statistics.insert(&key, &cell);
+
+            let mut experience = self
+                .loss_experience
+                .get(&policy.coverage_type)
+                .unwrap_or_default();
+            experience.policies_written += 1;
+            experience.premiums_written = experience
+                .premiums_written
+                .saturating_add(policy.premium_amount);
+            self.loss_experience
+                .insert(&policy.coverage_type, &experience);
+        }
+
+        fn import_policy(&mut self, item: PolicyImport) -> Result<u64, InsuranceError> {
+            let now = self.env().block_timestamp();
+            if item.coverage_amount == 0 || item.end_time <= item.start_time {
+                return Err(InsuranceError::InvalidParameters);
+            }
+            if item.end_time <= now {
+                return Err(InsuranceError::PolicyExpired);
+            }
+            let mut pool = self
+                .pools
+                .get(&item.pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+            if !pool.is_active {
+                return Err(InsuranceError::PoolNotFound);
+            }
+            if self.pool_runoffs.contains(&item.pool_id) {
+                return Err(InsuranceError::PoolClosing);
+            }
+            let max_exposure = pool
+                .available_capital
+                .saturating_mul(pool.max_coverage_ratio as u128)
+                / 10_000;
+            if item.coverage_amount > max_exposure {
+                return Err(InsuranceError::InsufficientPoolFunds);
+            }
+
+            pool.active_policies += 1;
+            self.pools.insert(&item.pool_id, &pool);
+
+            let policy_id = self.policy_count + 1;
+            self.policy_count = policy_id;
+            let policy = InsurancePolicy {
+                policy_id,
+                property_id: item.property_id,
+                policyholder: item.policyholder,
+                coverage_type: item.coverage_type,
+                coverage_amount: item.coverage_amount,
+                premium_amount: item.premium_amount,
+                deductible: item.deductible,
+                start_time: item.start_time,
+                end_time: item.end_time,
+                status: PolicyStatus::Active,
+                risk_level: self
+                    .risk_assessments
+                    .get(&item.property_id)
+                    .map(|assessment| assessment.risk_level)
+                    .unwrap_or(RiskLevel::Medium),
+                pool_id: item.pool_id,
+                claims_count: item.prior_claims_count,
+                total_claimed: 0,
+                metadata_url: item.metadata_url,
+                payout_mode: PayoutMode::Policyholder,
+                // Cover continues from the previous insurer without a new waiting period
+                waiting_period_ends: item.start_time,
+                copay_bps: 0,
+                cover_since: 0,
+            };
+            self.store_new_policy(&policy);
+            self.imported_policies.insert(&policy_id, &true);
+            self.imported_policy_count += 1;
+            self.internal_mint_token(policy_id, item.policyholder, item.coverage_amount)?;
+
+            self.env().emit_event(PolicyImported {
+                policy_id,
+                policyholder: item.policyholder,
+                property_id: item.property_id,
+                premium_amount: item.premium_amount,
+                start_time: item.start_time,
+                end_time: item.end_time,
+                prior_claims_count: item.prior_claims_count,
+            });
+
+            Ok(policy_id)
+        }
+
+        fn internal_mint_token(
+            &mut self,
+            policy_id: u64,
+            owner: AccountId,
+            face_value: u128,
+        ) -> Result<u64, InsuranceError> {
+            let token_id = self.token_count + 1;
+            self.token_count = token_id;
+
+            let token = InsuranceToken {
+                token_id,
+                policy_id,
+                owner,
+                face_value,
+                is_tradeable: true,
+                created_at: self.env().block_timestamp(),
+                listed_price: None,
+            };
+
+            self.insurance_tokens.insert(&token_id, &token);
+
+            self.env().emit_event(InsuranceTokenMinted {
+                token_id,
+                policy_id,
+                owner,
+                face_value,
+            });
+
+            Ok(token_id)
+        }
+
+        /// Splits a loss into the deductible applied, the policyholder's co-pay on
+        /// the remainder, and the insurer's share before coverage caps
+        fn split_loss(policy: &InsurancePolicy, loss: u128) -> (u128, u128, u128) {
+            let deductible_applied = loss.min(policy.deductible);
+            let after_deductible = loss.saturating_sub(deductible_applied);
+            let copay_amount = after_deductible.saturating_mul(policy.copay_bps as u128) / 10_000;
+            (
+                deductible_applied,
+                copay_amount,
+                after_deductible.saturating_sub(copay_amount),
+            )
+        }
+
+        fn execute_payout(
+            &mut self,
+            claim_id: u64,
+            policy_id: u64,
+            recipient: AccountId,
+            amount: u128,
+            deductible_applied: u128,
+            copay_amount: u128,
+        ) -> Result<(), InsuranceError> {
+            if amount == 0 {
+                return Ok(());
+            }
+            self.ensure_not_paused(PauseDomain::Payouts)?;
+
+            let mut policy = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?;
+            let mut pool = self
+                .pools
+                .get(&policy.pool_id)
+                .ok_or(InsuranceError::PoolNotFound)?;
+
+            // Check if reinsurance is needed
+            let use_reinsurance = amount > pool.reinsurance_threshold;
+
+            if use_reinsurance {
+                // Try to recover excess from reinsurance
+                self.try_reinsurance_recovery(claim_id, policy_id, amount)?;
+            }
+
+            if pool.available_capital < amount {
+                return Err(InsuranceError::InsufficientPoolFunds);
+            }
+
+            pool.available_capital = pool.available_capital.saturating_sub(amount);
+            pool.total_claims_paid += amount;
+            self.pools.insert(&policy.pool_id, &pool);
+
+            // Update policy
+            policy.total_claimed += amount;
+            if policy.total_claimed >= policy.coverage_amount {
+                policy.status = PolicyStatus::Claimed;
+            }
+            self.policies.insert(&policy_id, &policy);
+
+            // Update cooldown
+            self.claim_cooldowns
+                .insert(&policy.property_id, &self.env().block_timestamp());
+
+            // Update claim status
+            if let Some(mut claim) = self.claims.get(&claim_id) {
+                self.set_claim_status(&mut claim, ClaimStatus::Paid);
+                self.claims.insert(&claim_id, &claim);
+                let recovered = self
+                    .get_reinsurance_allocation(claim_id)
+                    .iter()
+                    .fold(0u128, |acc, layer| acc.saturating_add(layer.recovery));
+                let receivables = self.pool_receivables.get(&policy.pool_id).unwrap_or(0);
+                self.pool_receivables
+                    .insert(&policy.pool_id, &receivables.saturating_add(recovered));
+            }
+            self.record_nav(policy.pool_id);
+
+            // Third parties are paid directly whatever the policy's payout mode
+            let recipient = match policy.payout_mode {
+                _ if self.liability_claims.contains(&claim_id) => recipient,
+                PayoutMode::Policyholder => recipient,
+                PayoutMode::TokenDividends {
+                    token_contract,
+                    token_id,
+                } => {
+                    self.deposit_to_shareholders(token_contract, token_id, amount)?;
+                    self.env().emit_event(PayoutRoutedToShareholders {
+                        claim_id,
+                        token_contract,
+                        token_id,
+                        amount,
+                    });
+                    token_contract
+                }
+            };
+
+            self.env().emit_event(PayoutExecuted {
+                claim_id,
+                recipient,
+                amount,
+                deductible_applied,
+                copay_amount,
+                timestamp: self.env().block_timestamp(),
+            });
+            self.record_payout_velocity(amount);
+            self.monitor_coverage(policy.property_id);
+
+            Ok(())
+        }
+
+        /// Walks excess-of-loss layers in attachment order. Each agreement is a layer
+        /// covering the loss between `retention_limit` (attachment) and
+        /// `retention_limit + coverage_limit`.
+        fn try_reinsurance_recovery(
+            &mut self,
+            claim_id: u64,
+            policy_id: u64,
+            amount: u128,
+        ) -> Result<(), InsuranceError> {
+            let coverage_type = self
+                .policies
+                .get(&policy_id)
+                .ok_or(InsuranceError::PolicyNotFound)?
+                .coverage_type;
+            let now = self.env().block_timestamp();
+
+            let mut allocations = Vec::new();
+            for mut agreement in self.covering_layers(&coverage_type, now) {
+                let recovery = Self::charge_layer(&mut agreement, amount);
+                if recovery == 0 {
+                    continue;
+                }
+                self.reinsurance_agreements
+                    .insert(&agreement.agreement_id, &agreement);
+                allocations.push(LayerAllocation {
+                    agreement_id: agreement.agreement_id,
+                    recovery,
+                });
+
+                self.env().emit_event(ReinsuranceActivated {
+                    claim_id,
+                    agreement_id: agreement.agreement_id,
+                    recovery_amount: recovery,
+                    timestamp: now,
+                });
+            }
+
+            if !allocations.is_empty() {
+                self.claim_reinsurance_allocations
+                    .insert(&claim_id, &allocations);
+            }
+            Ok(())
+        }
+
+        /// Active agreements covering the peril, in attachment order
+        fn covering_layers(
+            &self,
+            coverage_type: &CoverageType,
+            now: u64,
+        ) -> Vec<ReinsuranceAgreement> {
+            let mut layers: Vec<ReinsuranceAgreement> = Vec::new();
+            for i in 1..=self.reinsurance_count {
+                if let Some(agreement) = self.reinsurance_agreements.get(&i) {
+                    if !agreement.is_active
+                        || now < agreement.start_time
+                        || now > agreement.end_time
+                    {
+                        continue;
+                    }
+                    if !agreement.coverage_types.is_empty()
+                        && !agreement.coverage_types.contains(coverage_type)
+                    {
+                        continue;
+                    }
+                    layers.push(agreement);
+                }
+            }
+            layers.sort_by_key(|a| a.retention_limit);
+            layers
+        }
+
+        /// Charges a loss against one layer, reinstating an exhausted limit if
+        /// allowed. Returns the recovery; the caller decides whether to persist.
+        fn charge_layer(agreement: &mut ReinsuranceAgreement, amount: u128) -> u128 {
+            let layer_loss = amount
+                .saturating_sub(agreement.retention_limit)
+                .min(agreement.coverage_limit);
+            if layer_loss == 0 {
+                return 0;
+            }
+            if agreement.limit_utilized >= agreement.coverage_limit
+                && agreement.reinstatements_used < agreement.reinstatements
+            {
+                agreement.reinstatements_used += 1;
+                agreement.limit_utilized = 0;
+            }
+            let remaining = agreement
+                .coverage_limit
+                .saturating_sub(agreement.limit_utilized);
+            let recovery = layer_loss.min(remaining);
+            agreement.limit_utilized += recovery;
+            agreement.total_recoveries += recovery;
+            recovery
+        }
+    }
+
+    impl propchain_traits::PropertyBridgeListener for PropertyInsurance {
+        #[ink(message)]
+        fn on_property_bridged(
+            &mut self,
+            property_id: u64,
+            destination_chain: u64,
+            transaction_hash: Hash,
+        ) -> Result<u32, BridgeHookError> {
+            self.ensure_bridge_hook_source()?;
+            Ok(self.port_policies(property_id, destination_chain, transaction_hash))
+        }
+
+        #[ink(message)]
+        fn on_property_returned(&mut self, property_id: u64) -> Result<u32, BridgeHookError> {
+            self.ensure_bridge_hook_source()?;
+            Ok(self.restore_policies(property_id))
+        }
+    }
+
+    impl propchain_traits::PoolAggregates for PropertyInsurance {
+        #[ink(message)]
+        fn aggregate_active_policies(&self, pool_id: u64) -> u64 {
+            self.pools
+                .get(&pool_id)
+                .map_or(0, |pool| pool.active_policies)
+        }
+
+        #[ink(message)]
+        fn aggregate_pool_capital(&self, pool_id: u64) -> u128 {
+            self.pools
+                .get(&pool_id)
+                .map_or(0, |pool| pool.available_capital)
+        }
+    }
+
+    impl Default for PropertyInsurance {
+        fn default() -> Self {
+            Self::new(AccountId::from([0x0; 32]))
+        }
+    }
+}
+
+pub use crate::propchain_insurance::{InsuranceError, PropertyInsurance};
+
+#[cfg(test)]
+mod insurance_tests {
+    use ink::env::{test, DefaultEnvironment};
+    use ink::primitives::Hash;
+
+    use crate::propchain_insurance::{
+        ClaimStatus, CoverageType, Denomination, EsgTier, InsuranceError, LevyRate,
+        LiabilityClaimStats, LienStatus, MitigationMeasure, NoticeKind, PauseDomain, PayoutMode,
+        PolicyImport, PolicyStatus, PolicyTerms, PropertyInsurance, PropertyProfile, RunOffStatus,
+        StressScenario, UnderwritingCriterion, UnderwritingDecision, MAX_COPAY_BPS, NAV_PRECISION,
+        PAUSE_CONFIRMATION_TTL, TREATY_RENEWAL_WINDOW,
+    };
+
+    fn setup() -> PropertyInsurance {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        // Start at 35 days so `now - last_claim(0) > 30-day cooldown`
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000);
+        PropertyInsurance::new(accounts.alice)
+    }
+
+    fn add_risk_assessment(contract: &mut PropertyInsurance, property_id: u64) {
+        contract
+            .update_risk_assessment(property_id, 75, 80, 85, 90, 86_400 * 365)
+            .expect("risk assessment failed");
+    }
+
+    fn create_pool(contract: &mut PropertyInsurance) -> u64 {
+        contract
+            .create_risk_pool(
+                "Fire & Flood Pool".into(),
+                CoverageType::Fire,
+                8000,
+                500_000_000_000u128,
+            )
+            .expect("pool creation failed")
+    }
+
+    /// Current template of the property's jurisdiction. An unplaced property is put
+    /// in "US-NY", and a template without constraints is registered if none exists.
+    /// Acts as the admin and restores the caller.
+    fn cite_template(contract: &mut PropertyInsurance, property_id: u64) -> u64 {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let caller = ink::env::caller::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let jurisdiction = match contract.get_property_jurisdiction(property_id) {
+            Some(jurisdiction) => jurisdiction,
+            None => {
+                contract
+                    .set_property_jurisdiction(property_id, "US-NY".into())
+                    .unwrap();
+                "US-NY".into()
+            }
+        };
+        let template_id = match contract.get_jurisdiction_template(jurisdiction.clone()) {
+            Some(template) => template.template_id,
+            None => contract
+                .register_policy_template(jurisdiction, 0, Vec::new(), 0, 0)
+                .unwrap(),
+        };
+        test::set_caller::<DefaultEnvironment>(caller);
+        template_id
+    }
+
+    /// Fire cover on property 1 matching `create_funded_policy`
+    fn fire_terms(contract: &mut PropertyInsurance, pool_id: u64) -> PolicyTerms {
+        PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(contract, 1),
+            copay_bps: None,
+        }
+    }
+
+    /// Funds a fresh pool and issues a Fire policy on property 1 to bob.
+    /// Leaves the caller set to bob; returns (pool_id, policy_id).
+    fn create_funded_policy(contract: &mut PropertyInsurance) -> (u64, u64) {
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        (pool_id, policy_id)
+    }
+
+    /// Reports (or looks up) the incident a claim on `policy_id` refers to, as the current caller
+    fn file_incident(contract: &mut PropertyInsurance, policy_id: u64) -> u64 {
+        let policy = contract.get_policy(policy_id).unwrap();
+        contract
+            .report_incident(policy.property_id, policy.coverage_type, policy.start_time)
+            .expect("incident report failed")
+    }
+
+    // =========================================================================
+    // CONSTRUCTOR
+    // =========================================================================
+
+    #[ink::test]
+    fn test_new_contract_initialised() {
+        let contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(contract.get_admin(), accounts.alice);
+        assert_eq!(contract.get_policy_count(), 0);
+        assert_eq!(contract.get_claim_count(), 0);
+    }
+
+    // =========================================================================
+    // POOL TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_create_risk_pool_works() {
+        let mut contract = setup();
+        let pool_id = create_pool(&mut contract);
+        assert_eq!(pool_id, 1);
+        let pool = contract.get_pool(1).unwrap();
+        assert_eq!(pool.pool_id, 1);
+        assert!(pool.is_active);
+        assert_eq!(pool.active_policies, 0);
+    }
+
+    #[ink::test]
+    fn test_create_risk_pool_unauthorized() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let result = contract.create_risk_pool(
+            "Unauthorized Pool".into(),
+            CoverageType::Fire,
+            8000,
+            1_000_000,
+        );
+        assert_eq!(result, Err(InsuranceError::Unauthorized));
+    }
+
+    #[ink::test]
+    fn test_provide_pool_liquidity_works() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
+        let result = contract.provide_pool_liquidity(pool_id);
+        assert!(result.is_ok());
+        let pool = contract.get_pool(pool_id).unwrap();
+        assert_eq!(pool.total_capital, 1_000_000_000_000u128);
+        assert_eq!(pool.available_capital, 1_000_000_000_000u128);
+    }
+
+    #[ink::test]
+    fn test_provide_liquidity_nonexistent_pool_fails() {
+        let mut contract = setup();
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000u128);
+        let result = contract.provide_pool_liquidity(999);
+        assert_eq!(result, Err(InsuranceError::PoolNotFound));
+    }
+
+    // =========================================================================
+    // RISK ASSESSMENT TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_update_risk_assessment_works() {
+        let mut contract = setup();
+        add_risk_assessment(&mut contract, 1);
+        let assessment = contract.get_risk_assessment(1).unwrap();
+        assert_eq!(assessment.property_id, 1);
+        assert_eq!(assessment.overall_risk_score, 82); // (75+80+85+90)/4
+        assert!(assessment.valid_until > 0);
+    }
+
+    #[ink::test]
+    fn test_risk_assessment_unauthorized() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let result = contract.update_risk_assessment(1, 70, 70, 70, 70, 86400);
+        assert_eq!(result, Err(InsuranceError::Unauthorized));
+    }
+
+    #[ink::test]
+    fn test_authorized_oracle_can_assess() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.authorize_oracle(accounts.bob).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let result = contract.update_risk_assessment(1, 70, 70, 70, 70, 86400);
+        assert!(result.is_ok());
+    }
+
+    // =========================================================================
+    // PREMIUM CALCULATION TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_calculate_premium_works() {
+        let mut contract = setup();
+        add_risk_assessment(&mut contract, 1);
+        let result = contract.calculate_premium(1, 1_000_000_000_000u128, CoverageType::Fire);
+        assert!(result.is_ok());
+        let calc = result.unwrap();
+        assert!(calc.annual_premium > 0);
+        assert!(calc.monthly_premium > 0);
+        assert!(calc.deductible > 0);
+        assert_eq!(calc.base_rate, 150);
+    }
+
+    #[ink::test]
+    fn test_premium_without_assessment_fails() {
+        let contract = setup();
+        let result = contract.calculate_premium(999, 1_000_000u128, CoverageType::Fire);
+        assert_eq!(result, Err(InsuranceError::PropertyNotInsurable));
+    }
+
+    #[ink::test]
+    fn test_comprehensive_coverage_higher_premium() {
+        let mut contract = setup();
+        add_risk_assessment(&mut contract, 1);
+        let fire_calc = contract
+            .calculate_premium(1, 1_000_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        let comp_calc = contract
+            .calculate_premium(1, 1_000_000_000_000u128, CoverageType::Comprehensive)
+            .unwrap();
+        assert!(comp_calc.annual_premium > fire_calc.annual_premium);
+    }
+
+    #[ink::test]
+    fn test_risk_import_requires_authorized_engine() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            contract.import_risk_assessment(1, 86_400),
+            Err(InsuranceError::RiskSourceUnavailable)
+        );
+        contract
+            .set_risk_score_source(Some(accounts.django))
+            .unwrap();
+        // The engine must be cited as an authorized oracle before its scores count
+        assert_eq!(
+            contract.import_risk_assessment(1, 86_400),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.set_risk_score_source(None),
+            Err(InsuranceError::Unauthorized)
+        );
+        assert_eq!(
+            contract.import_risk_assessment(1, 86_400),
+            Err(InsuranceError::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_seasonal_curve_adjusts_premium_and_keeps_history() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        add_risk_assessment(&mut contract, 1);
+        let flat = contract
+            .calculate_premium(1, 1_000_000_000_000u128, CoverageType::Flood)
+            .unwrap();
+        assert_eq!(flat.seasonal_factor, 10_000);
+        assert_eq!(flat.seasonal_curve_version, 0);
+
+        // setup() runs in February 1970; flood risk doubles that month
+        let mut factors = vec![10_000u32; 12];
+        factors[1] = 20_000;
+        assert_eq!(
+            contract.set_seasonal_curve(CoverageType::Flood, vec![10_000; 11]),
+            Err(InsuranceError::InvalidParameters)
+        );
+        assert_eq!(
+            contract.set_seasonal_curve(CoverageType::Flood, factors),
+            Ok(1)
+        );
+        let peak = contract
+            .calculate_premium(1, 1_000_000_000_000u128, CoverageType::Flood)
+            .unwrap();
+        assert_eq!(peak.seasonal_factor, 20_000);
+        assert_eq!(peak.seasonal_curve_version, 1);
+        assert!(peak.annual_premium >= flat.annual_premium * 2 - 1);
+        // Other coverage types are unaffected
+        let fire = contract
+            .calculate_premium(1, 1_000_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        assert_eq!(fire.seasonal_factor, 10_000);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.set_seasonal_curve(CoverageType::Flood, vec![10_000; 12]),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.authorize_oracle(accounts.bob).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.set_seasonal_curve(CoverageType::Flood, vec![10_000; 12]),
+            Ok(2)
+        );
+        // The superseded curve still reproduces the earlier quote
+        assert_eq!(
+            contract.get_seasonal_factor(CoverageType::Flood, 1, 3_000_000),
+            20_000
+        );
+        assert_eq!(
+            contract.get_seasonal_factor(CoverageType::Flood, 2, 3_000_000),
+            10_000
+        );
+        assert_eq!(contract.get_seasonal_curve_version(CoverageType::Flood), 2);
+    }
+
+    // =========================================================================
+    // MITIGATION CREDIT TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_verified_mitigation_discounts_premium_until_expiry() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        add_risk_assessment(&mut contract, 1);
+        contract.authorize_notary(accounts.charlie).unwrap();
+        contract
+            .set_mitigation_discount(CoverageType::Flood, MitigationMeasure::FloodBarrier, 1_000)
+            .unwrap();
+        let gross = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Flood)
+            .unwrap();
+        assert_eq!(gross.mitigation_discount, 0);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let valid_until = 3_000_000 + 86_400;
+        let document_id = contract
+            .attach_mitigation_document(
+                1,
+                MitigationMeasure::FloodBarrier,
+                Hash::from([4u8; 32]),
+                valid_until,
+            )
+            .unwrap();
+        // Unverified documents earn nothing
+        assert_eq!(contract.get_mitigation_discount(1, CoverageType::Flood), 0);
+        assert_eq!(
+            contract.verify_mitigation_document(document_id),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        contract.verify_mitigation_document(document_id).unwrap();
+        let discounted = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Flood)
+            .unwrap();
+        assert_eq!(discounted.mitigation_discount, 1_000);
+        assert_eq!(
+            discounted.annual_premium,
+            gross.annual_premium * 9_000 / 10_000
+        );
+        assert_eq!(contract.get_mitigation_discount(1, CoverageType::Fire), 0);
+
+        test::set_block_timestamp::<DefaultEnvironment>(valid_until + 1);
+        assert_eq!(contract.get_mitigation_discount(1, CoverageType::Flood), 0);
+    }
+
+    #[ink::test]
+    fn test_green_tier_discounts_premium_and_gates_esg_pool() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        contract.authorize_notary(accounts.charlie).unwrap();
+        contract.set_green_discount(EsgTier::Gold, 1_500).unwrap();
+        contract
+            .set_pool_esg_filter(pool_id, Some(EsgTier::Silver))
+            .unwrap();
+        let gross = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(gross.annual_premium * 2);
+        let create = |contract: &mut PropertyInsurance| {
+            let terms = PolicyTerms {
+                property_id: 1,
+                coverage_type: CoverageType::Fire,
+                coverage_amount: 500_000_000_000u128,
+                pool_id,
+                duration_seconds: 86_400 * 365,
+                metadata_url: "ipfs://green".into(),
+                template_id: cite_template(contract, 1),
+                copay_bps: None,
+            };
+            contract.create_policy(terms, None)
+        };
+        assert_eq!(create(&mut contract), Err(InsuranceError::EsgTierTooLow));
+        assert_eq!(
+            contract.attach_esg_profile(1, 2, true, Hash::from([6u8; 32]), 3_000_000 + 86_400),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let tier = contract
+            .attach_esg_profile(1, 2, true, Hash::from([6u8; 32]), 3_000_000 + 86_400)
+            .unwrap();
+        assert_eq!(tier, EsgTier::Gold);
+        // Unverified attestations do not count
+        assert_eq!(contract.get_esg_tier(1), EsgTier::Unrated);
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(
+            contract.verify_esg_profile(1, Hash::from([7u8; 32])),
+            Err(InsuranceError::DocumentMismatch)
+        );
+        contract
+            .verify_esg_profile(1, Hash::from([6u8; 32]))
+            .unwrap();
+        let green = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        assert_eq!(green.green_discount, 1_500);
+        assert_eq!(green.annual_premium, gross.annual_premium * 8_500 / 10_000);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        create(&mut contract).unwrap();
+        let report = contract.get_exposure_report(pool_id).unwrap();
+        assert_eq!(
+            report.insured_by_esg_tier,
+            vec![(EsgTier::Gold, 500_000_000_000u128)]
+        );
+    }
+
+    // =========================================================================
+    // POLICY CREATION TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_create_policy_works() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://policy-metadata".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let result = contract.create_policy(terms, None);
+        assert!(result.is_ok());
+
+        let policy_id = result.unwrap();
+        let policy = contract.get_policy(policy_id).unwrap();
+        assert_eq!(policy.property_id, 1);
+        assert_eq!(policy.policyholder, accounts.bob);
+        assert_eq!(policy.status, PolicyStatus::Active);
+        assert_eq!(contract.get_policy_count(), 1);
+    }
+
+    #[ink::test]
+    fn test_create_policy_insufficient_premium_fails() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(1u128);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://policy-metadata".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let result = contract.create_policy(terms, None);
+        assert_eq!(result, Err(InsuranceError::InsufficientPremium));
+    }
+
+    #[ink::test]
+    fn test_create_policy_nonexistent_pool_fails() {
+        let mut contract = setup();
+        add_risk_assessment(&mut contract, 1);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 100_000u128,
+            pool_id: 999,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://policy-metadata".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let result = contract.create_policy(terms, None);
+        assert_eq!(result, Err(InsuranceError::PoolNotFound));
+    }
+
+    #[ink::test]
+    fn test_import_policies_reports_per_item_results() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        let capital = contract.get_pool(pool_id).unwrap().available_capital;
+
+        let item = PolicyImport {
+            property_id: 7,
+            policyholder: accounts.bob,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 100_000_000_000u128,
+            premium_amount: 2_000_000_000u128,
+            deductible: 1_000_000_000u128,
+            start_time: 1_000_000,
+            end_time: 1_000_000 + 86_400 * 365,
+            pool_id,
+            prior_claims_count: 2,
+            metadata_url: "ipfs://legacy-book/7".into(),
+        };
+        let orphan = PolicyImport {
+            pool_id: 99,
+            ..item.clone()
+        };
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.import_policies(vec![item.clone()]),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let results = contract
+            .import_policies(vec![item.clone(), orphan])
+            .unwrap();
+        assert_eq!(results, vec![Ok(1), Err(InsuranceError::PoolNotFound)]);
+
+        let policy = contract.get_policy(1).unwrap();
+        assert_eq!(policy.start_time, 1_000_000);
+        assert_eq!(policy.premium_amount, 2_000_000_000u128);
+        assert_eq!(policy.claims_count, 2);
+        assert!(contract.is_imported_policy(1));
+        assert_eq!(contract.get_imported_policy_count(), 1);
+        assert_eq!(
+            contract.get_pool(pool_id).unwrap().available_capital,
+            capital
+        );
+
+        contract.set_max_import_batch(1).unwrap();
+        assert_eq!(
+            contract.import_policies(vec![item.clone(), item]),
+            Err(InsuranceError::InvalidParameters)
+        );
+    }
+
+    // =========================================================================
+    // POLICY CANCELLATION TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_cancel_policy_by_policyholder() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let result = contract.cancel_policy(policy_id);
+        assert!(result.is_ok());
+        let policy = contract.get_policy(policy_id).unwrap();
+        assert_eq!(policy.status, PolicyStatus::Cancelled);
+    }
+
+    #[ink::test]
+    fn test_cancel_policy_by_non_owner_fails() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let result = contract.cancel_policy(policy_id);
+        assert_eq!(result, Err(InsuranceError::Unauthorized));
+    }
+
+    #[ink::test]
+    fn test_certificate_verifies_until_policy_lapses() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(
+            contract.issue_certificate(policy_id),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let (certificate_id, content_hash) = contract.issue_certificate(policy_id).unwrap();
+        let certificate = contract.get_certificate(certificate_id).unwrap();
+        assert_eq!(certificate.policy_id, policy_id);
+        assert_eq!(certificate.policyholder, accounts.bob);
+        assert!(contract.verify_certificate(certificate_id, content_hash));
+        assert!(!contract.verify_certificate(certificate_id, Hash::from([1u8; 32])));
+
+        contract.cancel_policy(policy_id).unwrap();
+        assert!(!contract.verify_certificate(certificate_id, content_hash));
+        assert_eq!(
+            contract.issue_certificate(policy_id),
+            Err(InsuranceError::PolicyInactive)
+        );
+    }
+
+    #[ink::test]
+    fn test_pool_denominations_segregate_native_and_asset_pools() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let usd = Denomination::Asset(accounts.frank);
+        let asset_pool = create_pool(&mut contract);
+        assert_eq!(
+            contract.set_pool_denomination(asset_pool, usd),
+            Err(InsuranceError::AssetNotRegistered)
+        );
+        contract
+            .register_denomination_asset(accounts.frank, "USDx".into())
+            .unwrap();
+        contract.set_pool_denomination(asset_pool, usd).unwrap();
+        assert_eq!(contract.get_pool_denomination(asset_pool), usd);
+
+        // Native value cannot enter a stablecoin pool
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        assert_eq!(
+            contract.provide_pool_liquidity(asset_pool),
+            Err(InsuranceError::DenominationMismatch)
+        );
+        add_risk_assessment(&mut contract, 2);
+        let terms = PolicyTerms {
+            property_id: 2,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 1_000,
+            pool_id: asset_pool,
+            duration_seconds: 86_400,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 2),
+            copay_bps: None,
+        };
+        assert_eq!(
+            contract.create_policy(terms, None),
+            Err(InsuranceError::DenominationMismatch)
+        );
+
+        // Platform fees on native premiums accrue to the native treasury only
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let (native_pool, policy_id) = create_funded_policy(&mut contract);
+        let premium = contract.get_policy(policy_id).unwrap().premium_amount;
+        assert_eq!(
+            contract.get_asset_treasury(Denomination::Native),
+            premium * 200 / 10_000
+        );
+        assert_eq!(contract.get_asset_treasury(usd), 0);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            contract.set_pool_denomination(native_pool, usd),
+            Err(InsuranceError::InvalidParameters)
+        );
+
+        // Conversion needs treasury funds in the sold asset
+        assert_eq!(
+            contract.approve_asset_swap(accounts.django, usd, 1, Denomination::Native, 1),
+            Err(InsuranceError::InsufficientPoolFunds)
+        );
+        let swap_id = contract
+            .approve_asset_swap(accounts.django, Denomination::Native, 100, usd, 99)
+            .unwrap();
+        assert_eq!(
+            contract.execute_asset_swap(swap_id),
+            Err(InsuranceError::Unauthorized)
+        );
+        contract.cancel_asset_swap(swap_id).unwrap();
+        assert!(contract.get_asset_swap(swap_id).is_none());
+    }
+
+    #[ink::test]
+    fn test_jurisdiction_levies_withheld_from_premium_and_reported() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        contract
+            .set_property_jurisdiction(1, "US-NY".into())
+            .unwrap();
+        let template_id = contract
+            .register_policy_template("US-NY".into(), 0, Vec::new(), 0, 0)
+            .unwrap();
+        let levy = |name: &str, rate_bp, recipient| LevyRate {
+            name: name.into(),
+            rate_bp,
+            recipient,
+        };
+        assert_eq!(
+            contract.set_levy_schedule("US-NY".into(), vec![levy("tax", 9_900, accounts.django)]),
+            Err(InsuranceError::LevyScheduleInvalid)
+        );
+        contract
+            .set_levy_schedule(
+                "US-NY".into(),
+                vec![
+                    levy("premium-tax", 300, accounts.django),
+                    levy("fire-levy", 100, accounts.eve),
+                ],
+            )
+            .unwrap();
+
+        let capital_before = contract.get_pool(pool_id).unwrap().available_capital;
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id,
+            copay_bps: None,
+        };
+        contract.create_policy(terms, None).unwrap();
+
+        // 2% platform fee and 4% of levies stay out of the pool
+        assert_eq!(
+            contract.get_pool(pool_id).unwrap().available_capital - capital_before,
+            940_000_000_000u128
+        );
+        assert_eq!(
+            contract.get_levy_balance(accounts.django),
+            30_000_000_000u128
+        );
+        assert_eq!(contract.get_levy_balance(accounts.eve), 10_000_000_000u128);
+        let period = 3_000_000 / contract.get_statistics_period();
+        let report = contract.get_levy_report("US-NY".into(), period, Denomination::Native);
+        assert_eq!(report.policies, 1);
+        assert_eq!(report.premium_base, 1_000_000_000_000u128);
+        assert_eq!(report.total_levied, 40_000_000_000u128);
+        assert_eq!(
+            report.levies,
+            vec![
+                ("premium-tax".into(), 30_000_000_000u128),
+                ("fire-levy".into(), 10_000_000_000u128)
+            ]
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.eve);
+        test::set_account_balance::<DefaultEnvironment>(
+            test::callee::<DefaultEnvironment>(),
+            1_000_000_000_000u128,
+        );
+        assert_eq!(contract.withdraw_levy_balance(), Ok(10_000_000_000u128));
+        assert_eq!(contract.get_levy_balance(accounts.eve), 0);
+    }
+
+    #[ink::test]
+    fn test_property_jurisdiction_sets_template_and_levies() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        contract
+            .set_levy_schedule(
+                "US-NY".into(),
+                vec![LevyRate {
+                    name: "premium-tax".into(),
+                    rate_bp: 9_700,
+                    recipient: accounts.django,
+                }],
+            )
+            .unwrap();
+        // 2% fee + 97% levies fits; raising the fee to 4% would not
+        assert_eq!(
+            contract.set_platform_fee_rate(400),
+            Err(InsuranceError::LevyScheduleInvalid)
+        );
+        contract
+            .set_levy_schedule(
+                "US-NY".into(),
+                vec![LevyRate {
+                    name: "premium-tax".into(),
+                    rate_bp: 300,
+                    recipient: accounts.django,
+                }],
+            )
+            .unwrap();
+        contract
+            .set_property_jurisdiction(1, "US-NY".into())
+            .unwrap();
+        let template_id = contract
+            .register_policy_template("GB".into(), 0, Vec::new(), 0, 0)
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id,
+            copay_bps: None,
+        };
+        assert_eq!(
+            contract.create_policy(terms, None),
+            Err(InsuranceError::TemplateViolation)
+        );
+        let capital_before = contract.get_pool(pool_id).unwrap().available_capital;
+        let terms = fire_terms(&mut contract, pool_id);
+        contract.create_policy(terms, None).unwrap();
+        assert_eq!(
+            contract.get_pool(pool_id).unwrap().available_capital - capital_before,
+            950_000_000_000u128
+        );
+        assert_eq!(
+            contract.get_levy_balance(accounts.django),
+            30_000_000_000u128
+        );
+    }
+
+    #[ink::test]
+    fn test_policy_templates_enforce_jurisdiction_terms() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let notice = 86_400 * 30;
+        let v1 = contract
+            .register_policy_template(
+                "US-CA".into(),
+                100_000_000_000u128,
+                vec![CoverageType::Fire],
+                1_000,
+                notice,
+            )
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
+        let create = |contract: &mut PropertyInsurance, coverage, amount, template_id| {
+            contract.create_policy(
+                PolicyTerms {
+                    property_id: 1,
+                    coverage_type: coverage,
+                    coverage_amount: amount,
+                    pool_id,
+                    duration_seconds: 86_400 * 365,
+                    metadata_url: "ipfs://test".into(),
+                    template_id,
+                    copay_bps: None,
+                },
+                None,
+            )
+        };
+        assert_eq!(
+            create(
+                &mut contract,
+                CoverageType::Fire,
+                500_000_000_000u128,
+                v1 + 1
+            ),
+            Err(InsuranceError::TemplateNotFound)
+        );
+        // The template must belong to the property's recorded jurisdiction
+        assert_eq!(
+            create(&mut contract, CoverageType::Fire, 500_000_000_000u128, v1),
+            Err(InsuranceError::TemplateViolation)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .set_property_jurisdiction(1, "US-NY".into())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            create(&mut contract, CoverageType::Fire, 500_000_000_000u128, v1),
+            Err(InsuranceError::TemplateViolation)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .set_property_jurisdiction(1, "US-CA".into())
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            create(&mut contract, CoverageType::Flood, 500_000_000_000u128, v1),
+            Err(InsuranceError::TemplateViolation)
+        );
+        assert_eq!(
+            create(&mut contract, CoverageType::Fire, 50_000_000_000u128, v1),
+            Err(InsuranceError::TemplateViolation)
+        );
+        let policy_id = create(&mut contract, CoverageType::Fire, 500_000_000_000u128, v1).unwrap();
+        assert_eq!(contract.get_policy(policy_id).unwrap().deductible, 1_000);
+        assert_eq!(contract.get_policy_template_id(policy_id), Some(v1));
+
+        // A new version supersedes v1 for new policies only
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let v2 = contract
+            .register_policy_template("US-CA".into(), 0, Vec::new(), 0, 0)
+            .unwrap();
+        assert_eq!(
+            contract
+                .get_jurisdiction_template("US-CA".into())
+                .unwrap()
+                .version,
+            2
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            create(&mut contract, CoverageType::Fire, 500_000_000_000u128, v1),
+            Err(InsuranceError::TemplateSuperseded)
+        );
+        assert!(create(&mut contract, CoverageType::Flood, 500_000_000_000u128, v2).is_ok());
+
+        // Insurer cancellation honours the original notice period
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.cancel_policy(policy_id).unwrap();
+        let policy = contract.get_policy(policy_id).unwrap();
+        assert_eq!(policy.status, PolicyStatus::Active);
+        assert_eq!(policy.end_time, 3_000_000 + notice);
+    }
+
+    // =========================================================================
+    // COMPLIANCE SUSPENSION TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_compliance_lapse_suspends_and_reinstates_policy() {
+        let mut contract = setup();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let original_end = contract.get_policy(policy_id).unwrap().end_time;
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        assert_eq!(
+            contract.report_property_compliance(1, false),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            contract.check_property_compliance(1),
+            Err(InsuranceError::ComplianceSourceUnavailable)
+        );
+
+        // Within the grace period the policy stays active
+        assert_eq!(contract.report_property_compliance(1, false), Ok(0));
+        assert_eq!(contract.get_compliance_lapse(1), Some(3_000_000));
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().status,
+            PolicyStatus::Active
+        );
+
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 604_800);
+        assert_eq!(contract.report_property_compliance(1, false), Ok(1));
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().status,
+            PolicyStatus::Suspended
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.submit_claim(
+                policy_id,
+                1,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::PolicyInactive)
+        );
+
+        // Restored ten days later: active again with the term pushed out
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 604_800 + 864_000);
+        assert_eq!(contract.report_property_compliance(1, true), Ok(1));
+        let policy = contract.get_policy(policy_id).unwrap();
+        assert_eq!(policy.status, PolicyStatus::Active);
+        assert_eq!(policy.end_time, original_end + 864_000);
+        assert_eq!(contract.get_compliance_lapse(1), None);
+        assert_eq!(contract.get_policy_suspension(policy_id), None);
+    }
+
+    // =========================================================================
+    // POLICYHOLDER NOTICE TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_notices_are_logged_and_acknowledged_by_policyholder() {
+        let mut contract = setup();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let renewal_text = Hash::from([9u8; 32]);
+        assert_eq!(
+            contract.issue_notice(policy_id, NoticeKind::Renewal, renewal_text),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let renewal = contract
+            .issue_notice(policy_id, NoticeKind::Renewal, renewal_text)
+            .unwrap();
+        // Insurer cancellation is logged automatically
+        contract.cancel_policy(policy_id).unwrap();
+        let trail = contract.get_policy_notices(policy_id);
+        assert_eq!(trail.len(), 2);
+        assert_eq!(trail[0].content_hash, renewal_text);
+        assert_eq!(trail[1].kind, NoticeKind::Cancellation);
+        assert_eq!(contract.get_outstanding_notices(accounts.bob).len(), 2);
+        assert_eq!(
+            contract.acknowledge_notice(renewal),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_100);
+        contract.acknowledge_notice(renewal).unwrap();
+        assert_eq!(
+            contract.acknowledge_notice(renewal),
+            Err(InsuranceError::NoticeAlreadyAcknowledged)
+        );
+        assert_eq!(
+            contract.get_notice(renewal).unwrap().acknowledged_at,
+            Some(3_000_100)
+        );
+        let outstanding = contract.get_outstanding_notices(accounts.bob);
+        assert_eq!(outstanding.len(), 1);
+        assert_eq!(outstanding[0].kind, NoticeKind::Cancellation);
+        assert_eq!(
+            contract.acknowledge_notice(99),
+            Err(InsuranceError::NoticeNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn test_localized_terms_must_be_acknowledged_at_purchase_version() {
+        let mut contract = setup();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let english = Hash::from([1u8; 32]);
+        let german = Hash::from([2u8; 32]);
+        let amended = Hash::from([3u8; 32]);
+        let incident_id = file_incident(&mut contract, policy_id);
+        assert_eq!(
+            contract.publish_policy_document(
+                policy_id,
+                "en-US".into(),
+                english,
+                "ipfs://terms-en".into(),
+                3_000_000
+            ),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        for (locale, hash) in [("en-US", english), ("de-DE", german)] {
+            assert_eq!(
+                contract.publish_policy_document(
+                    policy_id,
+                    locale.into(),
+                    hash,
+                    "ipfs://terms".into(),
+                    3_000_000
+                ),
+                Ok(1)
+            );
+        }
+        assert_eq!(
+            contract.publish_policy_document(
+                policy_id,
+                "en-US".into(),
+                amended,
+                "ipfs://terms-en-2".into(),
+                3_000_000
+            ),
+            Err(InsuranceError::InvalidParameters)
+        );
+        assert_eq!(
+            contract.publish_policy_document(
+                policy_id,
+                "en-US".into(),
+                amended,
+                "ipfs://terms-en-2".into(),
+                3_500_000
+            ),
+            Ok(2)
+        );
+        assert_eq!(
+            contract.get_policy_locales(policy_id),
+            vec![String::from("en-US"), String::from("de-DE")]
+        );
+
+        // Terms published but not yet accepted: claims are held back
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::TermsNotAcknowledged)
+        );
+        assert_eq!(
+            contract.acknowledge_policy_terms(policy_id, "en-US".into(), amended),
+            Err(InsuranceError::TermsMismatch)
+        );
+        assert_eq!(
+            contract.acknowledge_policy_terms(policy_id, "fr-FR".into(), english),
+            Err(InsuranceError::TermsNotFound)
+        );
+        assert_eq!(
+            contract.acknowledge_policy_terms(policy_id, "en-US".into(), english),
+            Ok(1)
+        );
+        assert_eq!(
+            contract.acknowledge_policy_terms(policy_id, "de-DE".into(), german),
+            Err(InsuranceError::TermsAlreadyAcknowledged)
+        );
+        let accepted = contract.get_accepted_terms(policy_id).unwrap();
+        assert_eq!(accepted.locale, "en-US");
+        assert_eq!(accepted.version, 1);
+        assert_eq!(accepted.document_hash, english);
+
+        // Accepted terms can no longer be displaced by backdated versions
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_block_timestamp::<DefaultEnvironment>(3_600_000);
+        assert_eq!(
+            contract.publish_policy_document(
+                policy_id,
+                "de-DE".into(),
+                amended,
+                "ipfs://terms-de-2".into(),
+                3_100_000
+            ),
+            Err(InsuranceError::TermsAlreadyAcknowledged)
+        );
+        let current = contract
+            .get_current_policy_document(policy_id, "en-US".into())
+            .unwrap();
+        assert_eq!(current.version, 2);
+        assert_eq!(current.uri, "ipfs://terms-en-2");
+        assert_eq!(
+            contract
+                .get_purchase_terms(policy_id, "en-US".into())
+                .unwrap()
+                .document_hash,
+            english
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert!(contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            )
+            .is_ok());
+    }
+
+    #[ink::test]
+    fn test_terms_published_after_purchase_are_not_purchase_terms() {
+        let mut contract = setup();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let incident_id = file_incident(&mut contract, policy_id);
+        let start = contract.get_policy(policy_id).unwrap().start_time;
+
+        // Backdated to the purchase, but published after it
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_block_timestamp::<DefaultEnvironment>(start + 100_000);
+        let late = Hash::from([4u8; 32]);
+        assert_eq!(
+            contract.publish_policy_document(
+                policy_id,
+                "en-US".into(),
+                late,
+                "ipfs://terms-late".into(),
+                start
+            ),
+            Ok(1)
+        );
+        assert_eq!(contract.get_purchase_terms(policy_id, "en-US".into()), None);
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.acknowledge_policy_terms(policy_id, "en-US".into(), late),
+            Err(InsuranceError::TermsNotFound)
+        );
+        assert!(contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            )
+            .is_ok());
+    }
+
+    // =========================================================================
+    // POLICY PORTABILITY TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_bridged_property_ports_policy_and_blocks_local_claims() {
+        use propchain_traits::{BridgeHookError, PropertyBridgeListener};
+        let mut contract = setup();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let bridge_tx = Hash::from([7u8; 32]);
+        assert_eq!(
+            contract.on_property_bridged(1, 2, bridge_tx),
+            Err(BridgeHookError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .set_bridge_hook_source(Some(accounts.django))
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(contract.on_property_bridged(1, 2, bridge_tx), Ok(1));
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().status,
+            PolicyStatus::Portable
+        );
+        let coverage = contract.get_portable_coverage(policy_id).unwrap();
+        assert_eq!(coverage.destination_chain, 2);
+        assert_eq!(coverage.bridge_transaction, bridge_tx);
+        let attestation = contract.get_portability_attestation(policy_id).unwrap();
+        assert!(contract.verify_portable_coverage(policy_id, attestation));
+        assert!(!contract.verify_portable_coverage(policy_id, Hash::from([1u8; 32])));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.submit_claim(
+                policy_id,
+                1,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::PolicyInactive)
+        );
+
+        // Token comes home: claims reopen and the attestation is revoked
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(contract.on_property_returned(1), Ok(1));
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().status,
+            PolicyStatus::Active
+        );
+        assert!(!contract.verify_portable_coverage(policy_id, attestation));
+        assert_eq!(contract.get_portability_attestation(policy_id), None);
+    }
+
+    // =========================================================================
+    // KEEPER MAINTENANCE TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_run_maintenance_escalates_expires_and_pays_keeper() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_claim_sla(pool_id, 86_400, 0).unwrap();
+        contract.set_maintenance_reward(10).unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(15);
+        assert_eq!(contract.fund_maintenance(), Ok(15));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000u128,
+                "Smoke".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+
+        // Nothing is due yet
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let idle = contract.run_maintenance(10).unwrap();
+        assert_eq!((idle.claims_examined, idle.policies_examined), (1, 1));
+        assert_eq!(idle.reward_paid, 0);
+
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_401);
+        let report = contract.run_maintenance(10).unwrap();
+        assert_eq!(report.claims_escalated, 1);
+        assert_eq!(report.reward_paid, 10);
+        assert_eq!(contract.get_escalated_claims(0, 10), vec![claim_id]);
+
+        // The fund only covers part of the next reward
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_400 * 366);
+        let report = contract.run_maintenance(10).unwrap();
+        assert_eq!(report.policies_expired, 1);
+        assert_eq!(report.reward_paid, 5);
+        assert_eq!(contract.get_maintenance_fund(), 0);
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().status,
+            PolicyStatus::Expired
+        );
+        assert_eq!(contract.get_pool(pool_id).unwrap().active_policies, 0);
+    }
+
+    // =========================================================================
+    // PREMIUM FINANCING TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_lender_cancels_defaulted_policy_for_unearned_refund() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        contract.authorize_lender(accounts.charlie).unwrap();
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+
+        let terms = fire_terms(&mut contract, pool_id);
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.create_financed_policy(accounts.bob, terms.clone()),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium);
+        let policy_id = contract
+            .create_financed_policy(accounts.bob, terms)
+            .unwrap();
+        let policy = contract.get_policy(policy_id).unwrap();
+        assert_eq!(policy.policyholder, accounts.bob);
+        assert_eq!(
+            contract.get_premium_lien(policy_id).unwrap().status,
+            LienStatus::Active
+        );
+
+        // Borrower cannot walk away from the lender's collateral
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.cancel_policy(policy_id),
+            Err(InsuranceError::LienOutstanding)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(
+            contract.cancel_defaulted_policy(policy_id),
+            Err(InsuranceError::InvalidParameters)
+        );
+        contract.report_financing_default(policy_id).unwrap();
+
+        // Halfway through the term, half the premium is unearned
+        test::set_block_timestamp::<DefaultEnvironment>(
+            policy.start_time + (policy.end_time - policy.start_time) / 2,
+        );
+        test::set_account_balance::<DefaultEnvironment>(
+            test::callee::<DefaultEnvironment>(),
+            100_000_000_000_000,
+        );
+        let refund = contract.cancel_defaulted_policy(policy_id).unwrap();
+        assert_eq!(refund, calc.annual_premium / 2);
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().status,
+            PolicyStatus::Cancelled
+        );
+        let lien = contract.get_premium_lien(policy_id).unwrap();
+        assert_eq!(lien.status, LienStatus::Settled);
+        assert_eq!(lien.refund_paid, refund);
+    }
+
+    #[ink::test]
+    fn test_lender_coverage_requirement_tracks_shortfalls() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.authorize_lender(accounts.charlie).unwrap();
+        assert_eq!(
+            contract.check_coverage_requirement(1),
+            Err(InsuranceError::CoverageRequirementNotFound)
+        );
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let policy = contract.get_policy(policy_id).unwrap();
+
+        assert_eq!(
+            contract.set_coverage_requirement(1, 400_000_000_000u128),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let check = contract
+            .set_coverage_requirement(1, 400_000_000_000u128)
+            .unwrap();
+        assert!(check.satisfied);
+        assert_eq!(check.in_force, 500_000_000_000u128);
+
+        // The policy running out its term is caught by the next check
+        test::set_block_timestamp::<DefaultEnvironment>(policy.end_time + 1);
+        let check = contract.check_coverage_requirement(1).unwrap();
+        assert!(!check.satisfied);
+        assert_eq!(check.in_force, 0);
+        assert_eq!(check.shortfall_since, Some(policy.end_time + 1));
+        let emitted = test::recorded_events().count();
+        contract.check_coverage_requirement(1).unwrap();
+        assert_eq!(test::recorded_events().count(), emitted);
+
+        // A replacement policy clears the shortfall
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, policy.pool_id);
+        let renewal_id = contract.create_policy(terms, None).unwrap();
+        assert_eq!(
+            contract
+                .get_coverage_requirement(1)
+                .unwrap()
+                .shortfall_since,
+            None
+        );
+
+        // Cancelling it reopens the shortfall straight away
+        contract.cancel_policy(renewal_id).unwrap();
+        assert!(contract
+            .get_coverage_requirement(1)
+            .unwrap()
+            .shortfall_since
+            .is_some());
+
+        assert_eq!(
+            contract.remove_coverage_requirement(1),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        contract.remove_coverage_requirement(1).unwrap();
+        assert_eq!(contract.get_coverage_requirement(1), None);
+    }
+
+    // =========================================================================
+    // CLAIM SUBMISSION TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_waiting_period_rejects_incidents_before_cover_starts() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let waiting = 86_400 * 14;
+        contract
+            .set_waiting_period(CoverageType::Fire, waiting)
+            .unwrap();
+        assert_eq!(contract.get_waiting_period(CoverageType::Fire), waiting);
+        assert_eq!(contract.get_waiting_period(CoverageType::Flood), 0);
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let policy = contract.get_policy(policy_id).unwrap();
+        assert_eq!(policy.waiting_period_ends, policy.start_time + waiting);
+
+        // Fire a day after purchase falls inside the waiting period
+        test::set_block_timestamp::<DefaultEnvironment>(policy.start_time + 86_400 * 20);
+        let early = contract
+            .report_incident(1, CoverageType::Fire, policy.start_time + 86_400)
+            .unwrap();
+        assert_eq!(
+            contract.submit_claim(
+                policy_id,
+                early,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::WaitingPeriodActive)
+        );
+        let covered = contract
+            .report_incident(1, CoverageType::Fire, policy.waiting_period_ends)
+            .unwrap();
+        assert!(contract
+            .submit_claim(
+                policy_id,
+                covered,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            )
+            .is_ok());
+
+        // Renewing in force keeps the waiting period already served
+        let calc = contract
+            .calculate_premium(1, 100_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 100_000_000_000u128,
+            pool_id: policy.pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://renewal".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let renewal = contract.create_policy(terms, None).unwrap();
+        let renewed = contract.get_policy(renewal).unwrap();
+        assert_eq!(renewed.cover_since, policy.cover_since);
+        assert_eq!(renewed.waiting_period_ends, policy.waiting_period_ends);
+
+        // Broader cover waits out the incident peril's period, not its own
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .set_waiting_period(CoverageType::Flood, waiting * 2)
+            .unwrap();
+        let calc = contract
+            .calculate_premium(1, 100_000_000_000u128, CoverageType::Comprehensive)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Comprehensive,
+            coverage_amount: 100_000_000_000u128,
+            pool_id: policy.pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://comprehensive".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let comprehensive = contract.create_policy(terms, None).unwrap();
+        let broad = contract.get_policy(comprehensive).unwrap();
+        assert_eq!(broad.waiting_period_ends, broad.start_time);
+        let flood_at = broad.start_time + waiting + 86_400;
+        test::set_block_timestamp::<DefaultEnvironment>(flood_at);
+        let flood = contract
+            .report_incident(1, CoverageType::Flood, flood_at)
+            .unwrap();
+        assert_eq!(
+            contract.submit_claim(
+                comprehensive,
+                flood,
+                10_000_000_000u128,
+                "Flood damage".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::WaitingPeriodActive)
+        );
+
+        assert_eq!(
+            contract.set_waiting_period(CoverageType::Flood, waiting),
+            Err(InsuranceError::Unauthorized)
+        );
+    }
+
+    #[ink::test]
+    fn test_coordinated_claim_spans_policies_and_settles_once() {
+        let mut contract = setup();
+        let (pool_id, fire_policy) = create_funded_policy(&mut contract);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::LiabilityDamage)
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::LiabilityDamage,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://liability".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let liability_policy = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, fire_policy);
+        let parts = vec![
+            (fire_policy, 60_000_000_000u128),
+            (liability_policy, 40_000_000_000u128),
+        ];
+        assert_eq!(
+            contract.submit_coordinated_claim(
+                incident_id,
+                90_000_000_000,
+                parts.clone(),
+                "Kitchen fire".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::RecoveryExceedsLoss)
+        );
+        assert_eq!(
+            contract.submit_coordinated_claim(
+                incident_id,
+                200_000_000_000,
+                vec![(fire_policy, 1), (fire_policy, 1)],
+                "Kitchen fire".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::DuplicateClaim)
+        );
+
+        // The liability policy joins the fire incident through the coordinated filing
+        let coordination_id = contract
+            .submit_coordinated_claim(
+                incident_id,
+                100_000_000_000,
+                parts,
+                "Kitchen fire".into(),
+                "ipfs://evidence".into(),
+            )
+            .unwrap();
+        let coordination = contract.get_claim_coordination(coordination_id).unwrap();
+        assert_eq!(coordination.claim_ids.len(), 2);
+        let (fire_claim, liability_claim) = (coordination.claim_ids[0], coordination.claim_ids[1]);
+        assert_eq!(
+            contract.get_claim_coordination_id(liability_claim),
+            Some(coordination_id)
+        );
+        assert!(!contract.get_incident(incident_id).unwrap().flagged);
+        assert_eq!(
+            contract.submit_coordinated_claim(
+                incident_id,
+                100_000_000_000,
+                vec![(liability_policy, 1), (fire_policy, 1)],
+                "Kitchen fire".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::DuplicateClaim)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            contract.process_claim(fire_claim, true, "ipfs://report".into(), String::new()),
+            Err(InsuranceError::LossNotAssessed)
+        );
+        contract
+            .assess_coordinated_loss(coordination_id, 100_000_000_000)
+            .unwrap();
+        contract
+            .process_claim(fire_claim, true, "ipfs://report".into(), String::new())
+            .unwrap();
+        assert_eq!(
+            contract
+                .get_coordinated_claim_statement(coordination_id)
+                .unwrap()
+                .settled_at,
+            None
+        );
+        contract
+            .process_claim(
+                liability_claim,
+                false,
+                "ipfs://report".into(),
+                "No third-party damage".into(),
+            )
+            .unwrap();
+
+        let statement = contract
+            .get_coordinated_claim_statement(coordination_id)
+            .unwrap();
+        let fire_payout = contract.get_claim(fire_claim).unwrap().payout_amount;
+        assert!(fire_payout > 0);
+        assert_eq!(statement.total_claimed, 100_000_000_000);
+        assert_eq!(statement.total_paid, fire_payout);
+        assert_eq!(statement.lines[0].status, ClaimStatus::Paid);
+        assert_eq!(
+            statement.lines[1].coverage_type,
+            CoverageType::LiabilityDamage
+        );
+        assert_eq!(statement.lines[1].status, ClaimStatus::Rejected);
+        assert_eq!(statement.settled_at, Some(3_000_000));
+        assert_eq!(
+            contract.get_coordinated_claim_statement(9),
+            Err(InsuranceError::CoordinationNotFound)
+        );
+    }
+
+    #[ink::test]
+    fn test_coordinated_claim_needs_several_policies_covering_the_peril() {
+        let mut contract = setup();
+        let (pool_id, fire_policy) = create_funded_policy(&mut contract);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Theft)
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Theft,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://theft".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let theft_policy = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, fire_policy);
+        assert_eq!(
+            contract.submit_coordinated_claim(
+                incident_id,
+                100_000_000_000,
+                vec![(fire_policy, 60_000_000_000u128)],
+                "Kitchen fire".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::InvalidParameters)
+        );
+        // Theft cover does not respond to a fire
+        assert_eq!(
+            contract.submit_coordinated_claim(
+                incident_id,
+                100_000_000_000,
+                vec![
+                    (fire_policy, 60_000_000_000u128),
+                    (theft_policy, 40_000_000_000u128),
+                ],
+                "Kitchen fire".into(),
+                "ipfs://evidence".into(),
+            ),
+            Err(InsuranceError::InvalidParameters)
+        );
+    }
+
+    #[ink::test]
+    fn test_third_party_liability_claims_are_contested_and_adjudicated() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = contract
+            .create_risk_pool(
+                "Liability Pool".into(),
+                CoverageType::LiabilityDamage,
+                8000,
+                500_000_000_000u128,
+            )
+            .unwrap();
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        contract.authorize_assessor(accounts.django).unwrap();
+        contract.set_liability_fraud_threshold(30).unwrap();
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::LiabilityDamage)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::LiabilityDamage,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let policy = contract.get_policy(policy_id).unwrap();
+        test::set_block_timestamp::<DefaultEnvironment>(policy.start_time + 86_400 * 30);
+        let incident_id = contract
+            .report_incident(
+                1,
+                CoverageType::LiabilityDamage,
+                policy.start_time + 86_400 * 10,
+            )
+            .unwrap();
+
+        // The policyholder files through submit_claim, not as a third party
+        assert_eq!(
+            contract.file_liability_claim(
+                policy_id,
+                incident_id,
+                100_000_000_000u128,
+                "Slip on stairs".into(),
+                "ipfs://injury".into(),
+            ),
+            Err(InsuranceError::ThirdPartyOnly)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let claim_id = contract
+            .file_liability_claim(
+                policy_id,
+                incident_id,
+                100_000_000_000u128,
+                "Slip on stairs".into(),
+                "ipfs://injury".into(),
+            )
+            .unwrap();
+        assert_eq!(
+            contract.get_liability_claim(claim_id).unwrap().fraud_score,
+            0
+        );
+        let notices = contract.get_policy_notices(policy_id);
+        assert_eq!(notices.last().unwrap().kind, NoticeKind::ThirdPartyClaim);
+        assert_eq!(notices.last().unwrap().policyholder, accounts.bob);
 
-                    let recovery = amount.saturating_sub(agreement.retention_limit);
-                    let capped_recovery = recovery.min(agreement.coverage_limit);
+        // A repeat filing for most of the remaining cover scores past the threshold
+        let flagged_id = contract
+            .file_liability_claim(
+                policy_id,
+                incident_id,
+                450_000_000_000u128,
+                "Further injuries".into(),
+                "ipfs://injury-2".into(),
+            )
+            .unwrap();
+        assert_eq!(
+            contract
+                .get_liability_claim(flagged_id)
+                .unwrap()
+                .fraud_score,
+            35
+        );
+        assert_eq!(
+            contract.get_policy_liability_claims(policy_id),
+            vec![claim_id, flagged_id]
+        );
 
-                    if capped_recovery > 0 {
-                        agreement.total_recoveries += capped_recovery;
-                        self.reinsurance_agreements.insert(&i, &agreement);
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract
+            .contest_liability_claim(flagged_id, "Not on the premises".into())
+            .unwrap();
 
-                        self.env().emit_event(ReinsuranceActivated {
-                            claim_id,
-                            agreement_id: i,
-                            recovery_amount: capped_recovery,
-                            timestamp: now,
-                        });
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        let payout = contract
+            .adjudicate_liability_claim(claim_id, true, String::new())
+            .unwrap();
+        assert_eq!(payout, 100_000_000_000u128 - policy.deductible);
+        let claim = contract.get_liability_claim(claim_id).unwrap();
+        assert_eq!(claim.status, ClaimStatus::Paid);
+        assert_eq!(claim.payout_amount, payout);
+        assert_eq!(
+            contract.adjudicate_liability_claim(flagged_id, true, String::new()),
+            Err(InsuranceError::Unauthorized)
+        );
 
-                        return Ok(());
-                    }
-                }
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .adjudicate_liability_claim(flagged_id, false, "Contest upheld".into())
+            .unwrap();
+        assert_eq!(
+            contract.get_liability_claim_stats(),
+            LiabilityClaimStats {
+                filed: 2,
+                contested: 1,
+                flagged: 1,
+                approved: 1,
+                rejected: 1,
+                total_paid: payout,
             }
-            Ok(())
-        }
+        );
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().total_claimed,
+            payout
+        );
     }
 
-    impl Default for PropertyInsurance {
-        fn default() -> Self {
-            Self::new(AccountId::from([0x0; 32]))
-        }
+    #[ink::test]
+    fn test_submit_claim_works() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
+        let result = contract.submit_claim(
+            policy_id,
+            incident_id,
+            10_000_000_000u128,
+            "Fire damage to property".into(),
+            "ipfs://evidence123".into(),
+        );
+        assert!(result.is_ok());
+        let claim_id = result.unwrap();
+        let claim = contract.get_claim(claim_id).unwrap();
+        assert_eq!(claim.policy_id, policy_id);
+        assert_eq!(claim.claimant, accounts.bob);
+        assert_eq!(claim.status, ClaimStatus::Pending);
+        assert_eq!(contract.get_claim_count(), 1);
     }
-}
-
-pub use crate::propchain_insurance::{InsuranceError, PropertyInsurance};
-
-#[cfg(test)]
-mod insurance_tests {
-    use super::*;
-    use ink::env::{test, DefaultEnvironment};
-
-    use crate::propchain_insurance::{
-        ClaimStatus, CoverageType, InsuranceError, PolicyStatus, PropertyInsurance,
-    };
 
-    fn setup() -> PropertyInsurance {
+    #[ink::test]
+    fn test_claim_exceeds_coverage_fails() {
+        let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        test::set_caller::<DefaultEnvironment>(accounts.alice);
-        // Start at 35 days so `now - last_claim(0) > 30-day cooldown`
-        test::set_block_timestamp::<DefaultEnvironment>(3_000_000);
-        PropertyInsurance::new(accounts.alice)
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let coverage = 500_000_000_000u128;
+        let calc = contract
+            .calculate_premium(1, coverage, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: coverage,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
+        let result = contract.submit_claim(
+            policy_id,
+            incident_id,
+            coverage * 2,
+            "Huge fire".into(),
+            "ipfs://evidence".into(),
+        );
+        assert_eq!(result, Err(InsuranceError::ClaimExceedsCoverage));
     }
 
-    fn add_risk_assessment(contract: &mut PropertyInsurance, property_id: u64) {
-        contract
-            .update_risk_assessment(property_id, 75, 80, 85, 90, 86_400 * 365)
-            .expect("risk assessment failed");
+    #[ink::test]
+    fn test_claim_by_nonpolicyholder_fails() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let result = contract.submit_claim(
+            policy_id,
+            incident_id,
+            1_000u128,
+            "Fraud attempt".into(),
+            "ipfs://x".into(),
+        );
+        assert_eq!(result, Err(InsuranceError::Unauthorized));
     }
 
-    fn create_pool(contract: &mut PropertyInsurance) -> u64 {
+    #[ink::test]
+    fn test_incident_deduplicates_claims() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let now = contract.get_policy(policy_id).unwrap().start_time;
+        // Same property, peril and window resolves to the same incident
+        assert_eq!(
+            contract.report_incident(1, CoverageType::Fire, now),
+            Ok(incident_id)
+        );
+        assert_eq!(
+            contract.report_incident(1, CoverageType::Flood, now),
+            Ok(incident_id + 1)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(
+            contract.report_incident(1, CoverageType::Fire, now),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.submit_claim(
+                policy_id,
+                incident_id + 1,
+                1_000u128,
+                "Flood".into(),
+                "ipfs://e".into()
+            ),
+            Err(InsuranceError::InvalidParameters)
+        );
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000u128,
+                "Fire".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        assert_eq!(
+            contract.submit_claim(
+                policy_id,
+                incident_id,
+                2_000u128,
+                "Fire again".into(),
+                "ipfs://e".into()
+            ),
+            Err(InsuranceError::DuplicateClaim)
+        );
+
+        // A re-filing after rejection is allowed but flagged
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
         contract
-            .create_risk_pool(
-                "Fire & Flood Pool".into(),
-                CoverageType::Fire,
-                8000,
-                500_000_000_000u128,
+            .process_claim(
+                claim_id,
+                false,
+                String::new(),
+                "Insufficient evidence".into(),
             )
-            .expect("pool creation failed")
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000u128,
+                "Fire".into(),
+                "ipfs://e2".into(),
+            )
+            .unwrap();
+        let incident = contract.get_incident(incident_id).unwrap();
+        assert_eq!(incident.claim_ids.len(), 2);
+        assert!(incident.flagged);
     }
 
     // =========================================================================
-    // CONSTRUCTOR
+    // CLAIM PROCESSING TESTS
     // =========================================================================
 
     #[ink::test]
-    fn test_new_contract_initialised() {
-        let contract = setup();
+    fn test_process_claim_approve_works() {
+        let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        assert_eq!(contract.get_admin(), accounts.alice);
-        assert_eq!(contract.get_policy_count(), 0);
-        assert_eq!(contract.get_claim_count(), 0);
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let coverage = 500_000_000_000u128;
+        let calc = contract
+            .calculate_premium(1, coverage, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: coverage,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let result =
+            contract.process_claim(claim_id, true, "ipfs://oracle-report".into(), String::new());
+        assert!(result.is_ok());
+        let claim = contract.get_claim(claim_id).unwrap();
+        assert_eq!(claim.status, ClaimStatus::Paid);
+        assert!(claim.payout_amount > 0);
     }
 
-    // =========================================================================
-    // POOL TESTS
-    // =========================================================================
-
     #[ink::test]
-    fn test_create_risk_pool_works() {
+    fn test_process_claim_reject_works() {
         let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
-        assert_eq!(pool_id, 1);
-        let pool = contract.get_pool(1).unwrap();
-        assert_eq!(pool.pool_id, 1);
-        assert!(pool.is_active);
-        assert_eq!(pool.active_policies, 0);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                5_000_000_000u128,
+                "Fraudulent claim".into(),
+                "ipfs://fake-evidence".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let result = contract.process_claim(
+            claim_id,
+            false,
+            "ipfs://oracle-report".into(),
+            "Evidence does not support claim".into(),
+        );
+        assert!(result.is_ok());
+        let claim = contract.get_claim(claim_id).unwrap();
+        assert_eq!(claim.status, ClaimStatus::Rejected);
     }
 
     #[ink::test]
-    fn test_create_risk_pool_unauthorized() {
+    fn test_process_claim_unauthorized_fails() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
+        let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
-        let result = contract.create_risk_pool(
-            "Unauthorized Pool".into(),
-            CoverageType::Fire,
-            8000,
-            1_000_000,
-        );
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let result = contract.process_claim(claim_id, true, "ipfs://r".into(), String::new());
         assert_eq!(result, Err(InsuranceError::Unauthorized));
     }
 
     #[ink::test]
-    fn test_provide_pool_liquidity_works() {
+    fn test_authorized_assessor_can_process_claim() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
+        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        add_risk_assessment(&mut contract, 1);
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
-        let result = contract.provide_pool_liquidity(pool_id);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.authorize_assessor(accounts.charlie).unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let result = contract.process_claim(
+            claim_id,
+            false,
+            "ipfs://r".into(),
+            "Insufficient evidence".into(),
+        );
         assert!(result.is_ok());
-        let pool = contract.get_pool(pool_id).unwrap();
-        assert_eq!(pool.total_capital, 1_000_000_000_000u128);
-        assert_eq!(pool.available_capital, 1_000_000_000_000u128);
     }
 
     #[ink::test]
-    fn test_provide_liquidity_nonexistent_pool_fails() {
+    fn test_staked_assessor_assignment_and_overturned_dispute() {
         let mut contract = setup();
-        test::set_value_transferred::<DefaultEnvironment>(1_000_000u128);
-        let result = contract.provide_pool_liquidity(999);
-        assert_eq!(result, Err(InsuranceError::PoolNotFound));
-    }
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.authorize_assessor(accounts.charlie).unwrap();
+        contract.authorize_assessor(accounts.django).unwrap();
+        for assessor in [accounts.charlie, accounts.django] {
+            test::set_caller::<DefaultEnvironment>(assessor);
+            test::set_value_transferred::<DefaultEnvironment>(10_000u128);
+            assert_eq!(contract.bond_assessor(), Ok(10_000));
+        }
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        assert_eq!(contract.get_assessor_pool().len(), 2);
 
-    // =========================================================================
-    // RISK ASSESSMENT TESTS
-    // =========================================================================
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        let assigned = contract.get_claim_assignment(claim_id).expect("assigned");
+        let other = if assigned == accounts.charlie {
+            accounts.django
+        } else {
+            accounts.charlie
+        };
+
+        // Only the assigned assessor decides the claim
+        test::set_caller::<DefaultEnvironment>(other);
+        assert_eq!(
+            contract.process_claim(claim_id, false, "ipfs://r".into(), "No".into()),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(assigned);
+        assert_eq!(
+            contract.unbond_assessor(1),
+            Err(InsuranceError::AssessmentsPending)
+        );
+        contract
+            .process_claim(claim_id, false, "ipfs://r".into(), "No".into())
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract.dispute_claim(claim_id).unwrap();
+        let capital_before = contract.get_pool(pool_id).unwrap().available_capital;
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.resolve_dispute(claim_id, true), Ok(1_000));
+
+        let stats = contract.get_assessor_stats(assigned).unwrap();
+        assert_eq!(stats.stake, 9_000);
+        assert_eq!(stats.reputation, 4_000);
+        assert_eq!((stats.decisions, stats.overturned), (1, 1));
+        assert_eq!(
+            contract.get_pool(pool_id).unwrap().available_capital,
+            capital_before + 1_000
+        );
+        // The claim is back under review by the other assessor
+        assert_eq!(
+            contract.get_claim(claim_id).unwrap().status,
+            ClaimStatus::Pending
+        );
+        assert_eq!(contract.get_claim_assignment(claim_id), Some(other));
+        assert_eq!(
+            contract
+                .get_assessor_stats(assigned)
+                .unwrap()
+                .open_assignments,
+            0
+        );
+        assert_eq!(
+            contract.get_assessor_stats(other).unwrap().open_assignments,
+            1
+        );
+
+        // Cancelling the policy frees the slot its open claim held
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract.cancel_policy(policy_id).unwrap();
+        assert_eq!(contract.get_claim_assignment(claim_id), None);
+        assert_eq!(
+            contract.get_assessor_stats(other).unwrap().open_assignments,
+            0
+        );
+        test::set_caller::<DefaultEnvironment>(other);
+        test::set_account_balance::<DefaultEnvironment>(
+            test::callee::<DefaultEnvironment>(),
+            100_000_000_000_000,
+        );
+        assert_eq!(contract.unbond_assessor(1), Ok(()));
+    }
 
     #[ink::test]
-    fn test_update_risk_assessment_works() {
+    fn test_conflicted_assessors_are_skipped_and_can_recuse() {
         let mut contract = setup();
-        add_risk_assessment(&mut contract, 1);
-        let assessment = contract.get_risk_assessment(1).unwrap();
-        assert_eq!(assessment.property_id, 1);
-        assert_eq!(assessment.overall_risk_score, 82); // (75+80+85+90)/4
-        assert!(assessment.valid_until > 0);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let assessors = [accounts.charlie, accounts.django, accounts.eve];
+        for assessor in assessors {
+            contract.authorize_assessor(assessor).unwrap();
+        }
+        for assessor in assessors {
+            test::set_caller::<DefaultEnvironment>(assessor);
+            test::set_value_transferred::<DefaultEnvironment>(10_000u128);
+            contract.bond_assessor().unwrap();
+        }
+        test::set_value_transferred::<DefaultEnvironment>(0);
+        test::set_caller::<DefaultEnvironment>(accounts.frank);
+        assert_eq!(
+            contract.declare_assessor_conflict(accounts.bob, true),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        contract
+            .declare_assessor_conflict(accounts.bob, true)
+            .unwrap();
+        assert!(contract.has_assessor_conflict(accounts.charlie, accounts.bob));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        let first = contract.get_claim_assignment(claim_id).expect("assigned");
+        assert_ne!(first, accounts.charlie);
+        let second = if first == accounts.django {
+            accounts.eve
+        } else {
+            accounts.django
+        };
+        assert_eq!(
+            contract.recuse_from_claim(claim_id),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        // Recusing hands the claim to the only assessor left without a conflict
+        test::set_caller::<DefaultEnvironment>(first);
+        contract.recuse_from_claim(claim_id).unwrap();
+        assert!(contract.has_assessor_conflict(first, accounts.bob));
+        assert_eq!(contract.get_claim_assignment(claim_id), Some(second));
+        assert_eq!(
+            contract.get_assessor_stats(first).unwrap().open_assignments,
+            0
+        );
+
+        // With nobody left the claim falls back to the general queue
+        test::set_caller::<DefaultEnvironment>(second);
+        contract.recuse_from_claim(claim_id).unwrap();
+        assert_eq!(contract.get_claim_assignment(claim_id), None);
+        assert_eq!(
+            contract
+                .get_assessor_stats(second)
+                .unwrap()
+                .open_assignments,
+            0
+        );
     }
 
     #[ink::test]
-    fn test_risk_assessment_unauthorized() {
+    fn test_adjuster_expenses_feed_combined_ratio() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Fire".into(),
+                "ipfs://evidence".into(),
+            )
+            .unwrap();
+
         test::set_caller::<DefaultEnvironment>(accounts.bob);
-        let result = contract.update_risk_assessment(1, 70, 70, 70, 70, 86400);
-        assert_eq!(result, Err(InsuranceError::Unauthorized));
+        assert_eq!(
+            contract.record_adjuster_expense(claim_id, accounts.eve, 1_000, Hash::from([1u8; 32])),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        test::set_account_balance::<DefaultEnvironment>(
+            test::callee::<DefaultEnvironment>(),
+            100_000_000_000_000,
+        );
+        let capital = contract.get_pool(pool_id).unwrap().available_capital;
+        let premiums = contract.get_pool(pool_id).unwrap().total_premiums_collected;
+        let expense = premiums / 10;
+        contract
+            .record_adjuster_expense(claim_id, accounts.eve, expense, Hash::from([1u8; 32]))
+            .unwrap();
+        contract
+            .record_adjuster_expense(claim_id, accounts.django, expense, Hash::from([2u8; 32]))
+            .unwrap();
+
+        let expenses = contract.get_claim_expenses(claim_id);
+        assert_eq!(expenses.len(), 2);
+        assert_eq!(expenses[1].payee, accounts.django);
+        assert_eq!(
+            contract.get_pool(pool_id).unwrap().available_capital,
+            capital - expense * 2
+        );
+        let period = 3_000_000 / contract.get_statistics_period();
+        assert_eq!(contract.get_period_expenses(pool_id, period), expense * 2);
+        let kpis = contract.get_pool_kpis(pool_id).unwrap();
+        assert_eq!(kpis.loss_ratio, 0);
+        assert!(kpis.expense_ratio >= 1_999 && kpis.expense_ratio <= 2_000);
+        assert_eq!(kpis.combined_ratio, kpis.expense_ratio);
     }
 
     #[ink::test]
-    fn test_authorized_oracle_can_assess() {
+    fn test_assigned_adjuster_records_expenses_up_to_cap() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        contract.authorize_oracle(accounts.bob).unwrap();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.authorize_assessor(accounts.charlie).unwrap();
+        contract.authorize_assessor(accounts.django).unwrap();
+        for assessor in [accounts.charlie, accounts.django] {
+            test::set_caller::<DefaultEnvironment>(assessor);
+            test::set_value_transferred::<DefaultEnvironment>(10_000u128);
+            contract.bond_assessor().unwrap();
+        }
+        test::set_value_transferred::<DefaultEnvironment>(0);
+
         test::set_caller::<DefaultEnvironment>(accounts.bob);
-        let result = contract.update_risk_assessment(1, 70, 70, 70, 70, 86400);
-        assert!(result.is_ok());
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        let assigned = contract.get_claim_assignment(claim_id).expect("assigned");
+        let other = if assigned == accounts.charlie {
+            accounts.django
+        } else {
+            accounts.charlie
+        };
+        test::set_account_balance::<DefaultEnvironment>(
+            test::callee::<DefaultEnvironment>(),
+            100_000_000_000_000,
+        );
+
+        test::set_caller::<DefaultEnvironment>(assigned);
+        assert_eq!(
+            contract.record_adjuster_expense(claim_id, accounts.eve, 500, Hash::from([1u8; 32])),
+            Err(InsuranceError::ExpenseCapExceeded)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_adjuster_expense_cap(pool_id, 800).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(other);
+        assert_eq!(
+            contract.record_adjuster_expense(claim_id, accounts.eve, 500, Hash::from([1u8; 32])),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(assigned);
+        contract
+            .record_adjuster_expense(claim_id, accounts.eve, 500, Hash::from([1u8; 32]))
+            .unwrap();
+        assert_eq!(
+            contract.record_adjuster_expense(claim_id, accounts.eve, 400, Hash::from([2u8; 32])),
+            Err(InsuranceError::ExpenseCapExceeded)
+        );
+
+        // Past the cap, or once the claim is decided, only the admin records expenses
+        contract
+            .process_claim(claim_id, false, "ipfs://r".into(), "No".into())
+            .unwrap();
+        assert_eq!(
+            contract.record_adjuster_expense(claim_id, accounts.eve, 100, Hash::from([2u8; 32])),
+            Err(InsuranceError::ClaimAlreadyProcessed)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .record_adjuster_expense(claim_id, accounts.eve, 400, Hash::from([2u8; 32]))
+            .unwrap();
+        assert_eq!(contract.get_claim_expenses(claim_id).len(), 2);
     }
 
     // =========================================================================
-    // PREMIUM CALCULATION TESTS
+    // CLAIM SLA TESTS
     // =========================================================================
 
     #[ink::test]
-    fn test_calculate_premium_works() {
+    fn test_overdue_claim_escalates_and_credits_claimant() {
         let mut contract = setup();
-        add_risk_assessment(&mut contract, 1);
-        let result = contract.calculate_premium(1, 1_000_000_000_000u128, CoverageType::Fire);
-        assert!(result.is_ok());
-        let calc = result.unwrap();
-        assert!(calc.annual_premium > 0);
-        assert!(calc.monthly_premium > 0);
-        assert!(calc.deductible > 0);
-        assert_eq!(calc.base_rate, 150);
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_claim_sla(pool_id, 86_400, 100).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            )
+            .unwrap();
+        assert_eq!(
+            contract.escalate_overdue_claim(claim_id),
+            Err(InsuranceError::ClaimNotOverdue)
+        );
+
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_401);
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let compensation = contract.escalate_overdue_claim(claim_id).unwrap();
+        assert_eq!(compensation, 100_000_000);
+        assert_eq!(contract.get_sla_credit(accounts.bob), compensation);
+        assert_eq!(contract.get_escalated_claims(0, 10), vec![claim_id]);
+        assert_eq!(
+            contract.escalate_overdue_claim(claim_id),
+            Err(InsuranceError::ClaimNotOverdue)
+        );
+
+        let stats = contract.get_claim_sla_stats(pool_id);
+        assert_eq!(stats.claims_tracked, 1);
+        assert_eq!(stats.breaches, 1);
+        assert_eq!(stats.total_compensation, compensation);
     }
 
     #[ink::test]
-    fn test_premium_without_assessment_fails() {
-        let contract = setup();
-        let result = contract.calculate_premium(999, 1_000_000u128, CoverageType::Fire);
-        assert_eq!(result, Err(InsuranceError::PropertyNotInsurable));
+    fn test_decided_escalations_free_their_queue_slot() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, first_policy) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_claim_sla(pool_id, 86_400, 0).unwrap();
+        add_risk_assessment(&mut contract, 2);
+        let calc = contract
+            .calculate_premium(2, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        let terms = PolicyTerms {
+            property_id: 2,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 2),
+            copay_bps: None,
+        };
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
+        let second_policy = contract.create_policy(terms, None).unwrap();
+
+        let mut claims = Vec::new();
+        for (claimant, policy_id) in [
+            (accounts.bob, first_policy),
+            (accounts.charlie, second_policy),
+        ] {
+            test::set_caller::<DefaultEnvironment>(claimant);
+            let incident_id = file_incident(&mut contract, policy_id);
+            claims.push(
+                contract
+                    .submit_claim(
+                        policy_id,
+                        incident_id,
+                        1_000u128,
+                        "Smoke".into(),
+                        "ipfs://e".into(),
+                    )
+                    .unwrap(),
+            );
+        }
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_401);
+        for claim_id in claims.iter() {
+            contract.escalate_overdue_claim(*claim_id).unwrap();
+        }
+        assert_eq!(contract.get_escalated_claims(0, 10), claims);
+        assert_eq!(contract.get_escalated_claims(1, 10), vec![claims[1]]);
+
+        // The last claim moves into the first one's slot once it is decided
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .process_claim(claims[0], false, String::new(), "late".into())
+            .unwrap();
+        assert_eq!(contract.get_escalated_claim_count(), 1);
+        assert_eq!(contract.get_escalated_claims(0, 10), vec![claims[1]]);
     }
 
     #[ink::test]
-    fn test_comprehensive_coverage_higher_premium() {
+    fn test_escalated_claim_requires_admin_decision() {
         let mut contract = setup();
-        add_risk_assessment(&mut contract, 1);
-        let fire_calc = contract
-            .calculate_premium(1, 1_000_000_000_000u128, CoverageType::Fire)
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.set_claim_sla(pool_id, 86_400, 0).unwrap();
+        contract.authorize_assessor(accounts.django).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000u128,
+                "Smoke".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + 86_401);
+        contract.escalate_overdue_claim(claim_id).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            contract.process_claim(claim_id, false, String::new(), "late".into()),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .process_claim(claim_id, false, String::new(), "late".into())
+            .unwrap();
+        assert!(contract.get_escalated_claims(0, 10).is_empty());
+        assert_eq!(contract.get_escalated_claim_count(), 0);
+    }
+
+    #[ink::test]
+    fn test_payout_mode_locked_after_claim() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let mode = PayoutMode::TokenDividends {
+            token_contract: accounts.frank,
+            token_id: 7,
+        };
+
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(
+            contract.set_payout_mode(policy_id, mode.clone()),
+            Err(InsuranceError::Unauthorized)
+        );
+        // Routing to shareholders is confirmed against the token contract, which
+        // the off-chain engine cannot call
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        contract
+            .set_payout_mode(policy_id, PayoutMode::Policyholder)
             .unwrap();
-        let comp_calc = contract
-            .calculate_premium(1, 1_000_000_000_000u128, CoverageType::Comprehensive)
+
+        let incident_id = file_incident(&mut contract, policy_id);
+        contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000u128,
+                "Smoke".into(),
+                "ipfs://e".into(),
+            )
             .unwrap();
-        assert!(comp_calc.annual_premium > fire_calc.annual_premium);
+        assert_eq!(
+            contract.set_payout_mode(policy_id, mode),
+            Err(InsuranceError::PayoutModeLocked)
+        );
     }
 
     // =========================================================================
-    // POLICY CREATION TESTS
+    // CLAIM EVIDENCE TESTS
     // =========================================================================
 
     #[ink::test]
-    fn test_create_policy_works() {
+    fn test_private_evidence_access_is_granted_and_logged() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.authorize_assessor(accounts.django).unwrap();
 
-        let pool_id = create_pool(&mut contract);
-        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
-        contract.provide_pool_liquidity(pool_id).unwrap();
-        add_risk_assessment(&mut contract, 1);
-
-        let calc = contract
-            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                1_000u128,
+                "Smoke".into(),
+                String::new(),
+            )
+            .unwrap();
+        let content = b"medical report".to_vec();
+        let mut digest = [0u8; 32];
+        ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&content, &mut digest);
+        let content_hash = Hash::from(digest);
+        contract
+            .commit_claim_evidence(claim_id, content_hash, vec![0xAB, 0xCD])
             .unwrap();
+        assert_eq!(
+            contract.get_claim_evidence_hashes(claim_id),
+            vec![content_hash]
+        );
 
+        // Only granted assessors can open the evidence
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            contract.access_claim_evidence(claim_id),
+            Err(InsuranceError::Unauthorized)
+        );
         test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-
-        let result = contract.create_policy(
-            1,
-            CoverageType::Fire,
-            500_000_000_000u128,
-            pool_id,
-            86_400 * 365,
-            "ipfs://policy-metadata".into(),
+        assert_eq!(
+            contract.grant_evidence_access(claim_id, accounts.charlie),
+            Err(InsuranceError::Unauthorized)
         );
-        assert!(result.is_ok());
+        contract
+            .grant_evidence_access(claim_id, accounts.django)
+            .unwrap();
 
-        let policy_id = result.unwrap();
-        let policy = contract.get_policy(policy_id).unwrap();
-        assert_eq!(policy.property_id, 1);
-        assert_eq!(policy.policyholder, accounts.bob);
-        assert_eq!(policy.status, PolicyStatus::Active);
-        assert_eq!(contract.get_policy_count(), 1);
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        let evidence = contract.access_claim_evidence(claim_id).unwrap();
+        assert_eq!(evidence[0].encrypted_pointer, vec![0xAB, 0xCD]);
+        let log = contract.get_evidence_access_log(claim_id);
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].accessor, accounts.django);
+
+        assert!(contract.verify_claim_evidence(claim_id, 0, content));
+        assert!(!contract.verify_claim_evidence(claim_id, 0, b"forged".to_vec()));
     }
 
+    // =========================================================================
+    // SURPLUS SHARING TESTS
+    // =========================================================================
+
     #[ink::test]
-    fn test_create_policy_insufficient_premium_fails() {
+    fn test_surplus_shared_with_claim_free_policyholders() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        let pool_id = create_pool(&mut contract);
-        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
-        contract.provide_pool_liquidity(pool_id).unwrap();
-        add_risk_assessment(&mut contract, 1);
-        test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(1u128);
-        let result = contract.create_policy(
-            1,
-            CoverageType::Fire,
-            500_000_000_000u128,
+        let (pool_id, _) = create_funded_policy(&mut contract);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        add_risk_assessment(&mut contract, 2);
+        contract.set_surplus_sharing(pool_id, 5_000, 2_000).unwrap();
+        let calc = contract
+            .calculate_premium(2, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium);
+        let terms = PolicyTerms {
+            property_id: 2,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
             pool_id,
-            86_400 * 365,
-            "ipfs://policy-metadata".into(),
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 2),
+            copay_bps: None,
+        };
+        let charlie_policy = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, charlie_policy);
+        contract
+            .submit_claim(
+                charlie_policy,
+                incident_id,
+                1_000u128,
+                "Smoke".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        // Two policies: tallied one per call, then credited
+        assert_eq!(contract.close_surplus_period(pool_id, 1), Ok(None));
+        assert_eq!(contract.close_surplus_period(pool_id, 1), Ok(None));
+        let close = contract.get_surplus_close(pool_id).unwrap();
+        assert_eq!(close.eligible_policies, 1);
+        assert_eq!(close.pot, close.premiums * 2_000 / 10_000);
+        let report = contract.close_surplus_period(pool_id, 1).unwrap().unwrap();
+        assert!(contract.get_surplus_close(pool_id).is_none());
+        assert_eq!(report.period, 1);
+        assert_eq!(report.claims_paid, 0);
+        assert_eq!(report.eligible_policies, 1);
+        assert_eq!(report.distributed, report.premiums * 2_000 / 10_000);
+        assert_eq!(
+            contract.get_surplus_credit(accounts.bob),
+            report.distributed
         );
-        assert_eq!(result, Err(InsuranceError::InsufficientPremium));
+        assert_eq!(contract.get_surplus_credit(accounts.charlie), 0);
+        assert_eq!(contract.get_surplus_report(pool_id, 1), Some(report));
+
+        // Nothing collected in the next period, so nothing is shared
+        let next = contract.close_surplus_period(pool_id, 10).unwrap().unwrap();
+        assert_eq!(next.period, 2);
+        assert_eq!(next.distributed, 0);
     }
 
     #[ink::test]
-    fn test_create_policy_nonexistent_pool_fails() {
+    fn test_close_surplus_period_requires_config() {
         let mut contract = setup();
-        add_risk_assessment(&mut contract, 1);
-        let accounts = test::default_accounts::<DefaultEnvironment>();
-        test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
-        let result = contract.create_policy(
-            1,
-            CoverageType::Fire,
-            100_000u128,
-            999,
-            86_400 * 365,
-            "ipfs://policy-metadata".into(),
+        let pool_id = create_pool(&mut contract);
+        assert_eq!(
+            contract.close_surplus_period(pool_id, 10),
+            Err(InsuranceError::SurplusSharingNotConfigured)
         );
-        assert_eq!(result, Err(InsuranceError::PoolNotFound));
     }
 
     // =========================================================================
-    // POLICY CANCELLATION TESTS
+    // POOL RUN-OFF TESTS
     // =========================================================================
 
     #[ink::test]
-    fn test_cancel_policy_by_policyholder() {
+    fn test_pool_runoff_reserves_then_closes() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        let pool_id = create_pool(&mut contract);
-        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
-        contract.provide_pool_liquidity(pool_id).unwrap();
-        add_risk_assessment(&mut contract, 1);
-        let calc = contract
-            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
-            .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                500_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
-            .unwrap();
-        let result = contract.cancel_policy(policy_id);
-        assert!(result.is_ok());
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let runoff = contract.begin_pool_runoff(pool_id).unwrap();
+        assert_eq!(runoff.status, RunOffStatus::Closing);
+        assert_eq!(runoff.remaining_policies, 1);
+        assert_eq!(runoff.remaining_exposure, 500_000_000_000u128);
+
+        // No new business while closing
+        test::set_value_transferred::<DefaultEnvironment>(1_000u128);
+        assert_eq!(
+            contract.provide_pool_liquidity(pool_id),
+            Err(InsuranceError::PoolClosing)
+        );
+
+        // Capital above the reserve can leave; the reserve cannot
+        let available = contract.get_pool(pool_id).unwrap().available_capital;
+        let excess = available - 500_000_000_000u128;
+        test::set_account_balance::<DefaultEnvironment>(
+            test::callee::<DefaultEnvironment>(),
+            available,
+        );
+        assert_eq!(
+            contract.withdraw_runoff_capital(pool_id, excess + 1),
+            Err(InsuranceError::ReserveRequired)
+        );
+        contract.withdraw_runoff_capital(pool_id, excess).unwrap();
+
+        // Still in force, so the pool stays open
+        let runoff = contract.advance_pool_runoff(pool_id).unwrap();
+        assert_eq!(runoff.status, RunOffStatus::Closing);
+
         let policy = contract.get_policy(policy_id).unwrap();
-        assert_eq!(policy.status, PolicyStatus::Cancelled);
+        test::set_block_timestamp::<DefaultEnvironment>(policy.end_time + 1);
+        let runoff = contract.advance_pool_runoff(pool_id).unwrap();
+        assert_eq!(runoff.status, RunOffStatus::Closed);
+        assert_eq!(runoff.final_surplus, 500_000_000_000u128);
+        assert_eq!(
+            contract.get_policy(policy_id).unwrap().status,
+            PolicyStatus::Expired
+        );
+        assert_eq!(
+            contract.get_surplus_credit(accounts.alice),
+            500_000_000_000u128
+        );
+        assert_eq!(contract.get_pool_remaining_exposure(pool_id), 0);
     }
 
     #[ink::test]
-    fn test_cancel_policy_by_non_owner_fails() {
+    fn test_closing_pool_rejects_new_policies() {
         let mut contract = setup();
-        let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
-        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
-        contract.provide_pool_liquidity(pool_id).unwrap();
         add_risk_assessment(&mut contract, 1);
-        let calc = contract
-            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
-            .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                500_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
-            .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.charlie);
-        let result = contract.cancel_policy(policy_id);
-        assert_eq!(result, Err(InsuranceError::Unauthorized));
+        contract.begin_pool_runoff(pool_id).unwrap();
+        assert_eq!(
+            contract.begin_pool_runoff(pool_id),
+            Err(InsuranceError::PoolClosing)
+        );
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
+        let terms = fire_terms(&mut contract, pool_id);
+        assert_eq!(
+            contract.create_policy(terms, None),
+            Err(InsuranceError::PoolClosing)
+        );
     }
 
     // =========================================================================
-    // CLAIM SUBMISSION TESTS
+    // REINSURANCE TESTS
     // =========================================================================
 
     #[ink::test]
-    fn test_submit_claim_works() {
+    fn test_register_reinsurance_works() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        let pool_id = create_pool(&mut contract);
-        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
-        contract.provide_pool_liquidity(pool_id).unwrap();
-        add_risk_assessment(&mut contract, 1);
-        let calc = contract
-            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
-            .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                500_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
-            .unwrap();
-        let result = contract.submit_claim(
-            policy_id,
-            10_000_000_000u128,
-            "Fire damage to property".into(),
-            "ipfs://evidence123".into(),
+        let result = contract.register_reinsurance(
+            accounts.bob,
+            10_000_000_000_000u128,
+            500_000_000_000u128,
+            2000,
+            [CoverageType::Fire, CoverageType::Flood].to_vec(),
+            86_400 * 365,
         );
         assert!(result.is_ok());
-        let claim_id = result.unwrap();
-        let claim = contract.get_claim(claim_id).unwrap();
-        assert_eq!(claim.policy_id, policy_id);
-        assert_eq!(claim.claimant, accounts.bob);
-        assert_eq!(claim.status, ClaimStatus::Pending);
-        assert_eq!(contract.get_claim_count(), 1);
+        let agreement_id = result.unwrap();
+        let agreement = contract.get_reinsurance_agreement(agreement_id).unwrap();
+        assert_eq!(agreement.reinsurer, accounts.bob);
+        assert!(agreement.is_active);
     }
 
     #[ink::test]
-    fn test_claim_exceeds_coverage_fails() {
+    fn test_register_reinsurance_unauthorized_fails() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        let pool_id = create_pool(&mut contract);
-        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
-        contract.provide_pool_liquidity(pool_id).unwrap();
-        add_risk_assessment(&mut contract, 1);
-        let coverage = 500_000_000_000u128;
-        let calc = contract
-            .calculate_premium(1, coverage, CoverageType::Fire)
-            .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                coverage,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
-            .unwrap();
-        let result = contract.submit_claim(
-            policy_id,
-            coverage * 2,
-            "Huge fire".into(),
-            "ipfs://evidence".into(),
+        let result = contract.register_reinsurance(
+            accounts.bob,
+            1_000_000u128,
+            100_000u128,
+            2000,
+            [CoverageType::Fire].to_vec(),
+            86_400,
         );
-        assert_eq!(result, Err(InsuranceError::ClaimExceedsCoverage));
+        assert_eq!(result, Err(InsuranceError::Unauthorized));
     }
 
     #[ink::test]
-    fn test_claim_by_nonpolicyholder_fails() {
+    fn test_large_claim_walks_reinsurance_layers() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        let pool_id = create_pool(&mut contract);
-        test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
-        contract.provide_pool_liquidity(pool_id).unwrap();
-        add_risk_assessment(&mut contract, 1);
-        let calc = contract
-            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+        // Fire layers: 1e9..3e9 and 3e9..5e9; the theft-only treaty must be skipped
+        contract
+            .register_reinsurance(
+                accounts.django,
+                2_000_000_000u128,
+                3_000_000_000u128,
+                500,
+                vec![CoverageType::Fire],
+                86_400 * 365,
+            )
             .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                500_000_000_000u128,
-                pool_id,
+        let theft_only = contract
+            .register_reinsurance(
+                accounts.eve,
+                10_000_000_000u128,
+                0,
+                500,
+                vec![CoverageType::Theft],
                 86_400 * 365,
-                "ipfs://test".into(),
             )
             .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.charlie);
-        let result = contract.submit_claim(
-            policy_id,
-            1_000u128,
-            "Fraud attempt".into(),
-            "ipfs://x".into(),
-        );
-        assert_eq!(result, Err(InsuranceError::Unauthorized));
-    }
-
-    // =========================================================================
-    // CLAIM PROCESSING TESTS
-    // =========================================================================
+        let first_layer = contract
+            .register_reinsurance(
+                accounts.charlie,
+                2_000_000_000u128,
+                1_000_000_000u128,
+                500,
+                vec![CoverageType::Fire],
+                86_400 * 365,
+            )
+            .unwrap();
+        contract
+            .set_reinsurance_reinstatements(first_layer, 1)
+            .unwrap();
 
-    #[ink::test]
-    fn test_process_claim_approve_works() {
-        let mut contract = setup();
-        let accounts = test::default_accounts::<DefaultEnvironment>();
-        let pool_id = create_pool(&mut contract);
+        let pool_id = contract
+            .create_risk_pool("XoL Pool".into(), CoverageType::Fire, 8000, 0)
+            .unwrap();
         test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
         contract.provide_pool_liquidity(pool_id).unwrap();
         add_risk_assessment(&mut contract, 1);
-        let coverage = 500_000_000_000u128;
         let calc = contract
-            .calculate_premium(1, coverage, CoverageType::Fire)
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
             .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                coverage,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
-            .unwrap();
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let incident_id = file_incident(&mut contract, policy_id);
         let claim_id = contract
             .submit_claim(
                 policy_id,
-                10_000_000_000u128,
-                "Fire damage".into(),
+                incident_id,
+                4_000_000_000u128 + calc.deductible,
+                "Major fire".into(),
                 "ipfs://evidence".into(),
             )
             .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.alice);
-        let result =
-            contract.process_claim(claim_id, true, "ipfs://oracle-report".into(), String::new());
-        assert!(result.is_ok());
-        let claim = contract.get_claim(claim_id).unwrap();
-        assert_eq!(claim.status, ClaimStatus::Paid);
-        assert!(claim.payout_amount > 0);
+        contract
+            .process_claim(claim_id, true, "ipfs://report".into(), String::new())
+            .unwrap();
+
+        let allocation: Vec<(u64, u128)> = contract
+            .get_reinsurance_allocation(claim_id)
+            .into_iter()
+            .map(|layer| (layer.agreement_id, layer.recovery))
+            .collect();
+        assert_eq!(
+            allocation,
+            vec![(first_layer, 2_000_000_000u128), (1, 1_000_000_000u128)]
+        );
+        assert_eq!(
+            contract
+                .get_reinsurance_agreement(first_layer)
+                .unwrap()
+                .limit_utilized,
+            2_000_000_000u128
+        );
+        assert_eq!(
+            contract
+                .get_reinsurance_agreement(theft_only)
+                .unwrap()
+                .total_recoveries,
+            0
+        );
     }
 
     #[ink::test]
-    fn test_process_claim_reject_works() {
+    fn test_treaty_renewal_window_and_lapsed_layers() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
+        let year = 86_400 * 365;
+        let renewing = contract
+            .register_reinsurance(
+                accounts.charlie,
+                1_000_000u128,
+                0,
+                500,
+                vec![CoverageType::Fire],
+                year,
+            )
+            .unwrap();
+        let lapsing = contract
+            .register_reinsurance(
+                accounts.django,
+                1_000_000u128,
+                0,
+                500,
+                vec![CoverageType::Fire],
+                year,
+            )
+            .unwrap();
+        let end = 3_000_000 + year;
+
+        // Too early to negotiate
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        let propose = |contract: &mut PropertyInsurance, limit: u128| {
+            contract.propose_treaty_renewal(renewing, limit, 0, 600, vec![CoverageType::Fire], year)
+        };
+        assert_eq!(
+            propose(&mut contract, 2_000_000),
+            Err(InsuranceError::RenewalWindowClosed)
+        );
+
+        test::set_block_timestamp::<DefaultEnvironment>(end - TREATY_RENEWAL_WINDOW);
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            propose(&mut contract, 2_000_000),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        propose(&mut contract, 5_000_000).unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.reject_treaty_renewal(renewing).unwrap();
+        assert_eq!(
+            contract.accept_treaty_renewal(renewing),
+            Err(InsuranceError::RenewalNotFound)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        propose(&mut contract, 2_000_000).unwrap();
+        assert_eq!(
+            contract
+                .get_treaty_renewal(renewing)
+                .unwrap()
+                .coverage_limit,
+            2_000_000u128
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let renewed = contract.accept_treaty_renewal(renewing).unwrap();
+        assert_eq!(contract.get_treaty_successor(renewing), Some(renewed));
+        assert_eq!(contract.get_treaty_renewal(renewing), None);
+        let agreement = contract.get_reinsurance_agreement(renewed).unwrap();
+        assert_eq!(
+            (
+                agreement.start_time,
+                agreement.end_time,
+                agreement.coverage_limit
+            ),
+            (end + 1, end + year, 2_000_000u128)
+        );
+        // Renewed terms only attach once the current period ends
+        test::set_block_timestamp::<DefaultEnvironment>(end);
+        let report = contract.get_exposure_report(pool_id).unwrap();
+        assert!(report.uncovered_layers.is_empty());
+        assert_eq!(report.reinsurance_capacity, 2_000_000u128);
+
+        test::set_block_timestamp::<DefaultEnvironment>(end + 1);
+        let report = contract.get_exposure_report(pool_id).unwrap();
+        assert_eq!(report.uncovered_layers, vec![lapsing]);
+        assert_eq!(report.reinsurance_capacity, 2_000_000u128);
+        test::set_caller::<DefaultEnvironment>(accounts.django);
+        assert_eq!(
+            contract.propose_treaty_renewal(
+                lapsing,
+                1_000_000,
+                0,
+                500,
+                vec![CoverageType::Fire],
+                year
+            ),
+            Err(InsuranceError::RenewalWindowClosed)
+        );
+    }
+
+    #[ink::test]
+    fn test_simulate_claim_matches_layered_payout_without_writing() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let layer = contract
+            .register_reinsurance(
+                accounts.charlie,
+                2_000_000_000u128,
+                1_000_000_000u128,
+                500,
+                vec![CoverageType::Fire],
+                86_400 * 365,
+            )
+            .unwrap();
+        let pool_id = contract
+            .create_risk_pool("XoL Pool".into(), CoverageType::Fire, 8000, 0)
+            .unwrap();
         test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
         contract.provide_pool_liquidity(pool_id).unwrap();
         add_risk_assessment(&mut contract, 1);
@@ -2014,38 +12866,44 @@ mod insurance_tests {
             .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                500_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+
+        let sim = contract
+            .simulate_claim(policy_id, 4_000_000_000u128 + calc.deductible)
             .unwrap();
-        let claim_id = contract
-            .submit_claim(
-                policy_id,
-                5_000_000_000u128,
-                "Fraudulent claim".into(),
-                "ipfs://fake-evidence".into(),
-            )
+        assert!(!sim.binding);
+        assert!(sim.eligible);
+        assert_eq!(sim.expected_payout, 4_000_000_000u128);
+        assert_eq!(sim.reinsurance_recovery, 2_000_000_000u128);
+        assert_eq!(sim.reinsurance_layers.len(), 1);
+        assert_eq!(sim.liquidity_shortfall, 0);
+        assert_eq!(
+            contract
+                .get_reinsurance_agreement(layer)
+                .unwrap()
+                .limit_utilized,
+            0
+        );
+
+        // Over the remaining coverage: still estimated, but not fileable
+        let sim = contract
+            .simulate_claim(policy_id, 600_000_000_000u128)
             .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.alice);
-        let result = contract.process_claim(
-            claim_id,
-            false,
-            "ipfs://oracle-report".into(),
-            "Evidence does not support claim".into(),
+        assert!(!sim.eligible);
+        assert_eq!(sim.expected_payout, 500_000_000_000u128 - calc.deductible);
+        assert_eq!(
+            contract.simulate_claim(999, 1),
+            Err(InsuranceError::PolicyNotFound)
         );
-        assert!(result.is_ok());
-        let claim = contract.get_claim(claim_id).unwrap();
-        assert_eq!(claim.status, ClaimStatus::Rejected);
     }
 
+    // =========================================================================
+    // TOKEN / SECONDARY MARKET TESTS
+    // =========================================================================
+
     #[ink::test]
-    fn test_process_claim_unauthorized_fails() {
+    fn test_token_minted_on_policy_creation() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
@@ -2057,26 +12915,16 @@ mod insurance_tests {
             .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                500_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
-            .unwrap();
-        let claim_id = contract
-            .submit_claim(policy_id, 1_000_000u128, "Damage".into(), "ipfs://e".into())
-            .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.charlie);
-        let result = contract.process_claim(claim_id, true, "ipfs://r".into(), String::new());
-        assert_eq!(result, Err(InsuranceError::Unauthorized));
+        let terms = fire_terms(&mut contract, pool_id);
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        let token = contract.get_token(1).unwrap();
+        assert_eq!(token.policy_id, policy_id);
+        assert_eq!(token.owner, accounts.bob);
+        assert!(token.is_tradeable);
     }
 
     #[ink::test]
-    fn test_authorized_assessor_can_process_claim() {
+    fn test_list_and_purchase_token() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
@@ -2088,177 +12936,545 @@ mod insurance_tests {
             .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                500_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
-            )
-            .unwrap();
-        let claim_id = contract
-            .submit_claim(policy_id, 1_000_000u128, "Damage".into(), "ipfs://e".into())
-            .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.alice);
-        contract.authorize_assessor(accounts.charlie).unwrap();
+        let terms = fire_terms(&mut contract, pool_id);
+        contract.create_policy(terms, None).unwrap();
+        // Bob lists token 1
+        assert!(contract.list_token_for_sale(1, 100_000_000u128).is_ok());
+        assert!(contract.get_token_listings().contains(&1));
+        // Charlie buys token
         test::set_caller::<DefaultEnvironment>(accounts.charlie);
-        let result = contract.process_claim(
-            claim_id,
-            false,
-            "ipfs://r".into(),
-            "Insufficient evidence".into(),
-        );
-        assert!(result.is_ok());
+        test::set_value_transferred::<DefaultEnvironment>(100_000_000u128);
+        assert!(contract.purchase_token(1).is_ok());
+        let token = contract.get_token(1).unwrap();
+        assert_eq!(token.owner, accounts.charlie);
+        assert!(token.listed_price.is_none());
+        let policy = contract.get_policy(1).unwrap();
+        assert_eq!(policy.policyholder, accounts.charlie);
     }
 
     // =========================================================================
-    // REINSURANCE TESTS
+    // ACTUARIAL MODEL TESTS
     // =========================================================================
 
     #[ink::test]
-    fn test_register_reinsurance_works() {
+    fn test_update_actuarial_model_works() {
+        let mut contract = setup();
+        let result =
+            contract.update_actuarial_model(CoverageType::Fire, 50, 50_000_000u128, 4500, 95, 1000);
+        assert!(result.is_ok());
+        let model = contract.get_actuarial_model(result.unwrap()).unwrap();
+        assert_eq!(model.loss_frequency, 50);
+        assert_eq!(model.confidence_level, 95);
+    }
+
+    #[ink::test]
+    fn test_recalibration_flags_large_shifts_before_pricing_adopts() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
-        let result = contract.register_reinsurance(
-            accounts.bob,
-            10_000_000_000_000u128,
-            500_000_000_000u128,
-            2000,
-            [CoverageType::Fire, CoverageType::Flood].to_vec(),
-            86_400 * 365,
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let premium = contract.get_policy(policy_id).unwrap().premium_amount;
+        assert_eq!(contract.recalibrate_models(), Ok(Vec::new()));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                100_000_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .process_claim(claim_id, true, "ipfs://r".into(), String::new())
+            .unwrap();
+        let experience = contract.get_loss_experience(CoverageType::Fire);
+        assert_eq!(
+            (experience.policies_written, experience.claims_approved),
+            (1, 1)
         );
-        assert!(result.is_ok());
-        let agreement_id = result.unwrap();
-        let agreement = contract.get_reinsurance_agreement(agreement_id).unwrap();
-        assert_eq!(agreement.reinsurer, accounts.bob);
-        assert!(agreement.is_active);
+
+        // A first model has nothing to compare against, so it waits for review
+        let produced = contract.recalibrate_models().unwrap();
+        assert_eq!(produced.len(), 1);
+        let model = contract.get_actuarial_model(produced[0]).unwrap();
+        assert_eq!(model.loss_frequency, 10_000);
+        let payout = contract.get_claim(claim_id).unwrap().payout_amount;
+        assert_eq!(model.average_loss_severity, payout);
+        assert_eq!(model.expected_loss_ratio as u128, payout * 10_000 / premium);
+        assert_eq!(model.confidence_level, 1);
+        assert!(contract.is_model_pending_review(produced[0]));
+        assert_eq!(
+            contract.get_adopted_actuarial_model(CoverageType::Fire),
+            None
+        );
+        assert_eq!(contract.recalibrate_models(), Ok(Vec::new()));
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.review_actuarial_model(produced[0], true),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.review_actuarial_model(produced[0], true).unwrap();
+        assert_eq!(
+            contract.review_actuarial_model(produced[0], true),
+            Err(InsuranceError::ModelNotPendingReview)
+        );
+        assert_eq!(
+            contract.get_adopted_actuarial_model(CoverageType::Fire),
+            Some(model)
+        );
+        // Losses far above the 60% target lift the base rate to the 2x cap
+        let calc = contract
+            .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
+            .unwrap();
+        assert_eq!(calc.base_rate, 300);
     }
 
     #[ink::test]
-    fn test_register_reinsurance_unauthorized_fails() {
+    fn test_stress_test_projects_shocked_losses_and_recoveries() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        let scenario = StressScenario {
+            name: "1-in-200 wildfire".into(),
+            frequency_multiplier: 5_000,
+            severity_multiplier: 20_000,
+        };
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            contract.run_stress_test(pool_id, scenario.clone()),
+            Err(InsuranceError::NoAdoptedModel)
+        );
+
+        // Adopt a model from one approved claim: every policy claims, at its payout
         test::set_caller::<DefaultEnvironment>(accounts.bob);
-        let result = contract.register_reinsurance(
-            accounts.bob,
-            1_000_000u128,
-            100_000u128,
-            2000,
-            [CoverageType::Fire].to_vec(),
-            86_400,
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                100_000_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .process_claim(claim_id, true, "ipfs://r".into(), String::new())
+            .unwrap();
+        let model_id = contract.recalibrate_models().unwrap()[0];
+        contract.review_actuarial_model(model_id, true).unwrap();
+        let agreement_id = contract
+            .register_reinsurance(
+                accounts.charlie,
+                50_000_000_000,
+                10_000_000_000,
+                1_000,
+                vec![CoverageType::Fire],
+                86_400 * 365,
+            )
+            .unwrap();
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            contract.run_stress_test(pool_id, scenario.clone()),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(contract.run_stress_test(pool_id, scenario.clone()), Ok(0));
+
+        let payout = contract.get_claim(claim_id).unwrap().payout_amount;
+        let policy = contract.get_policy(policy_id).unwrap();
+        let remaining = policy.coverage_amount - policy.total_claimed;
+        let capital = contract.get_pool(pool_id).unwrap().available_capital;
+        let projected = (payout * 2).min(remaining) / 2;
+        let recoveries = (projected - 10_000_000_000).min(50_000_000_000);
+
+        let result = contract.get_stress_test(pool_id, 0).unwrap();
+        assert_eq!(result.scenario, scenario);
+        assert_eq!(result.model_id, model_id);
+        assert_eq!(result.run_at, 3_000_000);
+        assert_eq!((result.policies_in_force, result.exposure), (1, remaining));
+        assert_eq!(result.shocked_frequency, 5_000);
+        assert_eq!(result.shocked_severity, payout * 2);
+        assert_eq!(result.projected_losses, projected);
+        assert_eq!(result.reinsurance_recoveries, recoveries);
+        assert_eq!(result.net_losses, projected - recoveries);
+        assert_eq!(
+            result.post_shock_capital,
+            capital - (projected - recoveries)
+        );
+        assert!(result.solvent);
+        assert_eq!(contract.get_stress_test_count(pool_id), 1);
+        // Projected recoveries do not draw down the treaty
+        assert_eq!(
+            contract
+                .get_reinsurance_agreement(agreement_id)
+                .unwrap()
+                .limit_utilized,
+            0
+        );
+    }
+
+    #[ink::test]
+    fn test_nav_per_lp_unit_tracks_premiums_reserves_and_payouts() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (pool_id, policy_id) = create_funded_policy(&mut contract);
+        let capital = contract.get_pool(pool_id).unwrap().available_capital;
+
+        let nav = contract.get_nav(pool_id).unwrap();
+        assert_eq!(nav.lp_units, 10_000_000_000_000u128);
+        assert_eq!((nav.reserves, nav.receivables), (0, 0));
+        assert_eq!(nav.nav, capital);
+        // Premiums lift NAV above par
+        assert!(nav.nav_per_unit > NAV_PRECISION);
+        // Deposit and premium landed in the same block: one point
+        assert_eq!(contract.get_nav_history(pool_id).len(), 1);
+
+        let start = nav.computed_at;
+        test::set_block_timestamp::<DefaultEnvironment>(start + 1_000);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                100_000_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        let reserved = contract.get_nav(pool_id).unwrap();
+        assert_eq!(reserved.reserves, 100_000_000_000u128);
+        assert_eq!(reserved.nav, capital - 100_000_000_000u128);
+
+        test::set_block_timestamp::<DefaultEnvironment>(start + 2_000);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .process_claim(claim_id, true, "ipfs://r".into(), String::new())
+            .unwrap();
+        let paid = contract.get_nav(pool_id).unwrap();
+        assert_eq!(paid.reserves, 0);
+        assert_eq!(paid.nav, paid.capital);
+        // The deductible part of the reserve is released back to LPs
+        assert!(paid.nav_per_unit > reserved.nav_per_unit);
+        assert!(paid.nav_per_unit < nav.nav_per_unit);
+
+        let history = contract.get_nav_history(pool_id);
+        let timestamps: Vec<u64> = history.iter().map(|point| point.timestamp).collect();
+        assert_eq!(timestamps, vec![start, start + 1_000, start + 2_000]);
+        assert_eq!(history[0].nav_per_unit, nav.nav_per_unit);
+        assert_eq!(history[2].nav, paid.nav);
+        assert_eq!(contract.get_nav(99), Err(InsuranceError::PoolNotFound));
+
+        // A later deposit mints units at the current NAV, not at par
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        test::set_value_transferred::<DefaultEnvironment>(1_000_000_000_000u128);
+        contract.provide_pool_liquidity(pool_id).unwrap();
+        let minted = contract
+            .get_liquidity_provider(pool_id, accounts.charlie)
+            .unwrap()
+            .lp_units;
+        assert_eq!(
+            minted,
+            1_000_000_000_000u128 * NAV_PRECISION / paid.nav_per_unit
         );
-        assert_eq!(result, Err(InsuranceError::Unauthorized));
+        let deposited = contract.get_nav(pool_id).unwrap();
+        assert_eq!(deposited.lp_units, paid.lp_units + minted);
+        assert!(deposited.nav_per_unit.abs_diff(paid.nav_per_unit) <= 1);
     }
 
-    // =========================================================================
-    // TOKEN / SECONDARY MARKET TESTS
-    // =========================================================================
-
     #[ink::test]
-    fn test_token_minted_on_policy_creation() {
+    fn test_copay_discounts_premium_and_splits_payout() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
         test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
         contract.provide_pool_liquidity(pool_id).unwrap();
         add_risk_assessment(&mut contract, 1);
-        let calc = contract
+
+        let full = contract
             .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
             .unwrap();
-        test::set_caller::<DefaultEnvironment>(accounts.bob);
-        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        let policy_id = contract
-            .create_policy(
+        let calc = contract
+            .calculate_premium_with_copay(1, 500_000_000_000u128, CoverageType::Fire, 2_000)
+            .unwrap();
+        assert_eq!(full.copay_bps, 0);
+        assert_eq!(calc.copay_bps, 2_000);
+        assert_eq!(calc.annual_premium, full.annual_premium * 8_000 / 10_000);
+        assert_eq!(calc.deductible, full.deductible);
+        assert_eq!(
+            contract.calculate_premium_with_copay(
                 1,
-                CoverageType::Fire,
                 500_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
+                CoverageType::Fire,
+                MAX_COPAY_BPS + 1
+            ),
+            Err(InsuranceError::InvalidParameters)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium);
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 500_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: Some(2_000),
+        };
+        let policy_id = contract.create_policy(terms, None).unwrap();
+        assert_eq!(contract.get_policy(policy_id).unwrap().copay_bps, 2_000);
+
+        // 20% of the loss above the deductible stays with the policyholder
+        let loss = 10_000_000_000u128 + calc.deductible;
+        let sim = contract.simulate_claim(policy_id, loss).unwrap();
+        assert_eq!(sim.copay_amount, 2_000_000_000u128);
+        assert_eq!(sim.expected_payout, 8_000_000_000u128);
+
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                loss,
+                "Damage".into(),
+                "ipfs://e".into(),
             )
             .unwrap();
-        let token = contract.get_token(1).unwrap();
-        assert_eq!(token.policy_id, policy_id);
-        assert_eq!(token.owner, accounts.bob);
-        assert!(token.is_tradeable);
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        let emitted = test::recorded_events().count();
+        contract
+            .process_claim(claim_id, true, "ipfs://r".into(), String::new())
+            .unwrap();
+        assert_eq!(
+            contract.get_claim(claim_id).unwrap().payout_amount,
+            sim.expected_payout
+        );
+        assert!(test::recorded_events().count() > emitted);
     }
 
+    // =========================================================================
+    // UNDERWRITING TESTS
+    // =========================================================================
+
     #[ink::test]
-    fn test_list_and_purchase_token() {
+    fn test_set_underwriting_criteria_works() {
+        let mut contract = setup();
+        let pool_id = create_pool(&mut contract);
+        let result = contract.set_underwriting_criteria(
+            pool_id,
+            50,
+            10_000_000u128,
+            1_000_000_000_000_000u128,
+            true,
+            3,
+            40,
+        );
+        assert!(result.is_ok());
+        let criteria = contract.get_underwriting_criteria(pool_id).unwrap();
+        assert_eq!(criteria.max_property_age_years, 50);
+        assert_eq!(criteria.max_previous_claims, 3);
+        assert_eq!(criteria.min_risk_score, 40);
+    }
+
+    #[ink::test]
+    fn test_underwriting_worksheet_records_overrides_and_decision() {
         let mut contract = setup();
         let accounts = test::default_accounts::<DefaultEnvironment>();
         let pool_id = create_pool(&mut contract);
         test::set_value_transferred::<DefaultEnvironment>(10_000_000_000_000u128);
         contract.provide_pool_liquidity(pool_id).unwrap();
         add_risk_assessment(&mut contract, 1);
+        contract
+            .set_underwriting_criteria(pool_id, 50, 10_000_000, 1_000_000_000_000, true, 3, 40)
+            .unwrap();
         let calc = contract
             .calculate_premium(1, 500_000_000_000u128, CoverageType::Fire)
             .unwrap();
+
+        // No verified safety features on file
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 2);
-        contract
-            .create_policy(
+        let apply = |contract: &mut PropertyInsurance| {
+            let terms = fire_terms(contract, pool_id);
+            contract.create_policy(terms, None)
+        };
+        assert_eq!(
+            apply(&mut contract),
+            Err(InsuranceError::UnderwritingDeclined)
+        );
+        assert_eq!(
+            contract.approve_underwriting_override(
+                pool_id,
                 1,
-                CoverageType::Fire,
-                500_000_000_000u128,
+                UnderwritingCriterion::SafetyFeatures,
+                "Sprinklers inspected on site".into(),
+            ),
+            Err(InsuranceError::Unauthorized)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .approve_underwriting_override(
                 pool_id,
-                86_400 * 365,
-                "ipfs://test".into(),
+                1,
+                UnderwritingCriterion::SafetyFeatures,
+                "Sprinklers inspected on site".into(),
             )
             .unwrap();
-        // Bob lists token 1
-        assert!(contract.list_token_for_sale(1, 100_000_000u128).is_ok());
-        assert!(contract.get_token_listings().contains(&1));
-        // Charlie buys token
-        test::set_caller::<DefaultEnvironment>(accounts.charlie);
-        test::set_value_transferred::<DefaultEnvironment>(100_000_000u128);
-        assert!(contract.purchase_token(1).is_ok());
-        let token = contract.get_token(1).unwrap();
-        assert_eq!(token.owner, accounts.charlie);
-        assert!(token.listed_price.is_none());
-        let policy = contract.get_policy(1).unwrap();
-        assert_eq!(policy.policyholder, accounts.charlie);
+
+        // Age, value and location are checked against the property's own profile
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            apply(&mut contract),
+            Err(InsuranceError::UnderwritingDeclined)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .set_property_profile(
+                1,
+                PropertyProfile {
+                    built_at: 0,
+                    appraised_value: 600_000_000_000,
+                    location: "Lagos".into(),
+                },
+            )
+            .unwrap();
+        contract
+            .set_excluded_locations(pool_id, vec!["Lagos".into()])
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(
+            apply(&mut contract),
+            Err(InsuranceError::UnderwritingDeclined)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .set_excluded_locations(pool_id, vec!["Abuja".into()])
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let policy_id = apply(&mut contract).unwrap();
+
+        let worksheet = contract.get_underwriting_worksheet(policy_id).unwrap();
+        assert_eq!(
+            worksheet.decision,
+            UnderwritingDecision::ApprovedWithOverrides
+        );
+        assert_eq!(worksheet.criteria.min_risk_score, 40);
+        assert_eq!(
+            worksheet.assessment,
+            contract.get_risk_assessment(1).unwrap()
+        );
+        assert_eq!(worksheet.results.len(), 7);
+        let value = worksheet
+            .results
+            .iter()
+            .find(|r| r.criterion == UnderwritingCriterion::MaxPropertyValue)
+            .unwrap();
+        assert_eq!(value.observed, 600_000_000_000);
+        let safety = worksheet
+            .results
+            .iter()
+            .find(|r| r.criterion == UnderwritingCriterion::SafetyFeatures)
+            .unwrap();
+        assert!(!safety.passed && safety.overridden);
+        assert!(worksheet
+            .results
+            .iter()
+            .filter(|r| r.criterion != UnderwritingCriterion::SafetyFeatures)
+            .all(|r| r.passed && !r.overridden));
+        assert_eq!(worksheet.overrides.len(), 1);
+        assert_eq!(worksheet.overrides[0].approver, accounts.alice);
+        // Overrides apply to one application only
+        assert!(contract.get_underwriting_overrides(pool_id, 1).is_empty());
     }
 
     // =========================================================================
-    // ACTUARIAL MODEL TESTS
+    // EMERGENCY PAUSE TESTS
     // =========================================================================
 
     #[ink::test]
-    fn test_update_actuarial_model_works() {
+    fn test_payout_pause_needs_guardian_quorum() {
         let mut contract = setup();
-        let result =
-            contract.update_actuarial_model(CoverageType::Fire, 50, 50_000_000u128, 4500, 95, 1000);
-        assert!(result.is_ok());
-        let model = contract.get_actuarial_model(result.unwrap()).unwrap();
-        assert_eq!(model.loss_frequency, 50);
-        assert_eq!(model.confidence_level, 95);
-    }
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract
+            .set_guardians(vec![accounts.bob, accounts.charlie], 2)
+            .unwrap();
+        assert_eq!(
+            contract.pause_domain(PauseDomain::Payouts),
+            Err(InsuranceError::Unauthorized)
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.pause_domain(PauseDomain::Payouts), Ok(false));
+        assert!(!contract.is_domain_paused(PauseDomain::Payouts));
 
-    // =========================================================================
-    // UNDERWRITING TESTS
-    // =========================================================================
+        // A confirmation older than the TTL no longer counts
+        test::set_block_timestamp::<DefaultEnvironment>(3_000_000 + PAUSE_CONFIRMATION_TTL + 1);
+        assert!(contract.get_payout_pause_confirmations().is_empty());
+        test::set_caller::<DefaultEnvironment>(accounts.charlie);
+        assert_eq!(contract.pause_domain(PauseDomain::Payouts), Ok(false));
+        assert_eq!(
+            contract.get_payout_pause_confirmations(),
+            vec![accounts.charlie]
+        );
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        assert_eq!(contract.pause_domain(PauseDomain::Payouts), Ok(true));
+        assert!(contract.is_domain_paused(PauseDomain::Payouts));
+
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract.pause_domain(PauseDomain::NewPolicies).unwrap();
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 1,
+            pool_id: 1,
+            duration_seconds: 86_400,
+            metadata_url: "ipfs://test".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        assert_eq!(
+            contract.create_policy(terms, None),
+            Err(InsuranceError::DomainPaused)
+        );
+        contract.unpause_domain(PauseDomain::Payouts).unwrap();
+        assert!(!contract.is_domain_paused(PauseDomain::Payouts));
+    }
 
     #[ink::test]
-    fn test_set_underwriting_criteria_works() {
+    fn test_payout_velocity_trips_circuit_breaker() {
         let mut contract = setup();
-        let pool_id = create_pool(&mut contract);
-        let result = contract.set_underwriting_criteria(
-            pool_id,
-            50,
-            10_000_000u128,
-            1_000_000_000_000_000u128,
-            true,
-            3,
-            40,
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.set_circuit_breaker(1, 86_400).unwrap();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Fire damage".into(),
+                "ipfs://evidence".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .process_claim(claim_id, true, "ipfs://oracle-report".into(), String::new())
+            .unwrap();
+
+        // The breaching payout settles, later ones are held until the admin unpauses
+        assert_eq!(
+            contract.get_claim(claim_id).unwrap().status,
+            ClaimStatus::Paid
         );
-        assert!(result.is_ok());
-        let criteria = contract.get_underwriting_criteria(pool_id).unwrap();
-        assert_eq!(criteria.max_property_age_years, 50);
-        assert_eq!(criteria.max_previous_claims, 3);
-        assert_eq!(criteria.min_risk_score, 40);
+        assert!(contract.is_domain_paused(PauseDomain::Payouts));
     }
 
     // =========================================================================
@@ -2313,6 +13529,101 @@ mod insurance_tests {
         assert_eq!(provider.pool_id, pool_id);
     }
 
+    // =========================================================================
+    // REGULATORY REPORTING TESTS
+    // =========================================================================
+
+    #[ink::test]
+    fn test_exposure_report_and_snapshot() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        contract.set_property_region(1, "EU-West".into()).unwrap();
+        contract
+            .register_reinsurance(
+                accounts.django,
+                2_000_000_000_000u128,
+                300_000_000_000u128,
+                500,
+                vec![CoverageType::Fire],
+                86_400 * 365,
+            )
+            .unwrap();
+        let (pool_id, _) = create_funded_policy(&mut contract);
+
+        let report = contract.get_exposure_report(pool_id).unwrap();
+        assert_eq!(report.policies_in_force, 1);
+        assert_eq!(report.total_insured_value, 500_000_000_000u128);
+        assert_eq!(
+            report.insured_by_coverage,
+            vec![(CoverageType::Fire, 500_000_000_000u128)]
+        );
+        assert_eq!(
+            report.insured_by_region,
+            vec![("EU-West".into(), 500_000_000_000u128)]
+        );
+        assert_eq!(report.reinsurance_attachment, Some(300_000_000_000u128));
+        assert_eq!(report.reinsurance_capacity, 2_000_000_000_000u128);
+        assert!(report.solvency_ratio > 10_000);
+
+        let hash = contract.snapshot_exposure_report(pool_id).unwrap();
+        assert_eq!(
+            contract.snapshot_exposure_report(pool_id),
+            Err(InsuranceError::SnapshotTooSoon)
+        );
+        let snapshot = contract.get_exposure_snapshot(pool_id, 0).unwrap();
+        assert_eq!(snapshot.report_hash, hash);
+        assert_eq!(snapshot.policies_in_force, 1);
+        assert_eq!(contract.get_exposure_snapshot_count(pool_id), 1);
+    }
+
+    #[ink::test]
+    fn test_claims_statistics_withhold_small_cells() {
+        let mut contract = setup();
+        let accounts = test::default_accounts::<DefaultEnvironment>();
+        let (_, policy_id) = create_funded_policy(&mut contract);
+        let period = 3_000_000 / 2_592_000;
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        assert_eq!(
+            contract.set_statistics_params(0, 1),
+            Err(InsuranceError::InvalidParameters)
+        );
+
+        test::set_caller::<DefaultEnvironment>(accounts.bob);
+        let incident_id = file_incident(&mut contract, policy_id);
+        let claim_id = contract
+            .submit_claim(
+                policy_id,
+                incident_id,
+                10_000_000_000u128,
+                "Damage".into(),
+                "ipfs://e".into(),
+            )
+            .unwrap();
+        test::set_caller::<DefaultEnvironment>(accounts.alice);
+        contract
+            .process_claim(claim_id, false, "ipfs://r".into(), "No".into())
+            .unwrap();
+
+        // One settled claim is below the default minimum cell of five
+        assert_eq!(
+            contract.get_claims_statistics(CoverageType::Fire, String::new(), period),
+            None
+        );
+        contract.set_statistics_params(2_592_000, 1).unwrap();
+        let stats = contract
+            .get_claims_statistics(CoverageType::Fire, String::new(), period)
+            .expect("published");
+        assert_eq!(stats.policies_written, 1);
+        assert_eq!(stats.claims_settled, 1);
+        assert_eq!(stats.claims_approved, 0);
+        assert_eq!(stats.frequency_bps, 10_000);
+        assert_eq!(stats.average_severity, 0);
+        assert_eq!(
+            contract.get_claims_statistics(CoverageType::Flood, String::new(), period),
+            None
+        );
+    }
+
     // =========================================================================
     // QUERY TESTS
     // =========================================================================
@@ -2330,26 +13641,28 @@ mod insurance_tests {
             .unwrap();
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(calc.annual_premium * 4);
-        contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                100_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://p1".into(),
-            )
-            .unwrap();
-        contract
-            .create_policy(
-                1,
-                CoverageType::Theft,
-                100_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://p2".into(),
-            )
-            .unwrap();
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 100_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://p1".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        contract.create_policy(terms, None).unwrap();
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Theft,
+            coverage_amount: 100_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://p2".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        contract.create_policy(terms, None).unwrap();
         let property_policies = contract.get_property_policies(1);
         assert_eq!(property_policies.len(), 2);
     }
@@ -2372,26 +13685,28 @@ mod insurance_tests {
         let total = (calc1.annual_premium + calc2.annual_premium) * 2;
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(total);
-        contract
-            .create_policy(
-                1,
-                CoverageType::Fire,
-                100_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://p1".into(),
-            )
-            .unwrap();
-        contract
-            .create_policy(
-                2,
-                CoverageType::Flood,
-                100_000_000_000u128,
-                pool_id,
-                86_400 * 365,
-                "ipfs://p2".into(),
-            )
-            .unwrap();
+        let terms = PolicyTerms {
+            property_id: 1,
+            coverage_type: CoverageType::Fire,
+            coverage_amount: 100_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://p1".into(),
+            template_id: cite_template(&mut contract, 1),
+            copay_bps: None,
+        };
+        contract.create_policy(terms, None).unwrap();
+        let terms = PolicyTerms {
+            property_id: 2,
+            coverage_type: CoverageType::Flood,
+            coverage_amount: 100_000_000_000u128,
+            pool_id,
+            duration_seconds: 86_400 * 365,
+            metadata_url: "ipfs://p2".into(),
+            template_id: cite_template(&mut contract, 2),
+            copay_bps: None,
+        };
+        contract.create_policy(terms, None).unwrap();
         let holder_policies = contract.get_policyholder_policies(accounts.bob);
         assert_eq!(holder_policies.len(), 2);
     }
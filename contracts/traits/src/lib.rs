@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use ink::prelude::string::String;
+use ink::prelude::vec::Vec;
 use ink::primitives::AccountId;
 
 /// Error types for the Property Valuation Oracle
@@ -20,6 +21,115 @@ pub enum OracleError {
     RequestPending,
 }
 
+/// Errors returned by the PropertyToken contract. Shared here so cross-contract
+/// callers can match on failures. Discriminants are the SCALE encoding and are
+/// stable across upgrades: never reorder or reuse them, only append.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PropertyTokenError {
+    // Standard ERC errors
+    TokenNotFound = 0,
+    Unauthorized = 1,
+    // Property-specific errors
+    PropertyNotFound = 2,
+    InvalidMetadata = 3,
+    DocumentNotFound = 4,
+    ComplianceFailed = 5,
+    // Cross-chain bridge errors
+    BridgeNotSupported = 6,
+    InvalidChain = 7,
+    BridgeLocked = 8,
+    InsufficientSignatures = 9,
+    RequestExpired = 10,
+    InvalidRequest = 11,
+    BridgePaused = 12,
+    GasLimitExceeded = 13,
+    MetadataCorruption = 14,
+    InvalidBridgeOperator = 15,
+    DuplicateBridgeRequest = 16,
+    BridgeTimeout = 17,
+    AlreadySigned = 18,
+    InsufficientBalance = 19,
+    InvalidAmount = 20,
+    ProposalNotFound = 21,
+    ProposalClosed = 22,
+    AskNotFound = 23,
+    BridgeProofAlreadyConsumed = 24,
+    MeetingNotFound = 25,
+    MeetingNotActive = 26,
+    InvalidMeetingItem = 27,
+    ProposalInMeeting = 28,
+    HolderLimitReached = 29,
+    WaitlistEntryNotFound = 30,
+    WaitlistEntryActive = 31,
+    InvalidScalingFactor = 32,
+    SharesOutstanding = 33,
+    ArithmeticOverflow = 34,
+    ChecklistIncomplete = 35,
+    ScheduledTransferNotFound = 36,
+    ScheduledTransferNotReady = 37,
+    ScheduledTransferUnlocked = 38,
+    TransferScheduled = 39,
+    ComplianceChangeNotReady = 40,
+    PermitExpired = 41,
+    InvalidSignature = 42,
+    DividendsNotExpired = 43,
+    AppealWindowOpen = 44,
+    BridgeCooldownActive = 45,
+    ShareClassNotFound = 46,
+    ReceiptNotFound = 47,
+    TokenNotDissolved = 48,
+    OfferNotFound = 49,
+    OfferExpired = 50,
+    SelfReferral = 51,
+    RandomnessRoundNotFound = 52,
+    RandomnessNotRevealed = 53,
+    SeedMismatch = 54,
+    SessionClosed = 55,
+    AuctionNotFound = 56,
+    AuctionNotEnded = 57,
+    AuctionAlreadyCleared = 58,
+    AskExpired = 59,
+    BelowMinimumOrder = 60,
+    InvalidTickSize = 61,
+    SnapshotNotFound = 62,
+    InvalidMerkleProof = 63,
+    SnapshotAlreadyClaimed = 64,
+    SnapshotClaimExpired = 65,
+    RecoveryNotFound = 66,
+    RecoveryPending = 67,
+    RecoveryTimelockActive = 68,
+    SwapNotFound = 69,
+    SwapExpired = 70,
+    BasketNotFound = 71,
+    ValuationUnavailable = 72,
+    TravelRuleReceiptRequired = 73,
+    TravelRuleReceiptInvalid = 74,
+    BackstopNotFound = 75,
+    BackstopWindowClosed = 76,
+    BackstopWindowOpen = 77,
+    AccountStillActive = 78,
+    PayoutBelowThreshold = 79,
+    ClawbackNotFound = 80,
+    ClawbackNotApproved = 81,
+    ClawbackTimelockActive = 82,
+    SettlementPaymentMismatch = 83,
+    DeedNotFound = 84,
+    DeedExpired = 85,
+    TokenQuarantined = 86,
+    MetadataSchemaViolation = 87,
+    MetadataSchemaNotFound = 88,
+    RandomnessRoundUsed = 89,
+    SharesLockedUp = 90,
+    TransferCooldownActive = 91,
+}
+
+impl From<PropertyTokenError> for u8 {
+    fn from(error: PropertyTokenError) -> Self {
+        error as u8
+    }
+}
+
 /// Trait definitions for PropChain contracts
 pub trait PropertyRegistry {
     /// Error type for the contract
@@ -630,6 +740,73 @@ pub struct MultisigBridgeRequest {
     pub metadata: PropertyMetadata,
 }
 
+/// Current schema version of bridged metadata envelopes
+pub const METADATA_SCHEMA_VERSION: u16 = 1;
+
+/// Controls how much property metadata crosses the bridge
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum MetadataPreservation {
+    /// Every metadata field is carried to the destination chain
+    Full,
+    /// Only location, size and valuation are carried
+    Minimal,
+    /// Only the metadata commitment hash is carried
+    HashOnly,
+}
+
+/// Schema-versioned metadata payload attached to a bridge request
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BridgedMetadata {
+    pub schema_version: u16,
+    pub policy: MetadataPreservation,
+    /// Blake2x256 hash of the full SCALE-encoded source metadata
+    pub metadata_hash: ink::primitives::Hash,
+    /// Metadata fields retained under `policy`; stripped fields are empty
+    pub payload: PropertyMetadata,
+}
+
+/// Metadata reconstructed on the destination chain
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ReceivedMetadata {
+    pub token_id: TokenId,
+    pub schema_version: u16,
+    pub policy: MetadataPreservation,
+    pub metadata: PropertyMetadata,
+    /// True when the full metadata was verified against the committed hash
+    pub complete: bool,
+    pub received_at: u64,
+}
+
+/// Destination delivery tracking for an executed bridge request
+#[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct DeliveryRecord {
+    pub transaction_hash: ink::primitives::Hash,
+    pub executed_at: u64,
+    /// Last block at which the destination mint can still be confirmed
+    pub confirm_by: u64,
+    pub remote_token_id: Option<TokenId>,
+    /// Reference to the destination mint (e.g. its transaction or event hash)
+    pub proof: Option<ink::primitives::Hash>,
+    pub confirmed_by: Option<ink::primitives::AccountId>,
+    pub confirmed_at: Option<u64>,
+}
+
 /// Bridge configuration
 #[derive(Debug, Clone, PartialEq, scale::Encode, scale::Decode)]
 #[cfg_attr(
@@ -643,7 +820,7 @@ pub struct BridgeConfig {
     pub default_timeout_blocks: u64,
     pub gas_limit_per_bridge: u64,
     pub emergency_pause: bool,
-    pub metadata_preservation: bool,
+    pub metadata_preservation: MetadataPreservation,
 }
 
 /// Chain-specific bridge information
@@ -683,6 +860,102 @@ pub enum FeeOperation {
     OracleUpdate,
 }
 
+/// Trait for contracts that execute operation intents deferred by the fee manager
+#[ink::trait_definition]
+pub trait DeferredExecutor {
+    /// Execute a queued intent on behalf of `owner`; returns false if it could not run
+    #[ink(message)]
+    fn execute_deferred(
+        &mut self,
+        owner: AccountId,
+        operation: FeeOperation,
+        payload: Vec<u8>,
+    ) -> bool;
+}
+
+/// Trait for staking/selection contracts that publish the active validator set
+#[ink::trait_definition]
+pub trait ValidatorSetProvider {
+    /// Active validators with their stake weights
+    #[ink(message)]
+    fn active_validators(&self) -> Vec<(AccountId, u128)>;
+}
+
+/// Read-only token aggregates used by analytics reconciliation
+#[ink::trait_definition]
+pub trait TokenAggregates {
+    /// Number of property tokens in existence
+    #[ink(message)]
+    fn aggregate_total_supply(&self) -> u64;
+
+    /// Fractional shares outstanding for `token_id`
+    #[ink(message)]
+    fn aggregate_total_shares(&self, token_id: u64) -> u128;
+}
+
+/// Ownership lookup for tokenized properties
+#[ink::trait_definition]
+pub trait PropertyOwnership {
+    /// Current owner of the token representing `property_id`, if any
+    #[ink(message)]
+    fn property_owner(&self, property_id: u64) -> Option<AccountId>;
+}
+
+/// Compliance status of tokenized properties
+#[ink::trait_definition]
+pub trait PropertyCompliance {
+    /// True while the token representing `property_id` holds a current compliance verification
+    #[ink(message)]
+    fn is_property_compliant(&self, property_id: u64) -> bool;
+}
+
+/// Receiver of low-consensus valuation alerts (implemented by the analytics contract)
+#[ink::trait_definition]
+pub trait ValuationAlertSink {
+    /// Record that the valuation ensemble disagreed on `property_id`; returns the record id
+    #[ink(message)]
+    fn ingest_valuation_disagreement(
+        &mut self,
+        property_id: u64,
+        consensus_score: u32,
+        threshold: u32,
+        valuation: u128,
+        model_count: u32,
+    ) -> u64;
+}
+
+/// Insurance risk sub-scores derived by the valuation engine; each 0-100,
+/// higher meaning lower risk
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct DerivedRiskScores {
+    pub location_score: u32,
+    pub construction_score: u32,
+    pub age_score: u32,
+    pub claims_history_score: u32,
+    pub derived_at: u64,
+}
+
+/// Source of derived property risk scores (implemented by the AI valuation engine)
+#[ink::trait_definition]
+pub trait RiskScoreSource {
+    /// Risk sub-scores for `property_id`, or None without extracted features
+    #[ink(message)]
+    fn derive_risk_scores(&self, property_id: u64) -> Option<DerivedRiskScores>;
+}
+
+/// Read-only insurance pool aggregates used by analytics reconciliation
+#[ink::trait_definition]
+pub trait PoolAggregates {
+    /// Policies currently counted as active in `pool_id`
+    #[ink(message)]
+    fn aggregate_active_policies(&self, pool_id: u64) -> u64;
+
+    /// Capital available in `pool_id`
+    #[ink(message)]
+    fn aggregate_pool_capital(&self, pool_id: u64) -> u128;
+}
+
 /// Trait for dynamic fee provider (implemented by fee manager contract)
 #[ink::trait_definition]
 pub trait DynamicFeeProvider {
@@ -719,3 +992,122 @@ pub trait ComplianceChecker {
     #[ink(message)]
     fn is_compliant(&self, account: ink::primitives::AccountId) -> bool;
 }
+
+// =============================================================================
+// Dividend routing (insurance payouts to token shareholders)
+// =============================================================================
+
+/// Reason a cross-contract dividend deposit was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum DividendDepositError {
+    /// Token has no shares outstanding to distribute to
+    NoShareholders,
+    /// Deposit was zero or could not be accounted
+    InvalidDeposit,
+}
+
+/// Trait for contracts that distribute deposited value to token shareholders
+#[ink::trait_definition]
+pub trait DividendDistributor {
+    /// Distribute the transferred value to the holders of `token_id` pro-rata to shares
+    #[ink(message, payable)]
+    fn deposit_token_dividends(&mut self, token_id: u64) -> Result<(), DividendDepositError>;
+
+    /// Token issued for `property_id`, if any
+    #[ink(message)]
+    fn property_token_id(&self, property_id: u64) -> Option<u64>;
+}
+
+// =============================================================================
+// Bridge movement notifications (property token -> insurance)
+// =============================================================================
+
+/// Reason a bridge movement notification was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum BridgeHookError {
+    /// Caller is not the registered bridge hook source
+    Unauthorized,
+}
+
+/// Receiver of property bridge movements (implemented by the insurance contract)
+#[ink::trait_definition]
+pub trait PropertyBridgeListener {
+    /// `property_id` left this chain for `destination_chain` in bridge transaction
+    /// `transaction_hash`; returns the number of policies made portable
+    #[ink(message)]
+    fn on_property_bridged(
+        &mut self,
+        property_id: u64,
+        destination_chain: ChainId,
+        transaction_hash: ink::primitives::Hash,
+    ) -> Result<u32, BridgeHookError>;
+
+    /// `property_id` is back on this chain; returns the number of policies restored
+    #[ink(message)]
+    fn on_property_returned(&mut self, property_id: u64) -> Result<u32, BridgeHookError>;
+}
+
+// =============================================================================
+// PSP22 fungible tokens
+// =============================================================================
+
+/// Error returned by PSP22 token contracts
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Psp22Error {
+    Custom(String),
+    InsufficientBalance,
+    InsufficientAllowance,
+    ZeroRecipientAddress,
+    ZeroSenderAddress,
+    SafeTransferCheckFailed(String),
+}
+
+/// Transfer messages of the PSP22 standard, under the standard's selectors,
+/// for moving balances of stablecoins held by PropChain contracts
+#[ink::trait_definition]
+pub trait Psp22 {
+    #[ink(message, selector = 0xdb20f9f5)]
+    fn transfer(&mut self, to: AccountId, value: u128, data: Vec<u8>) -> Result<(), Psp22Error>;
+
+    #[ink(message, selector = 0x54b3c76e)]
+    fn transfer_from(
+        &mut self,
+        from: AccountId,
+        to: AccountId,
+        value: u128,
+        data: Vec<u8>,
+    ) -> Result<(), Psp22Error>;
+}
+
+// =============================================================================
+// Treasury yield strategies
+// =============================================================================
+
+/// Reason a yield strategy refused a deposit or withdrawal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum YieldStrategyError {
+    /// Requested more than the caller's position holds
+    InsufficientPosition,
+    /// Strategy is not accepting deposits or cannot pay out now
+    Unavailable,
+}
+
+/// Adapter for a yield-bearing contract that holds idle treasury funds
+#[ink::trait_definition]
+pub trait YieldStrategy {
+    /// Add the transferred value to the caller's position
+    #[ink(message, payable)]
+    fn deposit(&mut self) -> Result<(), YieldStrategyError>;
+
+    /// Withdraw up to `amount` of the caller's position; returns the amount paid out
+    #[ink(message)]
+    fn withdraw(&mut self, amount: u128) -> Result<u128, YieldStrategyError>;
+
+    /// Current value of `owner`'s position, principal plus accrued yield
+    #[ink(message)]
+    fn position_value(&self, owner: ink::primitives::AccountId) -> u128;
+}
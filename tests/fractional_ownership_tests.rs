@@ -63,7 +63,7 @@ mod fractional_tests {
 
         test::set_caller::<DefaultEnvironment>(accounts.bob);
         test::set_value_transferred::<DefaultEnvironment>(5_000);
-        assert!(contract.buy_shares(token_id, accounts.alice, 500).is_ok());
+        assert!(contract.buy_shares(token_id, accounts.alice, 500, None).is_ok());
         assert_eq!(contract.share_balance_of(accounts.bob, token_id), 500);
         assert_eq!(contract.get_last_trade_price(token_id), Some(10));
     }
@@ -499,7 +499,7 @@ mod integration_tests {
             default_timeout_blocks: 200,
             gas_limit_per_bridge: 1000000,
             emergency_pause: false,
-            metadata_preservation: true,
+            metadata_preservation: MetadataPreservation::Full,
         };
         
         token_contract.update_bridge_config(new_config.clone()).unwrap();